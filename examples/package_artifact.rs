@@ -1,7 +1,10 @@
+use fcupdater as _;
 use std::env;
 use std::fs::{self, File};
 use std::io::{self, BufWriter, Write as _};
 use std::path::{Path, PathBuf};
+#[cfg(feature = "rustls")]
+use {rustls as _, webpki_roots as _};
 const TAR_BLOCK_LEN: usize = 512;
 const TAR_BLOCK_LEN_U64: u64 = 512;
 const ZERO_BLOCK: [u8; TAR_BLOCK_LEN] = [0; TAR_BLOCK_LEN];
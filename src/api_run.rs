@@ -0,0 +1,527 @@
+use crate::{
+    change_log::{ChangeLogEntries, ChangeLogEntry},
+    diagnostic::{
+        AppError, Result, append_fmt, err, err_with_source, path_context_message, terminal_safe,
+    },
+    excel::{FuelValues, writer::Workbook as StdWorkbook, xlsx_container::XlsxContainer},
+    json::append_json_string,
+    kst::KstTime,
+    logging,
+    master_sheet::{MasterStation, MasterStations},
+    region::TargetRegion,
+    temp_entry::{FileIdentity, ValidatedFile, open_regular},
+    write_line,
+};
+use core::{
+    fmt::{self, Display},
+    result::Result as CoreResult,
+    str,
+    time::Duration,
+};
+use std::{
+    io::{self, Read as _, Write},
+    net::{TcpListener, TcpStream},
+    path::Path,
+    time::Instant,
+};
+#[cfg(test)]
+mod tests;
+const API_REQUEST_DEADLINE: Duration = Duration::from_secs(5);
+const API_DEFAULT_LIMIT: usize = 20;
+const API_MAX_LIMIT: usize = 1_000;
+const API_MAX_REQUEST_HEAD_BYTES: usize = 8 * 1024;
+#[derive(Clone, Copy)]
+enum ApiFuel {
+    Diesel,
+    Gasoline,
+    Premium,
+}
+pub(super) struct ApiRun<'out> {
+    pub listen: &'out str,
+    pub master_path: &'out Path,
+    pub out: &'out mut dyn Write,
+}
+struct ApiRequest<'req> {
+    head_only: bool,
+    path: &'req str,
+    query: &'req str,
+}
+struct ApiResponse {
+    body: String,
+    status: u16,
+}
+struct ApiSnapshot {
+    changes: ChangeLogEntries,
+    identity: FileIdentity,
+    loaded_at: String,
+    source_hash: Option<String>,
+    stations: MasterStations,
+}
+struct ChangeJson<'entry>(&'entry ChangeLogEntry);
+struct FuelPricesJson(FuelValues<Option<i32>>);
+struct StationJson<'station>(&'station MasterStation);
+impl ApiFuel {
+    const fn key(self) -> &'static str {
+        match self {
+            Self::Diesel => "diesel",
+            Self::Gasoline => "gasoline",
+            Self::Premium => "premium",
+        }
+    }
+    const fn price(self, fuels: FuelValues<Option<i32>>) -> Option<i32> {
+        match self {
+            Self::Diesel => fuels.diesel,
+            Self::Gasoline => fuels.gasoline,
+            Self::Premium => fuels.premium,
+        }
+    }
+}
+impl ApiResponse {
+    fn error(status: u16, message: &str) -> Self {
+        let mut body = String::from("{\"error\":");
+        append_json_string(&mut body, message);
+        body.push('}');
+        Self { body, status }
+    }
+    const fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "Internal Server Error",
+        }
+    }
+}
+impl ApiRun<'_> {
+    pub(super) fn run(&mut self) -> Result<()> {
+        let snapshot = ApiSnapshot::try_from((self.master_path, open_master(self.master_path)?))?;
+        let listener = TcpListener::bind(self.listen).map_err(|source| {
+            err_with_source(
+                format!("API 서버 주소 바인딩 실패: {}", self.listen),
+                source,
+            )
+        })?;
+        let local_addr = listener
+            .local_addr()
+            .map_err(|source| err_with_source("API 서버 주소 조회 실패", source))?;
+        write_line(
+            self.out,
+            format_args!(
+                "API 서버 시작: http://{local_addr} (워크북: {}, 업체 {}건)",
+                terminal_safe(&self.master_path.display()),
+                snapshot.stations.stations.len()
+            ),
+        )?;
+        self.serve(listener.incoming(), snapshot)
+    }
+    fn serve(
+        &mut self,
+        connections: impl Iterator<Item = io::Result<TcpStream>>,
+        mut snapshot: ApiSnapshot,
+    ) -> Result<()> {
+        for incoming in connections {
+            let mut stream = match incoming {
+                Ok(stream) => stream,
+                Err(accept_err) => {
+                    logging::warn(format_args!("API 연결 수락 실패: {accept_err}"));
+                    continue;
+                }
+            };
+            match open_master(self.master_path) {
+                Ok(file) if file.identity != snapshot.identity => {
+                    match ApiSnapshot::try_from((self.master_path, file)) {
+                        Ok(reloaded) => {
+                            snapshot = reloaded;
+                            write_line(
+                                self.out,
+                                format_args!(
+                                    "워크북 교체 감지: 다시 읽었습니다 (업체 {}건)",
+                                    snapshot.stations.stations.len()
+                                ),
+                            )?;
+                        }
+                        Err(reload_err) => write_line(
+                            self.out,
+                            format_args!(
+                                "워크북 다시 읽기 실패, 이전 데이터로 응답합니다: {reload_err}"
+                            ),
+                        )?,
+                    }
+                }
+                Ok(_) => {}
+                Err(open_err) => write_line(
+                    self.out,
+                    format_args!("워크북 확인 실패, 이전 데이터로 응답합니다: {open_err}"),
+                )?,
+            }
+            let serve_connection = |connection: &mut TcpStream| -> Result<()> {
+                let deadline = Instant::now().checked_add(API_REQUEST_DEADLINE);
+                let remaining = || {
+                    deadline
+                        .and_then(|at| at.checked_duration_since(Instant::now()))
+                        .filter(|left| !left.is_zero())
+                        .ok_or_else(|| err("요청 처리 제한 시간을 넘겼습니다."))
+                };
+                let mut head = Vec::new();
+                let mut chunk = [0_u8; 1024];
+                while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+                    connection.set_read_timeout(Some(remaining()?))?;
+                    let read_len = connection.read(&mut chunk)?;
+                    let Some(read) = chunk.get(..read_len).filter(|read| !read.is_empty()) else {
+                        break;
+                    };
+                    head.extend_from_slice(read);
+                    if head.len() > API_MAX_REQUEST_HEAD_BYTES {
+                        break;
+                    }
+                }
+                let parsed = str::from_utf8(&head)
+                    .map_err(|_utf8_err| ApiResponse::error(400, "요청 헤더가 UTF-8이 아닙니다."))
+                    .and_then(ApiRequest::try_from);
+                let (response, head_only) = match parsed {
+                    Ok(request) => (ApiResponse::from((&snapshot, &request)), request.head_only),
+                    Err(response) => (response, false),
+                };
+                connection.set_write_timeout(Some(remaining()?))?;
+                write!(connection, "{response}")?;
+                if !head_only {
+                    connection.write_all(response.body.as_bytes())?;
+                }
+                connection.flush()?;
+                Ok(())
+            };
+            if let Err(connection_err) = serve_connection(&mut stream) {
+                write_line(
+                    self.out,
+                    format_args!("API 요청 처리 실패: {connection_err}"),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+impl Display for ApiResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n",
+            self.status,
+            self.reason(),
+            self.body.len()
+        )?;
+        if self.status == 405 {
+            f.write_str("Allow: GET, HEAD\r\n")?;
+        }
+        f.write_str("\r\n")
+    }
+}
+impl Display for ChangeJson<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut json = String::new();
+        append_fmt(&mut json, format_args!("{{\"row\":{}", self.0.row));
+        for (key, value) in [
+            ("region", &self.0.region),
+            ("name", &self.0.name),
+            ("address", &self.0.address),
+            ("reason", &self.0.reason),
+        ] {
+            append_fmt(&mut json, format_args!(",\"{key}\":"));
+            append_json_string(&mut json, value);
+        }
+        append_fmt(
+            &mut json,
+            format_args!(
+                ",\"old\":{},\"new\":{}}}",
+                FuelPricesJson(self.0.old_fuels),
+                FuelPricesJson(self.0.new_fuels)
+            ),
+        );
+        f.write_str(&json)
+    }
+}
+impl Display for FuelPricesJson {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, fuel) in [ApiFuel::Gasoline, ApiFuel::Premium, ApiFuel::Diesel]
+            .into_iter()
+            .enumerate()
+        {
+            f.write_str(if index == 0 { "{" } else { "," })?;
+            write!(f, "\"{}\":", fuel.key())?;
+            match fuel.price(self.0) {
+                Some(price) => write!(f, "{price}")?,
+                None => f.write_str("null")?,
+            }
+        }
+        f.write_str("}")
+    }
+}
+impl Display for StationJson<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut json = String::new();
+        append_fmt(&mut json, format_args!("{{\"row\":{}", self.0.row));
+        for (key, value) in [
+            ("region", &self.0.region),
+            ("name", &self.0.name),
+            ("brand", &self.0.brand),
+            ("self_service", &self.0.self_service),
+            ("address", &self.0.address),
+        ] {
+            append_fmt(&mut json, format_args!(",\"{key}\":"));
+            append_json_string(&mut json, value);
+        }
+        append_fmt(
+            &mut json,
+            format_args!(",\"prices\":{}}}", FuelPricesJson(self.0.fuels)),
+        );
+        f.write_str(&json)
+    }
+}
+impl From<(&ApiSnapshot, &ApiRequest<'_>)> for ApiResponse {
+    fn from((snapshot, request): (&ApiSnapshot, &ApiRequest<'_>)) -> Self {
+        let result = (|| -> Result<Option<String>> {
+            let mut body = String::new();
+            let limit = match query_param(request.query, "limit")? {
+                None => API_DEFAULT_LIMIT,
+                Some(value) => value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&limit| (1..=API_MAX_LIMIT).contains(&limit))
+                    .ok_or_else(|| {
+                        err(format!(
+                            "limit은 1 이상 {API_MAX_LIMIT} 이하의 정수여야 합니다: {value}"
+                        ))
+                    })?,
+            };
+            let region = query_param(request.query, "region")?
+                .map(|value| {
+                    let trimmed = value.trim();
+                    let label = trimmed.strip_suffix('시').unwrap_or(trimmed);
+                    TargetRegion::from_label(label)
+                        .map(|_target| label.to_owned())
+                        .ok_or_else(|| err(format!("알 수 없는 지역입니다: {value}")))
+                })
+                .transpose()?;
+            match request.path {
+                "/status" => {
+                    body.push_str("{\"loaded_at\":");
+                    append_json_string(&mut body, &snapshot.loaded_at);
+                    body.push_str(",\"source_hash\":");
+                    match snapshot.source_hash.as_deref() {
+                        Some(hash) => append_json_string(&mut body, hash),
+                        None => body.push_str("null"),
+                    }
+                    body.push_str(",\"change_log_updated_on\":");
+                    append_json_string(&mut body, &snapshot.changes.updated_on);
+                    append_fmt(
+                        &mut body,
+                        format_args!(
+                            ",\"station_count\":{},\"change_count\":{}}}",
+                            snapshot.stations.stations.len(),
+                            snapshot.changes.entries.len()
+                        ),
+                    );
+                }
+                "/ranking" => {
+                    let fuel = match query_param(request.query, "fuel")?.as_deref() {
+                        None | Some("gasoline") => ApiFuel::Gasoline,
+                        Some("premium") => ApiFuel::Premium,
+                        Some("diesel") => ApiFuel::Diesel,
+                        Some(other) => {
+                            return Err(err(format!(
+                                "fuel은 gasoline, premium 또는 diesel이어야 합니다: {other}"
+                            )));
+                        }
+                    };
+                    let mut ranked: Vec<(i32, &MasterStation)> = snapshot
+                        .stations
+                        .stations
+                        .iter()
+                        .filter(|station| {
+                            region
+                                .as_deref()
+                                .is_none_or(|label| station.region == label)
+                        })
+                        .filter_map(|station| Some((fuel.price(station.fuels)?, station)))
+                        .collect();
+                    ranked.sort_by_key(|&(price, station)| (price, station.row));
+                    body.push_str("{\"region\":");
+                    match region.as_deref() {
+                        Some(label) => append_json_string(&mut body, label),
+                        None => body.push_str("null"),
+                    }
+                    append_fmt(
+                        &mut body,
+                        format_args!(
+                            ",\"fuel\":\"{}\",\"total\":{},\"stations\":[",
+                            fuel.key(),
+                            ranked.len()
+                        ),
+                    );
+                    for (rank, &(price, station)) in (1_usize..).zip(ranked.iter().take(limit)) {
+                        if rank != 1 {
+                            body.push(',');
+                        }
+                        append_fmt(
+                            &mut body,
+                            format_args!(
+                                "{{\"rank\":{rank},\"price\":{price},\"station\":{}}}",
+                                StationJson(station)
+                            ),
+                        );
+                    }
+                    body.push_str("]}");
+                }
+                "/stations" => {
+                    let query = query_param(request.query, "q")?
+                        .filter(|value| !value.trim().is_empty())
+                        .ok_or_else(|| err("q 파라미터에 찾을 상호 또는 주소를 지정하세요."))?;
+                    let needle = query.trim();
+                    let mut matched = snapshot.stations.stations.iter().filter(|station| {
+                        region
+                            .as_deref()
+                            .is_none_or(|label| station.region == label)
+                            && (station.name.contains(needle) || station.address.contains(needle))
+                    });
+                    body.push_str("{\"query\":");
+                    append_json_string(&mut body, needle);
+                    body.push_str(",\"stations\":[");
+                    for (index, station) in matched.by_ref().take(limit).enumerate() {
+                        if index != 0 {
+                            body.push(',');
+                        }
+                        append_fmt(&mut body, format_args!("{}", StationJson(station)));
+                    }
+                    append_fmt(
+                        &mut body,
+                        format_args!("],\"truncated\":{}}}", matched.next().is_some()),
+                    );
+                }
+                "/changes" => {
+                    let reason = query_param(request.query, "reason")?;
+                    let mut matched = snapshot.changes.entries.iter().filter(|entry| {
+                        region.as_deref().is_none_or(|label| entry.region == label)
+                            && reason
+                                .as_deref()
+                                .is_none_or(|wanted| entry.reason.contains(wanted))
+                    });
+                    body.push_str("{\"updated_on\":");
+                    append_json_string(&mut body, &snapshot.changes.updated_on);
+                    body.push_str(",\"changes\":[");
+                    for (index, entry) in matched.by_ref().take(limit).enumerate() {
+                        if index != 0 {
+                            body.push(',');
+                        }
+                        append_fmt(&mut body, format_args!("{}", ChangeJson(entry)));
+                    }
+                    append_fmt(
+                        &mut body,
+                        format_args!("],\"truncated\":{}}}", matched.next().is_some()),
+                    );
+                }
+                _ => return Ok(None),
+            }
+            Ok(Some(body))
+        })();
+        match result {
+            Ok(Some(body)) => Self { body, status: 200 },
+            Ok(None) => Self::error(
+                404,
+                "지원하지 않는 경로입니다. /status, /ranking, /stations, /changes를 사용하세요.",
+            ),
+            Err(request_err) => Self::error(400, &request_err.to_string()),
+        }
+    }
+}
+impl<'req> TryFrom<&'req str> for ApiRequest<'req> {
+    type Error = ApiResponse;
+    fn try_from(head: &'req str) -> CoreResult<Self, ApiResponse> {
+        let request_line = head.split("\r\n").next().unwrap_or_default();
+        let mut parts = request_line.split(' ');
+        let (Some(method), Some(target), Some(version), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(ApiResponse::error(
+                400,
+                "HTTP 요청 줄 형식이 올바르지 않습니다.",
+            ));
+        };
+        if !version.starts_with("HTTP/1.") || !target.starts_with('/') {
+            return Err(ApiResponse::error(
+                400,
+                "HTTP 요청 줄 형식이 올바르지 않습니다.",
+            ));
+        }
+        let head_only = match method {
+            "GET" => false,
+            "HEAD" => true,
+            _ => {
+                return Err(ApiResponse::error(
+                    405,
+                    "읽기 전용 API입니다. GET 또는 HEAD만 사용할 수 있습니다.",
+                ));
+            }
+        };
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        Ok(Self {
+            head_only,
+            path,
+            query,
+        })
+    }
+}
+impl TryFrom<(&Path, ValidatedFile)> for ApiSnapshot {
+    type Error = AppError;
+    fn try_from((path, file): (&Path, ValidatedFile)) -> Result<Self> {
+        let identity = file.identity;
+        let book = StdWorkbook::from_container(XlsxContainer::from_validated_file(file, path)?)?;
+        Ok(Self {
+            changes: ChangeLogEntries::try_from(&book)?,
+            identity,
+            loaded_at: KstTime::now()?.timestamp(),
+            source_hash: book.source_hash().map(str::to_owned),
+            stations: MasterStations::try_from(&book)?,
+        })
+    }
+}
+fn decode_query_component(text: &str) -> Result<String> {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0_usize;
+    while let Some(&byte) = bytes.get(index) {
+        match byte {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let value = bytes
+                    .get(index.strict_add(1)..index.strict_add(3))
+                    .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                    .and_then(|hex| str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or_else(|| err(format!("잘못된 퍼센트 인코딩입니다: {text}")))?;
+                decoded.push(value);
+                index = index.strict_add(2);
+            }
+            _ => decoded.push(byte),
+        }
+        index = index.strict_add(1);
+    }
+    String::from_utf8(decoded)
+        .map_err(|source| err_with_source("쿼리 문자열이 UTF-8이 아닙니다", source))
+}
+fn open_master(path: &Path) -> Result<ValidatedFile> {
+    open_regular(path, false).map_err(|source| {
+        err_with_source(
+            path_context_message("마스터 xlsx 파일 열기 실패", path),
+            source,
+        )
+    })
+}
+fn query_param(query: &str, name: &str) -> Result<Option<String>> {
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        if decode_query_component(key)? == name {
+            return decode_query_component(value).map(Some);
+        }
+    }
+    Ok(None)
+}
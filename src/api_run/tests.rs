@@ -0,0 +1,258 @@
+use super::{
+    API_REQUEST_DEADLINE, ApiRun, ApiSnapshot, decode_query_component, open_master, query_param,
+};
+use crate::diagnostic::{Result, ensure, err};
+use core::{net::SocketAddr, str, time::Duration};
+use std::{
+    env, fs,
+    io::{Read as _, Write as _},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    process, thread,
+    time::Instant,
+};
+#[test]
+fn query_components_are_percent_and_plus_decoded() -> Result<()> {
+    for (encoded, decoded) in [
+        ("", ""),
+        ("diesel", "diesel"),
+        ("a+b", "a b"),
+        ("%2B1", "+1"),
+        ("50%25", "50%"),
+        ("%ea%b2%bd%EC%9C%A0", "경유"),
+        (
+            "%EC%B2%9C%EC%95%88+%EC%A3%BC%EC%9C%A0%EC%86%8C",
+            "천안 주유소",
+        ),
+    ] {
+        ensure(
+            decode_query_component(encoded)? == decoded,
+            format!("decoding {encoded}"),
+        )?;
+    }
+    Ok(())
+}
+#[test]
+fn malformed_query_components_are_rejected() -> Result<()> {
+    for (encoded, expected) in [
+        ("%", "잘못된 퍼센트 인코딩입니다: %"),
+        ("abc%4", "잘못된 퍼센트 인코딩입니다: abc%4"),
+        ("%zz", "잘못된 퍼센트 인코딩입니다: %zz"),
+        ("%+1", "잘못된 퍼센트 인코딩입니다: %+1"),
+        ("%FF", "쿼리 문자열이 UTF-8이 아닙니다"),
+        ("%EA%B2", "쿼리 문자열이 UTF-8이 아닙니다"),
+    ] {
+        let Err(decode_err) = decode_query_component(encoded) else {
+            return Err(err(format!("malformed component must fail: {encoded}")));
+        };
+        let message = decode_err.to_string();
+        ensure(
+            message.starts_with(expected),
+            format!("error for {encoded}: {message}"),
+        )?;
+    }
+    Ok(())
+}
+#[test]
+fn query_param_returns_the_first_decoded_match() -> Result<()> {
+    let query = "region=%EC%B2%9C%EC%95%88&fuel=diesel&&limit&fuel=gasoline&q%5B%5D=a+b";
+    ensure(
+        query_param(query, "region")?.as_deref() == Some("천안"),
+        "decoded value",
+    )?;
+    ensure(
+        query_param(query, "fuel")?.as_deref() == Some("diesel"),
+        "first occurrence wins",
+    )?;
+    ensure(
+        query_param(query, "limit")?.as_deref() == Some(""),
+        "key without value",
+    )?;
+    ensure(
+        query_param(query, "q[]")?.as_deref() == Some("a b"),
+        "encoded key",
+    )?;
+    ensure(query_param(query, "reason")?.is_none(), "missing key")?;
+    ensure(query_param("", "q")?.is_none(), "empty query")?;
+    ensure(
+        query_param("q=%zz", "q").is_err(),
+        "malformed value of the requested key fails",
+    )?;
+    ensure(
+        query_param("other=%zz&q=1", "q")?.as_deref() == Some("1"),
+        "values of other keys are not decoded",
+    )?;
+    ensure(
+        query_param("%zz=1&q=2", "q").is_err(),
+        "malformed keys before the match fail",
+    )
+}
+#[test]
+fn endpoints_answer_from_the_snapshot_and_follow_atomic_replaces() -> Result<()> {
+    let master = scratch_master("endpoints")?;
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    let snapshot = ApiSnapshot::try_from((master.as_path(), open_master(&master)?))?;
+    let mut log = Vec::new();
+    let responses = thread::scope(|scope| -> Result<Vec<String>> {
+        let server = scope.spawn(|| {
+            ApiRun {
+                listen: "127.0.0.1:0",
+                master_path: &master,
+                out: &mut log,
+            }
+            .serve(listener.incoming().take(8), snapshot)
+        });
+        let mut responses = Vec::new();
+        for request in [
+            "GET /ranking?fuel=diesel&limit=3 HTTP/1.1\r\n\r\n",
+            "GET /stations?q=%EC%A3%BC%EC%9C%A0%EC%86%8C&limit=2 HTTP/1.1\r\n\r\n",
+            "GET /changes?limit=1 HTTP/1.1\r\n\r\n",
+            "POST /status HTTP/1.1\r\nContent-Length: 0\r\n\r\n",
+            "GET /status HTTP/1.1\r\n\r\n",
+        ] {
+            responses.push(fetch(addr, request)?);
+        }
+        let staged = master.with_extension("xlsx.tmp");
+        fs::copy(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("fuel_cost_chungcheong.xlsx"),
+            &staged,
+        )?;
+        fs::rename(&staged, &master)?;
+        responses.push(fetch(addr, "GET /status HTTP/1.1\r\n\r\n")?);
+        fs::write(&staged, b"not a workbook")?;
+        fs::rename(&staged, &master)?;
+        responses.push(fetch(addr, "GET /status HTTP/1.1\r\n\r\n")?);
+        responses.push(fetch(addr, "HEAD /status HTTP/1.1\r\n\r\n")?);
+        server
+            .join()
+            .map_err(|_panic| err("API server thread panicked"))??;
+        Ok(responses)
+    })?;
+    let mut pending = responses.iter();
+    let mut next = || pending.next().ok_or("missing response");
+    let ranking = next()?;
+    ensure(
+        ranking.starts_with("HTTP/1.1 200 OK\r\n")
+            && ranking.contains("{\"region\":null,\"fuel\":\"diesel\",\"total\":")
+            && ranking.contains("[{\"rank\":1,\"price\":")
+            && !ranking.contains("{\"rank\":4,"),
+        format!("ranking: {ranking}"),
+    )?;
+    let stations = next()?;
+    ensure(
+        stations.starts_with("HTTP/1.1 200 OK\r\n")
+            && stations.contains("{\"query\":\"주유소\",\"stations\":[{\"row\":")
+            && stations.ends_with("],\"truncated\":true}"),
+        format!("stations: {stations}"),
+    )?;
+    let changes = next()?;
+    ensure(
+        changes.starts_with("HTTP/1.1 200 OK\r\n")
+            && changes.contains("\r\n\r\n{\"updated_on\":")
+            && changes.contains(",\"changes\":["),
+        format!("changes: {changes}"),
+    )?;
+    let rejected = next()?;
+    ensure(
+        rejected.starts_with("HTTP/1.1 405 Method Not Allowed\r\n")
+            && rejected.contains("\r\nAllow: GET, HEAD\r\n"),
+        format!("POST: {rejected}"),
+    )?;
+    let before = next()?;
+    let reloaded = next()?;
+    let kept = next()?;
+    let station_count = |response: &str| {
+        response
+            .split_once("\"station_count\":")
+            .map(|(_head, rest)| rest.split(',').next().unwrap_or_default().to_owned())
+    };
+    ensure(
+        [before, reloaded, kept]
+            .iter()
+            .all(|response| response.starts_with("HTTP/1.1 200 OK\r\n"))
+            && station_count(before).is_some()
+            && station_count(reloaded) == station_count(before)
+            && station_count(kept) == station_count(before),
+        format!("status around replaces: {before} / {reloaded} / {kept}"),
+    )?;
+    let head = next()?;
+    ensure(
+        head.starts_with("HTTP/1.1 200 OK\r\n") && head.ends_with("\r\n\r\n"),
+        format!("HEAD: {head}"),
+    )?;
+    let log_text = str::from_utf8(&log).map_err(|_utf8_err| err("server log is not UTF-8"))?;
+    ensure(
+        log_text.contains("워크북 교체 감지: 다시 읽었습니다 (업체 ")
+            && log_text.contains("워크북 다시 읽기 실패, 이전 데이터로 응답합니다: "),
+        format!("server log: {log_text}"),
+    )
+}
+#[test]
+fn slow_clients_are_cut_off_at_the_request_deadline() -> Result<()> {
+    let master = scratch_master("deadline")?;
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    let snapshot = ApiSnapshot::try_from((master.as_path(), open_master(&master)?))?;
+    let mut log = Vec::new();
+    let waited = thread::scope(|scope| -> Result<Duration> {
+        let server = scope.spawn(|| {
+            ApiRun {
+                listen: "127.0.0.1:0",
+                master_path: &master,
+                out: &mut log,
+            }
+            .serve(listener.incoming().take(2), snapshot)
+        });
+        let mut slow = TcpStream::connect(addr)?;
+        slow.write_all(b"GET /status HTTP/1.1\r\n")?;
+        let started = Instant::now();
+        let trickle = scope.spawn(move || {
+            while started.elapsed() < API_REQUEST_DEADLINE.saturating_mul(3_u32)
+                && slow.write_all(b"X").is_ok()
+            {
+                thread::sleep(Duration::from_millis(100_u64));
+            }
+        });
+        let response = fetch(addr, "GET /status HTTP/1.1\r\n\r\n")?;
+        let waited = started.elapsed();
+        ensure(
+            response.starts_with("HTTP/1.1 200 OK\r\n"),
+            format!("queued client: {response}"),
+        )?;
+        trickle
+            .join()
+            .map_err(|_panic| err("slow client thread panicked"))?;
+        server
+            .join()
+            .map_err(|_panic| err("API server thread panicked"))??;
+        Ok(waited)
+    })?;
+    ensure(
+        waited < API_REQUEST_DEADLINE.saturating_add(Duration::from_secs(2_u64)),
+        format!("queued client waited {waited:?} behind a trickling one"),
+    )?;
+    let log_text = str::from_utf8(&log).map_err(|_utf8_err| err("server log is not UTF-8"))?;
+    ensure(
+        log_text.contains("API 요청 처리 실패: "),
+        format!("server log: {log_text}"),
+    )
+}
+fn fetch(addr: SocketAddr, request: &str) -> Result<String> {
+    let mut stream = TcpStream::connect(addr)?;
+    stream.set_read_timeout(Some(API_REQUEST_DEADLINE.saturating_mul(3_u32)))?;
+    stream.write_all(request.as_bytes())?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    Ok(response)
+}
+fn scratch_master(name: &str) -> Result<PathBuf> {
+    let dir = env::temp_dir().join(format!("fcupdater-api-{}-{name}", process::id()));
+    fs::create_dir_all(&dir)?;
+    let path = dir.join("fuel_cost_chungcheong.xlsx");
+    fs::copy(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("fuel_cost_chungcheong.xlsx"),
+        &path,
+    )?;
+    Ok(path)
+}
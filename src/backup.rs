@@ -0,0 +1,124 @@
+use crate::{
+    diagnostic::{Result, err, err_with_source, path_context_message},
+    kst::KstTime,
+};
+use std::{
+    ffi::OsStr,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+const BACKUP_EXTENSION: &str = ".xlsx";
+const BACKUP_NAME_ATTEMPTS: u32 = 100;
+const BACKUP_STAMP_DATE_LEN: usize = 8;
+const BACKUP_STAMP_LEN: usize = 15;
+const TIMESTAMP_SECONDS_LEN: usize = 19;
+pub(super) struct BackupEntry {
+    pub file_name: String,
+    pub path: PathBuf,
+    pub stamp: String,
+    pub summary: String,
+}
+pub(super) struct BackupStore<'dir> {
+    pub dir: &'dir Path,
+    pub keep: usize,
+}
+impl BackupStore<'_> {
+    pub(super) fn entries(&self) -> Result<Vec<BackupEntry>> {
+        let read_dir = match fs::read_dir(self.dir) {
+            Ok(read_dir) => read_dir,
+            Err(read_err) if read_err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(read_err) => {
+                return Err(err_with_source(
+                    path_context_message("백업 폴더 읽기 실패", self.dir),
+                    read_err,
+                ));
+            }
+        };
+        let mut entries = Vec::new();
+        for dir_entry in read_dir {
+            let entry = dir_entry.map_err(|source| {
+                err_with_source(
+                    path_context_message("백업 폴더 읽기 실패", self.dir),
+                    source,
+                )
+            })?;
+            let path = entry.path();
+            let Some((stamp, summary)) = path
+                .file_name()
+                .and_then(OsStr::to_str)
+                .and_then(|file_name| file_name.strip_suffix(BACKUP_EXTENSION))
+                .and_then(|stem| stem.split_once('_'))
+                .filter(|&(stamp, _)| {
+                    stamp.len() == BACKUP_STAMP_LEN
+                        && stamp.bytes().enumerate().all(|(index, byte)| {
+                            if index == BACKUP_STAMP_DATE_LEN {
+                                byte == b'T'
+                            } else {
+                                byte.is_ascii_digit()
+                            }
+                        })
+                })
+            else {
+                continue;
+            };
+            if !entry
+                .file_type()
+                .is_ok_and(|file_type| !file_type.is_dir() && !file_type.is_symlink())
+            {
+                continue;
+            }
+            entries.push(BackupEntry {
+                file_name: format!("{stamp}_{summary}{BACKUP_EXTENSION}"),
+                stamp: stamp.to_owned(),
+                summary: summary.to_owned(),
+                path,
+            });
+        }
+        entries.sort_by(|left, right| right.file_name.cmp(&left.file_name));
+        Ok(entries)
+    }
+    pub(super) fn prune(&self) -> Result<Vec<BackupEntry>> {
+        let expired: Vec<BackupEntry> = self.entries()?.into_iter().skip(self.keep).collect();
+        for entry in &expired {
+            fs::remove_file(&entry.path).map_err(|source| {
+                err_with_source(
+                    path_context_message("오래된 백업 삭제 실패", &entry.path),
+                    source,
+                )
+            })?;
+        }
+        Ok(expired)
+    }
+    pub(super) fn reserve_path(&self, at: KstTime, summary: &str) -> Result<PathBuf> {
+        fs::create_dir_all(self.dir).map_err(|source| {
+            err_with_source(
+                path_context_message("백업 폴더 생성 실패", self.dir),
+                source,
+            )
+        })?;
+        let stamp: String = at
+            .timestamp()
+            .chars()
+            .take(TIMESTAMP_SECONDS_LEN)
+            .filter(|character| !matches!(character, '-' | ':'))
+            .collect();
+        for sequence in 1..=BACKUP_NAME_ATTEMPTS {
+            let path = self.dir.join(if sequence == 1 {
+                format!("{stamp}_{summary}{BACKUP_EXTENSION}")
+            } else {
+                format!("{stamp}_{summary}-{sequence}{BACKUP_EXTENSION}")
+            });
+            let exists = path.try_exists().map_err(|source| {
+                err_with_source(path_context_message("백업 파일 확인 실패", &path), source)
+            })?;
+            if !exists {
+                return Ok(path);
+            }
+        }
+        Err(err(format!(
+            "같은 시각의 백업 파일 이름이 너무 많습니다: {}",
+            self.dir.display()
+        )))
+    }
+}
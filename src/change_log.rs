@@ -1,8 +1,8 @@
 use crate::{
-    diagnostic::{Result, append_fmt, err, try_string_with_capacity},
+    diagnostic::{AppError, Result, append_fmt, err, try_string_with_capacity},
     excel::{
         FuelValues, SourceRecord,
-        writer::{SharedStringTable, Worksheet},
+        writer::{SharedStringTable, Workbook as StdWorkbook, Worksheet},
     },
     master_sheet::{ChangeRow, StoreRow},
     sheet_util::add_row_offset,
@@ -24,12 +24,26 @@ const CHANGELOG_COL_DELTA_PREMIUM: u32 = 10;
 const CHANGELOG_COL_OLD_DIESEL: u32 = 11;
 const CHANGELOG_COL_NEW_DIESEL: u32 = 12;
 const CHANGELOG_COL_DELTA_DIESEL: u32 = 13;
+const CHANGELOG_DATE_PREFIX: &str = "현행화 일자: ";
 const ROW_DECIMAL_TEXT_MAX_LEN: usize = 10;
 const DELTA_FORMULA_COLUMNS: [(&str, &str, u32); 3] = [
     ("E", "F", CHANGELOG_COL_DELTA_GAS),
     ("H", "I", CHANGELOG_COL_DELTA_PREMIUM),
     ("K", "L", CHANGELOG_COL_DELTA_DIESEL),
 ];
+pub(super) struct ChangeLogEntry {
+    pub address: String,
+    pub name: String,
+    pub new_fuels: FuelValues<Option<i32>>,
+    pub old_fuels: FuelValues<Option<i32>>,
+    pub reason: String,
+    pub region: String,
+    pub row: u32,
+}
+pub(super) struct ChangeLogEntries {
+    pub entries: Vec<ChangeLogEntry>,
+    pub updated_on: String,
+}
 pub(super) struct ChangeLogUpdater<'sheet, 'shared, 'data, 'source> {
     pub added: &'data [&'source SourceRecord],
     pub changes: &'data [ChangeRow<'source>],
@@ -38,6 +52,10 @@ pub(super) struct ChangeLogUpdater<'sheet, 'shared, 'data, 'source> {
     pub today: &'data str,
     pub worksheet: &'sheet mut Worksheet,
 }
+pub(super) struct SavedChangeLog<'book> {
+    pub shared_strings: &'book SharedStringTable,
+    pub worksheet: &'book Worksheet,
+}
 struct ChangeLogRowValues<'row> {
     address: &'row str,
     name: &'row str,
@@ -184,7 +202,7 @@ impl ChangeLogUpdater<'_, '_, '_, '_> {
         {
             return Err(err("변경내역 243행에 고정 style template이 없습니다."));
         }
-        let date_text = format!("현행화 일자: {}", self.today);
+        let date_text = format!("{CHANGELOG_DATE_PREFIX}{}", self.today);
         self.shared_string_table
             .set_cell(self.worksheet, 1, 2, &date_text)?;
         let old_data_rows = self.clear_existing_rows()?;
@@ -264,3 +282,88 @@ impl ChangeLogUpdater<'_, '_, '_, '_> {
             .truncate_rows_after(last_change_row.max(style_template_row))
     }
 }
+impl SavedChangeLog<'_> {
+    pub(super) fn verify_entry_count(&self, expected_count: usize) -> Result<()> {
+        let cols = [
+            CHANGELOG_COL_REGION,
+            CHANGELOG_COL_NAME,
+            CHANGELOG_COL_ADDRESS,
+            CHANGELOG_COL_REASON,
+        ];
+        let mut saved_count = 0_usize;
+        for row in self.worksheet.row_numbers_from(CHANGELOG_DATA_START_ROW)? {
+            if !self
+                .worksheet
+                .row_has_any_data(row, &cols, self.shared_strings)?
+            {
+                continue;
+            }
+            let expected_row =
+                add_row_offset(CHANGELOG_DATA_START_ROW, saved_count, "변경내역 저장 검증")?;
+            if row != expected_row {
+                return Err(err(format!(
+                    "변경내역 데이터 행이 연속되지 않습니다: row={row}, expected={expected_row}"
+                )));
+            }
+            saved_count = saved_count.strict_add(1);
+        }
+        if saved_count != expected_count {
+            return Err(err(format!(
+                "변경내역 저장 행 수가 기록한 항목 수와 다릅니다: saved={saved_count}, expected={expected_count}"
+            )));
+        }
+        Ok(())
+    }
+}
+impl TryFrom<&StdWorkbook> for ChangeLogEntries {
+    type Error = AppError;
+    fn try_from(book: &StdWorkbook) -> Result<Self> {
+        let (ws, shared_strings) = book.change_log_sheet();
+        let text_at = |col: u32, row: u32| -> Result<String> {
+            Ok(ws
+                .try_get_display_at(col, row, shared_strings)?
+                .trim()
+                .to_owned())
+        };
+        let date_text = text_at(1, 2)?;
+        let updated_on = date_text
+            .strip_prefix(CHANGELOG_DATE_PREFIX)
+            .map_or_else(|| date_text.clone(), str::to_owned);
+        let mut entries = Vec::new();
+        for row in ws.row_numbers_from(CHANGELOG_DATA_START_ROW)? {
+            if !ws.row_has_any_data(
+                row,
+                &[
+                    CHANGELOG_COL_REGION,
+                    CHANGELOG_COL_NAME,
+                    CHANGELOG_COL_ADDRESS,
+                    CHANGELOG_COL_REASON,
+                ],
+                shared_strings,
+            )? {
+                continue;
+            }
+            entries.push(ChangeLogEntry {
+                address: text_at(CHANGELOG_COL_ADDRESS, row)?,
+                name: text_at(CHANGELOG_COL_NAME, row)?,
+                new_fuels: FuelValues {
+                    diesel: ws.get_i32_at(CHANGELOG_COL_NEW_DIESEL, row, shared_strings)?,
+                    gasoline: ws.get_i32_at(CHANGELOG_COL_NEW_GAS, row, shared_strings)?,
+                    premium: ws.get_i32_at(CHANGELOG_COL_NEW_PREMIUM, row, shared_strings)?,
+                },
+                old_fuels: FuelValues {
+                    diesel: ws.get_i32_at(CHANGELOG_COL_OLD_DIESEL, row, shared_strings)?,
+                    gasoline: ws.get_i32_at(CHANGELOG_COL_OLD_GAS, row, shared_strings)?,
+                    premium: ws.get_i32_at(CHANGELOG_COL_OLD_PREMIUM, row, shared_strings)?,
+                },
+                reason: text_at(CHANGELOG_COL_REASON, row)?,
+                region: text_at(CHANGELOG_COL_REGION, row)?,
+                row,
+            });
+        }
+        Ok(Self {
+            entries,
+            updated_on,
+        })
+    }
+}
@@ -0,0 +1,608 @@
+#[cfg(feature = "sqlite")]
+use crate::HISTORY_PATH;
+use crate::{
+    HELP_TEXT, MASTER_PATH, OPEN_API_KEY_ENV,
+    backup::BackupStore,
+    diagnostic::{AppError, Result, err, err_with_source},
+    diff_run::DiffFormat,
+    excel::SaveVerification,
+    export_run::ExportFormat,
+    i18n::Lang,
+    logging::{LogLevel, LogOptions},
+    master_sheet::{MAP_URL_DEFAULT, validate_map_url_template},
+    update_run::UpdateRun,
+    webhook::{WEBHOOK_DEFAULT_TOP, WEBHOOK_MAX_TOP, WebhookTarget},
+};
+use alloc::vec;
+use core::iter::Peekable;
+use std::{
+    env,
+    ffi::{OsStr, OsString},
+    io::Write,
+    path::{Path, PathBuf},
+};
+const API_LISTEN: &str = "127.0.0.1:8080";
+const BACKUP_DIR: &str = "fcupdater_backups";
+pub(super) const BACKUP_KEEP: usize = 10;
+const SCHEDULE_TIME_ZONE: &str = "KST";
+const STATUS_PATH: &str = "fcupdater_status.json";
+pub(super) type CliArgs = Peekable<vec::IntoIter<OsString>>;
+struct DailySchedule(Vec<u64>);
+#[derive(Clone, Copy)]
+pub(super) enum ErrorFormat {
+    Human,
+    Json,
+}
+pub(super) struct ApiOptions {
+    pub listen: String,
+    pub master_path: PathBuf,
+}
+pub(super) struct DiffOptions {
+    pub format: DiffFormat,
+    pub new_path: PathBuf,
+    pub old_path: PathBuf,
+}
+pub(super) struct ExportOptions {
+    pub bom: bool,
+    pub format: ExportFormat,
+    pub master_path: PathBuf,
+    pub output_path: Option<PathBuf>,
+}
+pub(super) struct RestoreOptions {
+    pub backup_dir: PathBuf,
+    pub backup_keep: usize,
+    pub target: Option<String>,
+}
+pub(super) struct ServeOptions {
+    pub schedule: Vec<u64>,
+    pub status_path: PathBuf,
+    pub update: UpdateOptions,
+}
+pub(super) struct UpdateOptions {
+    backup_dir: Option<PathBuf>,
+    backup_keep: Option<usize>,
+    force: bool,
+    geo_table: Option<PathBuf>,
+    history_path: Option<PathBuf>,
+    map_links: bool,
+    map_url: Option<String>,
+    ods_path: Option<PathBuf>,
+    open_api_key: Option<String>,
+    rank_by_detour: Option<bool>,
+    save_verification: SaveVerification,
+    source_path: Option<PathBuf>,
+    webhook_top: Option<usize>,
+    webhook_url: Option<String>,
+}
+impl UpdateOptions {
+    const fn empty() -> Self {
+        Self {
+            backup_dir: None,
+            backup_keep: None,
+            force: false,
+            geo_table: None,
+            history_path: None,
+            map_links: false,
+            map_url: None,
+            ods_path: None,
+            open_api_key: None,
+            rank_by_detour: None,
+            save_verification: SaveVerification::Skip,
+            source_path: None,
+            webhook_top: None,
+            webhook_url: None,
+        }
+    }
+    fn finish(self) -> Result<Self> {
+        if self.open_api_key.is_some() && self.source_path.is_some() {
+            return Err(err(
+                "--source-api와 --source-file은 함께 사용할 수 없습니다.",
+            ));
+        }
+        #[cfg(not(feature = "sqlite"))]
+        if self.history_path.is_some() {
+            return Err(err(
+                "--history는 sqlite 기능을 포함해 빌드한 경우에만 사용할 수 있습니다.",
+            ));
+        }
+        if let Some(template) = self.map_url.as_deref() {
+            validate_map_url_template(template)?;
+        }
+        if self.rank_by_detour == Some(true) && self.geo_table.is_none() {
+            return Err(err(
+                "--rank-by detour는 --geo-table과 함께 사용해야 합니다.",
+            ));
+        }
+        if let Some(url) = self.webhook_url.as_deref() {
+            WebhookTarget::try_from((url, Lang::Ko))?;
+        } else if self.webhook_top.is_some() {
+            return Err(err("--webhook-top은 --webhook과 함께 사용해야 합니다."));
+        } else {
+            return Ok(self);
+        }
+        Ok(self)
+    }
+    fn parse_option(&mut self, token: OsString, args: &mut CliArgs) -> Result<Option<OsString>> {
+        if token == OsStr::new("--verify")
+            && matches!(self.save_verification, SaveVerification::Skip)
+        {
+            self.save_verification = SaveVerification::Verify;
+        } else if token == OsStr::new("--force") && !self.force {
+            self.force = true;
+        } else if token == OsStr::new("--backup-dir") && self.backup_dir.is_none() {
+            self.backup_dir = Some(PathBuf::from(option_value(
+                args,
+                "--backup-dir",
+                "백업 폴더 경로가",
+            )?));
+        } else if token == OsStr::new("--backup-keep") && self.backup_keep.is_none() {
+            self.backup_keep = Some(backup_keep(args)?);
+        } else if token == OsStr::new("--source-api") && self.open_api_key.is_none() {
+            let api_key = env::var(OPEN_API_KEY_ENV).map_err(|source_err| {
+                err_with_source(
+                    format!("--source-api 사용 시 {OPEN_API_KEY_ENV} 환경 변수가 필요합니다"),
+                    source_err,
+                )
+            })?;
+            self.open_api_key = Some(api_key);
+        } else if token == OsStr::new("--source-file") && self.source_path.is_none() {
+            self.source_path = Some(PathBuf::from(option_value(
+                args,
+                "--source-file",
+                "소스 파일 경로가",
+            )?));
+        } else if token == OsStr::new("--history") && self.history_path.is_none() {
+            self.history_path = Some(PathBuf::from(option_value(
+                args,
+                "--history",
+                "이력 DB 경로가",
+            )?));
+        } else if token == OsStr::new("--ods") && self.ods_path.is_none() {
+            self.ods_path = Some(PathBuf::from(option_value(
+                args,
+                "--ods",
+                "ODS 파일 경로가",
+            )?));
+        } else if token == OsStr::new("--map-links") && !self.map_links {
+            self.map_links = true;
+        } else if token == OsStr::new("--map-url") && self.map_url.is_none() {
+            let template = option_value(args, "--map-url", "지도 URL 템플릿이")?;
+            self.map_url = Some(template.into_string().map_err(|invalid| {
+                err(format!(
+                    "--map-url 값은 UTF-8 문자열이어야 합니다: {}",
+                    invalid.to_string_lossy()
+                ))
+            })?);
+        } else if token == OsStr::new("--geo-table") && self.geo_table.is_none() {
+            self.geo_table = Some(PathBuf::from(option_value(
+                args,
+                "--geo-table",
+                "좌표표 경로가",
+            )?));
+        } else if token == OsStr::new("--rank-by") && self.rank_by_detour.is_none() {
+            let value = option_value(args, "--rank-by", "price 또는 detour가")?;
+            self.rank_by_detour = Some(if value == OsStr::new("price") {
+                false
+            } else if value == OsStr::new("detour") {
+                true
+            } else {
+                return Err(unknown_value("--rank-by", &value));
+            });
+        } else if token == OsStr::new("--webhook") && self.webhook_url.is_none() {
+            let url = option_value(args, "--webhook", "웹훅 URL이")?;
+            self.webhook_url = Some(url.into_string().map_err(|invalid| {
+                err(format!(
+                    "--webhook 값은 UTF-8 문자열이어야 합니다: {}",
+                    invalid.to_string_lossy()
+                ))
+            })?);
+        } else if token == OsStr::new("--webhook-top") && self.webhook_top.is_none() {
+            let value = option_value(args, "--webhook-top", "지역별 표시 건수가")?;
+            self.webhook_top = Some(
+                value
+                    .to_str()
+                    .and_then(|text| text.parse::<usize>().ok())
+                    .filter(|&top| (1..=WEBHOOK_MAX_TOP).contains(&top))
+                    .ok_or_else(|| unknown_value("--webhook-top", &value))?,
+            );
+        } else {
+            return Ok(Some(token));
+        }
+        Ok(None)
+    }
+    pub(super) fn update_run<'out>(
+        &'out self,
+        lang: Lang,
+        out: &'out mut dyn Write,
+    ) -> UpdateRun<'out> {
+        UpdateRun {
+            backups: BackupStore {
+                dir: self
+                    .backup_dir
+                    .as_deref()
+                    .unwrap_or_else(|| Path::new(BACKUP_DIR)),
+                keep: self
+                    .backup_keep
+                    .or_else(|| self.backup_dir.as_ref().map(|_| BACKUP_KEEP))
+                    .unwrap_or(0),
+            },
+            detour_ranking: self.rank_by_detour.unwrap_or(false),
+            force: self.force,
+            geo_table_path: self.geo_table.as_deref(),
+            #[cfg(feature = "sqlite")]
+            history_path: Some(
+                self.history_path
+                    .as_deref()
+                    .unwrap_or_else(|| Path::new(HISTORY_PATH)),
+            ),
+            map_url: self
+                .map_url
+                .as_deref()
+                .or_else(|| self.map_links.then_some(MAP_URL_DEFAULT)),
+            master_path: Path::new(MASTER_PATH),
+            ods_path: self.ods_path.as_deref(),
+            open_api_key: self.open_api_key.clone(),
+            out,
+            save_verification: self.save_verification,
+            source_path: self.source_path.as_deref(),
+            webhook: self
+                .webhook_url
+                .as_deref()
+                .and_then(|url| WebhookTarget::try_from((url, lang)).ok()),
+            webhook_top: self.webhook_top.unwrap_or(WEBHOOK_DEFAULT_TOP),
+        }
+    }
+}
+impl TryFrom<&str> for DailySchedule {
+    type Error = AppError;
+    fn try_from(value: &str) -> Result<Self> {
+        let trimmed = value.trim();
+        let times = match trimmed.rsplit_once(char::is_whitespace) {
+            Some((times, zone)) if !zone.contains(':') => {
+                if !zone.eq_ignore_ascii_case(SCHEDULE_TIME_ZONE) {
+                    return Err(err(format!(
+                        "--schedule 시간대는 {SCHEDULE_TIME_ZONE}만 지원합니다: {zone}"
+                    )));
+                }
+                times
+            }
+            _ => trimmed,
+        };
+        let mut schedule = Vec::new();
+        for time in times.split(',').map(str::trim) {
+            let parsed = time
+                .split_once(':')
+                .filter(|&(hour, minute)| {
+                    !hour.is_empty()
+                        && hour.len() <= 2
+                        && minute.len() == 2
+                        && hour
+                            .bytes()
+                            .chain(minute.bytes())
+                            .all(|byte| byte.is_ascii_digit())
+                })
+                .and_then(|(hour, minute)| {
+                    Some((hour.parse::<u64>().ok()?, minute.parse::<u64>().ok()?))
+                })
+                .filter(|&(hour, minute)| hour < 24 && minute < 60);
+            let Some((hour, minute)) = parsed else {
+                return Err(err(format!(
+                    "--schedule 시각은 HH:MM 형식이어야 합니다: {time}"
+                )));
+            };
+            schedule.push(hour.strict_mul(3_600).strict_add(minute.strict_mul(60)));
+        }
+        schedule.sort_unstable();
+        schedule.dedup();
+        Ok(Self(schedule))
+    }
+}
+impl TryFrom<&mut CliArgs> for ApiOptions {
+    type Error = AppError;
+    fn try_from(args: &mut CliArgs) -> Result<Self> {
+        let mut listen = None;
+        let mut master_arg = None;
+        while let Some(token) = args.next() {
+            if token == OsStr::new("--listen") && listen.is_none() {
+                let value = option_value(args, "--listen", "\"주소:포트\" 형식의 수신 주소가")?;
+                listen = Some(value.into_string().map_err(|invalid| {
+                    err(format!(
+                        "--listen 값은 UTF-8 문자열이어야 합니다: {}",
+                        invalid.to_string_lossy()
+                    ))
+                })?);
+            } else if token.to_string_lossy().starts_with('-') || master_arg.is_some() {
+                return Err(unknown_option(&token));
+            } else {
+                master_arg = Some(PathBuf::from(token));
+            }
+        }
+        Ok(Self {
+            listen: listen.unwrap_or_else(|| API_LISTEN.to_owned()),
+            master_path: master_arg.unwrap_or_else(|| PathBuf::from(MASTER_PATH)),
+        })
+    }
+}
+impl TryFrom<&mut CliArgs> for DiffOptions {
+    type Error = AppError;
+    fn try_from(args: &mut CliArgs) -> Result<Self> {
+        let mut format = None;
+        let mut old_arg = None;
+        let mut new_arg = None;
+        while let Some(token) = args.next() {
+            if token == OsStr::new("--format") && format.is_none() {
+                let value = option_value(args, "--format", "human 또는 json이")?;
+                format = Some(if value == OsStr::new("human") {
+                    DiffFormat::Human
+                } else if value == OsStr::new("json") {
+                    DiffFormat::Json
+                } else {
+                    return Err(unknown_value("--format", &value));
+                });
+            } else if token.to_string_lossy().starts_with('-') {
+                return Err(unknown_option(&token));
+            } else if old_arg.is_none() {
+                old_arg = Some(PathBuf::from(token));
+            } else if new_arg.is_none() {
+                new_arg = Some(PathBuf::from(token));
+            } else {
+                return Err(unknown_option(&token));
+            }
+        }
+        let (Some(old_path), Some(new_path)) = (old_arg, new_arg) else {
+            return Err(err(format!(
+                "diff 명령에는 기존/비교 xlsx 경로 두 개가 필요합니다\n\n{HELP_TEXT}"
+            )));
+        };
+        Ok(Self {
+            format: format.unwrap_or(DiffFormat::Human),
+            new_path,
+            old_path,
+        })
+    }
+}
+impl TryFrom<&mut Vec<OsString>> for ErrorFormat {
+    type Error = AppError;
+    fn try_from(args: &mut Vec<OsString>) -> Result<Self> {
+        let Some(value) = take_global_option(args, "--error-format", "human 또는 json이")?
+        else {
+            return Ok(Self::Human);
+        };
+        if value == OsStr::new("human") {
+            Ok(Self::Human)
+        } else if value == OsStr::new("json") {
+            Ok(Self::Json)
+        } else {
+            Err(unknown_value("--error-format", &value))
+        }
+    }
+}
+impl TryFrom<&mut CliArgs> for ExportOptions {
+    type Error = AppError;
+    fn try_from(args: &mut CliArgs) -> Result<Self> {
+        let mut bom = false;
+        let mut format = None;
+        let mut master_arg = None;
+        let mut output_path = None;
+        while let Some(token) = args.next() {
+            if token == OsStr::new("--format") && format.is_none() {
+                let value = option_value(args, "--format", "csv, json 또는 ods가")?;
+                format = Some(if value == OsStr::new("csv") {
+                    ExportFormat::Csv
+                } else if value == OsStr::new("json") {
+                    ExportFormat::Json
+                } else if value == OsStr::new("ods") {
+                    ExportFormat::Ods
+                } else {
+                    return Err(unknown_value("--format", &value));
+                });
+            } else if token == OsStr::new("--bom") && !bom {
+                bom = true;
+            } else if token == OsStr::new("--output") && output_path.is_none() {
+                output_path = Some(PathBuf::from(option_value(
+                    args,
+                    "--output",
+                    "출력 파일 경로가",
+                )?));
+            } else if token.to_string_lossy().starts_with('-') || master_arg.is_some() {
+                return Err(unknown_option(&token));
+            } else {
+                master_arg = Some(PathBuf::from(token));
+            }
+        }
+        let export_format = format.unwrap_or(ExportFormat::Csv);
+        if bom && !matches!(export_format, ExportFormat::Csv) {
+            return Err(err("--bom은 CSV 형식에서만 사용할 수 있습니다."));
+        }
+        if matches!(export_format, ExportFormat::Ods) && output_path.is_none() {
+            return Err(err(
+                "--format ods는 --output으로 저장할 파일 경로가 필요합니다.",
+            ));
+        }
+        Ok(Self {
+            bom,
+            format: export_format,
+            master_path: master_arg.unwrap_or_else(|| PathBuf::from(MASTER_PATH)),
+            output_path,
+        })
+    }
+}
+impl TryFrom<&mut Vec<OsString>> for Lang {
+    type Error = AppError;
+    fn try_from(args: &mut Vec<OsString>) -> Result<Self> {
+        let Some(value) = take_global_option(args, "--lang", "en 또는 ko가")? else {
+            return Ok(Self::default());
+        };
+        if value == OsStr::new("en") {
+            Ok(Self::En)
+        } else if value == OsStr::new("ko") {
+            Ok(Self::Ko)
+        } else {
+            Err(unknown_value("--lang", &value))
+        }
+    }
+}
+impl TryFrom<&mut Vec<OsString>> for LogOptions {
+    type Error = AppError;
+    fn try_from(args: &mut Vec<OsString>) -> Result<Self> {
+        let verbose = take_global_flag(args, ["-v", "--verbose"])?;
+        let quiet = take_global_flag(args, ["-q", "--quiet"])?;
+        if verbose && quiet {
+            return Err(err("-v와 -q는 함께 사용할 수 없습니다."));
+        }
+        let level = if verbose {
+            LogLevel::Debug
+        } else if quiet {
+            LogLevel::Warn
+        } else {
+            LogLevel::Info
+        };
+        Ok(Self {
+            file: take_global_option(args, "--log-file", "로그 파일 경로가")?.map(PathBuf::from),
+            level,
+        })
+    }
+}
+impl TryFrom<&mut CliArgs> for RestoreOptions {
+    type Error = AppError;
+    fn try_from(args: &mut CliArgs) -> Result<Self> {
+        let mut backup_dir = None;
+        let mut keep = None;
+        let mut list = false;
+        let mut target = None;
+        while let Some(token) = args.next() {
+            if token == OsStr::new("--list") && !list {
+                list = true;
+            } else if token == OsStr::new("--backup-dir") && backup_dir.is_none() {
+                backup_dir = Some(PathBuf::from(option_value(
+                    args,
+                    "--backup-dir",
+                    "백업 폴더 경로가",
+                )?));
+            } else if token == OsStr::new("--backup-keep") && keep.is_none() {
+                keep = Some(backup_keep(args)?);
+            } else if token.to_string_lossy().starts_with('-') || target.is_some() {
+                return Err(unknown_option(&token));
+            } else {
+                target = Some(token.into_string().map_err(|invalid| {
+                    err(format!(
+                        "복원할 백업 시각은 UTF-8 문자열이어야 합니다: {}",
+                        invalid.to_string_lossy()
+                    ))
+                })?);
+            }
+        }
+        if list == target.is_some() {
+            return Err(err(format!(
+                "restore 명령에는 --list 또는 복원할 백업 시각 하나가 필요합니다\n\n{HELP_TEXT}"
+            )));
+        }
+        Ok(Self {
+            backup_dir: backup_dir.unwrap_or_else(|| PathBuf::from(BACKUP_DIR)),
+            backup_keep: keep.unwrap_or(BACKUP_KEEP),
+            target,
+        })
+    }
+}
+impl TryFrom<&mut CliArgs> for ServeOptions {
+    type Error = AppError;
+    fn try_from(args: &mut CliArgs) -> Result<Self> {
+        let mut schedule = None;
+        let mut status_path = None;
+        let mut update = UpdateOptions::empty();
+        while let Some(next_token) = args.next() {
+            let Some(token) = update.parse_option(next_token, args)? else {
+                continue;
+            };
+            if token == OsStr::new("--schedule") && schedule.is_none() {
+                let value = option_value(args, "--schedule", "\"HH:MM,HH:MM KST\" 형식의 시각이")?;
+                schedule = Some(DailySchedule::try_from(value.to_string_lossy().as_ref())?.0);
+            } else if token == OsStr::new("--status") && status_path.is_none() {
+                status_path = Some(PathBuf::from(option_value(
+                    args,
+                    "--status",
+                    "상태 파일 경로가",
+                )?));
+            } else {
+                return Err(unknown_option(&token));
+            }
+        }
+        let Some(schedule_times) = schedule else {
+            return Err(err(format!(
+                "serve 명령에는 --schedule 실행 시각이 필요합니다\n\n{HELP_TEXT}"
+            )));
+        };
+        Ok(Self {
+            schedule: schedule_times,
+            status_path: status_path.unwrap_or_else(|| PathBuf::from(STATUS_PATH)),
+            update: update.finish()?,
+        })
+    }
+}
+impl TryFrom<&mut CliArgs> for UpdateOptions {
+    type Error = AppError;
+    fn try_from(args: &mut CliArgs) -> Result<Self> {
+        let mut options = Self::empty();
+        while let Some(token) = args.next() {
+            if let Some(unknown) = options.parse_option(token, args)? {
+                return Err(unknown_option(&unknown));
+            }
+        }
+        options.finish()
+    }
+}
+fn backup_keep(args: &mut CliArgs) -> Result<usize> {
+    let value = option_value(args, "--backup-keep", "보관할 백업 개수가")?;
+    value
+        .to_str()
+        .and_then(|text| text.parse::<usize>().ok())
+        .ok_or_else(|| unknown_value("--backup-keep", &value))
+}
+fn option_value(args: &mut CliArgs, option: &str, expected: &str) -> Result<OsString> {
+    args.next().ok_or_else(|| {
+        err(format!(
+            "{option} 뒤에 {expected} 필요합니다\n\n{HELP_TEXT}"
+        ))
+    })
+}
+fn take_global_flag(args: &mut Vec<OsString>, names: [&str; 2]) -> Result<bool> {
+    let is_flag = |arg: &OsString| names.iter().any(|name| arg == OsStr::new(name));
+    if let Some(duplicate) = args.iter().filter(|arg| is_flag(arg)).nth(1) {
+        return Err(unknown_option(duplicate));
+    }
+    let before = args.len();
+    args.retain(|arg| !is_flag(arg));
+    Ok(args.len() != before)
+}
+fn take_global_option(
+    args: &mut Vec<OsString>,
+    option: &str,
+    expected: &str,
+) -> Result<Option<OsString>> {
+    let Some(index) = args.iter().position(|arg| arg == OsStr::new(option)) else {
+        return Ok(None);
+    };
+    args.remove(index);
+    if index >= args.len() {
+        return Err(err(format!(
+            "{option} 뒤에 {expected} 필요합니다\n\n{HELP_TEXT}"
+        )));
+    }
+    let value = args.remove(index);
+    if let Some(duplicate) = args.iter().find(|arg| *arg == OsStr::new(option)) {
+        return Err(unknown_option(duplicate));
+    }
+    Ok(Some(value))
+}
+fn unknown_option(token: &OsStr) -> AppError {
+    err(format!(
+        "알 수 없는 옵션: {}\n\n{HELP_TEXT}",
+        token.to_string_lossy()
+    ))
+}
+fn unknown_value(option: &str, value: &OsStr) -> AppError {
+    err(format!(
+        "알 수 없는 {option} 값: {}\n\n{HELP_TEXT}",
+        value.to_string_lossy()
+    ))
+}
@@ -1,3 +1,4 @@
+use crate::{i18n::Lang, json::append_json_string};
 use alloc::borrow::Cow;
 use core::{
     error::Error,
@@ -7,21 +8,89 @@ use core::{
 use std::{io::Error as IoError, path::Path};
 type BoxError = Box<dyn Error + Send + Sync>;
 pub(super) type Result<T> = CoreResult<T, AppError>;
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub(super) enum ErrorCategory {
+    Download,
+    LockHeld,
+    MasterStructure,
+    Other,
+    RegionValidation,
+    SafetyThreshold,
+    Save,
+    SourceFormat,
+    Usage,
+}
 pub(super) struct AppError {
+    category: Option<ErrorCategory>,
     message: Cow<'static, str>,
     source: Option<BoxError>,
 }
+struct ErrorChain<'error> {
+    next: Option<&'error (dyn Error + 'static)>,
+}
+pub(super) struct ErrorReport<'error> {
+    pub error: &'error AppError,
+    pub lang: Lang,
+}
+pub(super) struct LocalizedError<'error> {
+    pub error: &'error AppError,
+    pub lang: Lang,
+}
 struct ControlEscapingWriter<'formatter, 'output>(&'formatter mut fmt::Formatter<'output>);
 struct TerminalSafeDisplay<'value, T: ?Sized>(&'value T);
+impl ErrorCategory {
+    pub(super) const fn code(self) -> &'static str {
+        match self {
+            Self::Download => "download",
+            Self::LockHeld => "lock_held",
+            Self::MasterStructure => "master_structure",
+            Self::Other => "other",
+            Self::RegionValidation => "region_validation",
+            Self::SafetyThreshold => "safety_threshold",
+            Self::Save => "save",
+            Self::SourceFormat => "source_format",
+            Self::Usage => "usage",
+        }
+    }
+    pub(super) const fn exit_code(self) -> u8 {
+        match self {
+            Self::Other => 1,
+            Self::Usage => 2,
+            Self::Download => 10,
+            Self::SourceFormat => 11,
+            Self::RegionValidation => 12,
+            Self::MasterStructure => 13,
+            Self::SafetyThreshold => 14,
+            Self::LockHeld => 15,
+            Self::Save => 16,
+        }
+    }
+}
 impl AppError {
+    pub(super) fn category(&self) -> ErrorCategory {
+        let mut category = self.category;
+        let mut next = self.source();
+        while let Some(current) = next {
+            if let Some(inner) = current
+                .downcast_ref::<Self>()
+                .and_then(|app_error| app_error.category)
+            {
+                category = Some(inner);
+            }
+            next = current.source();
+        }
+        category.unwrap_or(ErrorCategory::Other)
+    }
     fn context(context: impl Into<Cow<'static, str>>, source: impl Into<BoxError>) -> Self {
         Self {
+            category: None,
             message: context.into(),
             source: Some(source.into()),
         }
     }
     fn message(message: impl Into<Cow<'static, str>>) -> Self {
         Self {
+            category: None,
             message: message.into(),
             source: None,
         }
@@ -29,6 +98,10 @@ impl AppError {
     pub(super) fn update_message(&mut self, update: impl FnOnce(&str) -> String) {
         self.message = Cow::Owned(update(self.message.as_ref()));
     }
+    pub(super) fn with_category(mut self, category: ErrorCategory) -> Self {
+        self.category.get_or_insert(category);
+        self
+    }
 }
 impl Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -40,6 +113,60 @@ impl Display for AppError {
         Ok(())
     }
 }
+impl Display for ErrorReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let category = self.error.category();
+        let mut json = String::from("{\"category\":");
+        append_json_string(&mut json, category.code());
+        let chain: Vec<String> = ErrorChain {
+            next: Some(self.error),
+        }
+        .map(|message| self.lang.translate(&message).into_owned())
+        .collect();
+        append_fmt(
+            &mut json,
+            format_args!(",\"exit_code\":{},\"message\":", category.exit_code()),
+        );
+        append_json_string(&mut json, &chain.join(": "));
+        json.push_str(",\"chain\":[");
+        for (index, message) in chain.iter().enumerate() {
+            if index != 0 {
+                json.push(',');
+            }
+            append_json_string(&mut json, message);
+        }
+        json.push_str("]}");
+        f.write_str(&json)
+    }
+}
+impl Display for LocalizedError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.lang == Lang::Ko {
+            return Display::fmt(self.error, f);
+        }
+        let chain = ErrorChain {
+            next: Some(self.error),
+        };
+        for (index, message) in chain.enumerate() {
+            if index != 0 {
+                f.write_str(": ")?;
+            }
+            write_control_escaped(f, &self.lang.translate(&message))?;
+        }
+        Ok(())
+    }
+}
+impl Iterator for ErrorChain<'_> {
+    type Item = String;
+    fn next(&mut self) -> Option<String> {
+        let current = self.next?;
+        self.next = current.source();
+        Some(current.downcast_ref::<AppError>().map_or_else(
+            || current.to_string(),
+            |app_error| app_error.message.clone().into_owned(),
+        ))
+    }
+}
 impl fmt::Write for ControlEscapingWriter<'_, '_> {
     fn write_str(&mut self, s: &str) -> fmt::Result {
         write_control_escaped(self.0, s)
@@ -89,6 +216,10 @@ pub(super) fn err_with_source(
 ) -> AppError {
     AppError::context(context, source)
 }
+#[cfg(test)]
+pub(super) fn ensure(condition: bool, msg: impl Into<Cow<'static, str>>) -> Result<()> {
+    if condition { Ok(()) } else { Err(err(msg)) }
+}
 pub(super) fn try_string_with_capacity(capacity: usize, context: &'static str) -> Result<String> {
     let mut value = String::new();
     value
@@ -0,0 +1,372 @@
+use crate::{
+    diagnostic::{
+        ErrorCategory, Result, append_fmt, err, err_with_source, path_context_message,
+        terminal_safe, try_vec_with_capacity,
+    },
+    excel::writer::Workbook as StdWorkbook,
+    json::append_json_string,
+    master_sheet::{MasterStation, MasterStations},
+    write_line,
+};
+use core::fmt::{self, Display};
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    io::Write,
+    path::Path,
+};
+#[derive(Clone, Copy)]
+pub(super) enum DiffFormat {
+    Human,
+    Json,
+}
+#[derive(Clone, Copy)]
+enum StationField {
+    Brand,
+    Diesel,
+    Gasoline,
+    Name,
+    Premium,
+    Region,
+    SelfService,
+}
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum FieldValue<'station> {
+    Price(Option<i32>),
+    Text(&'station str),
+}
+pub(super) struct DiffRun<'out> {
+    pub format: DiffFormat,
+    pub new_path: &'out Path,
+    pub old_path: &'out Path,
+    pub out: &'out mut dyn Write,
+}
+struct StationChange<'station> {
+    fields: Vec<(StationField, FieldValue<'station>, FieldValue<'station>)>,
+    new: &'station MasterStation,
+    old: &'station MasterStation,
+}
+struct WorkbookDiff<'station> {
+    added: Vec<&'station MasterStation>,
+    changed: Vec<StationChange<'station>>,
+    new: &'station MasterStations,
+    old: &'station MasterStations,
+    removed: Vec<&'station MasterStation>,
+}
+impl StationField {
+    const ALL: [Self; 7] = [
+        Self::Region,
+        Self::Name,
+        Self::Brand,
+        Self::SelfService,
+        Self::Gasoline,
+        Self::Premium,
+        Self::Diesel,
+    ];
+    const fn key(self) -> &'static str {
+        match self {
+            Self::Brand => "brand",
+            Self::Diesel => "diesel",
+            Self::Gasoline => "gasoline",
+            Self::Name => "name",
+            Self::Premium => "premium",
+            Self::Region => "region",
+            Self::SelfService => "self_service",
+        }
+    }
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Brand => "상표",
+            Self::Diesel => "경유",
+            Self::Gasoline => "휘발유",
+            Self::Name => "상호",
+            Self::Premium => "고급휘발유",
+            Self::Region => "지역",
+            Self::SelfService => "셀프여부",
+        }
+    }
+    fn value(self, station: &MasterStation) -> FieldValue<'_> {
+        match self {
+            Self::Brand => FieldValue::Text(&station.brand),
+            Self::Diesel => FieldValue::Price(station.fuels.diesel),
+            Self::Gasoline => FieldValue::Price(station.fuels.gasoline),
+            Self::Name => FieldValue::Text(&station.name),
+            Self::Premium => FieldValue::Price(station.fuels.premium),
+            Self::Region => FieldValue::Text(&station.region),
+            Self::SelfService => FieldValue::Text(&station.self_service),
+        }
+    }
+}
+impl FieldValue<'_> {
+    fn append_json(self, out: &mut String) {
+        match self {
+            Self::Price(Some(price)) => append_fmt(out, format_args!("{price}")),
+            Self::Price(None) => out.push_str("null"),
+            Self::Text(text) => append_json_string(out, text),
+        }
+    }
+}
+impl Display for FieldValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Price(Some(price)) => write!(f, "{price}"),
+            Self::Price(None) => f.write_str("-"),
+            Self::Text("") => f.write_str("(빈 값)"),
+            Self::Text(text) => write!(f, "{}", terminal_safe(text)),
+        }
+    }
+}
+impl DiffRun<'_> {
+    fn load(path: &Path) -> Result<MasterStations> {
+        StdWorkbook::open_input(path)
+            .and_then(|book| MasterStations::try_from(&book))
+            .map_err(|source| {
+                err_with_source(
+                    path_context_message("비교 대상 워크북 읽기 실패", path),
+                    source,
+                )
+                .with_category(ErrorCategory::MasterStructure)
+            })
+    }
+    pub(super) fn run(&mut self) -> Result<()> {
+        let old = Self::load(self.old_path)?;
+        let new = Self::load(self.new_path)?;
+        let mut old_by_address: HashMap<&str, &MasterStation> = HashMap::new();
+        old_by_address
+            .try_reserve(old.stations.len())
+            .map_err(|source| err_with_source("비교 주소 맵 메모리 확보 실패", source))?;
+        for station in &old.stations {
+            match old_by_address.entry(station.address_key.as_str()) {
+                Entry::Occupied(entry) => {
+                    return Err(err(format!(
+                        "기존 워크북 주소 중복: normalized_address={}, first_row={}, duplicate_row={}",
+                        entry.key(),
+                        entry.get().row,
+                        station.row
+                    )));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(station);
+                }
+            }
+        }
+        let mut new_rows: HashMap<&str, u32> = HashMap::new();
+        new_rows
+            .try_reserve(new.stations.len())
+            .map_err(|source| err_with_source("비교 주소 맵 메모리 확보 실패", source))?;
+        let mut diff = WorkbookDiff {
+            added: Vec::new(),
+            changed: Vec::new(),
+            new: &new,
+            old: &old,
+            removed: Vec::new(),
+        };
+        for station in &new.stations {
+            match new_rows.entry(station.address_key.as_str()) {
+                Entry::Occupied(entry) => {
+                    return Err(err(format!(
+                        "비교 워크북 주소 중복: normalized_address={}, first_row={}, duplicate_row={}",
+                        entry.key(),
+                        entry.get(),
+                        station.row
+                    )));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(station.row);
+                }
+            }
+            let Some(old_station) = old_by_address.remove(station.address_key.as_str()) else {
+                diff.added.push(station);
+                continue;
+            };
+            let mut fields =
+                try_vec_with_capacity(StationField::ALL.len(), "비교 변경 항목 메모리 확보 실패")?;
+            for field in StationField::ALL {
+                let old_value = field.value(old_station);
+                let new_value = field.value(station);
+                if old_value != new_value {
+                    fields.push((field, old_value, new_value));
+                }
+            }
+            if !fields.is_empty() {
+                diff.changed.push(StationChange {
+                    fields,
+                    new: station,
+                    old: old_station,
+                });
+            }
+        }
+        diff.removed.extend(
+            old.stations
+                .iter()
+                .filter(|station| old_by_address.contains_key(station.address_key.as_str())),
+        );
+        match self.format {
+            DiffFormat::Human => self.write_human(&diff),
+            DiffFormat::Json => self.write_json(&diff),
+        }
+    }
+    fn write_human(&mut self, diff: &WorkbookDiff<'_>) -> Result<()> {
+        write_line(self.out, format_args!("==== 워크북 비교 ===="))?;
+        write_line(
+            self.out,
+            format_args!(
+                "- 기존 파일: {} (업체 {}건)",
+                terminal_safe(&self.old_path.display()),
+                diff.old.stations.len()
+            ),
+        )?;
+        write_line(
+            self.out,
+            format_args!(
+                "- 비교 파일: {} (업체 {}건)",
+                terminal_safe(&self.new_path.display()),
+                diff.new.stations.len()
+            ),
+        )?;
+        if diff.old.unaddressed_count != 0 || diff.new.unaddressed_count != 0 {
+            write_line(
+                self.out,
+                format_args!(
+                    "- 주소 없는 행(비교 제외): 기존 {}건 / 비교 {}건",
+                    diff.old.unaddressed_count, diff.new.unaddressed_count
+                ),
+            )?;
+        }
+        write_line(
+            self.out,
+            format_args!("- 정보 변경: {}건", diff.changed.len()),
+        )?;
+        write_line(
+            self.out,
+            format_args!("- 신규 업체: {}건", diff.added.len()),
+        )?;
+        write_line(
+            self.out,
+            format_args!("- 삭제 업체: {}건", diff.removed.len()),
+        )?;
+        if diff.changed.is_empty() && diff.added.is_empty() && diff.removed.is_empty() {
+            write_line(self.out, format_args!("\n차이가 없습니다."))?;
+        }
+        if !diff.changed.is_empty() {
+            write_line(self.out, format_args!("\n정보 변경 목록"))?;
+        }
+        for (display_index, change) in (1_usize..).zip(&diff.changed) {
+            write_line(
+                self.out,
+                format_args!(
+                    "  {display_index}. {} / {} / {} (행 {} -> {})",
+                    terminal_safe(&change.new.region),
+                    terminal_safe(&change.new.name),
+                    terminal_safe(&change.new.address),
+                    change.old.row,
+                    change.new.row
+                ),
+            )?;
+            for &(field, old_value, new_value) in &change.fields {
+                write_line(
+                    self.out,
+                    format_args!("     {}: {old_value} -> {new_value}", field.label()),
+                )?;
+            }
+        }
+        for (title, stations) in [
+            ("신규 업체 목록", &diff.added),
+            ("삭제 업체 목록", &diff.removed),
+        ] {
+            if stations.is_empty() {
+                continue;
+            }
+            write_line(self.out, format_args!("\n{title}"))?;
+            for (display_index, station) in (1_usize..).zip(stations) {
+                write_line(
+                    self.out,
+                    format_args!(
+                        "  {display_index}. {} / {} / {} / 휘발유 {} / 고급휘발유 {} / 경유 {}",
+                        terminal_safe(&station.region),
+                        terminal_safe(&station.name),
+                        terminal_safe(&station.address),
+                        FieldValue::Price(station.fuels.gasoline),
+                        FieldValue::Price(station.fuels.premium),
+                        FieldValue::Price(station.fuels.diesel)
+                    ),
+                )?;
+            }
+        }
+        write_line(self.out, format_args!("====================="))?;
+        Ok(())
+    }
+    fn write_json(&mut self, diff: &WorkbookDiff<'_>) -> Result<()> {
+        let mut json = String::new();
+        for (prefix, path, stations) in [
+            ("{\"old\":", self.old_path, diff.old),
+            (",\"new\":", self.new_path, diff.new),
+        ] {
+            json.push_str(prefix);
+            json.push_str("{\"path\":");
+            append_json_string(&mut json, &path.to_string_lossy());
+            append_fmt(
+                &mut json,
+                format_args!(
+                    ",\"station_count\":{},\"unaddressed_count\":{}}}",
+                    stations.stations.len(),
+                    stations.unaddressed_count
+                ),
+            );
+        }
+        json.push_str(",\"changed\":[");
+        for (index, change) in diff.changed.iter().enumerate() {
+            if index != 0 {
+                json.push(',');
+            }
+            json.push_str("{\"address\":");
+            append_json_string(&mut json, &change.new.address);
+            json.push_str(",\"name\":");
+            append_json_string(&mut json, &change.new.name);
+            json.push_str(",\"region\":");
+            append_json_string(&mut json, &change.new.region);
+            append_fmt(
+                &mut json,
+                format_args!(
+                    ",\"old_row\":{},\"new_row\":{},\"changes\":[",
+                    change.old.row, change.new.row
+                ),
+            );
+            for (field_index, &(field, old_value, new_value)) in change.fields.iter().enumerate() {
+                if field_index != 0 {
+                    json.push(',');
+                }
+                append_fmt(
+                    &mut json,
+                    format_args!("{{\"field\":\"{}\",\"old\":", field.key()),
+                );
+                old_value.append_json(&mut json);
+                json.push_str(",\"new\":");
+                new_value.append_json(&mut json);
+                json.push('}');
+            }
+            json.push_str("]}");
+        }
+        for (prefix, stations) in [
+            ("],\"added\":[", &diff.added),
+            ("],\"removed\":[", &diff.removed),
+        ] {
+            json.push_str(prefix);
+            for (index, station) in stations.iter().enumerate() {
+                if index != 0 {
+                    json.push(',');
+                }
+                append_fmt(&mut json, format_args!("{{\"row\":{}", station.row));
+                json.push_str(",\"address\":");
+                append_json_string(&mut json, &station.address);
+                for field in StationField::ALL {
+                    append_fmt(&mut json, format_args!(",\"{}\":", field.key()));
+                    field.value(station).append_json(&mut json);
+                }
+                json.push('}');
+            }
+        }
+        json.push_str("]}");
+        write_line(self.out, format_args!("{json}"))?;
+        Ok(())
+    }
+}
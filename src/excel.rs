@@ -1,8 +1,13 @@
-pub(super) use self::source_reader::{FuelValues, SourceReader, SourceRecord};
+pub(super) use self::source_reader::{
+    FuelValues, SourceReader, SourceRecord, SourceRecordRef, StationService,
+    parse_source_fuel_price,
+};
 use crate::diagnostic::{Result, try_string_with_capacity};
+use alloc::borrow::Cow;
 #[cfg(any(target_os = "linux", target_os = "macos"))]
 use std::fs::Permissions;
 use std::{fs::File, path::Path};
+pub mod reader;
 mod source_reader;
 pub(super) mod writer;
 pub(super) mod xlsx_container;
@@ -16,7 +21,7 @@ pub(super) const MASTER_SHEET_NAME: &str = "유류비";
 pub(super) const MASTER_SHEET_PATH: &str = "xl/worksheets/sheet1.xml";
 pub(super) const CALC_CHAIN_PATH: &str = "xl/calcChain.xml";
 pub(super) const MAX_XLSX_PART_BYTES: usize = 64 * 1024 * 1024;
-const XLSX_PARTS: [(&str, XlsxPartRole); 16] = [
+const XLSX_PARTS: [(&str, XlsxPartRole); 15] = [
     ("[Content_Types].xml", XlsxPartRole::Required),
     ("_rels/.rels", XlsxPartRole::Required),
     ("xl/workbook.xml", XlsxPartRole::Required),
@@ -30,12 +35,11 @@ const XLSX_PARTS: [(&str, XlsxPartRole); 16] = [
     (CALC_CHAIN_PATH, XlsxPartRole::InputOnly),
     ("docProps/core.xml", XlsxPartRole::Required),
     ("docProps/app.xml", XlsxPartRole::Required),
-    ("docProps/custom.xml", XlsxPartRole::InputOnly),
+    ("docProps/custom.xml", XlsxPartRole::OptionalInput),
     (
         "xl/worksheets/_rels/sheet1.xml.rels",
-        XlsxPartRole::InputOnly,
+        XlsxPartRole::OptionalInput,
     ),
-    ("xl/drawings/drawing1.xml", XlsxPartRole::InputOnly),
 ];
 #[derive(Clone, Copy, Eq, PartialEq)]
 enum XlsxPartRole {
@@ -67,7 +71,7 @@ struct ArchiveFingerprint {
 }
 struct PackagePart {
     bytes: Vec<u8>,
-    name: &'static str,
+    name: Cow<'static, str>,
 }
 struct ZipArchiveBuilder<'part, 'path> {
     archive_path: &'path Path,
@@ -76,6 +80,7 @@ struct ZipArchiveBuilder<'part, 'path> {
     #[cfg(any(target_os = "linux", target_os = "macos"))]
     permissions: Permissions,
 }
+struct ZipArchiveView<'bytes>(&'bytes [u8]);
 struct ZipPackageReader<'path> {
     archive_file: File,
     archive_path: &'path Path,
@@ -0,0 +1,593 @@
+//! 임의의 xlsx 워크북을 시트 이름으로 열거하고 셀 값, 수식, 계산값을 읽습니다.
+use super::{
+    ZipArchiveView,
+    writer::cell_ref::shift_formula,
+    xml::{
+        XmlAttrScanner, XmlScanner, decode_xml_entities, extract_all_tag_text, extract_attr,
+        extract_first_tag_text,
+    },
+    zip_archive::ZIP_MAX_ARCHIVE_BYTES,
+};
+use crate::{
+    UpdaterError,
+    diagnostic::{Result, err, err_with_source, path_context_message},
+    temp_entry::open_regular,
+};
+use alloc::borrow::Cow;
+use core::{fmt, result::Result as CoreResult};
+use std::{collections::HashMap, io::Read as _, path::Path};
+#[cfg(test)]
+mod tests;
+const MAX_SHARED_STRING_COUNT: usize = 16 * 1024 * 1024;
+/// Transitional과 Strict OOXML의 문서 관계 namespace입니다.
+const RELATIONSHIPS_NAMESPACES: [&str; 2] = [
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+    "http://purl.oclc.org/ooxml/officeDocument/relationships",
+];
+const SHARED_STRINGS_PATH: &str = "xl/sharedStrings.xml";
+const SHARED_STRING_GROWTH: usize = 256;
+const SHEET_MAX_COL: usize = 0x4000;
+const SHEET_MAX_ROW: usize = 0x0010_0000;
+const WORKBOOK_PATH: &str = "xl/workbook.xml";
+const WORKBOOK_RELS_PATH: &str = "xl/_rels/workbook.xml.rels";
+/// 셀 하나의 위치, 수식과 값입니다.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Cell {
+    /// 1부터 시작하는 열 번호(A열이 1).
+    pub col: u32,
+    /// 수식 텍스트(앞의 `=` 제외). 공유 수식은 이 셀 위치에 맞게 옮긴 수식입니다.
+    pub formula: Option<String>,
+    /// 1부터 시작하는 행 번호.
+    pub row: u32,
+    /// 셀 값. 수식 셀이면 마지막으로 저장된 계산값입니다.
+    pub value: CellValue,
+}
+/// 셀에 저장된 값입니다.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum CellValue {
+    /// 논리값.
+    Bool(bool),
+    /// 계산값이 저장되지 않은 수식 셀.
+    Empty,
+    /// `#N/A`, `#DIV/0!` 같은 오류 값.
+    Error(String),
+    /// 숫자. 날짜와 시각도 Excel 일련번호로 표시됩니다.
+    Number(f64),
+    /// 문자열.
+    Text(String),
+}
+/// 한 시트에서 값이나 수식이 있는 셀 목록입니다.
+#[derive(Clone, Debug)]
+pub struct Sheet {
+    cells: Vec<Cell>,
+    name: String,
+}
+/// 읽기 전용으로 연 xlsx 워크북입니다.
+pub struct Workbook {
+    archive: Vec<u8>,
+    shared_strings: Vec<String>,
+    sheets: Vec<SheetEntry>,
+}
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub(super) enum RawCellKind {
+    Bool,
+    Error,
+    Number,
+    Text,
+}
+pub(super) struct RawCell<'xml> {
+    pub col: usize,
+    pub formula: Option<Cow<'xml, str>>,
+    pub kind: RawCellKind,
+    pub text: Option<Cow<'xml, str>>,
+}
+pub(super) struct SheetEntry {
+    pub name: String,
+    pub path: String,
+}
+pub(super) struct SheetLimits {
+    pub max_col: usize,
+    pub max_row: usize,
+}
+struct SharedFormula {
+    anchor_col: usize,
+    anchor_row: usize,
+    formula: String,
+}
+impl Cell {
+    /// 숫자 값이면 그 값을 돌려줍니다.
+    #[inline]
+    #[must_use]
+    pub const fn number(&self) -> Option<f64> {
+        match self.value {
+            CellValue::Number(number) => Some(number),
+            CellValue::Bool(_) | CellValue::Empty | CellValue::Error(_) | CellValue::Text(_) => {
+                None
+            }
+        }
+    }
+    /// `B3` 형식의 셀 주소입니다.
+    #[inline]
+    #[must_use]
+    pub fn reference(&self) -> String {
+        let mut letters = Vec::new();
+        let mut col = self.col;
+        while col > 0 {
+            let index = col.strict_sub(1);
+            letters.push(b'A'.strict_add(u8::try_from(index.rem_euclid(26)).unwrap_or(0)));
+            col = index.div_euclid(26);
+        }
+        letters.reverse();
+        let mut reference = String::from_utf8(letters).unwrap_or_default();
+        reference.push_str(&self.row.to_string());
+        reference
+    }
+    /// 값을 문자열로 돌려줍니다. 숫자는 십진 표기, 논리값은 `TRUE`/`FALSE`입니다.
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> String {
+        match self.value.clone() {
+            CellValue::Bool(value) => String::from(if value { "TRUE" } else { "FALSE" }),
+            CellValue::Empty => String::new(),
+            CellValue::Error(text) | CellValue::Text(text) => text,
+            CellValue::Number(number) => number.to_string(),
+        }
+    }
+}
+impl Sheet {
+    /// `B3` 형식(`$B$3`도 허용)의 주소로 셀을 찾습니다. 값도 수식도 없는 셀이면 `None`입니다.
+    #[inline]
+    #[must_use]
+    pub fn cell(&self, reference: &str) -> Option<&Cell> {
+        let unlocked = reference.replace('$', "");
+        let split = unlocked.find(|ch: char| ch.is_ascii_digit())?;
+        let (letters, digits) = unlocked.split_at(split);
+        if letters.is_empty() || letters.len() > 3 {
+            return None;
+        }
+        let mut col = 0_u32;
+        for letter in letters.bytes() {
+            if !letter.is_ascii_alphabetic() {
+                return None;
+            }
+            col = col
+                .strict_mul(26)
+                .strict_add(u32::from(letter.to_ascii_uppercase().strict_sub(b'A')).strict_add(1));
+        }
+        self.cell_at(digits.parse().ok()?, col)
+    }
+    /// 1부터 시작하는 행·열 번호로 셀을 찾습니다.
+    #[inline]
+    #[must_use]
+    pub fn cell_at(&self, row: u32, col: u32) -> Option<&Cell> {
+        let index = self
+            .cells
+            .binary_search_by(|cell| (cell.row, cell.col).cmp(&(row, col)))
+            .ok()?;
+        self.cells.get(index)
+    }
+    /// 값이나 수식이 있는 셀을 행 우선 순서로 돌려줍니다.
+    #[inline]
+    #[must_use]
+    pub fn cells(&self) -> &[Cell] {
+        &self.cells
+    }
+    /// 시트 이름입니다.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+impl fmt::Debug for Workbook {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Workbook")
+            .field("sheets", &self.sheet_names().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+impl Workbook {
+    /// 메모리에 읽어 둔 xlsx 바이트로 워크북을 엽니다.
+    ///
+    /// # Errors
+    ///
+    /// ZIP 구조나 워크북 목록(`xl/workbook.xml`), 공유 문자열을 읽을 수 없으면 오류를 돌려줍니다.
+    #[inline]
+    pub fn from_bytes(archive: Vec<u8>) -> CoreResult<Self, UpdaterError> {
+        let sheets = sheet_entries(&archive).map_err(UpdaterError)?;
+        let shared_strings =
+            shared_strings(&archive, MAX_SHARED_STRING_COUNT).map_err(UpdaterError)?;
+        Ok(Self {
+            archive,
+            shared_strings,
+            sheets,
+        })
+    }
+    /// 경로의 xlsx 파일을 엽니다.
+    ///
+    /// # Errors
+    ///
+    /// 파일을 읽을 수 없거나 크기가 한도를 넘거나 [`Workbook::from_bytes`]가 실패하면 오류를 돌려줍니다.
+    #[inline]
+    pub fn open(path: &Path) -> CoreResult<Self, UpdaterError> {
+        let read = || -> Result<Vec<u8>> {
+            let file = open_regular(path, false)?.file;
+            let limit = u64::try_from(ZIP_MAX_ARCHIVE_BYTES)
+                .map_err(|source| err_with_source("xlsx 크기 한도 변환 실패", source))?;
+            let mut archive = Vec::new();
+            file.take(limit.strict_add(1)).read_to_end(&mut archive)?;
+            if archive.len() > ZIP_MAX_ARCHIVE_BYTES {
+                return Err(err(format!(
+                    "xlsx 파일 크기가 허용 한도({ZIP_MAX_ARCHIVE_BYTES} bytes)를 초과했습니다."
+                )));
+            }
+            Ok(archive)
+        };
+        let archive = read()
+            .map_err(|source| {
+                err_with_source(path_context_message("xlsx 파일 읽기 실패", path), source)
+            })
+            .map_err(UpdaterError)?;
+        Self::from_bytes(archive)
+    }
+    /// 이름으로 시트를 읽습니다.
+    ///
+    /// # Errors
+    ///
+    /// 시트가 없거나 시트 XML이 올바르지 않으면 오류를 돌려줍니다.
+    #[inline]
+    pub fn sheet(&self, name: &str) -> CoreResult<Sheet, UpdaterError> {
+        let read = || -> Result<Sheet> {
+            let entry = self
+                .sheets
+                .iter()
+                .find(|entry| entry.name == name)
+                .ok_or_else(|| err(format!("xlsx에 시트가 없습니다: {name}")))?;
+            let sheet_xml = part_text(&self.archive, &entry.path)?
+                .ok_or_else(|| err(format!("xlsx sheet part가 없습니다: {}", entry.path)))?;
+            let mut cells = Vec::new();
+            let mut row_cells = Vec::new();
+            visit_rows(
+                &sheet_xml,
+                &self.shared_strings,
+                &SheetLimits {
+                    max_col: SHEET_MAX_COL,
+                    max_row: SHEET_MAX_ROW,
+                },
+                &mut row_cells,
+                |row_num, raw_cells| {
+                    let row = u32::try_from(row_num)
+                        .map_err(|source| err_with_source("xlsx 행 번호 변환 실패", source))?;
+                    for raw_cell in raw_cells.drain(..) {
+                        let value = match (raw_cell.kind, raw_cell.text) {
+                            (_, None) => CellValue::Empty,
+                            (RawCellKind::Bool, Some(text)) => CellValue::Bool(text.trim() == "1"),
+                            (RawCellKind::Error, Some(text)) => CellValue::Error(text.into_owned()),
+                            (RawCellKind::Number, Some(text)) => {
+                                CellValue::Number(text.trim().parse().map_err(|source| {
+                                    err_with_source(
+                                        format!(
+                                            "xlsx 숫자 셀 값 해석 실패: row={row_num}, value={text}"
+                                        ),
+                                        source,
+                                    )
+                                })?)
+                            }
+                            (RawCellKind::Text, Some(text)) => CellValue::Text(text.into_owned()),
+                        };
+                        cells.push(Cell {
+                            col: u32::try_from(raw_cell.col.strict_add(1)).map_err(|source| {
+                                err_with_source("xlsx 열 번호 변환 실패", source)
+                            })?,
+                            formula: raw_cell.formula.map(Cow::into_owned),
+                            row,
+                            value,
+                        });
+                    }
+                    Ok(())
+                },
+            )?;
+            Ok(Sheet {
+                cells,
+                name: entry.name.clone(),
+            })
+        };
+        read().map_err(UpdaterError)
+    }
+    /// 워크북에 정의된 순서대로 시트 이름을 돌려줍니다.
+    #[inline]
+    pub fn sheet_names(&self) -> impl Iterator<Item = &str> {
+        self.sheets.iter().map(|entry| entry.name.as_str())
+    }
+}
+pub(super) fn part_text(archive: &[u8], name: &str) -> Result<Option<String>> {
+    let Some(bytes) = ZipArchiveView(archive).read_entry(name)? else {
+        return Ok(None);
+    };
+    let mut text = String::from_utf8(bytes)
+        .map_err(|source| err_with_source(format!("xlsx {name} UTF-8 변환 실패"), source))?;
+    if text.starts_with('\u{feff}') {
+        text.replace_range(..'\u{feff}'.len_utf8(), "");
+    }
+    Ok(Some(text))
+}
+/// 태그에서 문서 관계 namespace에 묶인 접두사를 찾아 `접두사:id` 속성 이름을 돌려줍니다.
+fn relationship_id_attr(tag: &str) -> Result<Option<String>> {
+    let mut scanner = XmlAttrScanner::new(tag)?;
+    while let Some((name, value)) = scanner.next()? {
+        if let Some(prefix) = name.strip_prefix("xmlns:")
+            && RELATIONSHIPS_NAMESPACES.contains(&value.as_ref())
+        {
+            return Ok(Some(format!("{prefix}:id")));
+        }
+    }
+    Ok(None)
+}
+pub(super) fn shared_strings(archive: &[u8], max_count: usize) -> Result<Vec<String>> {
+    let Some(xml) = part_text(archive, SHARED_STRINGS_PATH)? else {
+        return Ok(Vec::new());
+    };
+    let mut values = Vec::new();
+    let mut scanner = XmlScanner::new(&xml);
+    while let Some(si) = scanner.next_element_named("si")? {
+        if values.len() >= max_count {
+            return Err(err(format!(
+                "xlsx sharedStrings 개수가 허용 한도({max_count})를 초과했습니다."
+            )));
+        }
+        if values.len() == values.capacity() {
+            values
+                .try_reserve(SHARED_STRING_GROWTH)
+                .map_err(|source| err_with_source("xlsx sharedStrings 메모리 확보 실패", source))?;
+        }
+        let value = extract_all_tag_text(si.body, "t")?.unwrap_or(Cow::Borrowed(""));
+        values.push(value.into_owned());
+    }
+    Ok(values)
+}
+pub(super) fn sheet_entries(archive: &[u8]) -> Result<Vec<SheetEntry>> {
+    let workbook_xml = part_text(archive, WORKBOOK_PATH)?
+        .ok_or_else(|| err("xlsx에 xl/workbook.xml이 없습니다."))?;
+    let rels_xml = part_text(archive, WORKBOOK_RELS_PATH)?
+        .ok_or_else(|| err("xlsx에 xl/_rels/workbook.xml.rels가 없습니다."))?;
+    let mut targets = HashMap::new();
+    let mut rels_scanner = XmlScanner::new(&rels_xml);
+    while let Some(relationship) = rels_scanner.next_start_named("Relationship") {
+        if let (Some(id), Some(target)) = (
+            extract_attr(relationship.raw, "Id")?,
+            extract_attr(relationship.raw, "Target")?,
+        ) {
+            targets.insert(id, target);
+        }
+    }
+    let mut scope_id_attr = None;
+    for scope in ["workbook", "sheets"] {
+        if let Some(tag) = XmlScanner::new(&workbook_xml).next_start_named(scope)
+            && let Some(id_attr) = relationship_id_attr(tag.raw)?
+        {
+            scope_id_attr = Some(id_attr);
+        }
+    }
+    let mut sheets = Vec::new();
+    let mut scanner = XmlScanner::new(&workbook_xml);
+    while let Some(sheet) = scanner.next_start_named("sheet") {
+        let name = extract_attr(sheet.raw, "name")?
+            .ok_or_else(|| err("xlsx workbook.xml의 sheet에 name 속성이 없습니다."))?;
+        let id_attr = relationship_id_attr(sheet.raw)?
+            .or_else(|| scope_id_attr.clone())
+            .ok_or_else(|| {
+                err(format!(
+                    "xlsx sheet의 relationships namespace 선언을 찾지 못했습니다: {name}"
+                ))
+            })?;
+        let relationship_id = extract_attr(sheet.raw, &id_attr)?
+            .ok_or_else(|| err(format!("xlsx sheet에 {id_attr} 속성이 없습니다: {name}")))?;
+        let target = targets.get(&relationship_id).ok_or_else(|| {
+            err(format!(
+                "xlsx workbook relationship을 찾지 못했습니다: {relationship_id}"
+            ))
+        })?;
+        if target.contains("..") || target.contains('\\') {
+            return Err(err(format!(
+                "xlsx sheet 경로가 올바르지 않습니다: {target}"
+            )));
+        }
+        let path = target
+            .strip_prefix('/')
+            .map_or_else(|| format!("xl/{target}"), ToOwned::to_owned);
+        sheets.push(SheetEntry {
+            name: name.into_owned(),
+            path,
+        });
+    }
+    if sheets.is_empty() {
+        return Err(err("xlsx workbook.xml에서 sheet를 찾지 못했습니다."));
+    }
+    Ok(sheets)
+}
+pub(super) fn visit_rows<'xml>(
+    sheet_xml: &'xml str,
+    shared_strings: &'xml [String],
+    limits: &SheetLimits,
+    row_cells: &mut Vec<RawCell<'xml>>,
+    mut visitor: impl FnMut(usize, &mut Vec<RawCell<'xml>>) -> Result<()>,
+) -> Result<()> {
+    let sheet_data = XmlScanner::new(sheet_xml)
+        .next_element_named("sheetData")?
+        .ok_or_else(|| err("xlsx sheet에 sheetData가 없습니다."))?;
+    let mut shared_formulas: HashMap<Cow<'xml, str>, SharedFormula> = HashMap::new();
+    let mut row_scanner = XmlScanner::new(sheet_data.body);
+    let mut previous_row = 0_usize;
+    while let Some(row_element) = row_scanner.next_element_named("row")? {
+        let row_num = match extract_attr(row_element.opening.raw, "r")? {
+            Some(row_text) => row_text.parse::<usize>().map_err(|source| {
+                err_with_source(format!("xlsx 행 번호 해석 실패: {row_text}"), source)
+            })?,
+            None => previous_row.strict_add(1),
+        };
+        if row_num <= previous_row {
+            return Err(err(format!(
+                "xlsx 행 순서가 올바르지 않습니다: row={row_num}"
+            )));
+        }
+        if row_num > limits.max_row {
+            return Err(err(format!(
+                "시트 행 인덱스가 비정상적으로 큽니다: {row_num} (최대 {})",
+                limits.max_row
+            )));
+        }
+        previous_row = row_num;
+        row_cells.clear();
+        let mut cell_scanner = XmlScanner::new(row_element.body);
+        let mut next_col = 0_usize;
+        while let Some(cell) = cell_scanner.next_element_named("c")? {
+            let col = match extract_attr(cell.opening.raw, "r")? {
+                Some(reference) => {
+                    let mut col_number = 0_usize;
+                    for letter in reference.bytes().take_while(u8::is_ascii_uppercase) {
+                        col_number = col_number
+                            .strict_mul(26)
+                            .strict_add(usize::from(letter.strict_sub(b'A')).strict_add(1));
+                        if col_number > limits.max_col {
+                            break;
+                        }
+                    }
+                    let row_text = reference.trim_start_matches(|ch: char| ch.is_ascii_uppercase());
+                    if col_number == 0 || row_text.parse::<usize>().ok() != Some(row_num) {
+                        return Err(err(format!(
+                            "xlsx cell reference 형식이 올바르지 않습니다: row={row_num}, ref={reference}"
+                        )));
+                    }
+                    col_number.strict_sub(1)
+                }
+                None => next_col,
+            };
+            if col < next_col {
+                return Err(err(format!(
+                    "xlsx 셀 순서가 올바르지 않습니다: row={row_num}, col={}",
+                    col.strict_add(1)
+                )));
+            }
+            if col >= limits.max_col {
+                return Err(err(format!(
+                    "시트 열 인덱스가 비정상적으로 큽니다: {}",
+                    col.strict_add(1)
+                )));
+            }
+            next_col = col.strict_add(1);
+            let cell_type = extract_attr(cell.opening.raw, "t")?;
+            let (kind, text) = match cell_type.as_deref() {
+                Some("inlineStr") => (RawCellKind::Text, extract_all_tag_text(cell.body, "t")?),
+                Some("s") => {
+                    let Some(index_text) = extract_first_tag_text(cell.body, "v")? else {
+                        return Err(err(format!(
+                            "xlsx shared string cell에 값이 없습니다: row={row_num}, col={}",
+                            col.strict_add(1)
+                        )));
+                    };
+                    let index = index_text.trim().parse::<usize>().map_err(|source| {
+                        err_with_source("xlsx shared string index 해석 실패", source)
+                    })?;
+                    let value = shared_strings.get(index).ok_or_else(|| {
+                        err(format!(
+                            "xlsx cell이 존재하지 않는 shared string index를 참조합니다: {index}"
+                        ))
+                    })?;
+                    (RawCellKind::Text, Some(Cow::Borrowed(value.as_str())))
+                }
+                Some(other) => {
+                    let kind = match other {
+                        "b" => RawCellKind::Bool,
+                        "e" => RawCellKind::Error,
+                        "n" => RawCellKind::Number,
+                        _ => RawCellKind::Text,
+                    };
+                    (
+                        kind,
+                        extract_first_tag_text(cell.body, "v")?
+                            .map(decode_xml_entities)
+                            .transpose()?,
+                    )
+                }
+                None => (
+                    RawCellKind::Number,
+                    extract_first_tag_text(cell.body, "v")?
+                        .map(decode_xml_entities)
+                        .transpose()?,
+                ),
+            };
+            let formula = match XmlScanner::new(cell.body).next_element_named("f")? {
+                Some(formula_element) => {
+                    let body = decode_xml_entities(formula_element.body)?;
+                    let shared_index = if extract_attr(formula_element.opening.raw, "t")?.as_deref()
+                        == Some("shared")
+                    {
+                        extract_attr(formula_element.opening.raw, "si")?
+                    } else {
+                        None
+                    };
+                    match shared_index {
+                        Some(index) if body.is_empty() => {
+                            let head = shared_formulas.get(&index).ok_or_else(|| {
+                                err(format!(
+                                    "xlsx shared formula 기준 셀을 찾지 못했습니다: si={index}, row={row_num}"
+                                ))
+                            })?;
+                            let col_delta = i32::try_from(col)
+                                .and_then(|col_i32| {
+                                    i32::try_from(head.anchor_col)
+                                        .map(|anchor| col_i32.strict_sub(anchor))
+                                })
+                                .map_err(|source| {
+                                    err_with_source("xlsx 수식 열 이동량 변환 실패", source)
+                                })?;
+                            let row_delta = i32::try_from(row_num)
+                                .and_then(|row_i32| {
+                                    i32::try_from(head.anchor_row)
+                                        .map(|anchor| row_i32.strict_sub(anchor))
+                                })
+                                .map_err(|source| {
+                                    err_with_source("xlsx 수식 행 이동량 변환 실패", source)
+                                })?;
+                            Some(Cow::Owned(
+                                shift_formula(&head.formula, col_delta, row_delta)?.into_owned(),
+                            ))
+                        }
+                        Some(index) => {
+                            shared_formulas.insert(
+                                index,
+                                SharedFormula {
+                                    anchor_col: col,
+                                    anchor_row: row_num,
+                                    formula: body.clone().into_owned(),
+                                },
+                            );
+                            Some(body)
+                        }
+                        None => Some(body),
+                    }
+                }
+                None => None,
+            };
+            if text.is_none() && formula.is_none() {
+                continue;
+            }
+            if row_cells.len() == row_cells.capacity() {
+                row_cells.try_reserve(1).map_err(|source| {
+                    err_with_source("xlsx 행 셀 목록 메모리 확보 실패", source)
+                })?;
+            }
+            row_cells.push(RawCell {
+                col,
+                formula,
+                kind,
+                text,
+            });
+        }
+        visitor(row_num, row_cells)?;
+    }
+    Ok(())
+}
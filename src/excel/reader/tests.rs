@@ -0,0 +1,135 @@
+use super::super::{PackagePart, ZipArchiveBuilder};
+use super::{Cell, CellValue, Workbook};
+use crate::diagnostic::{Result, ensure, err};
+use std::{
+    env,
+    fs::{self, File},
+    process,
+};
+const SHEET_REL: &str = "<Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\"><Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet\" Target=\"worksheets/sheet1.xml\"/></Relationships>";
+const TRANSITIONAL_WORKBOOK: &str = "<workbook xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\" xmlns:r=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships\"><sheets><sheet name=\"가격\" sheetId=\"1\" r:id=\"rId1\"/></sheets></workbook>";
+#[test]
+fn inline_strings_are_read_as_text() -> Result<()> {
+    let workbook = open_workbook(
+        "inline",
+        TRANSITIONAL_WORKBOOK,
+        "<sheetData><row r=\"1\"><c r=\"A1\" t=\"inlineStr\"><is><t>대전 &amp; 세종</t></is></c><c r=\"B1\" t=\"inlineStr\"><is><r><t>천</t></r><r><t xml:space=\"preserve\">안 </t></r></is></c><c r=\"C1\" t=\"inlineStr\"><is/></c></row></sheetData>",
+    )?;
+    let sheet = workbook.sheet("가격").map_err(|sheet_err| sheet_err.0)?;
+    ensure(
+        sheet.cell("A1").map(|cell| &cell.value)
+            == Some(&CellValue::Text("대전 & 세종".to_owned())),
+        "inline string entities are decoded",
+    )?;
+    ensure(
+        sheet.cell("B1").map(|cell| &cell.value) == Some(&CellValue::Text("천안 ".to_owned())),
+        "rich text runs are joined",
+    )?;
+    ensure(
+        sheet.cell("C1").is_none(),
+        "an empty inline string is not a cell",
+    )
+}
+#[test]
+fn relationship_prefix_is_resolved_from_the_namespace() -> Result<()> {
+    let sheet_xml = "<sheetData><row r=\"1\"><c r=\"A1\"><v>1650</v></c></row></sheetData>";
+    let custom_prefix = open_workbook(
+        "prefix",
+        "<x:workbook xmlns:x=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\" xmlns:r=\"urn:unrelated\" xmlns:rel=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships\"><x:sheets><sheet name=\"가격\" sheetId=\"1\" r:id=\"rId9\" rel:id=\"rId1\"/></x:sheets></x:workbook>",
+        sheet_xml,
+    )?;
+    let sheet = custom_prefix
+        .sheet("가격")
+        .map_err(|sheet_err| sheet_err.0)?;
+    ensure(
+        sheet.cell("A1").and_then(Cell::number) == Some(1650.0_f64),
+        "the sheet is found through rel:id",
+    )?;
+    let strict = open_workbook(
+        "strict",
+        "<workbook xmlns=\"http://purl.oclc.org/ooxml/spreadsheetml/main\"><sheets xmlns:o=\"http://purl.oclc.org/ooxml/officeDocument/relationships\"><sheet name=\"가격\" sheetId=\"1\" o:id=\"rId1\"/></sheets></workbook>",
+        sheet_xml,
+    )?;
+    ensure(
+        strict.sheet_names().eq(["가격"]),
+        "the Strict namespace declared on sheets is resolved",
+    )?;
+    let Err(undeclared) = open_workbook(
+        "undeclared",
+        "<workbook><sheets><sheet name=\"가격\" sheetId=\"1\" r:id=\"rId1\"/></sheets></workbook>",
+        sheet_xml,
+    ) else {
+        return Err(err("r:id without a namespace declaration must be rejected"));
+    };
+    ensure(
+        undeclared
+            .to_string()
+            .contains("relationships namespace 선언을 찾지 못했습니다"),
+        format!("undeclared prefix error: {undeclared}"),
+    )
+}
+#[test]
+fn shared_formulas_expand_relative_to_each_cell() -> Result<()> {
+    let workbook = open_workbook(
+        "shared",
+        TRANSITIONAL_WORKBOOK,
+        concat!(
+            "<sheetData>",
+            "<row r=\"1\"><c r=\"A1\"><v>1</v></c><c r=\"B1\"><f t=\"shared\" ref=\"B1:B3\" si=\"0\">A1*2+$A$1</f><v>3</v></c><c r=\"C1\" t=\"str\"><f t=\"shared\" ref=\"C1:D1\" si=\"1\">B1&amp;\"원\"</f><v>3원</v></c><c r=\"D1\" t=\"str\"><f t=\"shared\" si=\"1\"/><v>3원원</v></c></row>",
+            "<row r=\"2\"><c r=\"A2\"><v>2</v></c><c r=\"B2\"><f t=\"shared\" si=\"0\"/><v>5</v></c></row>",
+            "<row r=\"3\"><c r=\"B3\"><f t=\"shared\" si=\"0\"/></c></row>",
+            "</sheetData>",
+        ),
+    )?;
+    let sheet = workbook.sheet("가격").map_err(|sheet_err| sheet_err.0)?;
+    for (reference, formula, value) in [
+        ("B1", "A1*2+$A$1", CellValue::Number(3.0_f64)),
+        ("B2", "A2*2+$A$1", CellValue::Number(5.0_f64)),
+        ("B3", "A3*2+$A$1", CellValue::Empty),
+        ("C1", "B1&\"원\"", CellValue::Text("3원".to_owned())),
+        ("D1", "C1&\"원\"", CellValue::Text("3원원".to_owned())),
+    ] {
+        let cell = sheet.cell(reference).ok_or("formula cell")?;
+        ensure(
+            cell.formula.as_deref() == Some(formula),
+            format!("{reference} formula: {:?}", cell.formula),
+        )?;
+        ensure(
+            cell.value == value,
+            format!("{reference} value: {:?}", cell.value),
+        )?;
+    }
+    Ok(())
+}
+fn open_workbook(label: &str, workbook_xml: &str, sheet_data: &str) -> Result<Workbook> {
+    let dir = env::temp_dir().join(format!("fcupdater-reader-{label}-{}", process::id()));
+    fs::create_dir_all(&dir)?;
+    let path = dir.join("book.xlsx");
+    let parts = [
+        ("xl/workbook.xml", workbook_xml.to_owned()),
+        ("xl/_rels/workbook.xml.rels", SHEET_REL.to_owned()),
+        (
+            "xl/worksheets/sheet1.xml",
+            format!(
+                "<worksheet xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\">{sheet_data}</worksheet>"
+            ),
+        ),
+    ]
+    .map(|(name, xml)| PackagePart {
+        bytes: xml.into_bytes(),
+        name: name.into(),
+    });
+    let mut file = File::create(&path)?;
+    ZipArchiveBuilder {
+        archive_path: &path,
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        permissions: file.metadata()?.permissions(),
+        file: &mut file,
+        parts: &parts,
+    }
+    .create()?;
+    drop(file);
+    let archive = fs::read(&path)?;
+    fs::remove_dir_all(&dir)?;
+    Workbook::from_bytes(archive).map_err(|open_err| open_err.0)
+}
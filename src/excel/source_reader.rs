@@ -1,7 +1,11 @@
 use super::copy_text;
 use crate::diagnostic::{AppError, Result, err, err_with_source, try_vec_with_capacity};
+use alloc::borrow::Cow;
 use core::{fmt::Display, range::Range};
+mod csv;
+mod xlsx;
 const CFB_SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+const ZIP_LOCAL_FILE_SIGNATURE: [u8; 4] = [0x50, 0x4B, 0x03, 0x04];
 const CFB_FREE_SECT: u32 = 0xFFFF_FFFF;
 const CFB_END_OF_CHAIN: u32 = 0xFFFF_FFFE;
 const CFB_FAT_SECT: u32 = 0xFFFF_FFFD;
@@ -18,6 +22,14 @@ const BIFF_RECORD_CONTINUE: u16 = 0x003C;
 const BIFF_RECORD_EOF: u16 = 0x000A;
 const BIFF_RECORD_SST: u16 = 0x00FC;
 const BIFF_RECORD_LABEL_SST: u16 = 0x00FD;
+const BIFF_RECORD_BOOLERR: u16 = 0x0205;
+const BIFF_RECORD_FORMULA: u16 = 0x0006;
+const BIFF_RECORD_LABEL: u16 = 0x0204;
+const BIFF_RECORD_MULRK: u16 = 0x00BD;
+const BIFF_RECORD_NUMBER: u16 = 0x0203;
+const BIFF_RECORD_RK: u16 = 0x027E;
+const BIFF_RECORD_STRING: u16 = 0x0207;
+const BIFF_FORMULA_SPECIAL_MARKER: u16 = 0xFFFF;
 const BIFF_VERSION_8: u16 = 0x0600;
 const BIFF_SUBSTREAM_WORKBOOK_GLOBALS: u16 = 0x0005;
 const BIFF_SUBSTREAM_WORKSHEET: u16 = 0x0010;
@@ -29,16 +41,18 @@ const MAX_SOURCE_CELL_COUNT: usize = MAX_SOURCE_ROW * MAX_SOURCE_COL;
 const MAX_UTF8_BYTES_PER_COMPRESSED_XL_CHAR: usize = 2;
 const MAX_UTF8_BYTES_PER_UTF16_CODE_UNIT: usize = 3;
 const EXPECTED_BIFF_CODE_PAGE: u16 = 1200;
-const SOURCE_HEADER_ROW: usize = 4;
-const COL_REGION: usize = 1;
-const COL_NAME: usize = 2;
-const COL_ADDRESS: usize = 3;
-const COL_BRAND: usize = 4;
-const COL_SELF_YN: usize = 5;
-const COL_PREMIUM: usize = 6;
-const COL_GASOLINE: usize = 7;
-const COL_DIESEL: usize = 8;
-const SOURCE_COLUMN_COUNT: usize = COL_DIESEL + 1;
+const MAX_SOURCE_HEADER_ROW: usize = 32;
+const MIN_SOURCE_HEADER_CANDIDATE_MATCHES: usize = 2;
+const SOURCE_HEADER_LABELS: [&str; 8] = [
+    "지역",
+    "상호",
+    "주소",
+    "상표",
+    "셀프여부",
+    "고급휘발유",
+    "휘발유",
+    "경유",
+];
 const WORKBOOK_STREAM_NAME: [char; 8] = ['W', 'o', 'r', 'k', 'b', 'o', 'o', 'k'];
 const MIN_FUEL_PRICE: i32 = 100;
 const MAX_FUEL_PRICE: i32 = 100_000;
@@ -77,12 +91,17 @@ pub(crate) struct SourceRecordRef<'record> {
 pub(crate) enum StationService {
     General,
     SelfService,
+    Unknown,
 }
 impl StationService {
+    pub(crate) const fn is_known(self) -> bool {
+        !matches!(self, Self::Unknown)
+    }
     pub(crate) const fn label(self) -> &'static str {
         match self {
             Self::General => "일반",
             Self::SelfService => "셀프",
+            Self::Unknown => "",
         }
     }
 }
@@ -108,18 +127,43 @@ struct BiffRecordReader<'workbook> {
     pos: usize,
     workbook_stream: &'workbook [u8],
 }
+struct BiffRowBuffer<'strings> {
+    cells: [Option<Cow<'strings, str>>; MAX_SOURCE_COL],
+    pending_string: Option<(u16, u16)>,
+    previous_cell: Option<(usize, usize)>,
+    row_num: Option<usize>,
+}
 struct BiffSharedStrings {
     declared_total: usize,
     values: Vec<String>,
 }
 struct BiffWorkbookReader<'workbook>(&'workbook [u8]);
+struct CsvSourceReader<'bytes>(&'bytes [u8]);
 pub(crate) struct SourceReader(Vec<u8>);
-type SourceRow<'strings> = [Option<&'strings str>; SOURCE_COLUMN_COUNT];
+#[derive(Clone, Copy)]
+struct SourceColumns {
+    address: usize,
+    brand: usize,
+    diesel: usize,
+    gasoline: usize,
+    name: usize,
+    premium: usize,
+    region: usize,
+    self_yn: usize,
+}
+type SourceRow<'strings> = [Option<&'strings str>; MAX_SOURCE_COL];
+struct SourceRowSink<'visitor, V> {
+    columns: Option<SourceColumns>,
+    found_record: bool,
+    visitor: &'visitor mut V,
+    visitor_error: Option<AppError>,
+}
 struct SstChunkReader<'chunks, 'chunk> {
     chunk_index: usize,
     chunks: &'chunks [&'chunk [u8]],
     offset_in_chunk: usize,
 }
+struct XlsxSourceReader<'bytes>(&'bytes [u8]);
 impl SourceReader {
     fn build_fat_table(&self, fat_sector_ids: &[u32]) -> Result<Vec<u32>> {
         let entries_per_sector = CFB_SECTOR_SIZE.div_euclid(4);
@@ -369,15 +413,30 @@ impl SourceReader {
         self,
         mut visitor: impl FnMut(SourceRecordRef<'_>) -> Result<()>,
     ) -> Result<Result<()>> {
-        let workbook = self.read_xls_workbook()?;
-        let biff = BiffWorkbookReader(&workbook);
-        let (sheet_offset, shared_strings) = biff.parse_globals()?;
-        biff.visit_worksheet(
-            sheet_offset,
-            shared_strings.declared_total,
-            &shared_strings.values,
-            &mut visitor,
-        )
+        let mut sink = SourceRowSink {
+            columns: None,
+            found_record: false,
+            visitor: &mut visitor,
+            visitor_error: None,
+        };
+        if self.0.starts_with(&CFB_SIGNATURE) {
+            let workbook = self.read_xls_workbook()?;
+            let biff = BiffWorkbookReader(&workbook);
+            let (sheet_offset, shared_strings) = biff.parse_globals()?;
+            biff.visit_worksheet(
+                sheet_offset,
+                shared_strings.declared_total,
+                &shared_strings.values,
+                &mut sink,
+            )?;
+            sink.finish("xls")
+        } else if self.0.starts_with(&ZIP_LOCAL_FILE_SIGNATURE) {
+            XlsxSourceReader(&self.0).visit_rows(&mut sink)?;
+            sink.finish("xlsx")
+        } else {
+            CsvSourceReader(&self.0).visit_rows(&mut sink)?;
+            sink.finish("CSV")
+        }
     }
 }
 impl From<Vec<u8>> for SourceReader {
@@ -385,6 +444,150 @@ impl From<Vec<u8>> for SourceReader {
         Self(data)
     }
 }
+impl<V> SourceRowSink<'_, V>
+where
+    V: FnMut(SourceRecordRef<'_>) -> Result<()>,
+{
+    fn detect_header(&mut self, row_num: usize, row: &SourceRow<'_>) -> Result<()> {
+        let mut found = [None; SOURCE_HEADER_LABELS.len()];
+        for (col, cell) in row.iter().enumerate() {
+            let Some(text) = *cell else {
+                continue;
+            };
+            let header_chars = text.chars().filter(|ch| !ch.is_whitespace());
+            let Some((slot, label)) = found
+                .iter_mut()
+                .zip(SOURCE_HEADER_LABELS)
+                .find(|&(_, label)| header_chars.clone().eq(label.chars()))
+            else {
+                continue;
+            };
+            if slot.replace(col).is_some() {
+                return Err(err(format!(
+                    "Opinet 소스 헤더에 같은 열이 여러 번 있습니다: row={row_num}, header={label}"
+                )));
+            }
+        }
+        let matched = found.iter().flatten().count();
+        if let [
+            Some(region),
+            Some(name),
+            Some(address),
+            Some(brand),
+            Some(self_yn),
+            Some(premium),
+            Some(gasoline),
+            Some(diesel),
+        ] = found
+        {
+            self.columns = Some(SourceColumns {
+                address,
+                brand,
+                diesel,
+                gasoline,
+                name,
+                premium,
+                region,
+                self_yn,
+            });
+            return Ok(());
+        }
+        if matched >= MIN_SOURCE_HEADER_CANDIDATE_MATCHES {
+            let mut missing = String::new();
+            for (slot, label) in found.iter().zip(SOURCE_HEADER_LABELS) {
+                if slot.is_none() {
+                    if !missing.is_empty() {
+                        missing.push_str(", ");
+                    }
+                    missing.push_str(label);
+                }
+            }
+            return Err(err(format!(
+                "Opinet 소스 헤더에 필수 열이 없습니다: row={row_num}, missing={missing}"
+            )));
+        }
+        if row_num >= MAX_SOURCE_HEADER_ROW {
+            return Err(err(format!(
+                "Opinet 소스 헤더 행을 찾지 못했습니다. ({MAX_SOURCE_HEADER_ROW}행까지 확인)"
+            )));
+        }
+        Ok(())
+    }
+    fn finish(self, format_label: &str) -> Result<Result<()>> {
+        if self.columns.is_none() {
+            return Err(err("Opinet 소스 헤더 행을 찾지 못했습니다."));
+        }
+        if let Some(source) = self.visitor_error {
+            return Ok(Err(source));
+        }
+        if !self.found_record {
+            return Err(err(format!(
+                "{format_label} 시트에서 유효한 소스 데이터를 찾지 못했습니다."
+            )));
+        }
+        Ok(Ok(()))
+    }
+    fn push_row(&mut self, row_num: usize, row: &SourceRow<'_>) -> Result<()> {
+        let Some(columns) = self.columns else {
+            return self.detect_header(row_num, row);
+        };
+        let address = row_text_trimmed(row, columns.address);
+        if address.is_empty() {
+            if [
+                columns.brand,
+                columns.diesel,
+                columns.gasoline,
+                columns.name,
+                columns.premium,
+                columns.region,
+                columns.self_yn,
+            ]
+            .into_iter()
+            .any(|col| !row_text_trimmed(row, col).is_empty())
+            {
+                return Err(err(format!(
+                    "Opinet 소스 {row_num}행에 주소 없이 데이터가 존재합니다."
+                )));
+            }
+            return Ok(());
+        }
+        let diesel = row_fuel_price(row, columns.diesel, row_num, "경유")?;
+        let gasoline = row_fuel_price(row, columns.gasoline, row_num, "휘발유")?;
+        let name = row_text_trimmed(row, columns.name);
+        if name.is_empty() {
+            return Err(format!("Opinet 소스 {row_num}행 상호명 값이 비어 있습니다.").into());
+        }
+        let premium = row_fuel_price(row, columns.premium, row_num, "고급휘발유")?;
+        let service = match row_text_trimmed(row, columns.self_yn) {
+            "셀프" => StationService::SelfService,
+            "일반" => StationService::General,
+            self_yn => {
+                return Err(format!(
+                    "Opinet 소스 {row_num}행 셀프 여부 값이 올바르지 않습니다: {self_yn}"
+                )
+                .into());
+            }
+        };
+        self.found_record = true;
+        if self.visitor_error.is_none()
+            && let Err(source) = (self.visitor)(SourceRecordRef {
+                address,
+                brand: row_text_trimmed(row, columns.brand),
+                fuels: FuelValues {
+                    diesel,
+                    gasoline,
+                    premium,
+                },
+                name,
+                region: row_text_trimmed(row, columns.region),
+                service,
+            })
+        {
+            self.visitor_error = Some(source);
+        }
+        Ok(())
+    }
+}
 impl SstChunkReader<'_, '_> {
     fn ensure_available(&mut self) -> Result<()> {
         while let Some(chunk) = self.chunks.get(self.chunk_index) {
@@ -549,6 +752,82 @@ impl<'workbook> BiffRecordReader<'workbook> {
         Ok(Some((record_id, data)))
     }
 }
+impl<'strings> BiffRowBuffer<'strings> {
+    fn ensure_no_pending_string(&self) -> Result<()> {
+        if let Some((row, col)) = self.pending_string {
+            return Err(err(format!(
+                "FORMULA 문자열 결과 뒤에 STRING record가 없습니다: row={}, col={}",
+                usize::from(row).strict_add(1),
+                usize::from(col).strict_add(1)
+            )));
+        }
+        Ok(())
+    }
+    fn flush<V>(&mut self, sink: &mut SourceRowSink<'_, V>) -> Result<()>
+    where
+        V: FnMut(SourceRecordRef<'_>) -> Result<()>,
+    {
+        self.ensure_no_pending_string()?;
+        if let Some(row_num) = self.row_num.take() {
+            sink.push_row(row_num, &self.cells.each_ref().map(Option::as_deref))?;
+            self.cells = [const { None }; MAX_SOURCE_COL];
+        }
+        Ok(())
+    }
+    fn place<V>(
+        &mut self,
+        row_index: u16,
+        col_index: u16,
+        value: Cow<'strings, str>,
+        sink: &mut SourceRowSink<'_, V>,
+    ) -> Result<()>
+    where
+        V: FnMut(SourceRecordRef<'_>) -> Result<()>,
+    {
+        self.ensure_no_pending_string()?;
+        let row = usize::from(row_index).strict_add(1);
+        let col = usize::from(col_index);
+        if row > MAX_SOURCE_ROW {
+            return Err(err(display_limit_message(
+                "시트 행 인덱스가 비정상적으로 큽니다: ",
+                row,
+                "최대 ",
+                MAX_SOURCE_ROW,
+            )));
+        }
+        if col >= MAX_SOURCE_COL {
+            return Err(err(prefixed_display_message(
+                "시트 열 인덱스가 비정상적으로 큽니다: ",
+                col.strict_add(1),
+            )));
+        }
+        if self
+            .previous_cell
+            .is_some_and(|(previous_row, previous_col)| {
+                row < previous_row || (row == previous_row && col <= previous_col)
+            })
+        {
+            return Err(err(format!(
+                "Opinet 고정 소스 셀 순서가 올바르지 않습니다: row={row}, col={}",
+                col.strict_add(1)
+            )));
+        }
+        if self.row_num.is_some_and(|current| current != row) {
+            self.flush(sink)?;
+        }
+        self.row_num = Some(row);
+        self.previous_cell = Some((row, col));
+        if let Some(cell) = self.cells.get_mut(col)
+            && cell.replace(value).is_some()
+        {
+            return Err(err(format!(
+                "Opinet 고정 소스 셀이 중복 선언되었습니다: row={row}, col={}",
+                col.strict_add(1)
+            )));
+        }
+        Ok(())
+    }
+}
 impl<'workbook> BiffWorkbookReader<'workbook> {
     fn collect_sst_chunks(
         &self,
@@ -745,101 +1024,30 @@ impl<'workbook> BiffWorkbookReader<'workbook> {
             next_offset,
         ))
     }
-    fn visit_worksheet<'strings>(
+    fn visit_worksheet<V>(
         &self,
         sheet_offset: usize,
         declared_total: usize,
-        shared_strings: &'strings [String],
-        visitor: &mut impl FnMut(SourceRecordRef<'strings>) -> Result<()>,
-    ) -> Result<Result<()>> {
+        shared_strings: &[String],
+        sink: &mut SourceRowSink<'_, V>,
+    ) -> Result<()>
+    where
+        V: FnMut(SourceRecordRef<'_>) -> Result<()>,
+    {
         if sheet_offset >= self.0.len() {
             return Err(err(prefixed_display_message(
                 "worksheet offset이 workbook stream 범위를 벗어났습니다: ",
                 sheet_offset,
             )));
         }
-        let mut found_header = false;
-        let mut found_record = false;
-        let mut visitor_error: Option<AppError> = None;
-        let mut flush_row = |row_num: usize, row: &SourceRow<'strings>| -> Result<()> {
-            if row_num < SOURCE_HEADER_ROW {
-                return Ok(());
-            }
-            if row_num == SOURCE_HEADER_ROW {
-                for (expected_col, expected_text) in [
-                    (COL_REGION, "지역"),
-                    (COL_NAME, "상호"),
-                    (COL_ADDRESS, "주소"),
-                    (COL_BRAND, "상표"),
-                    (COL_SELF_YN, "셀프여부"),
-                    (COL_PREMIUM, "고급휘발유"),
-                    (COL_GASOLINE, "휘발유"),
-                    (COL_DIESEL, "경유"),
-                ] {
-                    let actual = row_text_trimmed(row, expected_col);
-                    if actual != expected_text {
-                        return Err(err(format!(
-                            "Opinet 소스 헤더가 예상과 다릅니다: col={}, expected={expected_text}, actual={actual}",
-                            expected_col.strict_add(1)
-                        )));
-                    }
-                }
-                found_header = true;
-                return Ok(());
-            }
-            if !found_header {
-                return Err(err("Opinet 소스 헤더 행을 찾지 못했습니다."));
-            }
-            let address = row_text_trimmed(row, COL_ADDRESS);
-            if address.is_empty() {
-                if row.iter().flatten().any(|text| !text.trim().is_empty()) {
-                    return Err(err(format!(
-                        "Opinet 소스 {row_num}행에 주소 없이 데이터가 존재합니다."
-                    )));
-                }
-                return Ok(());
-            }
-            let diesel = row_fuel_price(row, COL_DIESEL, row_num, "경유")?;
-            let gasoline = row_fuel_price(row, COL_GASOLINE, row_num, "휘발유")?;
-            let name = row_text_trimmed(row, COL_NAME);
-            if name.is_empty() {
-                return Err(format!("Opinet 소스 {row_num}행 상호명 값이 비어 있습니다.").into());
-            }
-            let premium = row_fuel_price(row, COL_PREMIUM, row_num, "고급휘발유")?;
-            let service = match row_text_trimmed(row, COL_SELF_YN) {
-                "셀프" => StationService::SelfService,
-                "일반" => StationService::General,
-                self_yn => {
-                    return Err(format!(
-                        "Opinet 소스 {row_num}행 셀프 여부 값이 올바르지 않습니다: {self_yn}"
-                    )
-                    .into());
-                }
-            };
-            found_record = true;
-            if visitor_error.is_none()
-                && let Err(source) = visitor(SourceRecordRef {
-                    address,
-                    brand: row_text_trimmed(row, COL_BRAND),
-                    fuels: FuelValues {
-                        diesel,
-                        gasoline,
-                        premium,
-                    },
-                    name,
-                    region: row_text_trimmed(row, COL_REGION),
-                    service,
-                })
-            {
-                visitor_error = Some(source);
-            }
-            Ok(())
+        let mut buffer = BiffRowBuffer {
+            cells: [const { None }; MAX_SOURCE_COL],
+            pending_string: None,
+            previous_cell: None,
+            row_num: None,
         };
-        let mut current_row = SourceRow::default();
-        let mut current_row_num = None;
         let mut first_record = true;
         let mut label_sst_count = 0_usize;
-        let mut previous_cell = None;
         let mut records = BiffRecordReader {
             context: "worksheet",
             pos: sheet_offset,
@@ -860,45 +1068,8 @@ impl<'workbook> BiffWorkbookReader<'workbook> {
             }
             match record_id {
                 BIFF_RECORD_LABEL_SST => {
-                    if record_data.len() != 10 {
-                        return Err(err(format!(
-                            "LABELSST record 길이가 예상과 다릅니다: expected=10, actual={}",
-                            record_data.len()
-                        )));
-                    }
+                    validate_biff_cell_len(record_data, 10, "LABELSST")?;
                     label_sst_count = label_sst_count.strict_add(1);
-                    let row = usize::from(read_u16_le(record_data, 0)?) + 1;
-                    let col = usize::from(read_u16_le(record_data, 2)?);
-                    if row > MAX_SOURCE_ROW {
-                        return Err(err(display_limit_message(
-                            "시트 행 인덱스가 비정상적으로 큽니다: ",
-                            row,
-                            "최대 ",
-                            MAX_SOURCE_ROW,
-                        )));
-                    }
-                    if col >= MAX_SOURCE_COL {
-                        return Err(err(prefixed_display_message(
-                            "시트 열 인덱스가 비정상적으로 큽니다: ",
-                            col + 1,
-                        )));
-                    }
-                    if previous_cell.is_some_and(|(previous_row, previous_col)| {
-                        row < previous_row || (row == previous_row && col <= previous_col)
-                    }) {
-                        return Err(err(format!(
-                            "Opinet 고정 소스 셀 순서가 올바르지 않습니다: row={row}, col={}",
-                            col.strict_add(1)
-                        )));
-                    }
-                    if let Some(completed_row_num) =
-                        current_row_num.filter(|&current| current != row)
-                    {
-                        flush_row(completed_row_num, &current_row)?;
-                        current_row = SourceRow::default();
-                    }
-                    current_row_num = Some(row);
-                    previous_cell = Some((row, col));
                     let idx = usize::try_from(read_u32_le(record_data, 6)?).map_err(|source| {
                         err_with_source("SST index 변환에 실패했습니다.", source)
                     })?;
@@ -907,43 +1078,162 @@ impl<'workbook> BiffWorkbookReader<'workbook> {
                             "LABELSST가 존재하지 않는 SST index를 참조합니다: {idx}"
                         ))
                     })?;
-                    if row >= SOURCE_HEADER_ROW
-                        && let Some(cell) = current_row.get_mut(col)
-                        && cell.replace(value).is_some()
+                    buffer.place(
+                        read_u16_le(record_data, 0)?,
+                        read_u16_le(record_data, 2)?,
+                        Cow::Borrowed(value),
+                        sink,
+                    )?;
+                }
+                BIFF_RECORD_LABEL => {
+                    let value = read_biff_cell_string(record_data.get(6..).unwrap_or_default())?;
+                    buffer.place(
+                        read_u16_le(record_data, 0)?,
+                        read_u16_le(record_data, 2)?,
+                        Cow::Owned(value),
+                        sink,
+                    )?;
+                }
+                BIFF_RECORD_NUMBER => {
+                    validate_biff_cell_len(record_data, 14, "NUMBER")?;
+                    let value = f64::from_le_bytes(read_le_array::<8>(
+                        record_data,
+                        6,
+                        "NUMBER 값 범위 오류: ",
+                    )?);
+                    buffer.place(
+                        read_u16_le(record_data, 0)?,
+                        read_u16_le(record_data, 2)?,
+                        Cow::Owned(biff_number_text(value, false)?),
+                        sink,
+                    )?;
+                }
+                BIFF_RECORD_RK => {
+                    validate_biff_cell_len(record_data, 10, "RK")?;
+                    buffer.place(
+                        read_u16_le(record_data, 0)?,
+                        read_u16_le(record_data, 2)?,
+                        Cow::Owned(decode_biff_rk(read_u32_le(record_data, 6)?)?),
+                        sink,
+                    )?;
+                }
+                BIFF_RECORD_MULRK => {
+                    let row_index = read_u16_le(record_data, 0)?;
+                    let first_col = read_u16_le(record_data, 2)?;
+                    let (cells, last_col) = record_data
+                        .get(4..)
+                        .and_then(|tail| tail.split_last_chunk::<2>())
+                        .ok_or_else(|| err("MULRK record가 너무 짧습니다."))?;
+                    let (rk_cells, &[]) = cells.as_chunks::<6>() else {
+                        return Err(err(format!(
+                            "MULRK record 길이가 올바르지 않습니다: {}",
+                            record_data.len()
+                        )));
+                    };
+                    let expected_last = usize::from(first_col)
+                        .strict_add(rk_cells.len())
+                        .checked_sub(1);
+                    if rk_cells.is_empty()
+                        || expected_last != Some(usize::from(u16::from_le_bytes(*last_col)))
                     {
                         return Err(err(format!(
-                            "Opinet 고정 소스 셀이 중복 선언되었습니다: row={row}, col={}",
-                            col.strict_add(1)
+                            "MULRK record 열 범위가 셀 수와 다릅니다: first={first_col}, cells={}",
+                            rk_cells.len()
                         )));
                     }
+                    for (col_index, rk_cell) in (first_col..).zip(rk_cells) {
+                        buffer.place(
+                            row_index,
+                            col_index,
+                            Cow::Owned(decode_biff_rk(read_u32_le(rk_cell, 2)?)?),
+                            sink,
+                        )?;
+                    }
+                }
+                BIFF_RECORD_BOOLERR => {
+                    validate_biff_cell_len(record_data, 8, "BOOLERR")?;
+                    let [_, _, _, _, _, _, value, is_error] =
+                        read_le_array::<8>(record_data, 0, "BOOLERR 값 범위 오류: ")?;
+                    let text = match is_error {
+                        0x00 => biff_bool_text(value),
+                        0x01 => biff_error_text(value),
+                        _ => {
+                            return Err(err(format!(
+                                "BOOLERR 값 종류를 알 수 없습니다: {is_error:#04x}"
+                            )));
+                        }
+                    };
+                    buffer.place(
+                        read_u16_le(record_data, 0)?,
+                        read_u16_le(record_data, 2)?,
+                        Cow::Borrowed(text),
+                        sink,
+                    )?;
+                }
+                BIFF_RECORD_FORMULA => {
+                    let row_index = read_u16_le(record_data, 0)?;
+                    let col_index = read_u16_le(record_data, 2)?;
+                    let result = read_le_array::<8>(record_data, 6, "FORMULA 결과 범위 오류: ")?;
+                    if read_u16_le(&result, 6)? != BIFF_FORMULA_SPECIAL_MARKER {
+                        buffer.place(
+                            row_index,
+                            col_index,
+                            Cow::Owned(biff_number_text(f64::from_le_bytes(result), false)?),
+                            sink,
+                        )?;
+                        continue;
+                    }
+                    let [result_type, _, result_value, ..] = result;
+                    let value = match result_type {
+                        0x00 => {
+                            buffer.ensure_no_pending_string()?;
+                            buffer.pending_string = Some((row_index, col_index));
+                            continue;
+                        }
+                        0x01 => Cow::Borrowed(biff_bool_text(result_value)),
+                        0x02 => Cow::Borrowed(biff_error_text(result_value)),
+                        0x03 => Cow::Borrowed(""),
+                        _ => {
+                            return Err(err(format!(
+                                "FORMULA 결과 형식을 알 수 없습니다: {result_type:#04x}"
+                            )));
+                        }
+                    };
+                    buffer.place(row_index, col_index, value, sink)?;
                 }
-                0x0203 | 0x027E | 0x00BD | 0x0204 => {
-                    return Err(err(format!(
-                        "Opinet 고정 소스에서 예상하지 않은 BIFF cell record입니다: {record_id:#06x}"
-                    )));
+                BIFF_RECORD_STRING => {
+                    let Some((row_index, col_index)) = buffer.pending_string.take() else {
+                        return Err(err("FORMULA 없이 STRING record가 나타났습니다."));
+                    };
+                    let value = read_biff_cell_string(record_data)?;
+                    buffer.place(row_index, col_index, Cow::Owned(value), sink)?;
                 }
                 BIFF_RECORD_EOF => break,
                 _ => {}
             }
         }
-        if let Some(row_num) = current_row_num {
-            flush_row(row_num, &current_row)?;
-        }
+        buffer.flush(sink)?;
         if label_sst_count != declared_total {
             return Err(err(format!(
                 "SST total count가 LABELSST 레코드 수와 다릅니다: declared={declared_total}, actual={label_sst_count}"
             )));
         }
-        if !found_header {
-            return Err(err("Opinet 소스 헤더 행을 찾지 못했습니다."));
-        }
-        if let Some(source) = visitor_error {
-            return Ok(Err(source));
-        }
-        if !found_record {
-            return Err(err("xls 시트에서 유효한 소스 데이터를 찾지 못했습니다."));
-        }
-        Ok(Ok(()))
+        Ok(())
+    }
+}
+const fn biff_bool_text(value: u8) -> &'static str {
+    if value == 0 { "FALSE" } else { "TRUE" }
+}
+const fn biff_error_text(code: u8) -> &'static str {
+    match code {
+        0x00 => "#NULL!",
+        0x07 => "#DIV/0!",
+        0x0F => "#VALUE!",
+        0x17 => "#REF!",
+        0x1D => "#NAME?",
+        0x24 => "#NUM!",
+        0x2A => "#N/A",
+        _ => "#ERROR!",
     }
 }
 fn validate_biff_bof(
@@ -986,7 +1276,14 @@ fn row_fuel_price(
     row_num: usize,
     label: &'static str,
 ) -> Result<Option<i32>> {
-    let Some(raw) = row.get(idx).copied().flatten() else {
+    parse_source_fuel_price(row.get(idx).copied().flatten(), row_num, label)
+}
+pub(crate) fn parse_source_fuel_price(
+    raw_text: Option<&str>,
+    row_num: usize,
+    label: &'static str,
+) -> Result<Option<i32>> {
+    let Some(raw) = raw_text else {
         return Ok(None);
     };
     let text = raw.trim();
@@ -1172,6 +1469,60 @@ fn read_stream_from_fat_chain(
     }
     Ok(out)
 }
+fn validate_biff_cell_len(data: &[u8], expected: usize, record_name: &str) -> Result<()> {
+    if data.len() != expected {
+        return Err(err(format!(
+            "{record_name} record 길이가 예상과 다릅니다: expected={expected}, actual={}",
+            data.len()
+        )));
+    }
+    Ok(())
+}
+fn read_biff_cell_string(data: &[u8]) -> Result<String> {
+    let chunks = [data];
+    let mut reader = SstChunkReader {
+        chunk_index: 0,
+        chunks: &chunks,
+        offset_in_chunk: 0,
+    };
+    let char_count = usize::from(reader.read_u16()?);
+    let option = reader.read_u8()?;
+    validate_sst_option(option, BIFF_SST_CONTINUATION_FLAGS_MASK, "셀 문자열 option")?;
+    reader.read_xl_unicode_chars(char_count, (option & 0x01) != 0)
+}
+fn decode_biff_rk(rk: u32) -> Result<String> {
+    let hundredths = (rk & 0x01) != 0;
+    if (rk & 0x02) != 0 {
+        let value = i32::from_le_bytes(rk.to_le_bytes()).wrapping_shr(2);
+        return biff_number_text(f64::from(value), hundredths);
+    }
+    let bits = u64::from(rk & 0xFFFF_FFFC).wrapping_shl(32);
+    biff_number_text(f64::from_bits(bits), hundredths)
+}
+fn biff_number_text(value: f64, hundredths: bool) -> Result<String> {
+    if !value.is_finite() {
+        return Err(err(prefixed_display_message(
+            "xls 숫자 셀 값이 유한하지 않습니다: ",
+            value,
+        )));
+    }
+    let text = value.to_string();
+    if !hundredths {
+        return Ok(text);
+    }
+    let (sign, magnitude) = text
+        .strip_prefix('-')
+        .map_or(("", text.as_str()), |unsigned| ("-", unsigned));
+    let (integer, fraction) = magnitude.split_once('.').unwrap_or((magnitude, ""));
+    let padded = format!("{integer:0>3}");
+    let (whole, cents) = padded.split_at(padded.len().strict_sub(2));
+    let decimals = format!("{cents}{fraction}");
+    let trimmed = decimals.trim_end_matches('0');
+    if trimmed.is_empty() {
+        return Ok(format!("{sign}{whole}"));
+    }
+    Ok(format!("{sign}{whole}.{trimmed}"))
+}
 fn read_u16_le(bytes: &[u8], offset: usize) -> Result<u16> {
     let arr = read_le_array::<2>(bytes, offset, "u16 read out of range at ")?;
     Ok(u16::from_le_bytes(arr))
@@ -0,0 +1,144 @@
+use super::{CsvSourceReader, MAX_SOURCE_COL, MAX_SOURCE_ROW, SourceRecordRef, SourceRowSink};
+use crate::diagnostic::{Result, err, err_with_source, try_string_with_capacity};
+use alloc::borrow::Cow;
+use core::str;
+const CP949_TABLE: &[u8; 47_880] = include_bytes!("cp949.bin");
+const CP949_LEAD_FIRST: u8 = 0x81;
+const CP949_TRAIL_FIRST: u8 = 0x41;
+const CP949_TRAIL_COUNT: usize = 190;
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+struct CsvCursor<'text>(&'text str);
+impl<'text> CsvCursor<'text> {
+    fn next_field(&mut self, row_num: usize) -> Result<Cow<'text, str>> {
+        let Some(quoted) = self.0.strip_prefix('"') else {
+            let end = self.0.find([',', '\r', '\n']).unwrap_or(self.0.len());
+            let (raw, tail) = self.0.split_at(end);
+            self.0 = tail;
+            return Ok(Cow::Borrowed(raw));
+        };
+        let mut value: Option<String> = None;
+        let mut segment = quoted;
+        loop {
+            let quote = segment.find('"').ok_or_else(|| {
+                err(format!(
+                    "CSV 소스 {row_num}행의 따옴표가 닫히지 않았습니다."
+                ))
+            })?;
+            let (literal, quote_tail) = segment.split_at(quote);
+            let after_quote = quote_tail.get(1..).unwrap_or_default();
+            let Some(escaped_tail) = after_quote.strip_prefix('"') else {
+                self.0 = after_quote;
+                let Some(mut owned) = value else {
+                    return Ok(Cow::Borrowed(literal));
+                };
+                owned
+                    .try_reserve(literal.len())
+                    .map_err(|source| err_with_source("CSV 필드 메모리 확보 실패", source))?;
+                owned.push_str(literal);
+                return Ok(Cow::Owned(owned));
+            };
+            let owned = value.get_or_insert_default();
+            owned
+                .try_reserve(literal.len().strict_add(1))
+                .map_err(|source| err_with_source("CSV 필드 메모리 확보 실패", source))?;
+            owned.push_str(literal);
+            owned.push('"');
+            segment = escaped_tail;
+        }
+    }
+}
+impl CsvSourceReader<'_> {
+    fn decode_cp949(&self) -> Result<String> {
+        let mut out = try_string_with_capacity(
+            self.0.len().strict_add(self.0.len().div_euclid(2)),
+            "CSV 소스 CP949 변환 메모리 확보 실패",
+        )?;
+        let mut bytes = self.0.iter().copied().enumerate();
+        while let Some((offset, lead)) = bytes.next() {
+            if lead.is_ascii() {
+                out.push(char::from(lead));
+                continue;
+            }
+            let decoded = bytes
+                .next()
+                .filter(|&(_, trail)| {
+                    (CP949_LEAD_FIRST..=0xFE).contains(&lead)
+                        && (CP949_TRAIL_FIRST..=0xFE).contains(&trail)
+                })
+                .and_then(|(_, trail)| {
+                    let index = usize::from(lead.strict_sub(CP949_LEAD_FIRST))
+                        .strict_mul(CP949_TRAIL_COUNT)
+                        .strict_add(usize::from(trail.strict_sub(CP949_TRAIL_FIRST)))
+                        .strict_mul(2);
+                    let unit = CP949_TABLE.get(index..)?.first_chunk::<2>()?;
+                    char::from_u32(u32::from(u16::from_le_bytes(*unit)))
+                })
+                .filter(|&ch| ch != '\0')
+                .ok_or_else(|| {
+                    err(format!(
+                        "CSV 소스를 UTF-8 또는 CP949로 해석하지 못했습니다: offset={offset}"
+                    ))
+                })?;
+            out.push(decoded);
+        }
+        Ok(out)
+    }
+    pub(super) fn visit_rows<V>(&self, sink: &mut SourceRowSink<'_, V>) -> Result<()>
+    where
+        V: FnMut(SourceRecordRef<'_>) -> Result<()>,
+    {
+        let text = if let Some(without_bom) = self.0.strip_prefix(UTF8_BOM) {
+            Cow::Borrowed(
+                str::from_utf8(without_bom)
+                    .map_err(|source| err_with_source("CSV 소스 UTF-8 변환 실패", source))?,
+            )
+        } else if let Ok(utf8) = str::from_utf8(self.0) {
+            Cow::Borrowed(utf8)
+        } else {
+            Cow::Owned(self.decode_cp949()?)
+        };
+        let mut cursor = CsvCursor(text.as_ref());
+        let mut row_num = 0_usize;
+        while !cursor.0.is_empty() {
+            row_num = row_num.strict_add(1);
+            if row_num > MAX_SOURCE_ROW {
+                return Err(err(format!(
+                    "시트 행 인덱스가 비정상적으로 큽니다: {row_num} (최대 {MAX_SOURCE_ROW})"
+                )));
+            }
+            let mut cells: [Option<Cow<'_, str>>; MAX_SOURCE_COL] =
+                [const { None }; MAX_SOURCE_COL];
+            let mut col = 0_usize;
+            loop {
+                if col >= MAX_SOURCE_COL {
+                    return Err(err(format!(
+                        "시트 열 인덱스가 비정상적으로 큽니다: {}",
+                        col.strict_add(1)
+                    )));
+                }
+                let field = cursor.next_field(row_num)?;
+                if let Some(slot) = cells.get_mut(col) {
+                    *slot = Some(field);
+                }
+                col = col.strict_add(1);
+                if let Some(tail) = cursor.0.strip_prefix(',') {
+                    cursor.0 = tail;
+                } else if let Some(tail) = cursor.0.strip_prefix("\r\n") {
+                    cursor.0 = tail;
+                    break;
+                } else if let Some(tail) = cursor.0.strip_prefix(['\r', '\n']) {
+                    cursor.0 = tail;
+                    break;
+                } else if cursor.0.is_empty() {
+                    break;
+                } else {
+                    return Err(err(format!(
+                        "CSV 소스 {row_num}행 {col}열 따옴표 뒤에 구분자가 없습니다."
+                    )));
+                }
+            }
+            sink.push_row(row_num, &cells.each_ref().map(Option::as_deref))?;
+        }
+        Ok(())
+    }
+}
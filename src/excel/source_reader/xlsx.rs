@@ -0,0 +1,44 @@
+use super::{
+    MAX_SOURCE_CELL_COUNT, MAX_SOURCE_COL, MAX_SOURCE_ROW, SourceRecordRef, SourceRowSink,
+    XlsxSourceReader,
+};
+use crate::{
+    diagnostic::{Result, err},
+    excel::reader::{SheetLimits, part_text, shared_strings, sheet_entries, visit_rows},
+};
+use alloc::borrow::Cow;
+impl XlsxSourceReader<'_> {
+    pub(super) fn visit_rows<V>(&self, sink: &mut SourceRowSink<'_, V>) -> Result<()>
+    where
+        V: FnMut(SourceRecordRef<'_>) -> Result<()>,
+    {
+        let sheet_path = sheet_entries(self.0)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| err("xlsx 소스 workbook.xml에서 sheet를 찾지 못했습니다."))?
+            .path;
+        let shared_strings = shared_strings(self.0, MAX_SOURCE_CELL_COUNT)?;
+        let sheet_xml = part_text(self.0, &sheet_path)?
+            .ok_or_else(|| err(format!("xlsx 소스 sheet part가 없습니다: {sheet_path}")))?;
+        let mut row_cells = Vec::new();
+        visit_rows(
+            &sheet_xml,
+            &shared_strings,
+            &SheetLimits {
+                max_col: MAX_SOURCE_COL,
+                max_row: MAX_SOURCE_ROW,
+            },
+            &mut row_cells,
+            |row_num, raw_cells| {
+                let mut cells: [Option<Cow<'_, str>>; MAX_SOURCE_COL] =
+                    [const { None }; MAX_SOURCE_COL];
+                for raw_cell in raw_cells.drain(..) {
+                    if let Some(slot) = cells.get_mut(raw_cell.col) {
+                        *slot = raw_cell.text;
+                    }
+                }
+                sink.push_row(row_num, &cells.each_ref().map(Option::as_deref))
+            },
+        )
+    }
+}
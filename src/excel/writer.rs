@@ -2,6 +2,8 @@ use self::cell_ref::{
     MAX_A1_COL, MAX_A1_ROW, parse_range_token, ref_with_locks, with_unlocked_ref_parts,
 };
 use self::cell_ref::{parse_ref_with_locks, shift_formula};
+use self::ods::OdsDocument;
+use self::recalc::FormulaEvaluator;
 use super::{
     CHANGE_LOG_SHEET_NAME, CHANGE_LOG_SHEET_PATH, CanonicalStyleMap, MASTER_SHEET_NAME,
     MASTER_SHEET_PATH, SPREADSHEETML_NAMESPACE, SaveVerification, copy_text,
@@ -11,8 +13,12 @@ use super::{
         extract_first_tag_text, find_start_tag, is_valid_xml_char,
     },
 };
-use crate::diagnostic::{
-    Result, append_fmt, err, err_with_source, try_string_with_capacity, try_vec_with_capacity,
+use crate::{
+    diagnostic::{
+        ErrorCategory, Result, append_fmt, err, err_with_source, path_context_message,
+        try_string_with_capacity, try_vec_with_capacity,
+    },
+    temp_entry::open_regular,
 };
 use alloc::{
     borrow::Cow,
@@ -25,8 +31,10 @@ use core::{
     range::{Range, RangeInclusive},
 };
 use std::collections::HashMap;
-use std::path::Path;
-mod cell_ref;
+use std::{io::ErrorKind, path::Path};
+pub(super) mod cell_ref;
+mod ods;
+mod recalc;
 const XML_SPACE_PRESERVE_ATTR: &str = " xml:space=\"preserve\"";
 const FILTER_DATABASE_NAME: &str = "_xlnm._FilterDatabase";
 const FILTER_DATABASE_REF_PREFIX: &str = "유류비!$A$14:$W$";
@@ -84,6 +92,8 @@ const MASTER_HEADERS: [&str; 23] = [
     "지역화폐 미적용 단가(원/L)",
     "정렬키",
 ];
+pub(crate) const MASTER_DETOUR_HEADERS: [&str; 3] =
+    ["최근접 출발지", "출발지 거리(km)", "우회 비용(원)"];
 const CHANGE_LOG_HEADERS: [&str; 13] = [
     "지역",
     "상호",
@@ -123,6 +133,11 @@ pub(crate) struct Workbook {
     shared_strings: SharedStringTable,
     xml_text: String,
 }
+pub(super) struct Hyperlink {
+    pub col: u32,
+    pub row: u32,
+    pub target: String,
+}
 pub(crate) struct SharedStringTable {
     entries: Vec<SharedStringEntry>,
     index: HashMap<Rc<str>, usize>,
@@ -132,6 +147,7 @@ struct SharedStringEntry {
     xml: String,
 }
 pub(crate) struct Worksheet {
+    hyperlinks: Vec<Hyperlink>,
     prefix: String,
     rows: Vec<Row>,
     suffix: String,
@@ -212,7 +228,7 @@ struct WorksheetSemanticFacts {
     shared_ref_count: usize,
 }
 #[derive(Clone, Copy)]
-enum XmlEscapeContext {
+pub(super) enum XmlEscapeContext {
     Attribute,
     Text,
 }
@@ -355,6 +371,16 @@ impl SharedStringTable {
     }
 }
 impl Workbook {
+    fn canonicalize_sheets(&mut self) -> Result<()> {
+        self.request_recalculation_caches()?;
+        self.master_sheet
+            .canonicalize_excel_output(ExcelSheetKind::Master, &self.input_styles)?;
+        self.change_log_sheet
+            .canonicalize_excel_output(ExcelSheetKind::ChangeLog, &self.input_styles)
+    }
+    pub(crate) const fn change_log_sheet(&self) -> (&Worksheet, &SharedStringTable) {
+        (&self.change_log_sheet, &self.shared_strings)
+    }
     pub(crate) const fn change_log_sheet_mut(
         &mut self,
     ) -> (&mut Worksheet, &mut SharedStringTable) {
@@ -498,9 +524,32 @@ impl Workbook {
         workbook.validate_fixed_semantics(declared_shared_count)?;
         Ok(workbook)
     }
+    pub(crate) const fn master_sheet(&self) -> (&Worksheet, &SharedStringTable) {
+        (&self.master_sheet, &self.shared_strings)
+    }
     pub(crate) const fn master_sheet_mut(&mut self) -> (&mut Worksheet, &mut SharedStringTable) {
         (&mut self.master_sheet, &mut self.shared_strings)
     }
+    pub(crate) fn open(path: &Path) -> Result<Self> {
+        let file = open_regular(path, false).map_err(|source| {
+            err_with_source(path_context_message("xlsx 파일 열기 실패", path), source)
+        })?;
+        Self::from_container(XlsxContainer::from_validated_file(file, path)?)
+    }
+    /// 명령행 인자로 받은 워크북을 엽니다. 파일이 없으면 사용법 오류, 열 수 없으면 기타 오류로
+    /// 분류해 워크북 구성 오류와 구분합니다.
+    pub(crate) fn open_input(path: &Path) -> Result<Self> {
+        let file = open_regular(path, false).map_err(|source| {
+            let category = if source.kind() == ErrorKind::NotFound {
+                ErrorCategory::Usage
+            } else {
+                ErrorCategory::Other
+            };
+            err_with_source(path_context_message("xlsx 파일 열기 실패", path), source)
+                .with_category(category)
+        })?;
+        Self::from_container(XlsxContainer::from_validated_file(file, path)?)
+    }
     fn request_full_recalculation(&mut self) -> Result<()> {
         let out = &mut self.xml_text;
         let root = find_start_tag_location(out, "workbook", 0)?
@@ -577,32 +626,65 @@ impl Workbook {
         self.master_sheet
             .set_formula_cached_value_at(2, 12, diesel_cache, false)
     }
-    pub(crate) fn save(mut self, target_path: &Path, verification: SaveVerification) -> Result<()> {
+    pub(crate) fn save(
+        mut self,
+        target_path: &Path,
+        verification: SaveVerification,
+        (source_hash, settings_hash): (&str, &str),
+        displaced_backup: Option<&Path>,
+        verify_content: impl FnOnce(&Self) -> Result<()>,
+    ) -> Result<()> {
         self.request_full_recalculation()?;
         self.update_shared_string_catalog()?;
         self.shared_strings.canonicalize_excel_text_runs()?;
-        self.request_recalculation_caches()?;
-        self.master_sheet
-            .canonicalize_excel_output(ExcelSheetKind::Master, &self.input_styles)?;
-        self.change_log_sheet
-            .canonicalize_excel_output(ExcelSheetKind::ChangeLog, &self.input_styles)?;
+        self.canonicalize_sheets()?;
         self.master_sheet.canonical_share_formulas()?;
         self.change_log_sheet.canonical_share_formulas()?;
         self.master_sheet
             .validate_fixed_header(ExcelSheetKind::Master, &self.shared_strings)?;
         let (master_xml, master_shared_count) = self.master_sheet.to_xml()?;
-        self.container.put_text(MASTER_SHEET_PATH, master_xml)?;
+        self.container.put_worksheet_text(
+            MASTER_SHEET_PATH,
+            master_xml,
+            &self.master_sheet.hyperlinks,
+        )?;
         self.change_log_sheet
             .validate_fixed_header(ExcelSheetKind::ChangeLog, &self.shared_strings)?;
         let (change_log_xml, change_log_shared_count) = self.change_log_sheet.to_xml()?;
         self.container
             .put_text(CHANGE_LOG_SHEET_PATH, change_log_xml)?;
-        let shared_string_reference_count = master_shared_count.strict_add(change_log_shared_count);
+        let shared_string_reference_count = master_shared_count
+            .strict_add(change_log_shared_count)
+            .strict_add(
+                self.container
+                    .pass_through_shared_string_count(self.shared_strings.entries.len())?,
+            );
         let shared_strings_xml = self.shared_strings.to_xml(shared_string_reference_count)?;
         self.container.put_text("xl/workbook.xml", self.xml_text)?;
         self.container
             .put_text("xl/sharedStrings.xml", shared_strings_xml)?;
-        self.container.save(target_path, verification)
+        self.container.put_run_hashes(source_hash, settings_hash)?;
+        self.container
+            .save(target_path, verification, displaced_backup, verify_content)
+    }
+    pub(crate) fn save_ods(mut self, target_path: &Path) -> Result<()> {
+        self.canonicalize_sheets()?;
+        self.master_sheet
+            .validate_fixed_header(ExcelSheetKind::Master, &self.shared_strings)?;
+        self.change_log_sheet
+            .validate_fixed_header(ExcelSheetKind::ChangeLog, &self.shared_strings)?;
+        OdsDocument {
+            change_log: &self.change_log_sheet,
+            master: &self.master_sheet,
+            shared_strings: &self.shared_strings,
+        }
+        .write(target_path)
+    }
+    pub(crate) fn settings_hash(&self) -> Option<&str> {
+        self.container.settings_hash()
+    }
+    pub(crate) fn source_hash(&self) -> Option<&str> {
+        self.container.source_hash()
     }
     pub(crate) fn update_filter_database_defined_name(&mut self, last_data_row: u32) -> Result<()> {
         let (row_span, _) = fixed_filter_database_row(&self.xml_text)?;
@@ -623,6 +705,8 @@ impl Workbook {
             .canonical_mark_shared_strings(&mut mapping)?;
         self.change_log_sheet
             .canonical_mark_shared_strings(&mut mapping)?;
+        self.container
+            .mark_pass_through_shared_strings(&mut mapping)?;
         let entries = &mut self.shared_strings.entries;
         let mut write = 0_usize;
         for (old_index, slot) in mapping.iter_mut().enumerate() {
@@ -637,6 +721,7 @@ impl Workbook {
         self.master_sheet.canonical_remap_shared_strings(&mapping)?;
         self.change_log_sheet
             .canonical_remap_shared_strings(&mapping)?;
+        self.container.remap_pass_through_shared_strings(&mapping)?;
         Ok(())
     }
     fn validate_fixed_semantics(&self, declared_shared_count: usize) -> Result<()> {
@@ -678,7 +763,11 @@ impl Workbook {
         )?;
         let shared_ref_count = master_facts
             .shared_ref_count
-            .strict_add(change_log_facts.shared_ref_count);
+            .strict_add(change_log_facts.shared_ref_count)
+            .strict_add(
+                self.container
+                    .pass_through_shared_string_count(shared_strings.entries.len())?,
+            );
         if declared_shared_count != shared_ref_count {
             return Err(err(format!(
                 "sharedStrings count가 실제 참조 수와 다릅니다: declared={declared_shared_count}, actual={shared_ref_count}"
@@ -686,6 +775,19 @@ impl Workbook {
         }
         Ok(())
     }
+    pub(crate) fn verify_formula_caches(&self) -> Result<()> {
+        for (sheet_name, worksheet) in [
+            (MASTER_SHEET_NAME, &self.master_sheet),
+            (CHANGE_LOG_SHEET_NAME, &self.change_log_sheet),
+        ] {
+            FormulaEvaluator {
+                shared_strings: &self.shared_strings,
+                worksheet,
+            }
+            .verify_caches(sheet_name)?;
+        }
+        Ok(())
+    }
 }
 impl WorksheetParser<'_> {
     fn parse_row(&mut self, row_body: &str, row_num: u32, row: &mut Row) -> Result<()> {
@@ -990,7 +1092,6 @@ impl WorksheetParser<'_> {
                     } else {
                         let row_delta = row_num.strict_sub(head.anchor_row).cast_signed();
                         shift_formula(&head.formula, 0, row_delta)?
-                            .map_or_else(|| Cow::Borrowed(head.formula.as_str()), Cow::Owned)
                     };
                     head.seen = head.seen.strict_add(1);
                     replace_formula_tag_at(
@@ -1118,6 +1219,7 @@ impl WorksheetParser<'_> {
             }
         }
         Ok(Worksheet {
+            hyperlinks: Vec::new(),
             prefix,
             rows,
             suffix,
@@ -1215,7 +1317,7 @@ impl Worksheet {
                     };
                     let row_delta = candidate_row.strict_sub(row).cast_signed();
                     let expected = shift_formula(&anchor, 0, row_delta)?;
-                    if candidate.as_ref() != expected.as_deref().unwrap_or(&anchor) {
+                    if candidate != expected {
                         break;
                     }
                     last_row = candidate_row;
@@ -1316,9 +1418,13 @@ impl Worksheet {
             let height = get_attr(&attrs, "ht");
             let mut canonical_attrs = mem::take(&mut row.attrs_xml);
             canonical_attrs.clear();
+            let span_last_col = row
+                .cells
+                .last()
+                .map_or(last_col, |cell| cell.col.max(last_col));
             append_fmt(
                 &mut canonical_attrs,
-                format_args!(" spans=\"1:{last_col}\""),
+                format_args!(" spans=\"1:{span_last_col}\""),
             );
             if let Some(style_id) = style {
                 append_fmt(&mut canonical_attrs, format_args!(" s=\"{style_id}\""));
@@ -1712,11 +1818,13 @@ impl Worksheet {
             let tail_start = row.cells.partition_point(|cell| cell.col <= max_col);
             let mut write = tail_start;
             for read in tail_start..row.cells.len() {
-                if cell_has_payload(
-                    row.cells
-                        .get(read)
-                        .ok_or_else(|| err("worksheet cell 정리 범위 오류"))?,
-                )? {
+                let cell = row
+                    .cells
+                    .get(read)
+                    .ok_or_else(|| err("worksheet cell 정리 범위 오류"))?;
+                if matches!(cell.value_type, CellValueType::SharedString(_))
+                    || cell_has_payload(cell)?
+                {
                     row.cells.swap(write, read);
                     write = write.strict_add(1);
                 }
@@ -1765,6 +1873,13 @@ impl Worksheet {
         }
         Ok(())
     }
+    pub(crate) fn push_hyperlink(&mut self, col: u32, row: u32, target: String) -> Result<()> {
+        self.hyperlinks
+            .try_reserve(1)
+            .map_err(|source| err_with_source("worksheet hyperlink 메모리 확보 실패", source))?;
+        self.hyperlinks.push(Hyperlink { col, row, target });
+        Ok(())
+    }
     fn replace_conditional_format_references(&mut self, references: &[String; 3]) -> Result<()> {
         let mut cursor = 0_usize;
         for reference in references {
@@ -1980,6 +2095,12 @@ impl Worksheet {
         }
         Ok(facts)
     }
+    pub(crate) fn set_decimal_at(&mut self, col: u32, row: u32, value: Option<&str>) -> Result<()> {
+        let cell = Self::get_or_create_cell_mut(&mut self.rows, col, row)?;
+        cell.value_type = CellValueType::General;
+        cell.inner_xml = value.map(|text| format!("<v>{text}</v>"));
+        Ok(())
+    }
     pub(crate) fn set_formula_at_with_cache(
         &mut self,
         col: u32,
@@ -2129,7 +2250,29 @@ impl Worksheet {
             }
             Some(capacity)
         })();
-        let capacity = estimated_capacity.ok_or_else(|| err("worksheet XML 용량 계산 실패"))?;
+        let mut hyperlinks_xml = String::new();
+        if !self.hyperlinks.is_empty() {
+            hyperlinks_xml.push_str("<hyperlinks>");
+            for (id, hyperlink) in (1_usize..).zip(&self.hyperlinks) {
+                hyperlinks_xml.push_str("<hyperlink ref=\"");
+                with_unlocked_ref_parts(hyperlink.col, hyperlink.row, |col_text, row_number| {
+                    hyperlinks_xml.push_str(col_text);
+                    push_decimal_text(&mut hyperlinks_xml, row_number);
+                })?;
+                append_fmt(&mut hyperlinks_xml, format_args!("\" r:id=\"rId{id}\"/>"));
+            }
+            hyperlinks_xml.push_str("</hyperlinks>");
+        }
+        let hyperlinks_at = if hyperlinks_xml.is_empty() {
+            self.suffix.len()
+        } else {
+            self.suffix
+                .find("<pageMargins")
+                .ok_or_else(|| err("worksheet hyperlink 삽입 위치를 찾지 못했습니다."))?
+        };
+        let capacity = estimated_capacity
+            .and_then(|capacity| capacity.checked_add(hyperlinks_xml.len()))
+            .ok_or_else(|| err("worksheet XML 용량 계산 실패"))?;
         let mut out = try_string_with_capacity(capacity, "worksheet XML 메모리 확보 실패")?;
         out.push_str(&self.prefix);
         for (row_num, row) in (1_u32..=MAX_A1_ROW).zip(&self.rows) {
@@ -2177,7 +2320,13 @@ impl Worksheet {
             }
             out.push_str("</row>");
         }
-        out.push_str(&self.suffix);
+        let (suffix_head, suffix_tail) = self
+            .suffix
+            .split_at_checked(hyperlinks_at)
+            .ok_or_else(|| err("worksheet suffix 범위가 손상되었습니다."))?;
+        out.push_str(suffix_head);
+        out.push_str(&hyperlinks_xml);
+        out.push_str(suffix_tail);
         Ok((out, shared_string_reference_count))
     }
     pub(crate) fn truncate_rows_after(&mut self, last_row_to_keep: u32) -> Result<()> {
@@ -2405,13 +2554,22 @@ impl Worksheet {
             ExcelSheetKind::Master => (MASTER_SHEET_NAME, 14, &MASTER_HEADERS, 23),
             ExcelSheetKind::ChangeLog => (CHANGE_LOG_SHEET_NAME, 3, &CHANGE_LOG_HEADERS, 13),
         };
-        if self.max_cell_col() != last_col {
+        let max_col = self.max_cell_col();
+        let optional_headers: &[&str] = if sheet == ExcelSheetKind::Master && max_col > last_col {
+            &MASTER_DETOUR_HEADERS
+        } else {
+            &[]
+        };
+        let expected_last_col = optional_headers
+            .len()
+            .try_into()
+            .map_or(last_col, |extra: u32| last_col.strict_add(extra));
+        if max_col != expected_last_col {
             return Err(err(format!(
-                "{sheet_name} 시트의 마지막 열이 고정 스키마와 다릅니다: expected={last_col}, actual={}",
-                self.max_cell_col()
+                "{sheet_name} 시트의 마지막 열이 고정 스키마와 다릅니다: expected={expected_last_col}, actual={max_col}"
             )));
         }
-        for (col, expected) in (1_u32..).zip(headers.iter().copied()) {
+        for (col, expected) in (1_u32..).zip(headers.iter().chain(optional_headers).copied()) {
             let actual = self.try_get_display_at(col, header_row, shared_strings)?;
             if actual.as_ref() != expected {
                 return Err(err(format!(
@@ -2699,16 +2857,24 @@ fn checked_usize_add(base: usize, add: usize, context: &str) -> Result<usize> {
 }
 fn fixed_filter_database_row(workbook_xml: &str) -> Result<(Range<usize>, u32)> {
     let mut scanner = XmlScanner::new(workbook_xml);
-    let element = scanner
-        .next_element_named("definedName")?
-        .ok_or_else(|| err("workbook.xml의 _FilterDatabase를 찾지 못했습니다."))?;
-    let tag = element.opening;
-    if tag.name != "definedName" || tag.self_closing {
-        return Err(err(
-            "workbook.xml의 _FilterDatabase 태그 형식이 고정 스키마와 다릅니다.",
-        ));
+    let mut filter_database = None;
+    while let Some(element) = scanner.next_element_named("definedName")? {
+        let tag = &element.opening;
+        if tag.name != "definedName" || tag.self_closing {
+            return Err(err(
+                "workbook.xml의 definedName 태그 형식이 고정 스키마와 다릅니다.",
+            ));
+        }
+        let attrs = parse_tag_attrs(tag.raw)?;
+        if get_attr(&attrs, "name") == Some(FILTER_DATABASE_NAME)
+            && get_attr(&attrs, "localSheetId") == Some("0")
+            && filter_database.replace((element, attrs)).is_some()
+        {
+            return Err(err("workbook.xml에 _FilterDatabase가 여러 개 있습니다."));
+        }
     }
-    let attrs = parse_tag_attrs(tag.raw)?;
+    let (element, attrs) =
+        filter_database.ok_or_else(|| err("workbook.xml의 _FilterDatabase를 찾지 못했습니다."))?;
     if attrs.len() != 3
         || get_attr(&attrs, "hidden") != Some("1")
         || get_attr(&attrs, "localSheetId") != Some("0")
@@ -2731,9 +2897,6 @@ fn fixed_filter_database_row(workbook_xml: &str) -> Result<(Range<usize>, u32)>
         .start
         .checked_add(FILTER_DATABASE_REF_PREFIX.len())
         .ok_or_else(|| err("_FilterDatabase 마지막 행 시작 계산 실패"))?;
-    if scanner.next_start_named("definedName").is_some() {
-        return Err(err("workbook.xml에 고정 스키마 외 definedName이 있습니다."));
-    }
     Ok((
         Range {
             start: row_start,
@@ -2995,7 +3158,7 @@ fn validated_xml_escaped_len(
             .ok_or_else(|| err(format!("{error_context} 용량 계산 실패")))
     })
 }
-fn append_xml_escaped(out: &mut String, text: &str, context: XmlEscapeContext) {
+pub(super) fn append_xml_escaped(out: &mut String, text: &str, context: XmlEscapeContext) {
     for ch in text.chars() {
         if let Some(replacement) = xml_escape_replacement(ch, context) {
             out.push_str(replacement);
@@ -1,5 +1,6 @@
 use super::CellReference;
 use crate::diagnostic::{Result, append_fmt, err, err_with_source};
+use alloc::borrow::Cow;
 use core::str;
 const COL_NAME_BUF_LEN: usize = 8;
 const _: () = assert!(COL_NAME_BUF_LEN >= 7, "COL_NAME_BUF_LEN too small");
@@ -34,7 +35,7 @@ fn col_name_text(mut col: u32, buffer: &mut [u8; COL_NAME_BUF_LEN]) -> Result<&s
 pub(super) fn parse_range_token(token: &str) -> (&str, &str) {
     token.split_once(':').unwrap_or((token, token))
 }
-fn parse_ref_prefix(reference: &str) -> Option<(CellReference, usize)> {
+pub(super) fn parse_ref_prefix(reference: &str) -> Option<(CellReference, usize)> {
     let bytes = reference.as_bytes();
     let mut index = 0_usize;
     let mut col_locked = false;
@@ -132,11 +133,11 @@ pub(super) fn with_unlocked_ref_parts<R>(
     }
     Ok(use_parts(col_name, row))
 }
-pub(super) fn shift_formula(
+pub(in crate::excel) fn shift_formula(
     formula: &str,
     col_delta: i32,
     row_delta: i32,
-) -> Result<Option<String>> {
+) -> Result<Cow<'_, str>> {
     let bytes = formula.as_bytes();
     let mut copy_start = 0_usize;
     let mut index = 0_usize;
@@ -263,14 +264,15 @@ pub(super) fn shift_formula(
         )?;
         copy_start = reference_end;
     }
-    if let Some(out) = output.as_mut() {
-        out.push_str(
-            formula
-                .get(copy_start..)
-                .ok_or_else(|| err("formula rewrite 나머지 범위가 손상되었습니다."))?,
-        );
-    }
-    Ok(output)
+    let Some(mut out) = output else {
+        return Ok(Cow::Borrowed(formula));
+    };
+    out.push_str(
+        formula
+            .get(copy_start..)
+            .ok_or_else(|| err("formula rewrite 나머지 범위가 손상되었습니다."))?,
+    );
+    Ok(Cow::Owned(out))
 }
 fn shift_reference_index(value: u32, delta: i32, max: u32) -> Result<u32> {
     let shifted = value
@@ -282,6 +284,6 @@ fn shift_reference_index(value: u32, delta: i32, max: u32) -> Result<u32> {
         ))
     })
 }
-const fn is_reference_neighbor(byte: u8) -> bool {
+pub(super) const fn is_reference_neighbor(byte: u8) -> bool {
     byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'.')
 }
@@ -0,0 +1,475 @@
+use super::super::{
+    CHANGE_LOG_SHEET_NAME, MASTER_SHEET_NAME, PackagePart, ZipArchiveBuilder,
+    xml::{XmlScanner, decode_xml_entities, extract_attr, extract_first_tag_text},
+};
+use super::{
+    CHANGE_LOG_LAST_COL, CellReference, CellValueType, ExcelSheetKind, MASTER_LAST_COL, Row,
+    SharedStringTable, Worksheet, XmlEscapeContext, append_xml_escaped,
+    cell_ref::{is_reference_neighbor, parse_range_token, parse_ref_prefix, parse_ref_with_locks},
+    get_attr, parse_tag_attrs, parse_u32_decimal, xml_bool_attr, xml_escape_replacement,
+};
+use crate::{
+    diagnostic::{AppError, Result, append_fmt, err, err_with_source, path_context_message},
+    temp_entry::configure_no_follow,
+};
+use alloc::{borrow::Cow, collections::BTreeMap};
+use core::fmt::{self, Display, Write as _};
+use std::{fs::File, path::Path};
+const ODS_CONTENT_PREFIX: &str = include_str!("../ods_content_prefix.xml");
+const ODS_MASTER_COLUMNS: &str = include_str!("../ods_sheet1_columns.xml");
+const ODS_CHANGE_LOG_COLUMNS: &str = include_str!("../ods_sheet2_columns.xml");
+const ODS_STYLES: &str = include_str!("../ods_styles.xml");
+const ODS_MIMETYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
+const ODS_MANIFEST: &str = concat!(
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+    "<manifest:manifest xmlns:manifest=\"urn:oasis:names:tc:opendocument:xmlns:manifest:1.0\" manifest:version=\"1.3\">",
+    "<manifest:file-entry manifest:full-path=\"/\" manifest:version=\"1.3\" manifest:media-type=\"application/vnd.oasis.opendocument.spreadsheet\"/>",
+    "<manifest:file-entry manifest:full-path=\"content.xml\" manifest:media-type=\"text/xml\"/>",
+    "<manifest:file-entry manifest:full-path=\"styles.xml\" manifest:media-type=\"text/xml\"/>",
+    "</manifest:manifest>\n"
+);
+const ODS_CONDITIONS: [(&str, &str); 2] = [
+    ("&gt;0", "Excel_CondFormat_Positive"),
+    ("&lt;0", "Excel_CondFormat_Negative"),
+];
+const ODS_DEFAULT_ROW_HEIGHT: &str = "16.5";
+pub(super) struct OdsDocument<'book> {
+    pub change_log: &'book Worksheet,
+    pub master: &'book Worksheet,
+    pub shared_strings: &'book SharedStringTable,
+}
+struct OdsTable<'book> {
+    kind: ExcelSheetKind,
+    rows: Vec<OdsRowFormat>,
+    worksheet: &'book Worksheet,
+}
+struct OdsRowFormat {
+    height: String,
+    style: Option<u32>,
+}
+struct OdsFormula(String);
+struct OdsText<'text>(&'text str);
+impl OdsDocument<'_> {
+    fn content_xml(&self) -> Result<String> {
+        let tables = [
+            OdsTable::try_from((ExcelSheetKind::Master, self.master))?,
+            OdsTable::try_from((ExcelSheetKind::ChangeLog, self.change_log))?,
+        ];
+        let mut row_styles = BTreeMap::<&str, usize>::new();
+        row_styles.insert(ODS_DEFAULT_ROW_HEIGHT, 0);
+        for format in tables.iter().flat_map(|table| &table.rows) {
+            let next = row_styles.len();
+            row_styles.entry(&format.height).or_insert(next);
+        }
+        let mut out = String::from(ODS_CONTENT_PREFIX);
+        for (height, index) in &row_styles {
+            append_fmt(
+                &mut out,
+                format_args!(
+                    "<style:style style:name=\"ro{index}\" style:family=\"table-row\"><style:table-row-properties style:row-height=\"{height}pt\" fo:break-before=\"auto\" style:use-optimal-row-height=\"false\"/></style:style>"
+                ),
+            );
+        }
+        out.push_str("</office:automatic-styles><office:body><office:spreadsheet>");
+        for table in &tables {
+            table.append_xml(&mut out, self.shared_strings, &row_styles)?;
+        }
+        let (_, _, filter_last_row) = self.master.fixed_master_auto_filter()?;
+        append_fmt(
+            &mut out,
+            format_args!(
+                "<table:database-ranges><table:database-range table:name=\"__Anonymous_Sheet_DB__0\" table:target-range-address=\"{MASTER_SHEET_NAME}.A14:{MASTER_SHEET_NAME}.W{filter_last_row}\" table:display-filter-buttons=\"true\"/></table:database-ranges>"
+            ),
+        );
+        out.push_str("</office:spreadsheet></office:body></office:document-content>\n");
+        Ok(out)
+    }
+    pub(super) fn write(&self, target_path: &Path) -> Result<()> {
+        let parts = [
+            PackagePart {
+                bytes: ODS_MIMETYPE.as_bytes().to_vec(),
+                name: Cow::Borrowed("mimetype"),
+            },
+            PackagePart {
+                bytes: ODS_MANIFEST.as_bytes().to_vec(),
+                name: Cow::Borrowed("META-INF/manifest.xml"),
+            },
+            PackagePart {
+                bytes: ODS_STYLES.as_bytes().to_vec(),
+                name: Cow::Borrowed("styles.xml"),
+            },
+            PackagePart {
+                bytes: self.content_xml()?.into_bytes(),
+                name: Cow::Borrowed("content.xml"),
+            },
+        ];
+        let mut options = File::options();
+        options.write(true).create(true).truncate(true);
+        configure_no_follow(&mut options);
+        let mut file = options.open(target_path).map_err(|source| {
+            err_with_source(
+                path_context_message("ODS 파일 열기 실패", target_path),
+                source,
+            )
+        })?;
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        let permissions = file
+            .metadata()
+            .map_err(|source| {
+                err_with_source(
+                    path_context_message("ODS 파일 권한 조회 실패", target_path),
+                    source,
+                )
+            })?
+            .permissions();
+        ZipArchiveBuilder {
+            archive_path: target_path,
+            file: &mut file,
+            parts: &parts,
+            #[cfg(any(target_os = "linux", target_os = "macos"))]
+            permissions,
+        }
+        .create()
+    }
+}
+impl OdsTable<'_> {
+    fn append_cell(
+        &self,
+        out: &mut String,
+        shared_strings: &SharedStringTable,
+        (col, row, row_style): (u32, u32, Option<u32>),
+        merges: &[(CellReference, CellReference)],
+    ) -> Result<()> {
+        let covered = merges.iter().any(|&(start, end)| {
+            (start.col..=end.col).contains(&col)
+                && (start.row..=end.row).contains(&row)
+                && (start.col, start.row) != (col, row)
+        });
+        let tag = if covered {
+            "table:covered-table-cell"
+        } else {
+            "table:table-cell"
+        };
+        out.push('<');
+        out.push_str(tag);
+        let cell = self.worksheet.cell_at(col, row);
+        if let Some(style) = cell.and_then(|found| found.style).or(row_style) {
+            append_fmt(out, format_args!(" table:style-name=\"ce{style}\""));
+        }
+        if let Some(&(start, end)) = merges
+            .iter()
+            .find(|&&(start, _)| (start.col, start.row) == (col, row))
+        {
+            append_fmt(
+                out,
+                format_args!(
+                    " table:number-columns-spanned=\"{}\" table:number-rows-spanned=\"{}\"",
+                    end.col.strict_sub(start.col).strict_add(1),
+                    end.row.strict_sub(start.row).strict_add(1)
+                ),
+            );
+        }
+        let Some(found) = cell else {
+            out.push_str("/>");
+            return Ok(());
+        };
+        let inner = found.inner_xml.as_deref().unwrap_or("");
+        if let Some(formula) = extract_first_tag_text(inner, "f")? {
+            let converted = OdsFormula::try_from(decode_xml_entities(formula)?.as_ref())?;
+            out.push_str(" table:formula=\"");
+            append_xml_escaped(out, &converted.0, XmlEscapeContext::Attribute);
+            out.push('"');
+        }
+        let cached = extract_first_tag_text(inner, "v")?
+            .map(decode_xml_entities)
+            .transpose()?;
+        let text = match found.value_type {
+            CellValueType::SharedString(index) => {
+                Some(Cow::Borrowed(shared_strings.get(index).ok_or_else(
+                    || err(format!("shared string index 범위 오류: {index}")),
+                )?))
+            }
+            CellValueType::String => Some(cached.unwrap_or(Cow::Borrowed(""))),
+            CellValueType::General | CellValueType::Number => {
+                if let Some(number) = cached.filter(|value| !value.is_empty()) {
+                    out.push_str(" office:value-type=\"float\" office:value=\"");
+                    append_xml_escaped(out, &number, XmlEscapeContext::Attribute);
+                    out.push_str("\" calcext:value-type=\"float\"");
+                }
+                None
+            }
+        };
+        match text {
+            Some(value) if !value.is_empty() => {
+                out.push_str(" office:value-type=\"string\" calcext:value-type=\"string\">");
+                append_fmt(out, format_args!("{}", OdsText(&value)));
+                out.push_str("</");
+                out.push_str(tag);
+                out.push('>');
+            }
+            Some(_) => {
+                out.push_str(
+                    " office:value-type=\"string\" office:string-value=\"\" calcext:value-type=\"string\"/>",
+                );
+            }
+            None => out.push_str("/>"),
+        }
+        Ok(())
+    }
+    fn append_xml(
+        &self,
+        out: &mut String,
+        shared_strings: &SharedStringTable,
+        row_styles: &BTreeMap<&str, usize>,
+    ) -> Result<()> {
+        let (name, columns, last_col) = match self.kind {
+            ExcelSheetKind::ChangeLog => (
+                CHANGE_LOG_SHEET_NAME,
+                ODS_CHANGE_LOG_COLUMNS,
+                CHANGE_LOG_LAST_COL,
+            ),
+            ExcelSheetKind::Master => (MASTER_SHEET_NAME, ODS_MASTER_COLUMNS, MASTER_LAST_COL),
+        };
+        let mut merges = Vec::new();
+        let mut scanner = XmlScanner::new(&self.worksheet.suffix);
+        while let Some(merge) = scanner.next_start_named("mergeCell") {
+            let reference = extract_attr(merge.raw, "ref")?
+                .ok_or_else(|| err(format!("{name} mergeCell에 ref가 없습니다.")))?;
+            let (start_ref, end_ref) = parse_range_token(&reference);
+            let range = parse_ref_with_locks(start_ref).zip(parse_ref_with_locks(end_ref));
+            merges.push(
+                range
+                    .ok_or_else(|| err(format!("{name} mergeCell 범위 해석 실패: {reference}")))?,
+            );
+        }
+        append_fmt(
+            out,
+            format_args!("<table:table table:name=\"{name}\">{columns}"),
+        );
+        for ((row_num, row), format) in (1_u32..).zip(&self.worksheet.rows).zip(&self.rows) {
+            let row_style = row_styles.get(format.height.as_str()).copied().unwrap_or(0);
+            append_fmt(
+                out,
+                format_args!("<table:table-row table:style-name=\"ro{row_style}\">"),
+            );
+            let row_last_col = row
+                .cells
+                .last()
+                .map_or(last_col, |cell| cell.col.max(last_col));
+            for col in 1..=row_last_col {
+                self.append_cell(out, shared_strings, (col, row_num, format.style), &merges)?;
+            }
+            out.push_str("</table:table-row>");
+        }
+        if matches!(self.kind, ExcelSheetKind::ChangeLog) {
+            out.push_str("<calcext:conditional-formats>");
+            for reference in self.worksheet.conditional_format_references()? {
+                let (start_ref, end_ref) = parse_range_token(&reference);
+                if parse_ref_with_locks(start_ref)
+                    .zip(parse_ref_with_locks(end_ref))
+                    .is_none()
+                {
+                    return Err(err(format!(
+                        "{name} 조건부 서식 범위 해석 실패: {reference}"
+                    )));
+                }
+                append_fmt(
+                    out,
+                    format_args!(
+                        "<calcext:conditional-format calcext:target-range-address=\"{name}.{start_ref}:{name}.{end_ref}\">"
+                    ),
+                );
+                for (condition, style) in ODS_CONDITIONS {
+                    append_fmt(
+                        out,
+                        format_args!(
+                            "<calcext:condition calcext:apply-style-name=\"{style}\" calcext:value=\"{condition}\" calcext:base-cell-address=\"{name}.{start_ref}\"/>"
+                        ),
+                    );
+                }
+                out.push_str("</calcext:conditional-format>");
+            }
+            out.push_str("</calcext:conditional-formats>");
+        }
+        out.push_str("</table:table>");
+        Ok(())
+    }
+}
+impl<'book> TryFrom<(ExcelSheetKind, &'book Worksheet)> for OdsTable<'book> {
+    type Error = AppError;
+    fn try_from((kind, worksheet): (ExcelSheetKind, &'book Worksheet)) -> Result<Self> {
+        let mut rows = Vec::with_capacity(worksheet.rows.len());
+        let mut source_tag = String::new();
+        for row in &worksheet.rows {
+            rows.push(OdsRowFormat::try_from((row, &mut source_tag))?);
+        }
+        Ok(Self {
+            kind,
+            rows,
+            worksheet,
+        })
+    }
+}
+impl TryFrom<(&Row, &mut String)> for OdsRowFormat {
+    type Error = AppError;
+    fn try_from((row, source_tag): (&Row, &mut String)) -> Result<Self> {
+        source_tag.clear();
+        source_tag.push_str("<row");
+        source_tag.push_str(&row.attrs_xml);
+        source_tag.push_str("/>");
+        let attrs = parse_tag_attrs(source_tag)?;
+        let height = get_attr(&attrs, "ht").unwrap_or(ODS_DEFAULT_ROW_HEIGHT);
+        if height.is_empty()
+            || !height
+                .bytes()
+                .all(|byte| byte.is_ascii_digit() || byte == b'.')
+        {
+            return Err(err(format!("row 높이가 올바르지 않습니다: {height}")));
+        }
+        let style = if xml_bool_attr(&attrs, "customFormat")? {
+            get_attr(&attrs, "s")
+                .map(|value| {
+                    parse_u32_decimal(
+                        value,
+                        || Cow::Borrowed("row style이 10진수가 아닙니다."),
+                        || Cow::Borrowed("row style 해석 실패"),
+                    )
+                })
+                .transpose()?
+        } else {
+            None
+        };
+        Ok(Self {
+            height: height.to_owned(),
+            style,
+        })
+    }
+}
+impl TryFrom<&str> for OdsFormula {
+    type Error = AppError;
+    fn try_from(formula: &str) -> Result<Self> {
+        let bytes = formula.as_bytes();
+        let mut converted = String::from("of:=");
+        let mut copy_start = 0_usize;
+        let mut index = 0_usize;
+        let copy = |target: &mut String, range: (usize, usize)| -> Result<()> {
+            target.push_str(
+                formula
+                    .get(range.0..range.1)
+                    .ok_or_else(|| err("ODS 수식 변환 복사 범위가 손상되었습니다."))?,
+            );
+            Ok(())
+        };
+        while let Some(&byte) = bytes.get(index) {
+            match byte {
+                b'"' => {
+                    index = index.strict_add(1);
+                    while let Some(&string_byte) = bytes.get(index) {
+                        index = index.strict_add(1);
+                        if string_byte == b'"' {
+                            if bytes.get(index) != Some(&b'"') {
+                                break;
+                            }
+                            index = index.strict_add(1);
+                        }
+                    }
+                    continue;
+                }
+                b',' => {
+                    copy(&mut converted, (copy_start, index))?;
+                    converted.push(';');
+                    index = index.strict_add(1);
+                    copy_start = index;
+                    continue;
+                }
+                b'\'' | b'!' | b'[' => {
+                    return Err(err(format!(
+                        "ODS로 변환할 수 없는 수식 참조입니다: {formula}"
+                    )));
+                }
+                _ => {}
+            }
+            let previous = index
+                .checked_sub(1)
+                .and_then(|position| bytes.get(position));
+            let reference = if matches!(byte, b'$' | b'A'..=b'Z' | b'a'..=b'z')
+                && !previous.is_some_and(|neighbor| is_reference_neighbor(*neighbor))
+            {
+                formula.get(index..).and_then(parse_ref_prefix)
+            } else {
+                None
+            };
+            let Some((_, start_len)) = reference else {
+                index = index.strict_add(1);
+                continue;
+            };
+            let start_end = index.strict_add(start_len);
+            let range_end = if bytes.get(start_end) == Some(&b':') {
+                formula
+                    .get(start_end.strict_add(1)..)
+                    .and_then(parse_ref_prefix)
+                    .map(|(_, end_len)| start_end.strict_add(1).strict_add(end_len))
+            } else {
+                None
+            };
+            let reference_end = range_end.unwrap_or(start_end);
+            if bytes
+                .get(reference_end)
+                .is_some_and(|neighbor| is_reference_neighbor(*neighbor) || *neighbor == b'(')
+            {
+                index = index.strict_add(1);
+                continue;
+            }
+            copy(&mut converted, (copy_start, index))?;
+            converted.push_str("[.");
+            copy(&mut converted, (index, start_end))?;
+            if range_end.is_some() {
+                converted.push_str(":.");
+                copy(&mut converted, (start_end.strict_add(1), reference_end))?;
+            }
+            converted.push(']');
+            index = reference_end;
+            copy_start = index;
+        }
+        copy(&mut converted, (copy_start, formula.len()))?;
+        Ok(Self(converted))
+    }
+}
+impl Display for OdsText<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.0.split('\n') {
+            f.write_str("<text:p>")?;
+            let mut spaces = 0_u32;
+            let mut line_start = true;
+            for ch in line.chars().chain(['\n']) {
+                if ch == ' ' {
+                    spaces = spaces.strict_add(1);
+                    continue;
+                }
+                if spaces > 0 {
+                    let collapsed = if line_start {
+                        spaces
+                    } else {
+                        f.write_char(' ')?;
+                        spaces.strict_sub(1)
+                    };
+                    if collapsed > 0 {
+                        write!(f, "<text:s text:c=\"{collapsed}\"/>")?;
+                    }
+                    spaces = 0;
+                }
+                line_start = false;
+                match ch {
+                    '\n' | '\r' => {}
+                    '\t' => f.write_str("<text:tab/>")?,
+                    _ => match xml_escape_replacement(ch, XmlEscapeContext::Text) {
+                        Some(replacement) => f.write_str(replacement)?,
+                        None => f.write_char(ch)?,
+                    },
+                }
+            }
+            f.write_str("</text:p>")?;
+        }
+        Ok(())
+    }
+}
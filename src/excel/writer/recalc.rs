@@ -0,0 +1,956 @@
+use super::{
+    CellReference, CellValueType, SharedStringTable, Worksheet,
+    cell_ref::{parse_ref_prefix, ref_with_locks},
+    format_excel_ratio_into,
+};
+use crate::{
+    diagnostic::{AppError, Result, err, err_with_source},
+    excel::xml::{decode_xml_entities, extract_first_tag_text},
+};
+use alloc::borrow::Cow;
+use core::{cmp::Ordering, result::Result as CoreResult};
+#[cfg(test)]
+mod tests;
+const FORMULA_CACHE_TOLERANCE: i128 = 1_000_000_000_000;
+const FORMULA_COMPARISON_OPERATORS: [&str; 6] = ["<=", ">=", "<>", "=", "<", ">"];
+const FORMULA_ERROR_DIV_ZERO: &str = "#DIV/0!";
+const FORMULA_ERROR_NOT_AVAILABLE: &str = "#N/A";
+const FORMULA_ERROR_REF: &str = "#REF!";
+const FORMULA_ERROR_VALUE: &str = "#VALUE!";
+const FORMULA_MAX_ARGUMENTS: usize = 16;
+const FORMULA_MAX_DEPTH: usize = 64;
+const FORMULA_OVERFLOW_MESSAGE: &str = "수식 검증 숫자 계산 중 overflow가 발생했습니다.";
+type FormulaOutcome<T> = CoreResult<T, &'static str>;
+pub(super) struct FormulaEvaluator<'sheet> {
+    pub shared_strings: &'sheet SharedStringTable,
+    pub worksheet: &'sheet Worksheet,
+}
+#[derive(Clone, Copy, Eq, PartialEq)]
+struct FormulaNumber {
+    denominator: i128,
+    numerator: i128,
+}
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum FormulaKind {
+    Blank,
+    Boolean(bool),
+    Error(&'static str),
+    Number(FormulaNumber),
+    Text,
+}
+struct FormulaValue<'sheet> {
+    kind: FormulaKind,
+    text: Cow<'sheet, str>,
+}
+enum FormulaOperand<'sheet> {
+    Range(CellReference, CellReference),
+    Value(FormulaValue<'sheet>),
+}
+struct FormulaParser<'eval, 'sheet> {
+    depth: usize,
+    evaluator: &'eval FormulaEvaluator<'sheet>,
+    rest: &'eval str,
+}
+impl FormulaNumber {
+    const ONE: Self = Self::from_integer(1);
+    const ZERO: Self = Self::from_integer(0);
+    fn abs(self) -> Option<Self> {
+        Some(Self {
+            denominator: self.denominator,
+            numerator: self.numerator.checked_abs()?,
+        })
+    }
+    fn approx_eq(self, other: Self) -> bool {
+        if self == other {
+            return true;
+        }
+        let tolerance_check = || {
+            let lhs_abs = self.abs()?;
+            let rhs_abs = other.abs()?;
+            let mut magnitude = if lhs_abs.compare(rhs_abs)? == Ordering::Less {
+                rhs_abs
+            } else {
+                lhs_abs
+            };
+            if magnitude.compare(Self::ONE)? == Ordering::Less {
+                magnitude = Self::ONE;
+            }
+            let scaled_diff = self
+                .checked_sub(other)?
+                .abs()?
+                .checked_mul(Self::from_integer(FORMULA_CACHE_TOLERANCE))?;
+            scaled_diff.compare(magnitude)
+        };
+        tolerance_check().is_some_and(|ordering| ordering != Ordering::Greater)
+    }
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::new(
+            self.numerator
+                .checked_mul(rhs.denominator)?
+                .checked_add(rhs.numerator.checked_mul(self.denominator)?)?,
+            self.denominator.checked_mul(rhs.denominator)?,
+        )
+    }
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        Self::new(
+            self.numerator.checked_mul(rhs.denominator)?,
+            self.denominator.checked_mul(rhs.numerator)?,
+        )
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::new(
+            self.numerator.checked_mul(rhs.numerator)?,
+            self.denominator.checked_mul(rhs.denominator)?,
+        )
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(Self {
+            denominator: rhs.denominator,
+            numerator: rhs.numerator.checked_neg()?,
+        })
+    }
+    fn compare(self, other: Self) -> Option<Ordering> {
+        let lhs = self.numerator.checked_mul(other.denominator)?;
+        let rhs = other.numerator.checked_mul(self.denominator)?;
+        Some(lhs.cmp(&rhs))
+    }
+    const fn from_integer(value: i128) -> Self {
+        Self {
+            denominator: 1,
+            numerator: value,
+        }
+    }
+    fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let numerator_abs = numerator.unsigned_abs();
+        let denominator_abs = denominator.unsigned_abs();
+        let (mut divisor, mut remainder) = (numerator_abs, denominator_abs);
+        while remainder != 0 {
+            (divisor, remainder) = (remainder, divisor.rem_euclid(remainder));
+        }
+        let magnitude = i128::try_from(numerator_abs.checked_div(divisor)?).ok()?;
+        Some(Self {
+            denominator: i128::try_from(denominator_abs.checked_div(divisor)?).ok()?,
+            numerator: if (numerator < 0) == (denominator < 0) {
+                magnitude
+            } else {
+                magnitude.checked_neg()?
+            },
+        })
+    }
+    fn parse(text: &str) -> Option<Self> {
+        let (negative, unsigned) = text.strip_prefix('-').map_or_else(
+            || (false, text.strip_prefix('+').unwrap_or(text)),
+            |rest| (true, rest),
+        );
+        let (mantissa, exponent) = match unsigned.split_once(['E', 'e']) {
+            Some((digits_part, exponent_text)) => (digits_part, exponent_text.parse::<i32>().ok()?),
+            None => (unsigned, 0_i32),
+        };
+        let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return None;
+        }
+        let mut digits = 0_i128;
+        for byte in whole.bytes().chain(fraction.bytes()) {
+            if !byte.is_ascii_digit() {
+                return None;
+            }
+            digits = digits
+                .checked_mul(10)?
+                .checked_add(i128::from(byte.strict_sub(b'0')))?;
+        }
+        let signed = if negative {
+            digits.checked_neg()?
+        } else {
+            digits
+        };
+        let scale = i32::try_from(fraction.len()).ok()?.checked_sub(exponent)?;
+        let power = 10_i128.checked_pow(scale.unsigned_abs())?;
+        if scale < 0_i32 {
+            return Self::new(signed.checked_mul(power)?, 1);
+        }
+        Self::new(signed, power)
+    }
+    fn round_half_away_from_zero(self) -> Option<i128> {
+        let denominator = self.denominator.unsigned_abs();
+        let magnitude = self.numerator.unsigned_abs();
+        let mut whole = magnitude.div_euclid(denominator);
+        if magnitude.rem_euclid(denominator).checked_mul(2)? >= denominator {
+            whole = whole.checked_add(1)?;
+        }
+        let rounded = i128::try_from(whole).ok()?;
+        if self.numerator < 0 {
+            return rounded.checked_neg();
+        }
+        Some(rounded)
+    }
+    fn truncate_digits(self, digits: u32) -> Option<Self> {
+        let scale = 10_i128.checked_pow(digits)?;
+        let scaled = self.numerator.checked_mul(scale)?;
+        let whole = i128::try_from(
+            scaled
+                .unsigned_abs()
+                .div_euclid(self.denominator.unsigned_abs()),
+        )
+        .ok()?;
+        Self::new(
+            if scaled < 0 {
+                whole.checked_neg()?
+            } else {
+                whole
+            },
+            scale,
+        )
+    }
+}
+impl From<FormulaKind> for FormulaValue<'_> {
+    fn from(kind: FormulaKind) -> Self {
+        Self {
+            kind,
+            text: Cow::Borrowed(""),
+        }
+    }
+}
+impl FormulaValue<'_> {
+    fn arithmetic(&self, rhs: &Self, operator: u8) -> Result<Self> {
+        let (lhs_number, rhs_number) = match (self.to_number(), rhs.to_number()) {
+            (Ok(lhs_number), Ok(rhs_number)) => (lhs_number, rhs_number),
+            (Err(code), _) | (_, Err(code)) => return Ok(Self::from(FormulaKind::Error(code))),
+        };
+        if operator == b'/' && rhs_number.numerator == 0 {
+            return Ok(Self::from(FormulaKind::Error(FORMULA_ERROR_DIV_ZERO)));
+        }
+        let result = match operator {
+            b'+' => lhs_number.checked_add(rhs_number),
+            b'-' => lhs_number.checked_sub(rhs_number),
+            b'*' => lhs_number.checked_mul(rhs_number),
+            _ => lhs_number.checked_div(rhs_number),
+        }
+        .ok_or_else(|| err(FORMULA_OVERFLOW_MESSAGE))?;
+        Ok(Self::from(FormulaKind::Number(result)))
+    }
+    fn compare(&self, other: &Self) -> FormulaOutcome<Ordering> {
+        match (self.kind, other.kind) {
+            (FormulaKind::Error(code), _) | (_, FormulaKind::Error(code)) => Err(code),
+            (FormulaKind::Blank | FormulaKind::Text, FormulaKind::Text)
+            | (FormulaKind::Text, FormulaKind::Blank) => {
+                Ok(self.text.to_lowercase().cmp(&other.text.to_lowercase()))
+            }
+            (FormulaKind::Boolean(lhs), FormulaKind::Boolean(rhs)) => Ok(lhs.cmp(&rhs)),
+            (FormulaKind::Blank, FormulaKind::Boolean(rhs)) => Ok(false.cmp(&rhs)),
+            (FormulaKind::Boolean(lhs), FormulaKind::Blank) => Ok(lhs.cmp(&false)),
+            (
+                FormulaKind::Blank | FormulaKind::Number(_),
+                FormulaKind::Blank | FormulaKind::Number(_),
+            ) => self
+                .to_number()?
+                .compare(other.to_number()?)
+                .ok_or(FORMULA_ERROR_VALUE),
+            (FormulaKind::Number(_), FormulaKind::Boolean(_) | FormulaKind::Text)
+            | (FormulaKind::Text, FormulaKind::Boolean(_)) => Ok(Ordering::Less),
+            (FormulaKind::Boolean(_) | FormulaKind::Text, FormulaKind::Number(_))
+            | (FormulaKind::Boolean(_), FormulaKind::Text) => Ok(Ordering::Greater),
+        }
+    }
+    fn compare_with(&self, rhs: &Self, operator: &str) -> Self {
+        let ordering = match self.compare(rhs) {
+            Ok(ordering) => ordering,
+            Err(code) => return Self::from(FormulaKind::Error(code)),
+        };
+        Self::from(FormulaKind::Boolean(match operator {
+            "=" => ordering == Ordering::Equal,
+            "<>" => ordering != Ordering::Equal,
+            "<" => ordering == Ordering::Less,
+            ">" => ordering == Ordering::Greater,
+            "<=" => ordering != Ordering::Greater,
+            _ => ordering != Ordering::Less,
+        }))
+    }
+    fn concat(&self, rhs: &Self) -> Result<Self> {
+        let (lhs_text, rhs_text) = match (self.to_text()?, rhs.to_text()?) {
+            (Ok(lhs_text), Ok(rhs_text)) => (lhs_text, rhs_text),
+            (Err(code), _) | (_, Err(code)) => return Ok(Self::from(FormulaKind::Error(code))),
+        };
+        let mut joined = String::new();
+        joined
+            .try_reserve(lhs_text.len().strict_add(rhs_text.len()))
+            .map_err(|source| err_with_source("수식 검증 문자열 메모리 확보 실패", source))?;
+        joined.push_str(&lhs_text);
+        joined.push_str(&rhs_text);
+        Ok(Self {
+            kind: FormulaKind::Text,
+            text: Cow::Owned(joined),
+        })
+    }
+    fn describe(&self) -> Result<String> {
+        Ok(match self.kind {
+            FormulaKind::Blank => "(빈 값)".to_owned(),
+            FormulaKind::Boolean(flag) => if flag { "TRUE" } else { "FALSE" }.to_owned(),
+            FormulaKind::Error(code) => code.to_owned(),
+            FormulaKind::Number(number) => {
+                let mut out = String::new();
+                format_excel_ratio_into(&mut out, number.numerator, number.denominator)?;
+                out
+            }
+            FormulaKind::Text => format!("\"{}\"", self.text),
+        })
+    }
+    fn format_text(&self, format: &Self) -> Result<Self> {
+        let (number, format_text) = match (self.to_number(), format.to_text()?) {
+            (Ok(number), Ok(format_text)) => (number, format_text),
+            (Err(code), _) | (_, Err(code)) => return Ok(Self::from(FormulaKind::Error(code))),
+        };
+        let grouped = match format_text.as_ref() {
+            "#,##0" => true,
+            "0" => false,
+            unsupported => {
+                return Err(err(format!(
+                    "수식 검증이 지원하지 않는 TEXT 서식입니다: {unsupported}"
+                )));
+            }
+        };
+        let rounded = number
+            .round_half_away_from_zero()
+            .ok_or_else(|| err(FORMULA_OVERFLOW_MESSAGE))?;
+        let digits = rounded.unsigned_abs().to_string();
+        let mut out = String::new();
+        if rounded < 0 {
+            out.push('-');
+        }
+        for (index, digit) in digits.chars().enumerate() {
+            if grouped && index > 0 && digits.len().strict_sub(index).is_multiple_of(3) {
+                out.push(',');
+            }
+            out.push(digit);
+        }
+        Ok(Self {
+            kind: FormulaKind::Text,
+            text: Cow::Owned(out),
+        })
+    }
+    fn round_down(&self, digits: &Self) -> Result<Self> {
+        let (number, digits_number) = match (self.to_number(), digits.to_number()) {
+            (Ok(number), Ok(digits_number)) => (number, digits_number),
+            (Err(code), _) | (_, Err(code)) => return Ok(Self::from(FormulaKind::Error(code))),
+        };
+        let digit_count = digits_number
+            .truncate_digits(0)
+            .and_then(|whole| u32::try_from(whole.numerator).ok())
+            .ok_or_else(|| err("수식 검증은 음수 ROUNDDOWN 자릿수를 지원하지 않습니다."))?;
+        let truncated = number
+            .truncate_digits(digit_count)
+            .ok_or_else(|| err(FORMULA_OVERFLOW_MESSAGE))?;
+        Ok(Self::from(FormulaKind::Number(truncated)))
+    }
+    fn search_in(&self, haystack: &Self, start: &Self) -> Result<Self> {
+        let (needle_text, haystack_text, start_number) =
+            match (self.to_text()?, haystack.to_text()?, start.to_number()) {
+                (Ok(needle_text), Ok(haystack_text), Ok(start_number)) => {
+                    (needle_text, haystack_text, start_number)
+                }
+                (Err(code), _, _) | (_, Err(code), _) | (_, _, Err(code)) => {
+                    return Ok(Self::from(FormulaKind::Error(code)));
+                }
+            };
+        if needle_text.contains(['*', '?', '~']) {
+            return Err(err(
+                "수식 검증은 와일드카드가 포함된 SEARCH를 지원하지 않습니다.",
+            ));
+        }
+        let needle = needle_text.to_lowercase();
+        let lowered = haystack_text.to_lowercase();
+        let found = start_number
+            .truncate_digits(0)
+            .and_then(|whole| usize::try_from(whole.numerator).ok())
+            .and_then(|position| position.checked_sub(1))
+            .and_then(|skip| {
+                let (offset, _) = lowered
+                    .char_indices()
+                    .chain([(lowered.len(), ' ')])
+                    .nth(skip)?;
+                let matched = lowered.get(offset..)?.find(needle.as_str())?;
+                let prefix = lowered.get(..offset.checked_add(matched)?)?;
+                i128::try_from(prefix.chars().count().checked_add(1)?).ok()
+            });
+        Ok(Self::from(found.map_or(
+            FormulaKind::Error(FORMULA_ERROR_VALUE),
+            |position| FormulaKind::Number(FormulaNumber::from_integer(position)),
+        )))
+    }
+    fn to_bool(&self) -> FormulaOutcome<bool> {
+        match self.kind {
+            FormulaKind::Blank => Ok(false),
+            FormulaKind::Boolean(flag) => Ok(flag),
+            FormulaKind::Error(code) => Err(code),
+            FormulaKind::Number(number) => Ok(number.numerator != 0),
+            FormulaKind::Text => {
+                if self.text.eq_ignore_ascii_case("TRUE") {
+                    return Ok(true);
+                }
+                if self.text.eq_ignore_ascii_case("FALSE") {
+                    return Ok(false);
+                }
+                Err(FORMULA_ERROR_VALUE)
+            }
+        }
+    }
+    fn to_number(&self) -> FormulaOutcome<FormulaNumber> {
+        match self.kind {
+            FormulaKind::Blank => Ok(FormulaNumber::ZERO),
+            FormulaKind::Boolean(flag) => Ok(if flag {
+                FormulaNumber::ONE
+            } else {
+                FormulaNumber::ZERO
+            }),
+            FormulaKind::Error(code) => Err(code),
+            FormulaKind::Number(number) => Ok(number),
+            FormulaKind::Text => FormulaNumber::parse(self.text.trim()).ok_or(FORMULA_ERROR_VALUE),
+        }
+    }
+    fn to_text(&self) -> Result<FormulaOutcome<Cow<'_, str>>> {
+        Ok(match self.kind {
+            FormulaKind::Blank | FormulaKind::Text => Ok(Cow::Borrowed(self.text.as_ref())),
+            FormulaKind::Boolean(flag) => Ok(Cow::Borrowed(if flag { "TRUE" } else { "FALSE" })),
+            FormulaKind::Error(code) => Err(code),
+            FormulaKind::Number(number) => {
+                let mut out = String::new();
+                format_excel_ratio_into(&mut out, number.numerator, number.denominator)?;
+                Ok(Cow::Owned(out))
+            }
+        })
+    }
+}
+impl<'sheet> TryFrom<FormulaOperand<'sheet>> for FormulaValue<'sheet> {
+    type Error = AppError;
+    fn try_from(operand: FormulaOperand<'sheet>) -> Result<Self> {
+        match operand {
+            FormulaOperand::Range(..) => Err(err(
+                "수식 검증은 범위를 단일 값으로 사용하는 수식을 지원하지 않습니다.",
+            )),
+            FormulaOperand::Value(value) => Ok(value),
+        }
+    }
+}
+impl<'sheet> FormulaEvaluator<'sheet> {
+    fn call_function(
+        &self,
+        name: &str,
+        mut args: Vec<FormulaOperand<'sheet>>,
+    ) -> Result<FormulaValue<'sheet>> {
+        let (min_args, max_args) = match name {
+            "TRUE" | "FALSE" => (0, 0),
+            "AND" | "OR" => (1, FORMULA_MAX_ARGUMENTS),
+            "IFERROR" | "COUNTIF" | "ROUNDDOWN" | "TEXT" => (2, 2),
+            "IF" | "SEARCH" => (2, 3),
+            "VLOOKUP" => (3, 4),
+            _ => {
+                return Err(err(format!("수식 검증이 지원하지 않는 함수입니다: {name}")));
+            }
+        };
+        let arity_error = || {
+            err(format!(
+                "수식 검증 대상 함수의 인자 형식이 올바르지 않습니다: {name}"
+            ))
+        };
+        if !(min_args..=max_args).contains(&args.len()) {
+            return Err(arity_error());
+        }
+        if name == "AND" || name == "OR" {
+            return self.logical(name == "AND", args);
+        }
+        if name == "COUNTIF" {
+            let Ok([FormulaOperand::Range(start, end), criteria]) = <[_; 2]>::try_from(args) else {
+                return Err(arity_error());
+            };
+            return self.count_if(start, end, &FormulaValue::try_from(criteria)?);
+        }
+        let default_arg = match name {
+            "IF" => FormulaKind::Boolean(false),
+            "SEARCH" => FormulaKind::Number(FormulaNumber::ONE),
+            _ => FormulaKind::Boolean(true),
+        };
+        if args.len() < max_args {
+            args.push(FormulaOperand::Value(FormulaValue::from(default_arg)));
+        }
+        if name == "VLOOKUP" {
+            let Ok([lookup, FormulaOperand::Range(start, end), col_index, exact]) =
+                <[_; 4]>::try_from(args)
+            else {
+                return Err(arity_error());
+            };
+            return self.vertical_lookup(
+                &FormulaValue::try_from(lookup)?,
+                (start, end),
+                &FormulaValue::try_from(col_index)?,
+                &FormulaValue::try_from(exact)?,
+            );
+        }
+        let mut values = args.into_iter().map(FormulaValue::try_from);
+        let (Some(first), Some(second)) = (values.next().transpose()?, values.next().transpose()?)
+        else {
+            return Ok(FormulaValue::from(FormulaKind::Boolean(name == "TRUE")));
+        };
+        match (name, values.next().transpose()?) {
+            ("IFERROR", _) => Ok(if matches!(first.kind, FormulaKind::Error(_)) {
+                second
+            } else {
+                first
+            }),
+            ("ROUNDDOWN", _) => first.round_down(&second),
+            ("TEXT", _) => first.format_text(&second),
+            ("SEARCH", Some(start)) => first.search_in(&second, &start),
+            (_, Some(when_false)) => Ok(match first.to_bool() {
+                Ok(true) => second,
+                Ok(false) => when_false,
+                Err(code) => FormulaValue::from(FormulaKind::Error(code)),
+            }),
+            (_, None) => Err(arity_error()),
+        }
+    }
+    fn cell_value(&self, col: u32, row: u32) -> Result<FormulaValue<'sheet>> {
+        let Some(cell) = self.worksheet.cell_at(col, row) else {
+            return Ok(FormulaValue::from(FormulaKind::Blank));
+        };
+        if let CellValueType::SharedString(index) = cell.value_type {
+            let text = self
+                .shared_strings
+                .get(index)
+                .ok_or_else(|| err(format!("shared string index 범위 오류: {index}")))?;
+            return Ok(FormulaValue {
+                kind: FormulaKind::Text,
+                text: Cow::Borrowed(text),
+            });
+        }
+        let Some(inner) = cell.inner_xml.as_deref() else {
+            return Ok(FormulaValue::from(FormulaKind::Blank));
+        };
+        let raw_value = extract_first_tag_text(inner, "v")?;
+        if cell.value_type == CellValueType::String {
+            return Ok(FormulaValue {
+                kind: FormulaKind::Text,
+                text: decode_xml_entities(raw_value.unwrap_or(""))?,
+            });
+        }
+        let Some(number_text) = raw_value.filter(|text| !text.is_empty()) else {
+            return Ok(FormulaValue::from(FormulaKind::Blank));
+        };
+        let number = FormulaNumber::parse(number_text.trim()).ok_or_else(|| {
+            err(format!(
+                "수식 검증 대상 숫자 cell 값을 해석하지 못했습니다: row={row}, col={col}, value={number_text}"
+            ))
+        })?;
+        Ok(FormulaValue::from(FormulaKind::Number(number)))
+    }
+    fn count_if(
+        &self,
+        start: CellReference,
+        end: CellReference,
+        criteria: &FormulaValue<'sheet>,
+    ) -> Result<FormulaValue<'sheet>> {
+        if let FormulaKind::Error(code) = criteria.kind {
+            return Ok(FormulaValue::from(FormulaKind::Error(code)));
+        }
+        let criteria_text = match criteria.to_text()? {
+            Ok(text) => text,
+            Err(code) => return Ok(FormulaValue::from(FormulaKind::Error(code))),
+        };
+        let (operator, operand) = FORMULA_COMPARISON_OPERATORS
+            .into_iter()
+            .find_map(|operator| {
+                criteria_text
+                    .strip_prefix(operator)
+                    .map(|operand| (operator, operand))
+            })
+            .unwrap_or_else(|| ("=", criteria_text.as_ref()));
+        let target = if let Some(number) = FormulaNumber::parse(operand.trim()) {
+            FormulaValue::from(FormulaKind::Number(number))
+        } else if operand.contains(['*', '?', '~']) {
+            return Err(err(
+                "수식 검증은 와일드카드가 포함된 COUNTIF를 지원하지 않습니다.",
+            ));
+        } else {
+            FormulaValue {
+                kind: FormulaKind::Text,
+                text: Cow::Borrowed(operand),
+            }
+        };
+        let mut count = 0_i128;
+        for (col, row) in range_cells(start, end) {
+            let cell = self.cell_value(col, row)?;
+            let same_kind = matches!(
+                (cell.kind, target.kind),
+                (FormulaKind::Number(_), FormulaKind::Number(_))
+                    | (FormulaKind::Text, FormulaKind::Text)
+            );
+            let matched = if same_kind {
+                cell.compare_with(&target, operator).kind == FormulaKind::Boolean(true)
+            } else {
+                operator == "<>"
+            };
+            if matched {
+                count = count.strict_add(1);
+            }
+        }
+        Ok(FormulaValue::from(FormulaKind::Number(
+            FormulaNumber::from_integer(count),
+        )))
+    }
+    fn logical(
+        &self,
+        all: bool,
+        args: Vec<FormulaOperand<'sheet>>,
+    ) -> Result<FormulaValue<'sheet>> {
+        let mut result = all;
+        for operand in args {
+            match operand {
+                FormulaOperand::Range(start, end) => {
+                    for (col, row) in range_cells(start, end) {
+                        let cell = self.cell_value(col, row)?;
+                        if matches!(cell.kind, FormulaKind::Blank | FormulaKind::Text) {
+                            continue;
+                        }
+                        match cell.to_bool() {
+                            Ok(flag) => result = if all { result && flag } else { result || flag },
+                            Err(code) => return Ok(FormulaValue::from(FormulaKind::Error(code))),
+                        }
+                    }
+                }
+                FormulaOperand::Value(value) => match value.to_bool() {
+                    Ok(flag) => result = if all { result && flag } else { result || flag },
+                    Err(code) => return Ok(FormulaValue::from(FormulaKind::Error(code))),
+                },
+            }
+        }
+        Ok(FormulaValue::from(FormulaKind::Boolean(result)))
+    }
+    pub(super) fn verify_caches(&self, sheet_name: &str) -> Result<()> {
+        for (row_offset, row) in self.worksheet.rows.iter().enumerate() {
+            let row_num = u32::try_from(row_offset.strict_add(1))
+                .map_err(|source| err_with_source("수식 검증 행 번호 변환 실패", source))?;
+            for cell in &row.cells {
+                let Some(formula) = self.worksheet.try_get_formula_at(cell.col, row_num)? else {
+                    continue;
+                };
+                let reference = ref_with_locks(CellReference {
+                    col: cell.col,
+                    col_locked: false,
+                    row: row_num,
+                    row_locked: false,
+                })?;
+                let mut expected = FormulaParser {
+                    depth: 0,
+                    evaluator: self,
+                    rest: &formula,
+                }
+                .evaluate()
+                .map_err(|source| {
+                    err_with_source(
+                        format!("{sheet_name}!{reference} 수식 재계산 실패: {formula}"),
+                        source,
+                    )
+                })?;
+                if expected.kind == FormulaKind::Blank {
+                    expected = FormulaValue::from(FormulaKind::Number(FormulaNumber::ZERO));
+                }
+                let cached = self.cell_value(cell.col, row_num)?;
+                let consistent = match (expected.kind, cached.kind) {
+                    (FormulaKind::Number(lhs), FormulaKind::Number(rhs)) => lhs.approx_eq(rhs),
+                    (FormulaKind::Text, FormulaKind::Text) => expected.text == cached.text,
+                    (FormulaKind::Boolean(lhs), FormulaKind::Boolean(rhs)) => lhs == rhs,
+                    _ => false,
+                };
+                if !consistent {
+                    return Err(err(format!(
+                        "{sheet_name}!{reference} 수식 cache가 재계산 결과와 다릅니다: cached={}, expected={}, formula={formula}",
+                        cached.describe()?,
+                        expected.describe()?
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+    fn vertical_lookup(
+        &self,
+        lookup: &FormulaValue<'sheet>,
+        (start, end): (CellReference, CellReference),
+        col_index: &FormulaValue<'sheet>,
+        exact: &FormulaValue<'sheet>,
+    ) -> Result<FormulaValue<'sheet>> {
+        let (offset, approximate) = match (col_index.to_number(), exact.to_bool()) {
+            (Ok(offset), Ok(approximate)) => (offset, approximate),
+            (Err(code), _) | (_, Err(code)) => {
+                return Ok(FormulaValue::from(FormulaKind::Error(code)));
+            }
+        };
+        if approximate {
+            return Err(err("수식 검증은 근사 일치 VLOOKUP을 지원하지 않습니다."));
+        }
+        if let FormulaKind::Error(code) = lookup.kind {
+            return Ok(FormulaValue::from(FormulaKind::Error(code)));
+        }
+        let first_col = start.col.min(end.col);
+        let width = start.col.max(end.col).strict_sub(first_col).strict_add(1);
+        let Some(col_offset) = offset
+            .truncate_digits(0)
+            .and_then(|whole| u32::try_from(whole.numerator).ok())
+            .and_then(|whole| whole.checked_sub(1))
+        else {
+            return Ok(FormulaValue::from(FormulaKind::Error(FORMULA_ERROR_VALUE)));
+        };
+        if col_offset >= width {
+            return Ok(FormulaValue::from(FormulaKind::Error(FORMULA_ERROR_REF)));
+        }
+        for row in start.row.min(end.row)..=start.row.max(end.row) {
+            let key = self.cell_value(first_col, row)?;
+            let same_kind = matches!(
+                (key.kind, lookup.kind),
+                (FormulaKind::Number(_), FormulaKind::Number(_))
+                    | (FormulaKind::Text, FormulaKind::Text)
+                    | (FormulaKind::Boolean(_), FormulaKind::Boolean(_))
+            );
+            if same_kind && key.compare(lookup) == Ok(Ordering::Equal) {
+                let found = self.cell_value(first_col.strict_add(col_offset), row)?;
+                if found.kind == FormulaKind::Blank {
+                    return Ok(FormulaValue::from(FormulaKind::Number(FormulaNumber::ZERO)));
+                }
+                return Ok(found);
+            }
+        }
+        Ok(FormulaValue::from(FormulaKind::Error(
+            FORMULA_ERROR_NOT_AVAILABLE,
+        )))
+    }
+}
+impl<'sheet> FormulaParser<'_, 'sheet> {
+    fn evaluate(mut self) -> Result<FormulaValue<'sheet>> {
+        let value = FormulaValue::try_from(self.parse_comparison()?)?;
+        self.skip_whitespace();
+        if !self.rest.is_empty() {
+            return Err(err(format!(
+                "수식 끝에 해석하지 못한 내용이 있습니다: {}",
+                self.rest
+            )));
+        }
+        Ok(value)
+    }
+    fn parse_additive(&mut self) -> Result<FormulaOperand<'sheet>> {
+        let mut lhs = self.parse_multiplicative()?;
+        loop {
+            self.skip_whitespace();
+            let Some(operator) = self
+                .rest
+                .bytes()
+                .next()
+                .filter(|byte| matches!(byte, b'+' | b'-'))
+            else {
+                return Ok(lhs);
+            };
+            self.rest = self.rest.get(1..).unwrap_or_default();
+            let rhs = FormulaValue::try_from(self.parse_multiplicative()?)?;
+            lhs = FormulaOperand::Value(FormulaValue::try_from(lhs)?.arithmetic(&rhs, operator)?);
+        }
+    }
+    fn parse_arguments(&mut self) -> Result<Vec<FormulaOperand<'sheet>>> {
+        let mut args = Vec::new();
+        self.skip_whitespace();
+        if let Some(tail) = self.rest.strip_prefix(')') {
+            self.rest = tail;
+            return Ok(args);
+        }
+        loop {
+            if args.len() >= FORMULA_MAX_ARGUMENTS {
+                return Err(err("수식 함수 인자 개수가 허용 한도를 초과했습니다."));
+            }
+            args.push(self.parse_nested()?);
+            self.skip_whitespace();
+            if let Some(tail) = self.rest.strip_prefix(',') {
+                self.rest = tail;
+                continue;
+            }
+            let Some(tail) = self.rest.strip_prefix(')') else {
+                return Err(err("수식 함수 인자 목록이 닫히지 않았습니다."));
+            };
+            self.rest = tail;
+            return Ok(args);
+        }
+    }
+    fn parse_comparison(&mut self) -> Result<FormulaOperand<'sheet>> {
+        let mut lhs = self.parse_concat()?;
+        loop {
+            self.skip_whitespace();
+            let Some(operator) = FORMULA_COMPARISON_OPERATORS
+                .into_iter()
+                .find(|operator| self.rest.starts_with(operator))
+            else {
+                return Ok(lhs);
+            };
+            self.rest = self.rest.get(operator.len()..).unwrap_or_default();
+            let rhs = FormulaValue::try_from(self.parse_concat()?)?;
+            lhs = FormulaOperand::Value(FormulaValue::try_from(lhs)?.compare_with(&rhs, operator));
+        }
+    }
+    fn parse_concat(&mut self) -> Result<FormulaOperand<'sheet>> {
+        let mut lhs = self.parse_additive()?;
+        loop {
+            self.skip_whitespace();
+            let Some(tail) = self.rest.strip_prefix('&') else {
+                return Ok(lhs);
+            };
+            self.rest = tail;
+            let rhs = FormulaValue::try_from(self.parse_additive()?)?;
+            lhs = FormulaOperand::Value(FormulaValue::try_from(lhs)?.concat(&rhs)?);
+        }
+    }
+    fn parse_multiplicative(&mut self) -> Result<FormulaOperand<'sheet>> {
+        let mut lhs = self.parse_unary()?;
+        loop {
+            self.skip_whitespace();
+            let Some(operator) = self
+                .rest
+                .bytes()
+                .next()
+                .filter(|byte| matches!(byte, b'*' | b'/'))
+            else {
+                return Ok(lhs);
+            };
+            self.rest = self.rest.get(1..).unwrap_or_default();
+            let rhs = FormulaValue::try_from(self.parse_unary()?)?;
+            lhs = FormulaOperand::Value(FormulaValue::try_from(lhs)?.arithmetic(&rhs, operator)?);
+        }
+    }
+    fn parse_nested(&mut self) -> Result<FormulaOperand<'sheet>> {
+        if self.depth >= FORMULA_MAX_DEPTH {
+            return Err(err("수식 중첩 깊이가 허용 한도를 초과했습니다."));
+        }
+        self.depth = self.depth.strict_add(1);
+        let operand = self.parse_comparison()?;
+        self.depth = self.depth.strict_sub(1);
+        Ok(operand)
+    }
+    fn parse_primary(&mut self) -> Result<FormulaOperand<'sheet>> {
+        self.skip_whitespace();
+        if let Some(tail) = self.rest.strip_prefix('"') {
+            let mut literal = String::new();
+            let mut remaining = tail;
+            loop {
+                let quote = remaining
+                    .find('"')
+                    .ok_or_else(|| err("수식 문자열 literal이 닫히지 않았습니다."))?;
+                let (segment, quote_tail) = remaining.split_at(quote);
+                literal.push_str(segment);
+                let after_quote = quote_tail.get(1..).unwrap_or_default();
+                let Some(escaped_tail) = after_quote.strip_prefix('"') else {
+                    self.rest = after_quote;
+                    break;
+                };
+                literal.push('"');
+                remaining = escaped_tail;
+            }
+            return Ok(FormulaOperand::Value(FormulaValue {
+                kind: FormulaKind::Text,
+                text: Cow::Owned(literal),
+            }));
+        }
+        if let Some(tail) = self.rest.strip_prefix('(') {
+            self.rest = tail;
+            let operand = self.parse_nested()?;
+            self.skip_whitespace();
+            self.rest = self
+                .rest
+                .strip_prefix(')')
+                .ok_or_else(|| err("수식 괄호가 닫히지 않았습니다."))?;
+            return Ok(operand);
+        }
+        if self
+            .rest
+            .starts_with(|ch: char| ch.is_ascii_digit() || ch == '.')
+        {
+            let end = self
+                .rest
+                .find(|ch: char| !ch.is_ascii_digit() && ch != '.')
+                .unwrap_or(self.rest.len());
+            let (literal, tail) = self.rest.split_at(end);
+            let number = FormulaNumber::parse(literal)
+                .ok_or_else(|| err(format!("수식 숫자 literal 해석 실패: {literal}")))?;
+            self.rest = tail;
+            return Ok(FormulaOperand::Value(FormulaValue::from(
+                FormulaKind::Number(number),
+            )));
+        }
+        if let Some((start, start_len)) = parse_ref_prefix(self.rest)
+            && !self
+                .rest
+                .get(start_len..)
+                .is_some_and(|tail| tail.starts_with(['(', '!']))
+        {
+            self.rest = self.rest.get(start_len..).unwrap_or_default();
+            let Some(range_tail) = self.rest.strip_prefix(':') else {
+                return Ok(FormulaOperand::Value(
+                    self.evaluator.cell_value(start.col, start.row)?,
+                ));
+            };
+            let (end, end_len) = parse_ref_prefix(range_tail)
+                .ok_or_else(|| err("수식 범위 참조 끝 형식이 올바르지 않습니다."))?;
+            self.rest = range_tail.get(end_len..).unwrap_or_default();
+            return Ok(FormulaOperand::Range(start, end));
+        }
+        let name_len = self
+            .rest
+            .find(|ch: char| !ch.is_ascii_alphanumeric() && ch != '.' && ch != '_')
+            .unwrap_or(self.rest.len());
+        let (name, tail) = self.rest.split_at(name_len);
+        if name.is_empty() {
+            return Err(err(format!(
+                "수식 검증이 해석하지 못한 token입니다: {}",
+                self.rest
+            )));
+        }
+        let upper_name = name.to_ascii_uppercase();
+        self.rest = tail;
+        self.skip_whitespace();
+        if let Some(args_tail) = self.rest.strip_prefix('(') {
+            self.rest = args_tail;
+            let args = self.parse_arguments()?;
+            return self
+                .evaluator
+                .call_function(&upper_name, args)
+                .map(FormulaOperand::Value);
+        }
+        match upper_name.as_str() {
+            "TRUE" => Ok(FormulaOperand::Value(FormulaValue::from(
+                FormulaKind::Boolean(true),
+            ))),
+            "FALSE" => Ok(FormulaOperand::Value(FormulaValue::from(
+                FormulaKind::Boolean(false),
+            ))),
+            _ => Err(err(format!("수식 검증이 지원하지 않는 이름입니다: {name}"))),
+        }
+    }
+    fn parse_unary(&mut self) -> Result<FormulaOperand<'sheet>> {
+        self.skip_whitespace();
+        if let Some(tail) = self.rest.strip_prefix('-') {
+            self.rest = tail;
+            let operand = FormulaValue::try_from(self.parse_unary()?)?;
+            return FormulaValue::from(FormulaKind::Number(FormulaNumber::ZERO))
+                .arithmetic(&operand, b'-')
+                .map(FormulaOperand::Value);
+        }
+        if let Some(tail) = self.rest.strip_prefix('+') {
+            self.rest = tail;
+            return self.parse_unary();
+        }
+        self.parse_primary()
+    }
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+}
+fn range_cells(start: CellReference, end: CellReference) -> impl Iterator<Item = (u32, u32)> {
+    let cols = start.col.min(end.col)..=start.col.max(end.col);
+    (start.row.min(end.row)..=start.row.max(end.row))
+        .flat_map(move |row| cols.clone().map(move |col| (col, row)))
+}
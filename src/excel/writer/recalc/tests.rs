@@ -0,0 +1,163 @@
+use super::{FormulaEvaluator, FormulaNumber, FormulaParser};
+use crate::{
+    diagnostic::{Result, ensure, err},
+    excel::writer::{SharedStringTable, Worksheet},
+};
+use std::collections::HashMap;
+#[test]
+fn supported_functions_match_excel_results() -> Result<()> {
+    let (worksheet, shared_strings) = price_sheet()?;
+    let evaluator = FormulaEvaluator {
+        shared_strings: &shared_strings,
+        worksheet: &worksheet,
+    };
+    for (formula, expected) in [
+        ("IF(B1>1600,\"high\",\"low\")", "\"low\""),
+        ("IF(B2>1600,\"high\")", "\"high\""),
+        ("IF(B1>1600,\"high\")", "FALSE"),
+        ("IF(A1,1,2)", "#VALUE!"),
+        ("IFERROR(1/0,\"none\")", "\"none\""),
+        ("IFERROR(B1,0)", "1550"),
+        ("VLOOKUP(\"대전\",$A$1:$B$2,2,FALSE)", "1620"),
+        ("VLOOKUP(\"대전\",A1:C2,3,FALSE)", "0"),
+        ("VLOOKUP(\"세종\",A1:B2,2,FALSE)", "#N/A"),
+        ("VLOOKUP(\"대전\",A1:B2,3,FALSE)", "#REF!"),
+        ("VLOOKUP(1550,A1:B2,2,FALSE)", "#N/A"),
+        ("COUNTIF(B1:B2,\">1600\")", "1"),
+        ("COUNTIF(B1:B2,\"<>1550\")", "1"),
+        ("COUNTIF(A1:B2,\"천안\")", "1"),
+        ("COUNTIF(A1:C2,\"<>천안\")", "5"),
+        ("ROUNDDOWN(C1,2)", "1234.56"),
+        ("ROUNDDOWN(-C1,0)", "-1234"),
+        ("TEXT(C1,\"#,##0\")", "\"1,235\""),
+        ("TEXT(B1*1000,\"0\")", "\"1550000\""),
+        ("TEXT(A1,\"0\")", "#VALUE!"),
+    ] {
+        let value = FormulaParser {
+            depth: 0,
+            evaluator: &evaluator,
+            rest: formula,
+        }
+        .evaluate()?;
+        let described = value.describe()?;
+        ensure(
+            described == expected,
+            format!("{formula}: {described} != {expected}"),
+        )?;
+    }
+    Ok(())
+}
+#[test]
+fn cached_numbers_match_within_the_relative_tolerance() -> Result<()> {
+    let number = |text: &str| FormulaNumber::parse(text).ok_or("test number");
+    for (lhs, rhs, equal) in [
+        ("1", "1.000000000001", true),
+        ("1", "1.000000000002", false),
+        ("0", "0.0000000000005", true),
+        ("0", "0.000000000002", false),
+        ("1000000", "1000000.000001", true),
+        ("1000000", "1000000.00001", false),
+        ("-1550.5", "-1550.5000000000001", true),
+        ("-1550.5", "1550.5", false),
+    ] {
+        ensure(
+            number(lhs)?.approx_eq(number(rhs)?) == equal,
+            format!("{lhs} ~ {rhs} should be {equal}"),
+        )?;
+    }
+    for (cache, consistent) in [("0.333333333333333", true), ("0.3333333333", false)] {
+        let (mut worksheet, shared_strings) = price_sheet()?;
+        worksheet.set_formula_at_with_cache(4, 1, "1/3", Some(cache), false)?;
+        let verified = FormulaEvaluator {
+            shared_strings: &shared_strings,
+            worksheet: &worksheet,
+        }
+        .verify_caches("주유소");
+        ensure(
+            verified.is_ok() == consistent,
+            format!("cache {cache} for 1/3"),
+        )?;
+    }
+    Ok(())
+}
+#[test]
+fn unsupported_formulas_are_rejected() -> Result<()> {
+    let (worksheet, shared_strings) = price_sheet()?;
+    let evaluator = FormulaEvaluator {
+        shared_strings: &shared_strings,
+        worksheet: &worksheet,
+    };
+    for (formula, expected) in [
+        ("SUM(B1:B2)", "수식 검증이 지원하지 않는 함수입니다: SUM"),
+        ("ROUND(C1,0)", "수식 검증이 지원하지 않는 함수입니다: ROUND"),
+        (
+            "VLOOKUP(\"대전\",A1:B2,2,TRUE)",
+            "수식 검증은 근사 일치 VLOOKUP을 지원하지 않습니다.",
+        ),
+        (
+            "COUNTIF(A1:A2,\"천*\")",
+            "수식 검증은 와일드카드가 포함된 COUNTIF를 지원하지 않습니다.",
+        ),
+        (
+            "TEXT(C1,\"0.00\")",
+            "수식 검증이 지원하지 않는 TEXT 서식입니다: 0.00",
+        ),
+        (
+            "IFERROR(B1)",
+            "수식 검증 대상 함수의 인자 형식이 올바르지 않습니다: IFERROR",
+        ),
+        (
+            "ROUNDDOWN(C1,-2)",
+            "수식 검증은 음수 ROUNDDOWN 자릿수를 지원하지 않습니다.",
+        ),
+        ("PI", "수식 검증이 지원하지 않는 이름입니다: PI"),
+    ] {
+        let evaluated = FormulaParser {
+            depth: 0,
+            evaluator: &evaluator,
+            rest: formula,
+        }
+        .evaluate();
+        let Err(evaluate_err) = evaluated else {
+            return Err(err(format!("{formula} must be rejected")));
+        };
+        ensure(
+            evaluate_err.to_string() == expected,
+            format!("{formula}: {evaluate_err}"),
+        )?;
+    }
+    let (mut worksheet_with_formula, strings) = price_sheet()?;
+    worksheet_with_formula.set_formula_at_with_cache(4, 2, "SUM(B1:B2)", Some("3170"), false)?;
+    let verified = FormulaEvaluator {
+        shared_strings: &strings,
+        worksheet: &worksheet_with_formula,
+    }
+    .verify_caches("주유소");
+    let Err(verify_err) = verified else {
+        return Err(err("verification must fail on an unsupported function"));
+    };
+    ensure(
+        verify_err
+            .to_string()
+            .starts_with("주유소!D2 수식 재계산 실패: SUM(B1:B2): "),
+        format!("verification error: {verify_err}"),
+    )
+}
+fn price_sheet() -> Result<(Worksheet, SharedStringTable)> {
+    let mut worksheet = Worksheet {
+        hyperlinks: Vec::new(),
+        prefix: String::new(),
+        rows: Vec::new(),
+        suffix: String::new(),
+    };
+    let mut shared_strings = SharedStringTable {
+        entries: Vec::new(),
+        index: HashMap::new(),
+    };
+    shared_strings.set_cell(&mut worksheet, 1, 1, "천안")?;
+    shared_strings.set_cell(&mut worksheet, 1, 2, "대전")?;
+    worksheet.set_decimal_at(2, 1, Some("1550"))?;
+    worksheet.set_decimal_at(2, 2, Some("1620"))?;
+    worksheet.set_decimal_at(3, 1, Some("1234.5678"))?;
+    Ok((worksheet, shared_strings))
+}
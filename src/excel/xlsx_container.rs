@@ -1,20 +1,27 @@
+use self::pass_through::{
+    PackageRelationship, PassThrough, is_pass_through, push_escaped_empty_element,
+};
 use super::{
     ArchiveFingerprint, CALC_CHAIN_PATH, CHANGE_LOG_SHEET_NAME, CanonicalStyleMap,
     MASTER_SHEET_NAME, PackagePart, SPREADSHEETML_NAMESPACE, SaveVerification, XLSX_PARTS,
     XlsxPartRole, ZipArchiveBuilder, ZipPackageReader,
-    xml::{XmlAttrScanner, XmlScanner, XmlTag, decode_xml_entities},
+    writer::Hyperlink,
+    xml::{XmlAttrScanner, XmlScanner, XmlTag, decode_xml_entities, extract_attr},
     zip_archive::scan_open_archive,
 };
-use crate::diagnostic::{
-    AppError, Result, err, err_with_source, path_context_message, terminal_safe,
-    try_string_with_capacity, try_vec_with_capacity,
-};
 #[cfg(target_os = "windows")]
 use crate::temp_entry::configure_replaceable_file;
 use crate::temp_entry::{
     FileIdentity, ValidatedFile, configure_no_follow, open_regular, validate_open_file,
     validate_regular_file,
 };
+use crate::{
+    diagnostic::{
+        AppError, Result, append_fmt, err, err_with_source, path_context_message, terminal_safe,
+        try_string_with_capacity, try_vec_with_capacity,
+    },
+    logging,
+};
 use alloc::borrow::Cow;
 use core::{array, convert::identity, mem, str};
 #[cfg(any(target_os = "linux", target_os = "macos"))]
@@ -27,7 +34,15 @@ use std::{
     time::{SystemTime, UNIX_EPOCH},
 };
 mod atomic_replace;
+mod pass_through;
 const MAX_XML_NESTING_DEPTH: usize = 64;
+const CUSTOM_PROPERTIES_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/custom-properties";
+const CUSTOM_PROPERTY_FMTID: &str = "{D5CDD505-2E9C-101B-9397-08002B2CF9AE}";
+const DOC_PROPS_VTYPES_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes";
+const SETTINGS_HASH_PROPERTY: &str = "fcupdater_settings_hash";
+const SOURCE_HASH_PROPERTY: &str = "fcupdater_source_hash";
 const CONTENT_TYPES_NAMESPACE: &str =
     "http://schemas.openxmlformats.org/package/2006/content-types";
 const OFFICE_DOCUMENT_REL_NAMESPACE: &str =
@@ -53,7 +68,7 @@ const EXCEL_CONTENT_TYPE_DEFAULTS: [(&str, &str); 3] = [
     ),
     ("xml", "application/xml"),
 ];
-const EXCEL_CONTENT_TYPE_OVERRIDES: [(&str, &str); 8] = [
+const EXCEL_CONTENT_TYPE_OVERRIDES: [(&str, &str); 9] = [
     (WORKBOOK_PART_NAME, WORKBOOK_CONTENT_TYPE),
     (
         "/xl/worksheets/sheet1.xml",
@@ -83,8 +98,12 @@ const EXCEL_CONTENT_TYPE_OVERRIDES: [(&str, &str); 8] = [
         "/docProps/app.xml",
         "application/vnd.openxmlformats-officedocument.extended-properties+xml",
     ),
+    (
+        "/docProps/custom.xml",
+        "application/vnd.openxmlformats-officedocument.custom-properties+xml",
+    ),
 ];
-const EXCEL_ROOT_RELATIONSHIPS: [(&str, &str, &str); 4] = [
+const EXCEL_ROOT_RELATIONSHIPS: [(&str, &str, &str); 5] = [
     (
         "rId3",
         "http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties",
@@ -101,6 +120,11 @@ const EXCEL_ROOT_RELATIONSHIPS: [(&str, &str, &str); 4] = [
         "http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties",
         "docProps/app.xml",
     ),
+    (
+        "rId5",
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/custom-properties",
+        "docProps/custom.xml",
+    ),
 ];
 const INPUT_ROOT_RELATIONSHIPS: [(&str, &str, Option<&str>); 5] = [
     (OFFICE_DOCUMENT_REL_TYPE, WORKBOOK_REL_TARGET, None),
@@ -168,30 +192,19 @@ const INPUT_WORKBOOK_RELATIONSHIPS: [(&str, &str, Option<&str>); 6] = [
         Some(CALC_CHAIN_PATH),
     ),
 ];
-const ADDITIONAL_INPUT_CONTENT_TYPE_DEFAULTS: [(&str, &str); 3] = [
-    ("fntdata", "application/x-fontdata"),
-    ("jpeg", "image/jpeg"),
-    ("png", "image/png"),
-];
-const ADDITIONAL_INPUT_CONTENT_TYPE_OVERRIDES: [(&str, &str); 3] = [
-    (
-        "/xl/calcChain.xml",
-        "application/vnd.openxmlformats-officedocument.spreadsheetml.calcChain+xml",
-    ),
-    (
-        "/docProps/custom.xml",
-        "application/vnd.openxmlformats-officedocument.custom-properties+xml",
-    ),
-    (
-        "/xl/drawings/drawing1.xml",
-        "application/vnd.openxmlformats-officedocument.drawing+xml",
-    ),
-];
+const ADDITIONAL_INPUT_CONTENT_TYPE_OVERRIDES: [(&str, &str); 1] = [(
+    "/xl/calcChain.xml",
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.calcChain+xml",
+)];
+const MASTER_DRAWING_PATH: &str = "xl/drawings/drawing1.xml";
 const INPUT_SHEET_RELATIONSHIPS: [(&str, &str, Option<&str>); 1] = [(
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/drawing",
     "../drawings/drawing1.xml",
-    None,
+    Some(MASTER_DRAWING_PATH),
 )];
+const HYPERLINK_RELATIONSHIP_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink";
+const SHEET_RELATIONSHIPS_PATH: &str = "xl/worksheets/_rels/sheet1.xml.rels";
 const EXCEL_STYLES_XML: &str = include_str!("excel_styles.xml");
 const EXCEL_THEME_XML: &str = include_str!("excel_theme.xml");
 const LIBREOFFICE_CELL_XFS_XML: &str = include_str!("libreoffice_cell_xfs.xml");
@@ -255,10 +268,20 @@ const BLANK_EXCEL_THUMBNAIL_DWORDS: [u32; 32] = [
     0,
     20,
 ];
+pub(crate) struct ArchiveRestore<'path> {
+    pub backup_xlsx: &'path Path,
+    pub displaced_backup: Option<&'path Path>,
+    pub target_xlsx: &'path Path,
+}
 pub(crate) struct XlsxContainer {
+    custom_properties: String,
     drawing_rid: Option<String>,
     parts: Vec<PackagePart>,
+    pass_through: PassThrough,
+    settings_hash: Option<String>,
     source_fingerprint: ArchiveFingerprint,
+    source_hash: Option<String>,
+    source_hash_pid: u32,
     source_identity: FileIdentity,
     #[cfg(any(target_os = "linux", target_os = "macos"))]
     source_permissions: fs::Permissions,
@@ -269,6 +292,53 @@ struct ReservedTempArchive {
     path: PathBuf,
     remove_on_drop: bool,
 }
+impl ArchiveRestore<'_> {
+    pub(crate) fn run(self) -> Result<()> {
+        let ArchiveRestore {
+            backup_xlsx,
+            displaced_backup,
+            target_xlsx,
+        } = self;
+        let target_file = open_regular(target_xlsx, false).map_err(|source| {
+            err_with_source(
+                path_context_message("복원 대상 xlsx 열기 실패", target_xlsx),
+                source,
+            )
+        })?;
+        let expected_fingerprint = scan_open_archive(&target_file.file, target_xlsx, None)?;
+        let mut backup_file = open_regular(backup_xlsx, false)
+            .map_err(|source| {
+                err_with_source(
+                    path_context_message("백업 xlsx 열기 실패", backup_xlsx),
+                    source,
+                )
+            })?
+            .file;
+        replace_with_new_archive(
+            target_xlsx,
+            (expected_fingerprint, target_file.identity),
+            displaced_backup,
+            |tmp_archive| {
+                let file = tmp_archive
+                    .file
+                    .as_mut()
+                    .ok_or_else(|| err("xlsx 임시 저장 파일 handle이 닫혀 있습니다."))?;
+                io::copy(&mut backup_file, file)
+                    .and_then(|_| {
+                        #[cfg(any(target_os = "linux", target_os = "macos"))]
+                        file.set_permissions(target_file.permissions)?;
+                        file.sync_all()
+                    })
+                    .map_err(|source| {
+                        err_with_source(
+                            path_context_message("백업 xlsx 복사 실패", backup_xlsx),
+                            source,
+                        )
+                    })
+            },
+        )
+    }
+}
 impl ReservedTempArchive {
     const fn disable_drop_cleanup(&mut self) {
         self.remove_on_drop = false;
@@ -307,7 +377,10 @@ impl ReservedTempArchive {
         }
         Ok(())
     }
-    fn verify_saved_archive(&self) -> Result<()> {
+    fn verify_saved_archive(
+        &self,
+        verify_content: impl FnOnce(&super::writer::Workbook) -> Result<()>,
+    ) -> Result<()> {
         let saved_archive = self.path();
         let mut saved_handle = self
             .file
@@ -344,9 +417,8 @@ impl ReservedTempArchive {
                 source_err,
             )
         })?;
-        super::writer::Workbook::from_container(saved_container)
-            .map(|_| ())
-            .map_err(|source_err| {
+        let saved_workbook =
+            super::writer::Workbook::from_container(saved_container).map_err(|source_err| {
                 err_with_source(
                     path_context_message(
                         "저장 검증 실패: 저장 직후 재열기 점검에 실패했습니다",
@@ -354,7 +426,27 @@ impl ReservedTempArchive {
                     ),
                     source_err,
                 )
-            })
+            })?;
+        saved_workbook
+            .verify_formula_caches()
+            .map_err(|source_err| {
+                err_with_source(
+                    path_context_message(
+                        "저장 검증 실패: 수식 cache 재계산 점검에 실패했습니다",
+                        saved_archive,
+                    ),
+                    source_err,
+                )
+            })?;
+        verify_content(&saved_workbook).map_err(|source_err| {
+            err_with_source(
+                path_context_message(
+                    "저장 검증 실패: 현행화 결과 내용 점검에 실패했습니다",
+                    saved_archive,
+                ),
+                source_err,
+            )
+        })
     }
     fn write_archive_from(
         &mut self,
@@ -396,6 +488,7 @@ impl Drop for ReservedTempArchive {
 struct TempArchivePromotion<'path> {
     #[cfg(target_os = "windows")]
     backup_archive: &'path mut ReservedTempArchive,
+    displaced_backup: Option<&'path Path>,
     expected_fingerprint: ArchiveFingerprint,
     expected_identity: FileIdentity,
     target_xlsx: &'path Path,
@@ -544,12 +637,30 @@ impl TempArchivePromotion<'_> {
         #[cfg(target_os = "windows")]
         self.backup_archive.disable_drop_cleanup();
         let captured_original = self.displaced_original_path();
-        fs::remove_file(captured_original).map_err(|source| {
-            err_with_source(
-                path_context_message("교체된 원본 xlsx 정리 실패", captured_original),
-                source,
-            )
-        })?;
+        if let Some(backup_path) = self.displaced_backup {
+            fs::rename(captured_original, backup_path)
+                .or_else(|_rename_err| {
+                    fs::copy(captured_original, backup_path)?;
+                    fs::remove_file(captured_original)
+                })
+                .map_err(|source| {
+                    err_with_source(
+                        format!(
+                            "xlsx는 저장됐지만 교체된 원본을 백업으로 옮기지 못했습니다: {} -> {}",
+                            captured_original.display(),
+                            backup_path.display()
+                        ),
+                        source,
+                    )
+                })?;
+        } else {
+            fs::remove_file(captured_original).map_err(|source| {
+                err_with_source(
+                    path_context_message("교체된 원본 xlsx 정리 실패", captured_original),
+                    source,
+                )
+            })?;
+        }
         #[cfg(any(target_os = "linux", target_os = "macos"))]
         {
             let parent = self
@@ -625,6 +736,43 @@ impl TempArchivePromotion<'_> {
     }
 }
 impl XlsxContainer {
+    fn custom_properties_xml(&self, run_hashes: Option<(&str, &str)>) -> Vec<u8> {
+        let mut xml = Vec::new();
+        xml.extend_from_slice(
+            b"<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n<Properties xmlns=\"",
+        );
+        xml.extend_from_slice(CUSTOM_PROPERTIES_NAMESPACE.as_bytes());
+        xml.extend_from_slice(b"\" xmlns:vt=\"");
+        xml.extend_from_slice(DOC_PROPS_VTYPES_NAMESPACE.as_bytes());
+        if run_hashes.is_none() && self.custom_properties.is_empty() {
+            xml.extend_from_slice(b"\"/>");
+            return xml;
+        }
+        xml.extend_from_slice(b"\">");
+        xml.extend_from_slice(self.custom_properties.as_bytes());
+        if let Some((source_hash, settings_hash)) = run_hashes {
+            for (pid, name, hash) in [
+                (self.source_hash_pid, SOURCE_HASH_PROPERTY, source_hash),
+                (
+                    self.source_hash_pid.strict_add(1),
+                    SETTINGS_HASH_PROPERTY,
+                    settings_hash,
+                ),
+            ] {
+                xml.extend_from_slice(b"<property fmtid=\"");
+                xml.extend_from_slice(CUSTOM_PROPERTY_FMTID.as_bytes());
+                xml.extend_from_slice(b"\" pid=\"");
+                xml.extend_from_slice(pid.to_string().as_bytes());
+                xml.extend_from_slice(b"\" name=\"");
+                xml.extend_from_slice(name.as_bytes());
+                xml.extend_from_slice(b"\"><vt:lpwstr>");
+                xml.extend_from_slice(hash.as_bytes());
+                xml.extend_from_slice(b"</vt:lpwstr></property>");
+            }
+        }
+        xml.extend_from_slice(b"</Properties>");
+        xml
+    }
     pub(super) fn ensure_fixed_sheet_catalog(&mut self, workbook_xml: &mut String) -> Result<()> {
         replace_single_self_closing_tag(
             workbook_xml,
@@ -714,10 +862,33 @@ impl XlsxContainer {
             }
             Ok(())
         })?;
+        let sheet_count = self.pass_through.sheets.len().strict_add(2);
         let mut defined_name_scanner = XmlScanner::new(workbook_xml);
-        let defined_name = defined_name_scanner
-            .next_start_named("definedName")
-            .filter(|tag| tag.name == "definedName" && !tag.self_closing)
+        let mut filter_database = None;
+        while let Some(tag) = defined_name_scanner.next_start_named("definedName") {
+            if tag.name != "definedName" || tag.self_closing {
+                return Err(err("workbook의 definedName 태그가 올바르지 않습니다."));
+            }
+            let name = required_xml_attr(tag.raw, "name", "workbook definedName")?;
+            let local_sheet_id = extract_attr(tag.raw, "localSheetId")?;
+            if name == "_xlnm._FilterDatabase" && local_sheet_id.as_deref() == Some("0") {
+                if filter_database.replace(tag).is_some() {
+                    return Err(err("workbook에 _FilterDatabase가 여러 개 있습니다."));
+                }
+                continue;
+            }
+            if local_sheet_id.is_some_and(|value| {
+                value
+                    .parse::<usize>()
+                    .ok()
+                    .is_none_or(|index| index < 2 || index >= sheet_count)
+            }) {
+                return Err(err(format!(
+                    "workbook definedName은 사용자 시트 범위에서만 허용됩니다: {name}"
+                )));
+            }
+        }
+        let defined_name = filter_database
             .ok_or_else(|| err("workbook의 _FilterDatabase 태그가 올바르지 않습니다."))?;
         let defined_name_span = defined_name.start
             ..defined_name
@@ -743,13 +914,6 @@ impl XlsxContainer {
         {
             return Err(err("_FilterDatabase 속성 구성이 올바르지 않습니다."));
         }
-        defined_name_scanner.skip_to(defined_name_span.end);
-        if defined_name_scanner
-            .next_start_named("definedName")
-            .is_some()
-        {
-            return Err(err("workbook에 definedName이 여러 개 있습니다."));
-        }
         workbook_xml.replace_range(
             defined_name_span,
             "<definedName name=\"_xlnm._FilterDatabase\" localSheetId=\"0\" hidden=\"1\">",
@@ -778,6 +942,30 @@ impl XlsxContainer {
         );
         Ok(())
     }
+    fn excel_companion_part(
+        &self,
+        name: &str,
+        source_parts: &mut [PackagePart],
+    ) -> Result<Vec<u8>> {
+        if name == "docProps/custom.xml" {
+            return Ok(self.custom_properties_xml(None));
+        }
+        if let Some(part) = source_parts
+            .iter_mut()
+            .find(|part| part.name == name && !part.bytes.is_empty())
+        {
+            return Ok(mem::take(&mut part.bytes));
+        }
+        let thumbnail_len = BLANK_EXCEL_THUMBNAIL_DWORDS
+            .len()
+            .checked_mul(size_of::<u32>())
+            .ok_or_else(|| err("Excel thumbnail 크기 계산 실패"))?;
+        let mut bytes = try_vec_with_capacity(thumbnail_len, "Excel thumbnail 메모리 확보 실패")?;
+        for value in BLANK_EXCEL_THUMBNAIL_DWORDS {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        Ok(bytes)
+    }
     pub(crate) fn from_validated_file(
         source_file: ValidatedFile,
         source_xlsx: &Path,
@@ -791,9 +979,14 @@ impl XlsxContainer {
         }
         .read()?;
         let mut container = Self {
+            custom_properties: String::new(),
             drawing_rid: None,
             parts,
+            pass_through: PassThrough::default(),
             source_fingerprint,
+            settings_hash: None,
+            source_hash: None,
+            source_hash_pid: 2,
             source_identity,
             #[cfg(any(target_os = "linux", target_os = "macos"))]
             source_permissions,
@@ -805,8 +998,10 @@ impl XlsxContainer {
             "_rels/.rels",
             &INPUT_ROOT_RELATIONSHIPS,
             &container,
+            None,
         )?;
         container.prepare_companion_parts()?;
+        container.pass_through.validate_parts(&container)?;
         container.part_mut("[Content_Types].xml")?.bytes = Vec::new();
         container.part_mut("_rels/.rels")?.bytes = Vec::new();
         Ok(container)
@@ -814,6 +1009,9 @@ impl XlsxContainer {
     fn has_part(&self, name: &str) -> bool {
         self.parts.iter().any(|part| part.name == name)
     }
+    pub(super) fn mark_pass_through_shared_strings(&self, mapping: &mut [usize]) -> Result<()> {
+        self.pass_through.mark_shared_strings(&self.parts, mapping)
+    }
     pub(super) fn package_prepare_excel_output(&mut self) -> Result<CanonicalStyleMap> {
         let source_styles = self.text("xl/styles.xml")?;
         let source_xfs = cell_xf_entries(source_styles)?;
@@ -845,6 +1043,9 @@ impl XlsxContainer {
             entries.push(canonical);
         }
         let input_styles = CanonicalStyleMap { entries };
+        let mut styles_xml = self
+            .pass_through
+            .merged_styles_xml(&mut self.parts, &input_styles)?;
         let mut source_parts = mem::take(&mut self.parts);
         let source_core = source_parts
             .iter_mut()
@@ -922,31 +1123,11 @@ impl XlsxContainer {
             .ok_or_else(|| err("Excel app.xml 원본 part를 찾지 못했습니다."))?;
         let source_app_xml = str::from_utf8(&source_app.bytes)
             .map_err(|source_error| err_with_source("app.xml UTF-8 해석 실패", source_error))?;
-        let (_, total_time_tail) = source_app_xml
-            .split_once("<TotalTime>")
-            .ok_or_else(|| err("app.xml의 TotalTime 태그를 찾지 못했습니다."))?;
-        let (total_time, after_total_time) = total_time_tail
-            .split_once("</TotalTime>")
-            .ok_or_else(|| err("app.xml의 TotalTime 종료 태그를 찾지 못했습니다."))?;
-        if after_total_time.contains("<TotalTime>")
-            || total_time.is_empty()
-            || !total_time.bytes().all(|byte| byte.is_ascii_digit())
-        {
-            return Err(err("app.xml의 TotalTime 형식이 올바르지 않습니다."));
-        }
-        let mut app_xml = try_string_with_capacity(
-            960_usize.strict_add(total_time.len()),
-            "Excel app.xml 메모리 확보 실패",
+        source_app.bytes = self.pass_through.excel_app_xml(source_app_xml)?;
+        let mut output_parts = try_vec_with_capacity(
+            XLSX_PARTS.len().strict_add(source_parts.len()),
+            "Excel package part 목록 메모리 확보 실패",
         )?;
-        app_xml.push_str(concat!(
-            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n",
-            "<Properties xmlns=\"http://schemas.openxmlformats.org/officeDocument/2006/extended-properties\" xmlns:vt=\"http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes\"><Template></Template><TotalTime>",
-        ));
-        app_xml.push_str(total_time);
-        app_xml.push_str("</TotalTime><Pages>2</Pages><Words>0</Words><Characters>0</Characters><Application>Microsoft Excel</Application><DocSecurity>0</DocSecurity><Paragraphs>0</Paragraphs><ScaleCrop>false</ScaleCrop><HeadingPairs><vt:vector size=\"2\" baseType=\"variant\"><vt:variant><vt:lpstr>워크시트</vt:lpstr></vt:variant><vt:variant><vt:i4>2</vt:i4></vt:variant></vt:vector></HeadingPairs><TitlesOfParts><vt:vector size=\"2\" baseType=\"lpstr\"><vt:lpstr>유류비</vt:lpstr><vt:lpstr>변경내역</vt:lpstr></vt:vector></TitlesOfParts><LinksUpToDate>false</LinksUpToDate><CharactersWithSpaces>0</CharactersWithSpaces><SharedDoc>false</SharedDoc><HyperlinksChanged>false</HyperlinksChanged><AppVersion>16.0300</AppVersion></Properties>");
-        source_app.bytes = app_xml.into_bytes();
-        let mut output_parts =
-            try_vec_with_capacity(XLSX_PARTS.len(), "Excel package part 목록 메모리 확보 실패")?;
         for (name, role) in XLSX_PARTS {
             if role == XlsxPartRole::InputOnly {
                 continue;
@@ -972,27 +1153,22 @@ impl XlsxContainer {
                             [("PartName", part_name), ("ContentType", content_type)],
                         );
                     }
+                    self.pass_through
+                        .push_content_types(&source_parts, &mut xml);
                     xml.extend_from_slice(b"</Types>");
                     xml
                 }
-                "_rels/.rels" => excel_relationships_xml(&EXCEL_ROOT_RELATIONSHIPS)?,
-                "xl/_rels/workbook.xml.rels" => {
-                    excel_relationships_xml(&EXCEL_WORKBOOK_RELATIONSHIPS)?
-                }
-                "xl/styles.xml" => excel_static_xml(EXCEL_STYLES_XML),
-                "xl/theme/theme1.xml" => excel_static_xml(EXCEL_THEME_XML),
-                "docProps/thumbnail.emf" => {
-                    let thumbnail_len = BLANK_EXCEL_THUMBNAIL_DWORDS
-                        .len()
-                        .checked_mul(size_of::<u32>())
-                        .ok_or_else(|| err("Excel thumbnail 크기 계산 실패"))?;
-                    let mut bytes =
-                        try_vec_with_capacity(thumbnail_len, "Excel thumbnail 메모리 확보 실패")?;
-                    for value in BLANK_EXCEL_THUMBNAIL_DWORDS {
-                        bytes.extend_from_slice(&value.to_le_bytes());
-                    }
-                    bytes
+                "_rels/.rels" => excel_relationships_xml(&EXCEL_ROOT_RELATIONSHIPS, None)?,
+                "xl/_rels/workbook.xml.rels" => excel_relationships_xml(
+                    &EXCEL_WORKBOOK_RELATIONSHIPS,
+                    Some(&self.pass_through),
+                )?,
+                "xl/styles.xml" => mem::take(&mut styles_xml),
+                "xl/theme/theme1.xml" => excel_static_text(EXCEL_THEME_XML).into_bytes(),
+                "docProps/custom.xml" | "docProps/thumbnail.emf" => {
+                    self.excel_companion_part(name, &mut source_parts)?
                 }
+                SHEET_RELATIONSHIPS_PATH => continue,
                 _ => {
                     let part = source_parts
                         .iter_mut()
@@ -1005,8 +1181,16 @@ impl XlsxContainer {
                     mem::take(&mut part.bytes)
                 }
             };
-            output_parts.push(PackagePart { bytes, name });
+            output_parts.push(PackagePart {
+                bytes,
+                name: Cow::Borrowed(name),
+            });
         }
+        output_parts.extend(
+            source_parts
+                .into_iter()
+                .filter(|part| is_pass_through(&part.name)),
+        );
         self.parts = output_parts;
         Ok(input_styles)
     }
@@ -1022,183 +1206,279 @@ impl XlsxContainer {
             .find(|part| part.name == name)
             .ok_or_else(|| err(format!("xlsx part를 찾지 못했습니다: {name}")))
     }
+    pub(super) fn pass_through_shared_string_count(&self, string_count: usize) -> Result<usize> {
+        self.pass_through
+            .shared_string_count(&self.parts, string_count)
+    }
     fn prepare_companion_parts(&mut self) -> Result<()> {
-        let has_sheet_relationships = self.has_part("xl/worksheets/_rels/sheet1.xml.rels");
-        let has_drawing = self.has_part("xl/drawings/drawing1.xml");
-        if has_sheet_relationships != has_drawing {
-            return Err(err(
-                "worksheet drawing 관계와 drawing part는 함께 존재해야 합니다.",
-            ));
-        }
-        if has_sheet_relationships {
+        if self.has_part(SHEET_RELATIONSHIPS_PATH) {
+            let mut hyperlinks = Vec::new();
             let mut relationships = validate_relationship_set(
-                self.text("xl/worksheets/_rels/sheet1.xml.rels")?,
+                self.text(SHEET_RELATIONSHIPS_PATH)?,
                 "sheet1.xml.rels",
                 &INPUT_SHEET_RELATIONSHIPS,
                 self,
+                Some(&mut hyperlinks),
             )?;
-            self.drawing_rid = Some(
-                relationships
-                    .get_mut(0)
-                    .and_then(Option::take)
-                    .ok_or_else(|| err("worksheet drawing relationship Id가 없습니다."))?
-                    .into_owned(),
-            );
+            if let Some(relationship) = hyperlinks.iter().find(|relationship| {
+                !relationship.external || relationship.type_ != HYPERLINK_RELATIONSHIP_TYPE
+            }) {
+                return Err(err(format!(
+                    "sheet1.xml.rels에 지원하지 않는 관계가 있습니다: {} -> {}",
+                    relationship.type_, relationship.target
+                )));
+            }
+            self.drawing_rid = relationships
+                .get_mut(0)
+                .and_then(Option::take)
+                .map(Cow::into_owned);
         }
         if self.has_part("docProps/custom.xml") {
-            validate_empty_xml_root(
-                self.text("docProps/custom.xml")?,
-                "Properties",
+            let custom_xml = self.text("docProps/custom.xml")?;
+            let mut properties_xml = custom_xml.to_owned();
+            let mut user_properties = String::new();
+            let mut property_names: Vec<Cow<'_, str>> = Vec::new();
+            let mut property_spans = Vec::new();
+            let mut max_pid = 1_u32;
+            let mut settings_hash = None;
+            let mut source_hash = None;
+            let mut scanner = XmlScanner::new(custom_xml);
+            while let Some(property) = scanner.next_element_named("property")? {
+                let [fmtid, pid_attr, name_attr, link_target] = parse_attrs(
+                    property.opening.raw,
+                    ["fmtid", "pid", "name", "linkTarget"],
+                    "custom.xml property",
+                )?;
+                let name = name_attr
+                    .filter(|value| !value.is_empty())
+                    .ok_or_else(|| err("custom.xml property 이름이 없습니다."))?;
+                let pid = pid_attr
+                    .and_then(|value| value.parse::<u32>().ok())
+                    .filter(|value| *value >= 2)
+                    .ok_or_else(|| {
+                        err(format!(
+                            "custom.xml property pid가 올바르지 않습니다: {name}"
+                        ))
+                    })?;
+                if fmtid.as_deref() != Some(CUSTOM_PROPERTY_FMTID)
+                    || property_names
+                        .iter()
+                        .any(|existing| existing.to_lowercase() == name.to_lowercase())
+                {
+                    return Err(err(format!(
+                        "custom.xml property 구성이 올바르지 않습니다: {name}"
+                    )));
+                }
+                if name == SOURCE_HASH_PROPERTY || name == SETTINGS_HASH_PROPERTY {
+                    let value = XmlScanner::new(property.body)
+                        .next_element_named("vt:lpwstr")?
+                        .filter(|value| {
+                            link_target.is_none()
+                                && value.span.start == 0
+                                && value.span.end == property.body.len()
+                                && value.body.len() == 64
+                                && value
+                                    .body
+                                    .bytes()
+                                    .all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f'))
+                        })
+                        .ok_or_else(|| {
+                            err(format!("custom.xml의 해시 값이 올바르지 않습니다: {name}"))
+                        })?;
+                    if name == SOURCE_HASH_PROPERTY {
+                        source_hash = Some(value.body.to_owned());
+                    } else {
+                        settings_hash = Some(value.body.to_owned());
+                    }
+                } else {
+                    if !property.body.trim_start().starts_with("<vt:") {
+                        return Err(err(format!(
+                            "custom.xml property 값이 올바르지 않습니다: {name}"
+                        )));
+                    }
+                    max_pid = max_pid.max(pid);
+                    user_properties.push_str(
+                        custom_xml
+                            .get(property.span)
+                            .ok_or_else(|| err("custom.xml property 범위가 손상되었습니다."))?,
+                    );
+                }
+                property_spans.push(property.span);
+                property_names.push(name);
+            }
+            for span in property_spans.into_iter().rev() {
+                properties_xml.replace_range(span, "");
+            }
+            let (mut root_scanner, root) =
+                scan_xml_root(&properties_xml, "Properties", "custom.xml")?;
+            validate_exact_attrs(
+                root.raw,
                 &[
-                    (
-                        "xmlns",
-                        "http://schemas.openxmlformats.org/officeDocument/2006/custom-properties",
-                    ),
-                    (
-                        "xmlns:vt",
-                        "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes",
-                    ),
+                    ("xmlns", CUSTOM_PROPERTIES_NAMESPACE),
+                    ("xmlns:vt", DOC_PROPS_VTYPES_NAMESPACE),
                 ],
                 "custom.xml",
             )?;
+            let root_end = root
+                .end
+                .checked_add(1)
+                .ok_or_else(|| err("custom.xml의 XML root 끝 계산 실패"))?;
+            let document_end = if root.self_closing {
+                root_end
+            } else {
+                root_scanner
+                    .next_tag()
+                    .filter(|tag| !tag.is_start && tag.name == "Properties")
+                    .ok_or_else(|| err("custom.xml에 지원하지 않는 요소가 있습니다."))?
+                    .end
+                    .checked_add(1)
+                    .ok_or_else(|| err("custom.xml의 XML root 종료 계산 실패"))?
+            };
+            if root_scanner.next_tag().is_some()
+                || !properties_xml
+                    .get(document_end..)
+                    .is_some_and(|trailing| xml_misc_only(trailing, false))
+            {
+                return Err(err("custom.xml의 XML root 뒤 내용이 올바르지 않습니다."));
+            }
+            self.custom_properties = user_properties;
+            self.settings_hash = settings_hash;
+            self.source_hash_pid = max_pid.strict_add(1);
+            self.source_hash = source_hash;
         }
-        if has_drawing {
-            validate_empty_xml_root(
-                self.text("xl/drawings/drawing1.xml")?,
-                "xdr:wsDr",
-                &[
-                    (
-                        "xmlns:xdr",
-                        "http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing",
-                    ),
-                    (
-                        "xmlns:a",
-                        "http://schemas.openxmlformats.org/drawingml/2006/main",
-                    ),
-                    (
-                        "xmlns:r",
-                        "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
-                    ),
-                ],
-                "drawing1.xml",
-            )?;
-        }
-        for name in [
-            "docProps/custom.xml",
-            "xl/worksheets/_rels/sheet1.xml.rels",
-            "xl/drawings/drawing1.xml",
-        ] {
+        for name in ["docProps/custom.xml", SHEET_RELATIONSHIPS_PATH] {
             if self.has_part(name) {
                 self.part_mut(name)?.bytes = Vec::new();
             }
         }
         Ok(())
     }
+    pub(super) fn put_run_hashes(&mut self, source_hash: &str, settings_hash: &str) -> Result<()> {
+        let custom_xml = self.custom_properties_xml(Some((source_hash, settings_hash)));
+        self.part_mut("docProps/custom.xml")?.bytes = custom_xml;
+        Ok(())
+    }
     pub(super) fn put_text(&mut self, name: &str, content: String) -> Result<()> {
         let part = self.part_mut(name)?;
         part.bytes = content.into_bytes();
         Ok(())
     }
-    pub(super) fn save(self, target_xlsx: &Path, verification: SaveVerification) -> Result<()> {
-        let parent = target_xlsx
-            .parent()
-            .filter(|path| !path.as_os_str().is_empty())
-            .unwrap_or_else(|| Path::new("."));
-        let target_file_name = crate::MASTER_PATH;
-        let temp_archive_prefix = format!(".{target_file_name}.tmp_");
-        #[cfg(target_os = "windows")]
-        let backup_archive_prefix = format!(".{target_file_name}.backup_");
-        let reserve_archive = |prefix: &str| -> io::Result<ReservedTempArchive> {
-            const ATTEMPTS: u32 = 1024;
-            let pid = process::id();
-            let nanos = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .map_err(io::Error::other)?
-                .as_nanos();
-            for sequence in 0..ATTEMPTS {
-                let path = parent.join(format!("{prefix}{pid}_{nanos}_{sequence}"));
-                let mut options = fs::File::options();
-                options.read(true).write(true).create_new(true);
-                configure_no_follow(&mut options);
-                #[cfg(target_os = "windows")]
-                configure_replaceable_file(&mut options);
-                #[cfg(any(target_os = "linux", target_os = "macos"))]
-                options.mode(0o600);
-                match options.open(&path) {
-                    Ok(file) => {
-                        let (_, identity) = validate_regular_file(&file)?;
-                        return Ok(ReservedTempArchive {
-                            file: Some(file),
-                            identity,
-                            path,
-                            remove_on_drop: true,
-                        });
+    pub(super) fn put_worksheet_text(
+        &mut self,
+        name: &str,
+        mut content: String,
+        hyperlinks: &[Hyperlink],
+    ) -> Result<()> {
+        if name != super::MASTER_SHEET_PATH || (hyperlinks.is_empty() && self.drawing_rid.is_none())
+        {
+            return self.put_text(name, content);
+        }
+        let mut relationships = excel_catalog_xml(
+            "Relationships",
+            PACKAGE_RELATIONSHIPS_NAMESPACE,
+            "worksheet relationship 메모리 확보 실패",
+        )?;
+        let mut id = String::new();
+        for (index, hyperlink) in (1_usize..).zip(hyperlinks) {
+            id.clear();
+            append_fmt(&mut id, format_args!("rId{index}"));
+            push_escaped_empty_element(
+                &mut relationships,
+                "Relationship",
+                [
+                    ("Id", id.as_str()),
+                    ("Type", HYPERLINK_RELATIONSHIP_TYPE),
+                    ("Target", hyperlink.target.as_str()),
+                    ("TargetMode", "External"),
+                ],
+            );
+        }
+        if self.drawing_rid.is_some() {
+            let [(type_, target, _)] = INPUT_SHEET_RELATIONSHIPS;
+            id.clear();
+            append_fmt(
+                &mut id,
+                format_args!("rId{}", hyperlinks.len().strict_add(1)),
+            );
+            push_empty_xml_element(
+                &mut relationships,
+                "Relationship",
+                [("Id", id.as_str()), ("Type", type_), ("Target", target)],
+            );
+            let end = content
+                .rfind("</worksheet>")
+                .ok_or_else(|| err("sheet1.xml 종료 태그를 찾지 못했습니다."))?;
+            content.insert_str(end, &format!("<drawing r:id=\"{id}\"/>"));
+        }
+        relationships.extend_from_slice(b"</Relationships>");
+        if let Some(part) = self
+            .parts
+            .iter_mut()
+            .find(|part| part.name == SHEET_RELATIONSHIPS_PATH)
+        {
+            part.bytes = relationships;
+        } else {
+            let index = self
+                .parts
+                .iter()
+                .position(|part| is_pass_through(&part.name))
+                .unwrap_or(self.parts.len());
+            self.parts.insert(
+                index,
+                PackagePart {
+                    bytes: relationships,
+                    name: Cow::Borrowed(SHEET_RELATIONSHIPS_PATH),
+                },
+            );
+        }
+        self.put_text(name, content)
+    }
+    pub(super) fn remap_pass_through_shared_strings(&mut self, mapping: &[usize]) -> Result<()> {
+        self.pass_through
+            .remap_shared_strings(&mut self.parts, mapping)
+    }
+    pub(super) fn save(
+        self,
+        target_xlsx: &Path,
+        verification: SaveVerification,
+        displaced_backup: Option<&Path>,
+        verify_content: impl FnOnce(&super::writer::Workbook) -> Result<()>,
+    ) -> Result<()> {
+        replace_with_new_archive(
+            target_xlsx,
+            (self.source_fingerprint, self.source_identity),
+            displaced_backup,
+            |tmp_archive| {
+                cfg_select! {
+                    any(target_os = "linux", target_os = "macos") => {
+                        tmp_archive.write_archive_from(&self.parts, self.source_permissions)?;
+                    }
+                    target_os = "windows" => {
+                        tmp_archive.write_archive_from(&self.parts)?;
                     }
-                    Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {}
-                    Err(error) => return Err(error),
-                }
-            }
-            Err(io::Error::new(
-                io::ErrorKind::AlreadyExists,
-                "임시 항목 이름 충돌이 반복되었습니다. 잠시 후 다시 시도하세요.",
-            ))
-        };
-        let mut tmp_archive = reserve_archive(&temp_archive_prefix).map_err(|source| {
-            err_with_source(
-                path_context_message("임시 저장 파일 생성 실패", target_xlsx),
-                source,
-            )
-        })?;
-        #[cfg(target_os = "windows")]
-        let mut backup_archive = reserve_archive(&backup_archive_prefix).map_err(|source| {
-            err_with_source(
-                path_context_message("교체 예약 파일 생성 실패", target_xlsx),
-                source,
-            )
-        })?;
-        let result = (|| -> Result<()> {
-            cfg_select! {
-                any(target_os = "linux", target_os = "macos") => {
-                    tmp_archive.write_archive_from(&self.parts, self.source_permissions)?;
-                }
-                target_os = "windows" => {
-                    tmp_archive.write_archive_from(&self.parts)?;
-                }
-            }
-            match verification {
-                SaveVerification::Skip => {}
-                SaveVerification::Verify => {
-                    tmp_archive.verify_saved_archive()?;
-                }
-            }
-            TempArchivePromotion {
-                #[cfg(target_os = "windows")]
-                backup_archive: &mut backup_archive,
-                expected_fingerprint: self.source_fingerprint,
-                expected_identity: self.source_identity,
-                target_xlsx,
-                temp_archive: &mut tmp_archive,
-            }
-            .promote()
-        })();
-        match result {
-            Ok(()) => Ok(()),
-            Err(source) => {
-                if !tmp_archive.remove_on_drop {
-                    return Err(source);
                 }
-                match tmp_archive.remove_owned() {
-                    Ok(()) => Err(source),
-                    Err(error) => Err(err_with_source(
-                        format!(
-                            "xlsx 임시 저장 파일 삭제 실패: {} ({error})",
-                            tmp_archive.path().display(),
-                        ),
-                        source,
-                    )),
+                logging::debug(format_args!(
+                    "저장 단계: 임시 아카이브 기록 완료 (파트 {}개)",
+                    self.parts.len()
+                ));
+                match verification {
+                    SaveVerification::Skip => Ok(()),
+                    SaveVerification::Verify => {
+                        tmp_archive.verify_saved_archive(verify_content)?;
+                        logging::debug(format_args!("저장 단계: 임시 아카이브 검증 완료"));
+                        Ok(())
+                    }
                 }
-            }
-        }
+            },
+        )?;
+        logging::debug(format_args!("저장 단계: 대상 파일 교체 완료"));
+        Ok(())
+    }
+    pub(super) fn settings_hash(&self) -> Option<&str> {
+        self.settings_hash.as_deref()
+    }
+    pub(super) fn source_hash(&self) -> Option<&str> {
+        self.source_hash.as_deref()
     }
     pub(super) fn take_shared_strings_text(&mut self) -> Result<String> {
         let xml = self.take_text("xl/sharedStrings.xml")?;
@@ -1210,14 +1490,19 @@ impl XlsxContainer {
         String::from_utf8(bytes)
             .map_err(|source| err_with_source(format!("xlsx part UTF-8 해석 실패: {name}"), source))
     }
-    fn take_workbook_dependencies(&mut self, workbook_xml: &str) -> Result<()> {
+    fn take_workbook_dependencies(&mut self, workbook_xml: &mut String) -> Result<()> {
         let workbook_relationships = self.take_text("xl/_rels/workbook.xml.rels")?;
+        let mut extra_relationships = Vec::new();
         let relationship_ids = validate_relationship_set(
             &workbook_relationships,
             "workbook.xml.rels",
             &INPUT_WORKBOOK_RELATIONSHIPS,
             self,
+            Some(&mut extra_relationships),
         )?;
+        let source_ids = self
+            .pass_through
+            .accept_workbook_relationships(extra_relationships, &self.parts)?;
         if self.has_part(CALC_CHAIN_PATH) {
             let xml = self.take_text(CALC_CHAIN_PATH)?;
             let child_count = visit_direct_xml_children(
@@ -1270,14 +1555,14 @@ impl XlsxContainer {
                 return Err(err("workbook.xml sheet 구성이 고정 스키마와 다릅니다."));
             }
         }
-        if workbook_scanner.next_start_named("sheet").is_some() {
-            return Err(err("workbook sheet 수가 고정 스키마의 2개보다 많습니다."));
-        }
-        Ok(())
+        self.pass_through
+            .accept_extra_sheets(&mut workbook_scanner, &source_ids)?;
+        self.pass_through
+            .rewrite_workbook_relationship_ids(workbook_xml, &sheet_ids, &source_ids)
     }
     pub(super) fn take_worksheet_text(&mut self, name: &str, sheet_name: &str) -> Result<String> {
         let drawing_rid = if name == super::MASTER_SHEET_PATH {
-            self.drawing_rid.take()
+            self.drawing_rid.clone()
         } else {
             None
         };
@@ -1321,16 +1606,15 @@ impl XlsxContainer {
         str::from_utf8(&part.bytes)
             .map_err(|source| err_with_source(format!("xlsx part UTF-8 해석 실패: {name}"), source))
     }
-    fn validate_content_types(&self) -> Result<()> {
+    fn validate_content_types(&mut self) -> Result<()> {
         let content_types_xml = self.text("[Content_Types].xml")?;
-        let mut seen_defaults = [false;
-            EXCEL_CONTENT_TYPE_DEFAULTS
-                .len()
-                .strict_add(ADDITIONAL_INPUT_CONTENT_TYPE_DEFAULTS.len())];
+        let mut seen_defaults = [false; EXCEL_CONTENT_TYPE_DEFAULTS.len()];
         let mut seen_overrides = [false;
             EXCEL_CONTENT_TYPE_OVERRIDES
                 .len()
                 .strict_add(ADDITIONAL_INPUT_CONTENT_TYPE_OVERRIDES.len())];
+        let mut extra_defaults: Vec<(String, String)> = Vec::new();
+        let mut extra_overrides: Vec<(String, String)> = Vec::new();
         visit_direct_xml_children(
             content_types_xml,
             "Types",
@@ -1356,38 +1640,61 @@ impl XlsxContainer {
                 let content_type = content_type_attr.ok_or_else(|| {
                     err("[Content_Types].xml entry에 ContentType 속성이 없습니다.")
                 })?;
+                if !is_default
+                    && !key
+                        .strip_prefix('/')
+                        .is_some_and(|name| self.has_part(name))
+                {
+                    return Err(err(format!(
+                        "[Content_Types].xml Override 대상 part가 없습니다: {key}"
+                    )));
+                }
                 let matching_entry = if is_default {
                     EXCEL_CONTENT_TYPE_DEFAULTS
                         .iter()
-                        .chain(&ADDITIONAL_INPUT_CONTENT_TYPE_DEFAULTS)
                         .zip(&mut seen_defaults)
-                        .find_map(|(&(candidate, value), present)| {
-                            (candidate == key && value == content_type).then_some(present)
-                        })
+                        .find(|item| item.0.0.eq_ignore_ascii_case(&key))
                 } else {
                     EXCEL_CONTENT_TYPE_OVERRIDES
                         .iter()
                         .chain(&ADDITIONAL_INPUT_CONTENT_TYPE_OVERRIDES)
                         .zip(&mut seen_overrides)
-                        .find_map(|(&(candidate, value), present)| {
-                            (candidate == key && value == content_type).then_some(present)
-                        })
+                        .find(|item| item.0.0 == key)
                 };
-                let Some(seen_entry) = matching_entry else {
-                    return Err(err(format!(
-                        "[Content_Types].xml에 지원하지 않는 {local_name} 항목이 있습니다: {key}"
-                    )));
+                let duplicate = match matching_entry {
+                    Some((&(candidate, value), seen_entry)) => {
+                        if candidate != key || value != content_type {
+                            return Err(err(format!(
+                                "[Content_Types].xml에 지원하지 않는 {local_name} 항목이 있습니다: {key}"
+                            )));
+                        }
+                        mem::replace(seen_entry, true)
+                    }
+                    None if is_default => {
+                        if key.is_empty() || key.contains(['.', '/']) {
+                            return Err(err(format!(
+                                "[Content_Types].xml Default 확장자가 올바르지 않습니다: {key}"
+                            )));
+                        }
+                        let duplicate = extra_defaults
+                            .iter()
+                            .any(|item| item.0.eq_ignore_ascii_case(&key));
+                        extra_defaults.push((key.to_string(), content_type.into_owned()));
+                        duplicate
+                    }
+                    None => {
+                        let part_name = key.trim_start_matches('/');
+                        if !is_pass_through(part_name) {
+                            return Err(err(format!(
+                                "[Content_Types].xml에 지원하지 않는 {local_name} 항목이 있습니다: {key}"
+                            )));
+                        }
+                        let duplicate = extra_overrides.iter().any(|item| item.0 == part_name);
+                        extra_overrides.push((part_name.to_owned(), content_type.into_owned()));
+                        duplicate
+                    }
                 };
-                if !is_default
-                    && !key
-                        .strip_prefix('/')
-                        .is_some_and(|name| self.has_part(name))
-                {
-                    return Err(err(format!(
-                        "[Content_Types].xml Override 대상 part가 없습니다: {key}"
-                    )));
-                }
-                if mem::replace(seen_entry, true) {
+                if duplicate {
                     return Err(err(format!(
                         "[Content_Types].xml 항목이 중복되었습니다: {key}"
                     )));
@@ -1395,11 +1702,7 @@ impl XlsxContainer {
                 Ok(())
             },
         )?;
-        for (&(key, _), present) in EXCEL_CONTENT_TYPE_DEFAULTS
-            .iter()
-            .chain(&ADDITIONAL_INPUT_CONTENT_TYPE_DEFAULTS)
-            .zip(seen_defaults)
-        {
+        for (&(key, _), present) in EXCEL_CONTENT_TYPE_DEFAULTS.iter().zip(seen_defaults) {
             let required = matches!(key, "rels" | "xml")
                 || key == "emf" && self.has_part("docProps/thumbnail.emf");
             if required && !present {
@@ -1420,6 +1723,8 @@ impl XlsxContainer {
                 )));
             }
         }
+        self.pass_through.content_type_defaults = extra_defaults;
+        self.pass_through.content_type_overrides = extra_overrides;
         Ok(())
     }
 }
@@ -1669,12 +1974,11 @@ fn find_equivalent_xf(
     }
     Ok(None)
 }
-fn excel_static_xml(source: &str) -> Vec<u8> {
+fn excel_static_text(source: &str) -> String {
     source
         .strip_suffix('\n')
         .unwrap_or(source)
         .replacen('\n', "\r\n", 1)
-        .into_bytes()
 }
 fn excel_catalog_xml(
     root: &str,
@@ -1701,7 +2005,10 @@ fn push_empty_xml_element<const N: usize>(xml: &mut Vec<u8>, name: &str, attrs:
     }
     xml.extend_from_slice(b"/>");
 }
-fn excel_relationships_xml(relationships: &[(&str, &str, &str)]) -> Result<Vec<u8>> {
+fn excel_relationships_xml(
+    relationships: &[(&str, &str, &str)],
+    pass_through: Option<&PassThrough>,
+) -> Result<Vec<u8>> {
     let mut xml = excel_catalog_xml(
         "Relationships",
         PACKAGE_RELATIONSHIPS_NAMESPACE,
@@ -1714,6 +2021,9 @@ fn excel_relationships_xml(relationships: &[(&str, &str, &str)]) -> Result<Vec<u
             [("Id", id), ("Type", type_), ("Target", target)],
         );
     }
+    if let Some(extra) = pass_through {
+        extra.push_relationships(&mut xml);
+    }
     xml.extend_from_slice(b"</Relationships>");
     Ok(xml)
 }
@@ -1810,6 +2120,7 @@ fn validate_relationship_set<'xml, const N: usize>(
     context: &str,
     expected: &[(&str, &str, Option<&str>); N],
     container: &XlsxContainer,
+    mut extra: Option<&mut Vec<PackageRelationship>>,
 ) -> Result<[Option<Cow<'xml, str>>; N]> {
     let mut ids: [Option<Cow<'xml, str>>; N] = array::from_fn(|_| None);
     visit_direct_xml_children(
@@ -1821,8 +2132,8 @@ fn validate_relationship_set<'xml, const N: usize>(
             if local_name != "Relationship" {
                 return Err(err(format!("{context} 관계 태그가 올바르지 않습니다.")));
             }
-            let [id_attr, type_attr, target_attr] =
-                parse_attrs(raw, ["Id", "Type", "Target"], context)?;
+            let [id_attr, type_attr, target_attr, target_mode] =
+                parse_attrs(raw, ["Id", "Type", "Target", "TargetMode"], context)?;
             let id = id_attr.ok_or_else(|| err(format!("{context} 관계에 Id가 없습니다.")))?;
             if id.is_empty() {
                 return Err(err(format!("{context} 관계 Id가 비어 있습니다.")));
@@ -1835,17 +2146,38 @@ fn validate_relationship_set<'xml, const N: usize>(
                 .iter()
                 .flatten()
                 .any(|candidate| candidate == id.as_ref())
+                || extra
+                    .as_deref()
+                    .is_some_and(|relationships| relationships.iter().any(|item| item.id == id))
             {
                 return Err(err(format!("{context} 관계 Id가 중복되었습니다: {id}")));
             }
-            let Some((candidate, slot)) = expected
+            let matching_entry = expected
                 .iter()
                 .zip(ids.iter_mut())
-                .find(|item| type_ == item.0.0 && target == item.0.1)
-            else {
-                return Err(err(format!(
-                    "{context}에 지원하지 않는 관계가 있습니다: {type_} -> {target}"
-                )));
+                .find(|item| target_mode.is_none() && type_ == item.0.0 && target == item.0.1);
+            let Some((candidate, slot)) = matching_entry else {
+                let Some(relationships) = extra.as_deref_mut() else {
+                    return Err(err(format!(
+                        "{context}에 지원하지 않는 관계가 있습니다: {type_} -> {target}"
+                    )));
+                };
+                let external = match target_mode.as_deref() {
+                    None | Some("Internal") => false,
+                    Some("External") => true,
+                    Some(mode) => {
+                        return Err(err(format!(
+                            "{context} 관계의 TargetMode가 올바르지 않습니다: {mode}"
+                        )));
+                    }
+                };
+                relationships.push(PackageRelationship {
+                    external,
+                    id: id.into_owned(),
+                    target: target.into_owned(),
+                    type_: type_.into_owned(),
+                });
+                return Ok(());
             };
             if candidate.2.is_some_and(|part| !container.has_part(part)) {
                 return Err(err(format!(
@@ -1968,41 +2300,6 @@ fn validate_spreadsheet_xml_document(xml: &str, expected_root: &str, context: &s
     }
     Err(err(format!("{context}에 닫히지 않은 XML 요소가 있습니다.")))
 }
-fn validate_empty_xml_root<const N: usize>(
-    xml: &str,
-    expected_name: &str,
-    expected_attrs: &[(&str, &str); N],
-    context: &str,
-) -> Result<()> {
-    let (mut scanner, root) = scan_xml_root(xml, expected_name, context)?;
-    validate_exact_attrs(root.raw, expected_attrs, context)?;
-    let root_end = root
-        .end
-        .checked_add(1)
-        .ok_or_else(|| err(format!("{context}의 XML root 끝 계산 실패")))?;
-    let document_end = if root.self_closing {
-        root_end
-    } else {
-        let close = scanner
-            .next_tag()
-            .filter(|tag| !tag.is_start && tag.name == expected_name)
-            .ok_or_else(|| err(format!("{context}의 XML root가 비어 있지 않습니다.")))?;
-        close
-            .end
-            .checked_add(1)
-            .ok_or_else(|| err(format!("{context}의 XML root 종료 계산 실패")))?
-    };
-    if scanner.next_tag().is_some()
-        || !xml
-            .get(document_end..)
-            .is_some_and(|trailing| xml_misc_only(trailing, false))
-    {
-        return Err(err(format!(
-            "{context}의 XML root 뒤 내용이 올바르지 않습니다."
-        )));
-    }
-    Ok(())
-}
 fn replace_single_self_closing_tag(
     xml: &mut String,
     name: &str,
@@ -2125,3 +2422,97 @@ fn visit_direct_xml_children<'xml>(
     }
     Err(err(format!("{context}의 XML 종료 태그가 없습니다.")))
 }
+fn replace_with_new_archive(
+    target_xlsx: &Path,
+    (expected_fingerprint, expected_identity): (ArchiveFingerprint, FileIdentity),
+    displaced_backup: Option<&Path>,
+    write_archive: impl FnOnce(&mut ReservedTempArchive) -> Result<()>,
+) -> Result<()> {
+    let parent = target_xlsx
+        .parent()
+        .filter(|path| !path.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let target_file_name = crate::MASTER_PATH;
+    let temp_archive_prefix = format!(".{target_file_name}.tmp_");
+    #[cfg(target_os = "windows")]
+    let backup_archive_prefix = format!(".{target_file_name}.backup_");
+    let reserve_archive = |prefix: &str| -> io::Result<ReservedTempArchive> {
+        const ATTEMPTS: u32 = 1024;
+        let pid = process::id();
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(io::Error::other)?
+            .as_nanos();
+        for sequence in 0..ATTEMPTS {
+            let path = parent.join(format!("{prefix}{pid}_{nanos}_{sequence}"));
+            let mut options = fs::File::options();
+            options.read(true).write(true).create_new(true);
+            configure_no_follow(&mut options);
+            #[cfg(target_os = "windows")]
+            configure_replaceable_file(&mut options);
+            #[cfg(any(target_os = "linux", target_os = "macos"))]
+            options.mode(0o600);
+            match options.open(&path) {
+                Ok(file) => {
+                    let (_, identity) = validate_regular_file(&file)?;
+                    return Ok(ReservedTempArchive {
+                        file: Some(file),
+                        identity,
+                        path,
+                        remove_on_drop: true,
+                    });
+                }
+                Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "임시 항목 이름 충돌이 반복되었습니다. 잠시 후 다시 시도하세요.",
+        ))
+    };
+    let mut tmp_archive = reserve_archive(&temp_archive_prefix).map_err(|source| {
+        err_with_source(
+            path_context_message("임시 저장 파일 생성 실패", target_xlsx),
+            source,
+        )
+    })?;
+    #[cfg(target_os = "windows")]
+    let mut backup_archive = reserve_archive(&backup_archive_prefix).map_err(|source| {
+        err_with_source(
+            path_context_message("교체 예약 파일 생성 실패", target_xlsx),
+            source,
+        )
+    })?;
+    let result = (|| -> Result<()> {
+        write_archive(&mut tmp_archive)?;
+        TempArchivePromotion {
+            #[cfg(target_os = "windows")]
+            backup_archive: &mut backup_archive,
+            displaced_backup,
+            expected_fingerprint,
+            expected_identity,
+            target_xlsx,
+            temp_archive: &mut tmp_archive,
+        }
+        .promote()
+    })();
+    match result {
+        Ok(()) => Ok(()),
+        Err(source) => {
+            if !tmp_archive.remove_on_drop {
+                return Err(source);
+            }
+            match tmp_archive.remove_owned() {
+                Ok(()) => Err(source),
+                Err(error) => Err(err_with_source(
+                    format!(
+                        "xlsx 임시 저장 파일 삭제 실패: {} ({error})",
+                        tmp_archive.path().display(),
+                    ),
+                    source,
+                )),
+            }
+        }
+    }
+}
@@ -0,0 +1,902 @@
+use super::super::{
+    CHANGE_LOG_SHEET_NAME, CanonicalStyleMap, MASTER_SHEET_NAME, PackagePart, XLSX_PARTS,
+    writer::{XmlEscapeContext, append_xml_escaped},
+    xml::{XmlAttrScanner, XmlScanner, XmlTag, decode_xml_entities, extract_attr},
+};
+use super::{
+    EXCEL_CONTENT_TYPE_DEFAULTS, EXCEL_STYLES_XML, EXCEL_WORKBOOK_RELATIONSHIPS,
+    INPUT_WORKBOOK_RELATIONSHIPS, WORKSHEET_REL_TYPE, XlsxContainer, excel_static_text,
+    parse_attrs, style_entries, validate_relationship_set,
+};
+use crate::diagnostic::{Result, err, err_with_source, try_string_with_capacity};
+use alloc::collections::BTreeSet;
+use core::{mem, str};
+#[cfg(test)]
+mod tests;
+const CHARTSHEET_REL_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chartsheet";
+const FIRST_CUSTOM_NUM_FMT_ID: u32 = 164;
+const PIVOT_TABLE_CONTENT_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotTable+xml";
+const TABLE_CONTENT_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.table+xml";
+const WORKSHEET_CONTENT_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml";
+#[derive(Default)]
+pub(super) struct PassThrough {
+    pub content_type_defaults: Vec<(String, String)>,
+    pub content_type_overrides: Vec<(String, String)>,
+    pub relationships: Vec<PackageRelationship>,
+    pub sheets: Vec<ExtraSheet>,
+}
+pub(super) struct PackageRelationship {
+    pub external: bool,
+    pub id: String,
+    pub target: String,
+    pub type_: String,
+}
+pub(super) struct ExtraSheet {
+    chart: bool,
+    name: String,
+}
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum StylePart {
+    Cells,
+    Formats,
+}
+#[derive(Clone, Copy)]
+enum StyleReference {
+    Dxf,
+    Xf,
+}
+struct StyleGroup {
+    entries: Vec<String>,
+    original_len: usize,
+}
+struct XmlSplice<'xml> {
+    copied: usize,
+    out: Option<String>,
+    xml: &'xml str,
+}
+impl PassThrough {
+    pub(super) fn accept_extra_sheets(
+        &mut self,
+        scanner: &mut XmlScanner<'_>,
+        source_ids: &[String],
+    ) -> Result<()> {
+        let mut sheet_ids = BTreeSet::from([1_u32, 2_u32]);
+        let mut used_relationships = BTreeSet::new();
+        while let Some(tag) = scanner.next_start_named("sheet") {
+            if tag.name != "sheet" || !tag.self_closing {
+                return Err(err("workbook sheet 태그가 올바르지 않습니다."));
+            }
+            let [name_attr, sheet_id_attr, state, rid_attr] = parse_attrs(
+                tag.raw,
+                ["name", "sheetId", "state", "r:id"],
+                "workbook.xml sheet",
+            )?;
+            let name = name_attr
+                .filter(|value| !value.is_empty() && value.chars().count() <= 31)
+                .ok_or_else(|| err("workbook.xml 사용자 시트 이름이 올바르지 않습니다."))?;
+            let lowered = name.to_lowercase();
+            if [MASTER_SHEET_NAME, CHANGE_LOG_SHEET_NAME]
+                .into_iter()
+                .chain(self.sheets.iter().map(|sheet| sheet.name.as_str()))
+                .any(|existing| existing.to_lowercase() == lowered)
+            {
+                return Err(err(format!(
+                    "workbook.xml에 같은 이름의 시트가 있습니다: {name}"
+                )));
+            }
+            let sheet_id = sheet_id_attr
+                .and_then(|value| value.parse::<u32>().ok())
+                .filter(|value| *value != 0)
+                .ok_or_else(|| {
+                    err(format!(
+                        "workbook.xml 사용자 시트 sheetId가 올바르지 않습니다: {name}"
+                    ))
+                })?;
+            if !sheet_ids.insert(sheet_id) {
+                return Err(err(format!(
+                    "workbook.xml sheetId가 중복되었습니다: {sheet_id}"
+                )));
+            }
+            if state
+                .as_deref()
+                .is_some_and(|value| !matches!(value, "visible" | "hidden" | "veryHidden"))
+            {
+                return Err(err(format!(
+                    "workbook.xml 사용자 시트 state가 올바르지 않습니다: {name}"
+                )));
+            }
+            let rid = rid_attr.ok_or_else(|| {
+                err(format!(
+                    "workbook.xml 사용자 시트에 r:id가 없습니다: {name}"
+                ))
+            })?;
+            let index = source_ids.iter().position(|id| *id == rid).ok_or_else(|| {
+                err(format!(
+                    "workbook.xml 사용자 시트의 관계를 찾지 못했습니다: {rid}"
+                ))
+            })?;
+            let relationship = self
+                .relationships
+                .get(index)
+                .ok_or_else(|| err("workbook 사용자 관계 목록이 손상되었습니다."))?;
+            let chart = match relationship.type_.as_str() {
+                WORKSHEET_REL_TYPE => false,
+                CHARTSHEET_REL_TYPE => true,
+                _ => {
+                    return Err(err(format!(
+                        "workbook.xml 사용자 시트의 관계 형식을 지원하지 않습니다: {}",
+                        relationship.type_
+                    )));
+                }
+            };
+            if relationship.external || !used_relationships.insert(index) {
+                return Err(err(format!(
+                    "workbook.xml 사용자 시트의 관계가 올바르지 않습니다: {rid}"
+                )));
+            }
+            self.sheets.push(ExtraSheet {
+                chart,
+                name: name.into_owned(),
+            });
+        }
+        for (index, relationship) in self.relationships.iter().enumerate() {
+            if matches!(
+                relationship.type_.as_str(),
+                WORKSHEET_REL_TYPE | CHARTSHEET_REL_TYPE
+            ) && !used_relationships.contains(&index)
+            {
+                return Err(err(format!(
+                    "workbook.xml.rels의 시트 관계가 workbook.xml에 없습니다: {}",
+                    relationship.target
+                )));
+            }
+        }
+        Ok(())
+    }
+    pub(super) fn accept_workbook_relationships(
+        &mut self,
+        relationships: Vec<PackageRelationship>,
+        parts: &[PackagePart],
+    ) -> Result<Vec<String>> {
+        let mut source_ids = Vec::new();
+        source_ids
+            .try_reserve_exact(relationships.len())
+            .map_err(|source| err_with_source("workbook 관계 목록 메모리 확보 실패", source))?;
+        let first_id = EXCEL_WORKBOOK_RELATIONSHIPS.len().strict_add(1);
+        for (offset, mut relationship) in relationships.into_iter().enumerate() {
+            if relationship.type_ != WORKSHEET_REL_TYPE
+                && INPUT_WORKBOOK_RELATIONSHIPS
+                    .iter()
+                    .any(|item| item.0 == relationship.type_)
+            {
+                return Err(err(format!(
+                    "workbook.xml.rels에 고정 관계와 같은 형식의 관계가 있습니다: {}",
+                    relationship.type_
+                )));
+            }
+            if !relationship.external {
+                require_pass_through_target(
+                    parts,
+                    "xl/workbook.xml",
+                    &relationship.target,
+                    "workbook.xml.rels",
+                )?;
+            }
+            let output_id = format!("rId{}", first_id.strict_add(offset));
+            source_ids.push(mem::replace(&mut relationship.id, output_id));
+            self.relationships.push(relationship);
+        }
+        Ok(source_ids)
+    }
+    fn content_type(&self, name: &str) -> Option<&str> {
+        if let Some(entry) = self
+            .content_type_overrides
+            .iter()
+            .find(|entry| entry.0 == name)
+        {
+            return Some(&entry.1);
+        }
+        let extension = part_extension(name)?;
+        EXCEL_CONTENT_TYPE_DEFAULTS
+            .iter()
+            .copied()
+            .chain(
+                self.content_type_defaults
+                    .iter()
+                    .map(|entry| (entry.0.as_str(), entry.1.as_str())),
+            )
+            .find(|entry| entry.0.eq_ignore_ascii_case(extension))
+            .map(|entry| entry.1)
+    }
+    pub(super) fn excel_app_xml(&self, source_app_xml: &str) -> Result<Vec<u8>> {
+        let (_, total_time_tail) = source_app_xml
+            .split_once("<TotalTime>")
+            .ok_or_else(|| err("app.xml의 TotalTime 태그를 찾지 못했습니다."))?;
+        let (total_time, after_total_time) = total_time_tail
+            .split_once("</TotalTime>")
+            .ok_or_else(|| err("app.xml의 TotalTime 종료 태그를 찾지 못했습니다."))?;
+        if after_total_time.contains("<TotalTime>")
+            || total_time.is_empty()
+            || !total_time.bytes().all(|byte| byte.is_ascii_digit())
+        {
+            return Err(err("app.xml의 TotalTime 형식이 올바르지 않습니다."));
+        }
+        let mut xml = try_string_with_capacity(
+            960_usize.strict_add(total_time.len()),
+            "Excel app.xml 메모리 확보 실패",
+        )?;
+        xml.push_str(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n",
+            "<Properties xmlns=\"http://schemas.openxmlformats.org/officeDocument/2006/extended-properties\" xmlns:vt=\"http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes\"><Template></Template><TotalTime>",
+        ));
+        xml.push_str(total_time);
+        xml.push_str("</TotalTime><Pages>2</Pages><Words>0</Words><Characters>0</Characters><Application>Microsoft Excel</Application><DocSecurity>0</DocSecurity><Paragraphs>0</Paragraphs><ScaleCrop>false</ScaleCrop>");
+        let chart_count = self.sheets.iter().filter(|sheet| sheet.chart).count();
+        let worksheet_count = self.sheets.len().strict_sub(chart_count).strict_add(2);
+        let heading_count = if chart_count == 0 { 2_usize } else { 4_usize };
+        xml.push_str("<HeadingPairs><vt:vector size=\"");
+        xml.push_str(&heading_count.to_string());
+        xml.push_str("\" baseType=\"variant\"><vt:variant><vt:lpstr>워크시트</vt:lpstr></vt:variant><vt:variant><vt:i4>");
+        xml.push_str(&worksheet_count.to_string());
+        xml.push_str("</vt:i4></vt:variant>");
+        if chart_count != 0 {
+            xml.push_str("<vt:variant><vt:lpstr>차트</vt:lpstr></vt:variant><vt:variant><vt:i4>");
+            xml.push_str(&chart_count.to_string());
+            xml.push_str("</vt:i4></vt:variant>");
+        }
+        xml.push_str("</vt:vector></HeadingPairs><TitlesOfParts><vt:vector size=\"");
+        xml.push_str(&self.sheets.len().strict_add(2).to_string());
+        xml.push_str("\" baseType=\"lpstr\">");
+        let worksheets = self.sheets.iter().filter(|sheet| !sheet.chart);
+        let charts = self.sheets.iter().filter(|sheet| sheet.chart);
+        for name in [MASTER_SHEET_NAME, CHANGE_LOG_SHEET_NAME]
+            .into_iter()
+            .chain(worksheets.chain(charts).map(|sheet| sheet.name.as_str()))
+        {
+            xml.push_str("<vt:lpstr>");
+            append_xml_escaped(&mut xml, name, XmlEscapeContext::Text);
+            xml.push_str("</vt:lpstr>");
+        }
+        xml.push_str("</vt:vector></TitlesOfParts><LinksUpToDate>false</LinksUpToDate><CharactersWithSpaces>0</CharactersWithSpaces><SharedDoc>false</SharedDoc><HyperlinksChanged>false</HyperlinksChanged><AppVersion>16.0300</AppVersion></Properties>");
+        Ok(xml.into_bytes())
+    }
+    pub(super) fn mark_shared_strings(
+        &self,
+        parts: &[PackagePart],
+        mapping: &mut [usize],
+    ) -> Result<()> {
+        self.visit_shared_strings(parts, |index| {
+            let slot = mapping
+                .get_mut(index)
+                .ok_or_else(|| err(format!("shared string index 범위 오류: {index}")))?;
+            *slot = 0;
+            Ok(())
+        })?;
+        Ok(())
+    }
+    pub(super) fn merged_styles_xml(
+        &self,
+        parts: &mut [PackagePart],
+        input_styles: &CanonicalStyleMap,
+    ) -> Result<Vec<u8>> {
+        let mut styles = excel_static_text(EXCEL_STYLES_XML);
+        let mut used_cell_styles = BTreeSet::new();
+        let mut used_formats = BTreeSet::new();
+        for part in parts.iter() {
+            let Some(style_part) = self.style_part(&part.name) else {
+                continue;
+            };
+            rewrite_style_references(part_text(part)?, style_part, |reference, index| {
+                match reference {
+                    StyleReference::Dxf => used_formats.insert(index),
+                    StyleReference::Xf => used_cell_styles.insert(index),
+                };
+                Ok(None)
+            })?;
+        }
+        if used_cell_styles.is_empty() && used_formats.is_empty() {
+            return Ok(styles.into_bytes());
+        }
+        let source_part = parts
+            .iter()
+            .find(|part| part.name == "xl/styles.xml")
+            .ok_or_else(|| err("xlsx part를 찾지 못했습니다: xl/styles.xml"))?;
+        let source_styles = part_text(source_part)?;
+        let source_cell_styles = optional_style_entries(source_styles, "cellXfs", "xf")?;
+        let source_formats = optional_style_entries(source_styles, "dxfs", "dxf")?;
+        let source_groups = [
+            optional_style_entries(source_styles, "borders", "border")?,
+            optional_style_entries(source_styles, "fills", "fill")?,
+            optional_style_entries(source_styles, "fonts", "font")?,
+            optional_style_entries(source_styles, "numFmts", "numFmt")?,
+        ];
+        let mut groups = [
+            (
+                "borders",
+                "borderId",
+                StyleGroup::from_styles(&styles, "borders", "border")?,
+            ),
+            (
+                "fills",
+                "fillId",
+                StyleGroup::from_styles(&styles, "fills", "fill")?,
+            ),
+            (
+                "fonts",
+                "fontId",
+                StyleGroup::from_styles(&styles, "fonts", "font")?,
+            ),
+            (
+                "numFmts",
+                "numFmtId",
+                StyleGroup::from_styles(&styles, "numFmts", "numFmt")?,
+            ),
+        ];
+        let mut cell_styles = StyleGroup::from_styles(&styles, "cellXfs", "xf")?;
+        let mut formats = StyleGroup::from_styles(&styles, "dxfs", "dxf")?;
+        let mut cell_style_map = Vec::new();
+        for index in used_cell_styles {
+            if let Some(canonical) = input_styles.get(index) {
+                cell_style_map.push((index, canonical));
+                continue;
+            }
+            let source_xf = usize::try_from(index)
+                .ok()
+                .and_then(|position| source_cell_styles.get(position))
+                .ok_or_else(|| {
+                    err(format!(
+                        "사용자 시트의 style index가 styles.xml 범위를 벗어났습니다: {index}"
+                    ))
+                })?;
+            let remapped = rewrite_attr_values(source_xf, |tag, name, value| {
+                if tag.name != "xf" {
+                    return Ok(None);
+                }
+                if name == "xfId" {
+                    return Ok(Some(String::from("0")));
+                }
+                let Some((target, source_entries)) = groups
+                    .iter_mut()
+                    .zip(&source_groups)
+                    .find(|item| item.0.1 == name)
+                else {
+                    return Ok(None);
+                };
+                let group = &mut target.2;
+                if name == "numFmtId" {
+                    return group.num_fmt_id(source_entries, value).map(Some);
+                }
+                let entry = value
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|position| source_entries.get(position))
+                    .ok_or_else(|| {
+                        err(format!(
+                            "사용자 시트 style의 {name} 값이 styles.xml 범위를 벗어났습니다: {value}"
+                        ))
+                    })?;
+                Ok(Some(group.index_of(entry)?.to_string()))
+            })?;
+            let remapped_xf = remapped.unwrap_or_else(|| (*source_xf).to_owned());
+            cell_style_map.push((index, cell_styles.index_of(&remapped_xf)?));
+        }
+        let mut format_map = Vec::new();
+        for index in used_formats {
+            let source_dxf = usize::try_from(index)
+                .ok()
+                .and_then(|position| source_formats.get(position))
+                .ok_or_else(|| {
+                    err(format!(
+                        "사용자 시트의 서식 index가 styles.xml 범위를 벗어났습니다: {index}"
+                    ))
+                })?;
+            format_map.push((index, formats.index_of(source_dxf)?));
+        }
+        for (group_name, group) in groups
+            .iter()
+            .map(|item| (item.0, &item.2))
+            .chain([("cellXfs", &cell_styles), ("dxfs", &formats)])
+        {
+            group.append_to(&mut styles, group_name)?;
+        }
+        for part in parts.iter_mut() {
+            let Some(style_part) = self.style_part(&part.name) else {
+                continue;
+            };
+            let rewritten =
+                rewrite_style_references(part_text(part)?, style_part, |reference, index| {
+                    let map = match reference {
+                        StyleReference::Dxf => &format_map,
+                        StyleReference::Xf => &cell_style_map,
+                    };
+                    map.iter()
+                        .find(|item| item.0 == index)
+                        .map(|item| Some(item.1))
+                        .ok_or_else(|| {
+                            err(format!(
+                                "사용자 시트 style 변환표에 없는 index입니다: {index}"
+                            ))
+                        })
+                })?;
+            if let Some(xml) = rewritten {
+                part.bytes = xml.into_bytes();
+            }
+        }
+        Ok(styles.into_bytes())
+    }
+    pub(super) fn push_content_types(&self, parts: &[PackagePart], xml: &mut Vec<u8>) {
+        for entry in &self.content_type_defaults {
+            let used = parts.iter().any(|part| {
+                is_pass_through(&part.name)
+                    && part_extension(&part.name)
+                        .is_some_and(|extension| extension.eq_ignore_ascii_case(&entry.0))
+                    && !self
+                        .content_type_overrides
+                        .iter()
+                        .any(|item| item.0 == part.name)
+            });
+            if used {
+                push_escaped_empty_element(
+                    xml,
+                    "Default",
+                    [("Extension", &entry.0), ("ContentType", &entry.1)],
+                );
+            }
+        }
+        for entry in &self.content_type_overrides {
+            if parts.iter().any(|part| part.name == entry.0.as_str()) {
+                push_escaped_empty_element(
+                    xml,
+                    "Override",
+                    [
+                        ("PartName", &format!("/{}", entry.0)),
+                        ("ContentType", &entry.1),
+                    ],
+                );
+            }
+        }
+    }
+    pub(super) fn push_relationships(&self, xml: &mut Vec<u8>) {
+        for relationship in &self.relationships {
+            let id = ("Id", relationship.id.as_str());
+            let type_ = ("Type", relationship.type_.as_str());
+            let target = ("Target", relationship.target.as_str());
+            if relationship.external {
+                push_escaped_empty_element(
+                    xml,
+                    "Relationship",
+                    [id, type_, target, ("TargetMode", "External")],
+                );
+            } else {
+                push_escaped_empty_element(xml, "Relationship", [id, type_, target]);
+            }
+        }
+    }
+    pub(super) fn remap_shared_strings(
+        &self,
+        parts: &mut [PackagePart],
+        mapping: &[usize],
+    ) -> Result<()> {
+        for part in parts.iter_mut() {
+            if self.style_part(&part.name) != Some(StylePart::Cells) {
+                continue;
+            }
+            let (rewritten, _) = rewrite_shared_string_indices(part_text(part)?, |index| {
+                mapping
+                    .get(index)
+                    .copied()
+                    .filter(|new_index| *new_index != usize::MAX)
+                    .map(Some)
+                    .ok_or_else(|| err("사용 중인 shared string의 compact index가 없습니다."))
+            })?;
+            if let Some(xml) = rewritten {
+                part.bytes = xml.into_bytes();
+            }
+        }
+        Ok(())
+    }
+    pub(super) fn rewrite_workbook_relationship_ids(
+        &self,
+        workbook_xml: &mut String,
+        fixed_ids: &[&str],
+        source_ids: &[String],
+    ) -> Result<()> {
+        if source_ids.is_empty() {
+            return Ok(());
+        }
+        let rewritten = rewrite_attr_values(workbook_xml, |_, name, value| {
+            if name != "r:id" || fixed_ids.contains(&value) {
+                return Ok(None);
+            }
+            source_ids
+                .iter()
+                .zip(&self.relationships)
+                .find(|item| item.0 == value)
+                .map(|item| Some(item.1.id.clone()))
+                .ok_or_else(|| {
+                    err(format!(
+                        "workbook.xml에 알 수 없는 관계 Id 참조가 있습니다: {value}"
+                    ))
+                })
+        })?;
+        if let Some(xml) = rewritten {
+            *workbook_xml = xml;
+        }
+        Ok(())
+    }
+    pub(super) fn shared_string_count(
+        &self,
+        parts: &[PackagePart],
+        string_count: usize,
+    ) -> Result<usize> {
+        self.visit_shared_strings(parts, |index| {
+            if index >= string_count {
+                return Err(err(format!(
+                    "사용자 시트의 shared string index 범위 오류: {index}"
+                )));
+            }
+            Ok(())
+        })
+    }
+    fn style_part(&self, name: &str) -> Option<StylePart> {
+        if !is_pass_through(name) {
+            return None;
+        }
+        match self.content_type(name)? {
+            WORKSHEET_CONTENT_TYPE => Some(StylePart::Cells),
+            PIVOT_TABLE_CONTENT_TYPE | TABLE_CONTENT_TYPE => Some(StylePart::Formats),
+            _ => None,
+        }
+    }
+    pub(super) fn validate_parts(&self, container: &XlsxContainer) -> Result<()> {
+        let parts = container.parts.as_slice();
+        for part in parts.iter().filter(|part| is_pass_through(&part.name)) {
+            if self.content_type(&part.name).is_none() {
+                return Err(err(format!(
+                    "보존할 package part의 content type이 없습니다: {}",
+                    part.name
+                )));
+            }
+            let Some((directory, file_name)) = part.name.rsplit_once('/') else {
+                continue;
+            };
+            let Some((parent, source_name)) = directory
+                .strip_suffix("_rels")
+                .filter(|parent| parent.is_empty() || parent.ends_with('/'))
+                .zip(file_name.strip_suffix(".rels"))
+            else {
+                continue;
+            };
+            let source = format!("{parent}{source_name}");
+            if source_name.is_empty()
+                || !is_pass_through(&source)
+                || !parts.iter().any(|candidate| candidate.name == source)
+            {
+                return Err(err(format!(
+                    "보존할 관계 part의 원본 part가 없습니다: {}",
+                    part.name
+                )));
+            }
+            let mut relationships = Vec::new();
+            validate_relationship_set(
+                part_text(part)?,
+                &part.name,
+                &[],
+                container,
+                Some(&mut relationships),
+            )?;
+            for relationship in relationships.iter().filter(|item| !item.external) {
+                require_pass_through_target(parts, &source, &relationship.target, &part.name)?;
+            }
+        }
+        Ok(())
+    }
+    fn visit_shared_strings(
+        &self,
+        parts: &[PackagePart],
+        mut visit: impl FnMut(usize) -> Result<()>,
+    ) -> Result<usize> {
+        let mut total = 0_usize;
+        for part in parts {
+            if self.style_part(&part.name) != Some(StylePart::Cells) {
+                continue;
+            }
+            let (_, count) = rewrite_shared_string_indices(part_text(part)?, |index| {
+                visit(index)?;
+                Ok(None)
+            })?;
+            total = total.strict_add(count);
+        }
+        Ok(total)
+    }
+}
+impl StyleGroup {
+    fn append_to(&self, styles: &mut String, group_name: &str) -> Result<()> {
+        let appended = self
+            .entries
+            .get(self.original_len..)
+            .ok_or_else(|| err("styles.xml 병합 항목 범위가 손상되었습니다."))?;
+        if appended.is_empty() {
+            return Ok(());
+        }
+        let element = XmlScanner::new(styles)
+            .next_element_named(group_name)?
+            .filter(|element| element.opening.name == group_name && !element.opening.self_closing)
+            .ok_or_else(|| {
+                err(format!(
+                    "styles.xml의 {group_name} 시작 태그가 올바르지 않습니다."
+                ))
+            })?;
+        let mut attributes = XmlAttrScanner::new(element.opening.raw)?;
+        let mut count_value = None;
+        while let Some((name, span)) = attributes.next_span()? {
+            if name == "count" {
+                count_value = Some(span);
+            }
+        }
+        let count_span = count_value
+            .ok_or_else(|| err(format!("styles.xml {group_name}에 count 속성이 없습니다.")))?;
+        let count_start = element.opening.start.strict_add(count_span.start);
+        let count_end = element.opening.start.strict_add(count_span.end);
+        styles.insert_str(element.body_span.end, &appended.concat());
+        styles.replace_range(count_start..count_end, &self.entries.len().to_string());
+        Ok(())
+    }
+    fn from_styles(styles: &str, group_name: &str, entry_name: &str) -> Result<Self> {
+        let entries = style_entries(styles, group_name, entry_name)?
+            .into_iter()
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+        Ok(Self {
+            original_len: entries.len(),
+            entries,
+        })
+    }
+    fn index_of(&mut self, entry: &str) -> Result<u32> {
+        let position = if let Some(position) = self.entries.iter().position(|item| item == entry) {
+            position
+        } else {
+            self.entries.push(entry.to_owned());
+            self.entries.len().strict_sub(1)
+        };
+        u32::try_from(position)
+            .map_err(|source| err_with_source("styles.xml 병합 index 변환 실패", source))
+    }
+    fn num_fmt_id(&mut self, source_entries: &[&str], source_id: &str) -> Result<String> {
+        let id = source_id
+            .parse::<u32>()
+            .map_err(|source| err_with_source("사용자 시트 numFmtId 해석 실패", source))?;
+        if id < FIRST_CUSTOM_NUM_FMT_ID {
+            return Ok(id.to_string());
+        }
+        let mut source_code = None;
+        for entry in source_entries {
+            let [entry_id, code] =
+                parse_attrs(entry, ["numFmtId", "formatCode"], "styles.xml numFmt")?;
+            if entry_id.as_deref() == Some(source_id) {
+                source_code = code;
+                break;
+            }
+        }
+        let format_code = source_code.ok_or_else(|| {
+            err(format!(
+                "사용자 시트 style의 숫자 서식을 찾지 못했습니다: {id}"
+            ))
+        })?;
+        let mut next_id = FIRST_CUSTOM_NUM_FMT_ID;
+        for entry in &self.entries {
+            let [id_attr, code] =
+                parse_attrs(entry, ["numFmtId", "formatCode"], "styles.xml numFmt")?;
+            let entry_id = id_attr
+                .and_then(|value| value.parse::<u32>().ok())
+                .ok_or_else(|| err("styles.xml numFmtId가 올바르지 않습니다."))?;
+            if code.as_deref() == Some(format_code.as_ref()) {
+                return Ok(entry_id.to_string());
+            }
+            next_id = next_id.max(entry_id.strict_add(1));
+        }
+        let mut entry = String::from("<numFmt numFmtId=\"");
+        entry.push_str(&next_id.to_string());
+        entry.push_str("\" formatCode=\"");
+        append_xml_escaped(&mut entry, &format_code, XmlEscapeContext::Attribute);
+        entry.push_str("\"/>");
+        self.entries.push(entry);
+        Ok(next_id.to_string())
+    }
+}
+impl<'xml> XmlSplice<'xml> {
+    fn finish(mut self) -> Result<Option<String>> {
+        if let Some(out) = self.out.as_mut() {
+            out.push_str(
+                self.xml
+                    .get(self.copied..)
+                    .ok_or_else(|| err("XML 치환 나머지 범위가 손상되었습니다."))?,
+            );
+        }
+        Ok(self.out)
+    }
+    const fn new(xml: &'xml str) -> Self {
+        Self {
+            copied: 0,
+            out: None,
+            xml,
+        }
+    }
+    fn replace(&mut self, start: usize, end: usize, replacement: &str) -> Result<()> {
+        let out = if let Some(out) = self.out.as_mut() {
+            out
+        } else {
+            let out = try_string_with_capacity(self.xml.len(), "XML 치환 메모리 확보 실패")?;
+            self.out.insert(out)
+        };
+        out.push_str(
+            self.xml
+                .get(self.copied..start)
+                .ok_or_else(|| err("XML 치환 범위가 손상되었습니다."))?,
+        );
+        out.push_str(replacement);
+        self.copied = end;
+        Ok(())
+    }
+}
+pub(super) fn is_pass_through(name: &str) -> bool {
+    !XLSX_PARTS.iter().any(|&(fixed, _)| fixed == name)
+}
+fn optional_style_entries<'text>(
+    styles_xml: &'text str,
+    group_name: &str,
+    entry_name: &str,
+) -> Result<Vec<&'text str>> {
+    if XmlScanner::new(styles_xml)
+        .next_start_named(group_name)
+        .is_none()
+    {
+        return Ok(Vec::new());
+    }
+    style_entries(styles_xml, group_name, entry_name)
+}
+fn part_extension(name: &str) -> Option<&str> {
+    let file_name = name
+        .rsplit_once('/')
+        .map_or(name, |(_, file_name)| file_name);
+    file_name.rsplit_once('.').map(|(_, extension)| extension)
+}
+fn part_text(part: &PackagePart) -> Result<&str> {
+    str::from_utf8(&part.bytes).map_err(|source| {
+        err_with_source(format!("xlsx part UTF-8 해석 실패: {}", part.name), source)
+    })
+}
+pub(super) fn push_escaped_empty_element<const N: usize>(
+    xml: &mut Vec<u8>,
+    name: &str,
+    attrs: [(&str, &str); N],
+) {
+    let mut element = String::from("<");
+    element.push_str(name);
+    for (attr_name, value) in attrs {
+        element.push(' ');
+        element.push_str(attr_name);
+        element.push_str("=\"");
+        append_xml_escaped(&mut element, value, XmlEscapeContext::Attribute);
+        element.push('"');
+    }
+    element.push_str("/>");
+    xml.extend_from_slice(element.as_bytes());
+}
+fn require_pass_through_target(
+    parts: &[PackagePart],
+    source: &str,
+    target: &str,
+    context: &str,
+) -> Result<()> {
+    let path = target.split('#').next().unwrap_or_default();
+    let base = if path.starts_with('/') {
+        ""
+    } else {
+        source
+            .rsplit_once('/')
+            .map_or("", |(directory, _)| directory)
+    };
+    let mut segments = Vec::new();
+    let mut resolved = true;
+    for segment in base.split('/').chain(path.split('/')) {
+        match segment {
+            "" | "." => {}
+            ".." => resolved &= segments.pop().is_some(),
+            _ => segments.push(segment),
+        }
+    }
+    let name = segments.join("/");
+    if resolved && is_pass_through(&name) && parts.iter().any(|part| part.name == name) {
+        Ok(())
+    } else {
+        Err(err(format!(
+            "{context} 관계 대상 part가 없습니다: {target}"
+        )))
+    }
+}
+fn rewrite_attr_values(
+    xml: &str,
+    mut rewrite: impl FnMut(&XmlTag<'_>, &str, &str) -> Result<Option<String>>,
+) -> Result<Option<String>> {
+    let mut scanner = XmlScanner::new(xml);
+    let mut splice = XmlSplice::new(xml);
+    while let Some(tag) = scanner.next_tag() {
+        if !tag.is_start {
+            continue;
+        }
+        let mut attributes = XmlAttrScanner::new(tag.raw)?;
+        while let Some((name, span)) = attributes.next_span()? {
+            let raw_value = tag
+                .raw
+                .get(span)
+                .ok_or_else(|| err("XML 속성 값 범위가 손상되었습니다."))?;
+            let value = decode_xml_entities(raw_value)?;
+            let Some(replacement) = rewrite(&tag, name, &value)? else {
+                continue;
+            };
+            let mut escaped = String::new();
+            append_xml_escaped(&mut escaped, &replacement, XmlEscapeContext::Attribute);
+            splice.replace(
+                tag.start.strict_add(span.start),
+                tag.start.strict_add(span.end),
+                &escaped,
+            )?;
+        }
+    }
+    splice.finish()
+}
+fn rewrite_shared_string_indices(
+    xml: &str,
+    mut visit: impl FnMut(usize) -> Result<Option<usize>>,
+) -> Result<(Option<String>, usize)> {
+    let mut scanner = XmlScanner::new(xml);
+    let mut splice = XmlSplice::new(xml);
+    let mut count = 0_usize;
+    while let Some(cell) = scanner.next_element_named("c")? {
+        if cell.opening.name != "c" || extract_attr(cell.opening.raw, "t")?.as_deref() != Some("s")
+        {
+            continue;
+        }
+        let value = XmlScanner::new(cell.body)
+            .next_element_named("v")?
+            .filter(|value| value.opening.name == "v" && !value.opening.self_closing)
+            .ok_or_else(|| err("사용자 시트의 공유 문자열 셀에 값이 없습니다."))?;
+        let index = value.body.trim().parse::<usize>().map_err(|source| {
+            err_with_source("사용자 시트 shared string index 해석 실패", source)
+        })?;
+        count = count.strict_add(1);
+        let Some(new_index) = visit(index)? else {
+            continue;
+        };
+        splice.replace(
+            cell.body_span.start.strict_add(value.body_span.start),
+            cell.body_span.start.strict_add(value.body_span.end),
+            &new_index.to_string(),
+        )?;
+    }
+    Ok((splice.finish()?, count))
+}
+fn rewrite_style_references(
+    xml: &str,
+    style_part: StylePart,
+    mut visit: impl FnMut(StyleReference, u32) -> Result<Option<u32>>,
+) -> Result<Option<String>> {
+    rewrite_attr_values(xml, |tag, name, value| {
+        let reference = match (name, tag.name) {
+            ("s", "c" | "row") | ("style", "col") if style_part == StylePart::Cells => {
+                StyleReference::Xf
+            }
+            _ if name == "dxfId" || name.ends_with("DxfId") => StyleReference::Dxf,
+            _ => return Ok(None),
+        };
+        let index = value.parse::<u32>().map_err(|source| {
+            err_with_source(format!("사용자 시트 style 참조 해석 실패: {name}"), source)
+        })?;
+        Ok(visit(reference, index)?.map(|new_index| new_index.to_string()))
+    })
+}
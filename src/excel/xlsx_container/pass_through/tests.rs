@@ -0,0 +1,230 @@
+use super::super::super::{
+    PackagePart, SaveVerification, ZipArchiveBuilder, ZipPackageReader, writer::Workbook,
+};
+use crate::diagnostic::{Result, ensure, err};
+use std::{
+    env,
+    fs::{self, File},
+    path::Path,
+    process,
+};
+const CHART_PATH: &str = "xl/charts/chart1.xml";
+const DRAWING_PATH: &str = "xl/drawings/drawing1.xml";
+const DRAWING_RELS_PATH: &str = "xl/drawings/_rels/drawing1.xml.rels";
+const SHEET_PATH: &str = "xl/worksheets/sheet3.xml";
+const SHEET_RELS_PATH: &str = "xl/worksheets/_rels/sheet3.xml.rels";
+const EXTRA_PARTS: [(&str, &str); 5] = [
+    (
+        SHEET_PATH,
+        concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n",
+            "<worksheet xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\" xmlns:r=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships\">",
+            "<sheetData><row r=\"1\"><c r=\"A1\" s=\"4\" t=\"s\"><v>6</v></c><c r=\"B1\" s=\"13\"><v>1550</v></c></row>",
+            "<row r=\"2\"><c r=\"A2\" t=\"s\"><v>12</v></c></row></sheetData>",
+            "<drawing r:id=\"rId1\"/></worksheet>",
+        ),
+    ),
+    (
+        SHEET_RELS_PATH,
+        concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n",
+            "<Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">",
+            "<Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/drawing\" Target=\"../drawings/drawing1.xml\"/>",
+            "</Relationships>",
+        ),
+    ),
+    (
+        DRAWING_PATH,
+        concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n",
+            "<xdr:wsDr xmlns:xdr=\"http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing\" xmlns:a=\"http://schemas.openxmlformats.org/drawingml/2006/main\">",
+            "<xdr:twoCellAnchor><xdr:from><xdr:col>3</xdr:col><xdr:colOff>0</xdr:colOff><xdr:row>1</xdr:row><xdr:rowOff>0</xdr:rowOff></xdr:from>",
+            "<xdr:to><xdr:col>9</xdr:col><xdr:colOff>0</xdr:colOff><xdr:row>16</xdr:row><xdr:rowOff>0</xdr:rowOff></xdr:to>",
+            "<xdr:graphicFrame macro=\"\"><xdr:nvGraphicFramePr><xdr:cNvPr id=\"2\" name=\"가격 차트\"/><xdr:cNvGraphicFramePr/></xdr:nvGraphicFramePr>",
+            "<xdr:xfrm><a:off x=\"0\" y=\"0\"/><a:ext cx=\"0\" cy=\"0\"/></xdr:xfrm><a:graphic><a:graphicData uri=\"http://schemas.openxmlformats.org/drawingml/2006/chart\">",
+            "<c:chart xmlns:c=\"http://schemas.openxmlformats.org/drawingml/2006/chart\" xmlns:r=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships\" r:id=\"rId1\"/>",
+            "</a:graphicData></a:graphic></xdr:graphicFrame><xdr:clientData/></xdr:twoCellAnchor></xdr:wsDr>",
+        ),
+    ),
+    (
+        DRAWING_RELS_PATH,
+        concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n",
+            "<Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">",
+            "<Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart\" Target=\"../charts/chart1.xml\"/>",
+            "</Relationships>",
+        ),
+    ),
+    (
+        CHART_PATH,
+        concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n",
+            "<c:chartSpace xmlns:c=\"http://schemas.openxmlformats.org/drawingml/2006/chart\"><c:chart><c:plotArea><c:barChart>",
+            "<c:barDir val=\"col\"/><c:ser><c:idx val=\"0\"/><c:order val=\"0\"/>",
+            "<c:cat><c:strRef><c:f>메모!$A$1:$A$2</c:f></c:strRef></c:cat>",
+            "<c:val><c:numRef><c:f>메모!$B$1:$B$2</c:f></c:numRef></c:val></c:ser>",
+            "</c:barChart></c:plotArea></c:chart></c:chartSpace>",
+        ),
+    ),
+];
+#[test]
+fn extra_sheet_parts_survive_a_save() -> Result<()> {
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("fuel_cost_chungcheong.xlsx");
+    let (_, mut parts) = ZipPackageReader {
+        archive_file: File::open(&fixture)?,
+        archive_path: &fixture,
+    }
+    .read()?;
+    // The extra sheet adds two shared string references to the declared count.
+    replace_in(
+        &mut parts,
+        "xl/sharedStrings.xml",
+        "count=\"4840\"",
+        "count=\"4842\"",
+    )?;
+    replace_in(
+        &mut parts,
+        "xl/workbook.xml",
+        "</sheets>",
+        "<sheet name=\"메모\" sheetId=\"5\" r:id=\"rId9\"/></sheets>",
+    )?;
+    replace_in(
+        &mut parts,
+        "xl/_rels/workbook.xml.rels",
+        "</Relationships>",
+        "<Relationship Id=\"rId9\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet\" Target=\"worksheets/sheet3.xml\"/></Relationships>",
+    )?;
+    replace_in(
+        &mut parts,
+        "[Content_Types].xml",
+        "</Types>",
+        concat!(
+            "<Override PartName=\"/xl/worksheets/sheet3.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml\"/>",
+            "<Override PartName=\"/xl/drawings/drawing1.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.drawing+xml\"/>",
+            "<Override PartName=\"/xl/charts/chart1.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.drawingml.chart+xml\"/>",
+            "</Types>",
+        ),
+    )?;
+    for (name, xml) in EXTRA_PARTS {
+        parts.push(PackagePart {
+            bytes: xml.as_bytes().to_vec(),
+            name: name.into(),
+        });
+    }
+    let dir = env::temp_dir().join(format!("fcupdater-pass-through-{}", process::id()));
+    fs::create_dir_all(&dir)?;
+    let master = dir.join("fuel_cost_chungcheong.xlsx");
+    let mut file = File::create(&master)?;
+    ZipArchiveBuilder {
+        archive_path: &master,
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        permissions: file.metadata()?.permissions(),
+        file: &mut file,
+        parts: &parts,
+    }
+    .create()?;
+    drop(file);
+    Workbook::open(&master)?.save(
+        &master,
+        SaveVerification::Verify,
+        (&"a".repeat(64), &"b".repeat(64)),
+        None,
+        |_saved| Ok(()),
+    )?;
+    let (_, saved) = ZipPackageReader {
+        archive_file: File::open(&master)?,
+        archive_path: &master,
+    }
+    .read()?;
+    let text = |name: &str| -> Result<String> {
+        let part = saved
+            .iter()
+            .find(|part| part.name == name)
+            .ok_or_else(|| err(format!("saved part is missing: {name}")))?;
+        Ok(String::from_utf8_lossy(&part.bytes).into_owned())
+    };
+    for &(name, xml) in EXTRA_PARTS.iter().filter(|&&(name, _)| name != SHEET_PATH) {
+        ensure(
+            text(name)? == xml,
+            format!("{name} must be kept byte for byte"),
+        )?;
+    }
+    let sheet = text(SHEET_PATH)?;
+    let shared_strings = text("xl/sharedStrings.xml")?;
+    let styles = text("xl/styles.xml")?;
+    let cell_value = |cell: &str| {
+        sheet
+            .split_once(&format!("<c r=\"{cell}\""))
+            .and_then(|(_, rest)| rest.split_once("<v>"))
+            .and_then(|(_, rest)| rest.split_once("</v>"))
+            .map(|(value, _)| value.to_owned())
+            .ok_or("sheet3 cell value")
+    };
+    let cell_style = |cell: &str| {
+        sheet
+            .split_once(&format!("<c r=\"{cell}\" s=\""))
+            .and_then(|(_, rest)| rest.split_once('"'))
+            .and_then(|(style, _)| style.parse::<usize>().ok())
+            .ok_or("sheet3 cell style")
+    };
+    for (cell, expected) in [("A1", "대전"), ("A2", "천안")] {
+        let index = cell_value(cell)?
+            .parse::<usize>()
+            .map_err(|_parse_err| err("shared string index"))?;
+        let entry = shared_strings
+            .split("<si>")
+            .nth(index.strict_add(1))
+            .ok_or("shared string entry")?;
+        ensure(
+            entry.starts_with(&format!("<t>{expected}</t>")),
+            format!("{cell} points at {entry}"),
+        )?;
+    }
+    ensure(cell_value("B1")? == "1550", "numeric cells are untouched")?;
+    let cell_xf = |index: usize| {
+        styles
+            .split_once("<cellXfs")
+            .and_then(|(_, rest)| rest.split("<xf ").nth(index.strict_add(1)))
+            .map(str::to_owned)
+            .ok_or("cellXfs entry")
+    };
+    let filled = cell_xf(cell_style("A1")?)?;
+    ensure(
+        filled.contains("fillId=\"") && !filled.contains("fillId=\"0\""),
+        format!("A1 keeps its fill: {filled}"),
+    )?;
+    let formatted = cell_xf(cell_style("B1")?)?;
+    let num_fmt_id = formatted
+        .split_once("numFmtId=\"")
+        .and_then(|(_, rest)| rest.split_once('"'))
+        .map(|(id, _)| id.to_owned())
+        .ok_or("B1 numFmtId")?;
+    ensure(
+        styles.contains(&format!(
+            "<numFmt numFmtId=\"{num_fmt_id}\" formatCode=\"_-* #,##0_-;\\-* #,##0_-;_-* \\-_-;_-@_-\"/>"
+        )),
+        format!("B1 keeps its number format: {formatted}"),
+    )?;
+    let app = text("docProps/app.xml")?;
+    ensure(
+        app.contains("<vt:lpstr>메모</vt:lpstr>"),
+        "app.xml lists the extra sheet",
+    )?;
+    let reopened = Workbook::open(&master)?;
+    ensure(
+        reopened.source_hash() == Some("a".repeat(64).as_str()),
+        "saved workbook reopens",
+    )?;
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+fn replace_in(parts: &mut [PackagePart], name: &str, from: &str, to: &str) -> Result<()> {
+    let part = parts
+        .iter_mut()
+        .find(|part| part.name == name)
+        .ok_or("fixture part")?;
+    let xml = String::from_utf8_lossy(&part.bytes).into_owned();
+    let (head, tail) = xml.split_once(from).ok_or("fixture anchor")?;
+    part.bytes = format!("{head}{to}{tail}").into_bytes();
+    Ok(())
+}
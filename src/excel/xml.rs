@@ -1,6 +1,8 @@
 use crate::diagnostic::{Result, err, err_with_source, try_string_with_capacity};
 use alloc::borrow::Cow;
 use core::{iter, range::Range};
+// 발음 표기(`<rPh>`) 안의 `<t>`는 셀 값이 아니므로 텍스트에서 제외합니다.
+const PHONETIC_RUN_TAG: &str = "rPh";
 pub(super) struct XmlTag<'xml> {
     pub end: usize,
     pub is_start: bool,
@@ -232,6 +234,16 @@ impl<'tag> XmlAttrScanner<'tag> {
         Ok(Self { cursor, tag })
     }
     pub(super) fn next(&mut self) -> Result<Option<(&'tag str, Cow<'tag, str>)>> {
+        let Some((name, value_span)) = self.next_span()? else {
+            return Ok(None);
+        };
+        let value = self
+            .tag
+            .get(value_span)
+            .ok_or_else(|| err("XML 속성 값 범위가 손상되었습니다."))?;
+        Ok(Some((name, decode_xml_entities(value)?)))
+    }
+    pub(super) fn next_span(&mut self) -> Result<Option<(&'tag str, Range<usize>)>> {
         let bytes = self.tag.as_bytes();
         let separator_start = self.cursor;
         while bytes.get(self.cursor).is_some_and(u8::is_ascii_whitespace) {
@@ -290,13 +302,15 @@ impl<'tag> XmlAttrScanner<'tag> {
             .tag
             .get(name_start..name_end)
             .ok_or_else(|| err("XML 속성 이름 범위가 손상되었습니다."))?;
-        let value = self
-            .tag
-            .get(value_start..value_end)
-            .ok_or_else(|| err("XML 속성 값 범위가 손상되었습니다."))?;
         self.cursor = checked_offset_add(value_end, 1)
             .ok_or_else(|| err("XML 다음 속성 cursor 계산에 실패했습니다."))?;
-        Ok(Some((name, decode_xml_entities(value)?)))
+        Ok(Some((
+            name,
+            Range {
+                start: value_start,
+                end: value_end,
+            },
+        )))
     }
 }
 const fn checked_offset_add(base: usize, add: usize) -> Option<usize> {
@@ -357,10 +371,23 @@ pub(super) fn extract_all_tag_text<'xml>(
     tag_name: &str,
 ) -> Result<Option<Cow<'xml, str>>> {
     let mut scanner = XmlScanner::new(xml);
+    let mut phonetic_scanner = XmlScanner::new(xml);
+    let mut phonetic_run = phonetic_scanner.next_element_named(PHONETIC_RUN_TAG)?;
     let mut first_text: Option<Cow<'xml, str>> = None;
     let mut out: Option<String> = None;
     let mut saw_text_tag = false;
     while let Some(element) = scanner.next_element_named(tag_name)? {
+        while let Some(run) = phonetic_run.as_ref()
+            && run.span.end <= element.span.start
+        {
+            phonetic_run = phonetic_scanner.next_element_named(PHONETIC_RUN_TAG)?;
+        }
+        if phonetic_run
+            .as_ref()
+            .is_some_and(|run| run.span.contains(&element.span.start))
+        {
+            continue;
+        }
         saw_text_tag = true;
         if element.opening.self_closing {
             continue;
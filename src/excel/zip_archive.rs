@@ -1,11 +1,12 @@
 use super::{
-    ArchiveFingerprint, MAX_XLSX_PART_BYTES, PackagePart, XLSX_PARTS, XlsxPartRole,
+    ArchiveFingerprint, MAX_XLSX_PART_BYTES, PackagePart, XLSX_PARTS, XlsxPartRole, ZipArchiveView,
     ZipPackageReader,
 };
 use crate::diagnostic::{
     AppError, Result, Result as ZipResult, err, err as zip_static, err_with_source,
     err_with_source as zip_with_source, path_context_message, try_vec_with_capacity,
 };
+use alloc::borrow::Cow;
 use core::str;
 use std::{fs::File, io::Read as _, path::Path};
 mod deflate;
@@ -64,7 +65,8 @@ const ZIP_CENTRAL_HEADER_RANGE: &str = "ZIP 중앙 디렉터리 header 범위 
 const ZIP_DATA_RANGE_MESSAGE: &str = "ZIP entry 데이터가 파일 범위를 벗어났습니다";
 const ZIP_EOCD_HEADER_RANGE: &str = "ZIP EOCD header 범위 오류";
 const ZIP_FINGERPRINT_BUFFER_BYTES: usize = 64 * 1024;
-const ZIP_MAX_ARCHIVE_BYTES: usize = 128 * 1024 * 1024;
+pub(super) const ZIP_MAX_ARCHIVE_BYTES: usize = 128 * 1024 * 1024;
+const ZIP_MAX_VIEW_ENTRIES: usize = 1024;
 const ZIP_MAX_TOTAL_UNCOMPRESSED_BYTES: usize = 256 * 1024 * 1024;
 const LENGTH_BASES: [usize; 29] = [
     3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
@@ -365,6 +367,38 @@ impl<'bytes> ZipCentralDirectory<'bytes> {
         }))
     }
 }
+impl ZipArchiveView<'_> {
+    pub(super) fn read_entry(&self, name: &str) -> Result<Option<Vec<u8>>> {
+        if self.0.len() > ZIP_MAX_ARCHIVE_BYTES {
+            return Err(err(format!(
+                "ZIP 압축 데이터 크기가 허용 한도({ZIP_MAX_ARCHIVE_BYTES} bytes)를 초과했습니다."
+            )));
+        }
+        let mut central_directory = locate_central_directory(self.0)?;
+        if central_directory.remaining_entries > ZIP_MAX_VIEW_ENTRIES {
+            return Err(err(format!(
+                "ZIP entry 수가 지원 상한을 초과했습니다: {}",
+                central_directory.remaining_entries
+            )));
+        }
+        let mut found = None;
+        while let Some(entry) = central_directory.next_entry()? {
+            if entry.name == name && found.replace(entry).is_some() {
+                return Err(err(format!("ZIP entry 이름이 중복되었습니다: {name}")));
+            }
+        }
+        let Some(entry) = found else {
+            return Ok(None);
+        };
+        let expected_len = usize::try_from(entry.uncompressed_size)
+            .map_err(|source| err_with_source("ZIP 해제 크기 변환 실패", source))?;
+        ensure_zip_size_limit("entry 해제", expected_len, MAX_XLSX_PART_BYTES, entry.name)?;
+        let local_offset = usize::try_from(entry.local_header_offset)
+            .map_err(|source| err_with_source("ZIP local header offset 변환 실패", source))?;
+        let (bytes, _) = entry.data(self.0, expected_len, local_offset)?;
+        Ok(Some(bytes))
+    }
+}
 impl ZipPackageReader<'_> {
     pub(super) fn read(self) -> Result<(ArchiveFingerprint, Vec<PackagePart>)> {
         let mut archive_bytes = Vec::new();
@@ -373,90 +407,45 @@ impl ZipPackageReader<'_> {
             self.archive_path,
             Some(&mut archive_bytes),
         )?;
-        if archive_bytes.len() < END_OF_CENTRAL_DIRECTORY_LEN {
-            return Err(zip_static("ZIP 파일이 너무 짧습니다."));
-        }
-        let search_window = END_OF_CENTRAL_DIRECTORY_LEN.strict_add(ZIP_COMMENT_MAX_LEN);
-        let min_offset = archive_bytes.len().saturating_sub(search_window);
-        let max_offset = archive_bytes.len().strict_sub(END_OF_CENTRAL_DIRECTORY_LEN);
-        let search_end = max_offset.strict_add(4_usize);
-        let search_bytes = archive_bytes
-            .get(min_offset..search_end)
-            .ok_or_else(|| zip_static("ZIP EOCD 검색 범위 오류"))?;
-        let eocd_signature = END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes();
-        let mut search_len = search_bytes.len();
-        let (eocd_offset, eocd) = loop {
-            let search_prefix = search_bytes
-                .get(..search_len)
-                .ok_or_else(|| zip_static("ZIP EOCD 검색 범위 오류"))?;
-            let Some(relative_offset) = search_prefix
-                .array_windows::<4>()
-                .rposition(|window| *window == eocd_signature)
-            else {
-                return Err(zip_static("ZIP EOCD를 찾지 못했습니다."));
-            };
-            let offset = min_offset.strict_add(relative_offset);
-            let (eocd, _) = split_header_at::<END_OF_CENTRAL_DIRECTORY_LEN>(
-                archive_bytes.as_slice(),
-                offset,
-                ZIP_EOCD_HEADER_RANGE,
-            )?;
-            let comment_len = usize::from(read_u16(eocd, 20)?);
-            if offset
-                .checked_add(END_OF_CENTRAL_DIRECTORY_LEN)
-                .and_then(|value| value.checked_add(comment_len))
-                == Some(archive_bytes.len())
-            {
-                break (offset, eocd);
-            }
-            search_len = relative_offset;
-        };
-        let disk_no = read_u16(eocd, 4)?;
-        let central_dir_start_disk = read_u16(eocd, 6)?;
-        let entries_this_disk = read_u16(eocd, 8)?;
-        let entries_total = read_u16(eocd, 10)?;
-        if disk_no != 0 || central_dir_start_disk != 0 || entries_this_disk != entries_total {
-            return Err(zip_static("분할 ZIP archive는 지원하지 않습니다."));
-        }
-        let entry_count = usize::from(entries_total);
-        if entry_count > XLSX_PARTS.len() {
+        let mut central_directory = locate_central_directory(archive_bytes.as_slice())?;
+        let entry_count = central_directory.remaining_entries;
+        if entry_count > ZIP_MAX_VIEW_ENTRIES {
             return Err(err(format!(
                 "ZIP entry 수가 지원 상한을 초과했습니다: {entry_count}"
             )));
         }
-        let central_dir_size = usize::try_from(read_u32(eocd, 12)?)
-            .map_err(|source| err_with_source("ZIP 중앙 디렉터리 크기 변환 실패", source))?;
-        let central_dir_offset = usize::try_from(read_u32(eocd, 16)?)
-            .map_err(|source| err_with_source("ZIP 중앙 디렉터리 offset 변환 실패", source))?;
-        let central_dir_end = central_dir_offset
-            .checked_add(central_dir_size)
-            .ok_or_else(|| zip_static("ZIP 중앙 디렉터리 범위 계산 실패"))?;
-        if central_dir_end != eocd_offset {
-            return Err(zip_static(
-                "ZIP 중앙 디렉터리와 EOCD 사이의 추가 데이터는 지원하지 않습니다.",
-            ));
-        }
-        let mut central_directory = ZipCentralDirectory {
-            bytes: archive_bytes.as_slice(),
-            cursor: central_dir_offset,
-            end: central_dir_end,
-            remaining_entries: entry_count,
-        };
+        let central_dir_offset = central_directory.cursor;
         let mut total_uncompressed = 0_usize;
-        let mut entries: Vec<(ZipEntry<'_>, &'static str, usize)> =
+        let mut entries: Vec<(ZipEntry<'_>, Cow<'static, str>, usize)> =
             try_vec_with_capacity(entry_count, "ZIP entry 목록 메모리 확보 실패")?;
         for _ in 0..entry_count {
             let entry = central_directory
                 .next_entry()?
                 .ok_or_else(|| zip_static("ZIP entry가 고정 스키마보다 적습니다."))?;
-            let Some(&(part_name, _)) = XLSX_PARTS.iter().find(|&&(name, _)| name == entry.name)
-            else {
-                return Err(err(format!(
-                    "ZIP entry 이름이 고정 스키마에 없습니다: {}",
-                    entry.name
-                )));
+            let part_name = if let Some(&(name, _)) =
+                XLSX_PARTS.iter().find(|&&(name, _)| name == entry.name)
+            {
+                Cow::Borrowed(name)
+            } else {
+                let valid = !entry.name.is_empty()
+                    && !entry.name.contains('\\')
+                    && entry.name.split('/').all(|segment| {
+                        !segment.is_empty()
+                            && !segment.ends_with('.')
+                            && !segment.chars().any(char::is_control)
+                    });
+                if !valid {
+                    return Err(err(format!(
+                        "ZIP entry 이름이 package part 이름 규칙에 맞지 않습니다: {}",
+                        entry.name
+                    )));
+                }
+                Cow::Owned(entry.name.to_owned())
             };
-            if entries.iter().any(|item| item.1 == part_name) {
+            if entries
+                .iter()
+                .any(|item| item.1.eq_ignore_ascii_case(&part_name))
+            {
                 return Err(err(format!("ZIP entry 이름이 중복되었습니다: {part_name}")));
             }
             let expected_len = usize::try_from(entry.uncompressed_size)
@@ -504,6 +493,71 @@ impl ZipPackageReader<'_> {
         Ok((fingerprint, parts))
     }
 }
+fn locate_central_directory(archive_bytes: &[u8]) -> Result<ZipCentralDirectory<'_>> {
+    if archive_bytes.len() < END_OF_CENTRAL_DIRECTORY_LEN {
+        return Err(zip_static("ZIP 파일이 너무 짧습니다."));
+    }
+    let search_window = END_OF_CENTRAL_DIRECTORY_LEN.strict_add(ZIP_COMMENT_MAX_LEN);
+    let min_offset = archive_bytes.len().saturating_sub(search_window);
+    let max_offset = archive_bytes.len().strict_sub(END_OF_CENTRAL_DIRECTORY_LEN);
+    let search_end = max_offset.strict_add(4_usize);
+    let search_bytes = archive_bytes
+        .get(min_offset..search_end)
+        .ok_or_else(|| zip_static("ZIP EOCD 검색 범위 오류"))?;
+    let eocd_signature = END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes();
+    let mut search_len = search_bytes.len();
+    let (eocd_offset, eocd) = loop {
+        let search_prefix = search_bytes
+            .get(..search_len)
+            .ok_or_else(|| zip_static("ZIP EOCD 검색 범위 오류"))?;
+        let Some(relative_offset) = search_prefix
+            .array_windows::<4>()
+            .rposition(|window| *window == eocd_signature)
+        else {
+            return Err(zip_static("ZIP EOCD를 찾지 못했습니다."));
+        };
+        let offset = min_offset.strict_add(relative_offset);
+        let (eocd, _) = split_header_at::<END_OF_CENTRAL_DIRECTORY_LEN>(
+            archive_bytes,
+            offset,
+            ZIP_EOCD_HEADER_RANGE,
+        )?;
+        let comment_len = usize::from(read_u16(eocd, 20)?);
+        if offset
+            .checked_add(END_OF_CENTRAL_DIRECTORY_LEN)
+            .and_then(|value| value.checked_add(comment_len))
+            == Some(archive_bytes.len())
+        {
+            break (offset, eocd);
+        }
+        search_len = relative_offset;
+    };
+    let disk_no = read_u16(eocd, 4)?;
+    let central_dir_start_disk = read_u16(eocd, 6)?;
+    let entries_this_disk = read_u16(eocd, 8)?;
+    let entries_total = read_u16(eocd, 10)?;
+    if disk_no != 0 || central_dir_start_disk != 0 || entries_this_disk != entries_total {
+        return Err(zip_static("분할 ZIP archive는 지원하지 않습니다."));
+    }
+    let central_dir_size = usize::try_from(read_u32(eocd, 12)?)
+        .map_err(|source| err_with_source("ZIP 중앙 디렉터리 크기 변환 실패", source))?;
+    let central_dir_offset = usize::try_from(read_u32(eocd, 16)?)
+        .map_err(|source| err_with_source("ZIP 중앙 디렉터리 offset 변환 실패", source))?;
+    let central_dir_end = central_dir_offset
+        .checked_add(central_dir_size)
+        .ok_or_else(|| zip_static("ZIP 중앙 디렉터리 범위 계산 실패"))?;
+    if central_dir_end != eocd_offset {
+        return Err(zip_static(
+            "ZIP 중앙 디렉터리와 EOCD 사이의 추가 데이터는 지원하지 않습니다.",
+        ));
+    }
+    Ok(ZipCentralDirectory {
+        bytes: archive_bytes,
+        cursor: central_dir_offset,
+        end: central_dir_end,
+        remaining_entries: usize::from(entries_total),
+    })
+}
 fn validate_zip_extra(extra: &[u8], entry_name: &str) -> Result<()> {
     let mut cursor = 0_usize;
     while cursor < extra.len() {
@@ -2,8 +2,8 @@ use super::super::{MAX_XLSX_PART_BYTES, PackagePart, ZipArchiveBuilder};
 use super::{
     CENTRAL_DIRECTORY_HEADER_LEN, CENTRAL_DIRECTORY_SIGNATURE, END_OF_CENTRAL_DIRECTORY_LEN,
     END_OF_CENTRAL_DIRECTORY_SIGNATURE, EXCEL_ENTRY_FLAGS, LOCAL_FILE_HEADER_LEN,
-    LOCAL_FILE_HEADER_SIGNATURE, METHOD_DEFLATE, VERSION_MADE_BY, VERSION_NEEDED,
-    ZIP_MAX_ARCHIVE_BYTES, deflate,
+    LOCAL_FILE_HEADER_SIGNATURE, METHOD_DEFLATE, METHOD_STORED, VERSION_MADE_BY, VERSION_NEEDED,
+    ZIP_MAX_ARCHIVE_BYTES, crc32_update, deflate,
 };
 use crate::diagnostic::{
     Result, err, err_with_source, path_context_message, try_vec_with_capacity,
@@ -16,11 +16,14 @@ use std::{
     io::{BufWriter, Write as IoWrite},
     path::Path,
 };
+const STORED_PART_NAME: &str = "mimetype";
 const ZIP_OUTPUT_BUFFER_CAPACITY: usize = 64 * 1024;
 struct WriteEntry<'part> {
     compressed_size: u32,
     crc32: u32,
+    flags: u16,
     local_header_offset: u32,
+    method: u16,
     part: &'part PackagePart,
     uncompressed_size: u32,
 }
@@ -62,8 +65,8 @@ impl<'part> StreamingZipWriter<'part, '_> {
             write_u32(&mut self.header_buffer, CENTRAL_DIRECTORY_SIGNATURE);
             write_u16(&mut self.header_buffer, VERSION_MADE_BY);
             write_u16(&mut self.header_buffer, VERSION_NEEDED);
-            write_u16(&mut self.header_buffer, EXCEL_ENTRY_FLAGS);
-            write_u16(&mut self.header_buffer, METHOD_DEFLATE);
+            write_u16(&mut self.header_buffer, entry.flags);
+            write_u16(&mut self.header_buffer, entry.method);
             write_u16(&mut self.header_buffer, 0);
             write_u16(&mut self.header_buffer, 0x0021);
             write_u32(&mut self.header_buffer, entry.crc32);
@@ -107,30 +110,50 @@ impl<'part> StreamingZipWriter<'part, '_> {
                 part.name
             )));
         }
-        let Some(plan) = (deflate::DeflateWriter {
-            bytes: &part.bytes,
-            workspace: &mut self.deflate_workspace,
-        })
-        .plan(part.name)?
-        else {
-            return Err(err(format!(
-                "고정 XLSX part 압축 작업 한도를 초과했습니다: {}",
-                part.name
-            )));
+        let plan = if part.name == STORED_PART_NAME {
+            None
+        } else {
+            let Some(deflate_plan) = (deflate::DeflateWriter {
+                bytes: &part.bytes,
+                workspace: &mut self.deflate_workspace,
+            })
+            .plan(&part.name)?
+            else {
+                return Err(err(format!(
+                    "고정 XLSX part 압축 작업 한도를 초과했습니다: {}",
+                    part.name
+                )));
+            };
+            Some(deflate_plan)
+        };
+        let (compressed_size, crc32, flags, method) = match plan.as_ref() {
+            Some(deflate_plan) => (
+                deflate_plan.len(),
+                deflate_plan.crc32(),
+                EXCEL_ENTRY_FLAGS,
+                METHOD_DEFLATE,
+            ),
+            None => (
+                uncompressed_size,
+                !crc32_update(u32::MAX, &part.bytes)?,
+                0,
+                METHOD_STORED,
+            ),
         };
-        let compressed_size = plan.len();
         let compressed_size_u32 = u32::try_from(compressed_size)
             .map_err(|source| err_with_source("ZIP entry 압축 크기 변환 실패", source))?;
         let uncompressed_size_u32 = u32::try_from(uncompressed_size)
             .map_err(|source| err_with_source("ZIP entry 원본 크기 변환 실패", source))?;
-        let crc32 = plan.crc32();
         let local_header_offset = u32::try_from(self.bytes_written)
             .map_err(|source| err_with_source("ZIP offset 변환 실패", source))?;
-        let local_extra = match part.name {
+        let local_extra = match part.name.as_ref() {
             "[Content_Types].xml" | "_rels/.rels" => {
                 Some((520, [0x20, 0xa2, 0x04, 0x02, 0x28, 0xa0, 0x00, 0x02]))
             }
-            "xl/_rels/workbook.xml.rels" | "docProps/core.xml" | "docProps/app.xml" => {
+            "xl/_rels/workbook.xml.rels"
+            | "docProps/core.xml"
+            | "docProps/app.xml"
+            | "docProps/custom.xml" => {
                 Some((264, [0x20, 0xa2, 0x04, 0x01, 0x28, 0xa0, 0x00, 0x01]))
             }
             _ => None,
@@ -147,8 +170,8 @@ impl<'part> StreamingZipWriter<'part, '_> {
         self.prepare_header_buffer(local_header_len, "ZIP local header 메모리 확보 실패")?;
         write_u32(&mut self.header_buffer, LOCAL_FILE_HEADER_SIGNATURE);
         write_u16(&mut self.header_buffer, VERSION_NEEDED);
-        write_u16(&mut self.header_buffer, EXCEL_ENTRY_FLAGS);
-        write_u16(&mut self.header_buffer, METHOD_DEFLATE);
+        write_u16(&mut self.header_buffer, flags);
+        write_u16(&mut self.header_buffer, method);
         write_u16(&mut self.header_buffer, 0);
         write_u16(&mut self.header_buffer, 0x0021);
         write_u32(&mut self.header_buffer, crc32);
@@ -179,19 +202,32 @@ impl<'part> StreamingZipWriter<'part, '_> {
             );
         }
         self.write_header_buffer("xlsx 압축 local header 쓰기 실패")?;
-        let actual_written = plan.write_to(&mut self.file)?;
+        let actual_written = if let Some(deflate_plan) = plan {
+            let written = deflate_plan.write_to(&mut self.file)?;
+            self.deflate_workspace.recycle(deflate_plan);
+            written
+        } else {
+            IoWrite::write_all(&mut self.file, &part.bytes).map_err(|source_err| {
+                err_with_source(
+                    path_context_message("ZIP stored entry 쓰기 실패", self.archive_path),
+                    source_err,
+                )
+            })?;
+            part.bytes.len()
+        };
         if actual_written != compressed_size {
             return Err(err(format!(
-                "ZIP deflate 출력 크기가 계획과 다릅니다: expected={compressed_size}, actual={actual_written}"
+                "ZIP entry 출력 크기가 계획과 다릅니다: expected={compressed_size}, actual={actual_written}"
             )));
         }
         self.bytes_written =
             self.ensure_output_limit(compressed_size, "ZIP 압축 데이터 출력 크기 계산")?;
-        self.deflate_workspace.recycle(plan);
         Ok(WriteEntry {
             compressed_size: compressed_size_u32,
             crc32,
+            flags,
             local_header_offset,
+            method,
             part,
             uncompressed_size: uncompressed_size_u32,
         })
@@ -0,0 +1,162 @@
+use crate::{
+    diagnostic::{
+        ErrorCategory, Result, append_fmt, err, err_with_source, path_context_message,
+        terminal_safe,
+    },
+    excel::writer::Workbook as StdWorkbook,
+    json::{JsonValue, append_json_string},
+    master_sheet::MasterTable,
+    temp_entry::configure_no_follow,
+    write_line,
+};
+use std::{fs::File, io::Write, path::Path};
+#[derive(Clone, Copy)]
+pub(super) enum ExportFormat {
+    Csv,
+    Json,
+    Ods,
+}
+pub(super) struct ExportRun<'out> {
+    pub bom: bool,
+    pub format: ExportFormat,
+    pub master_path: &'out Path,
+    pub out: &'out mut dyn Write,
+    pub output_path: Option<&'out Path>,
+}
+impl ExportRun<'_> {
+    pub(super) fn run(&mut self) -> Result<()> {
+        let book = StdWorkbook::open_input(self.master_path).map_err(|source| {
+            err_with_source(
+                path_context_message("내보낼 워크북 읽기 실패", self.master_path),
+                source,
+            )
+            .with_category(ErrorCategory::MasterStructure)
+        })?;
+        if let (ExportFormat::Ods, Some(output_path)) = (self.format, self.output_path) {
+            book.save_ods(output_path).map_err(|source| {
+                err_with_source(
+                    path_context_message("ODS 내보내기 실패", output_path),
+                    source,
+                )
+            })?;
+            write_line(
+                self.out,
+                format_args!(
+                    "내보내기 완료: {} (ODS)",
+                    terminal_safe(&output_path.display())
+                ),
+            )?;
+            return Ok(());
+        }
+        let table = MasterTable::try_from(&book).map_err(|source| {
+            err_with_source(
+                path_context_message("내보낼 워크북 읽기 실패", self.master_path),
+                source,
+            )
+            .with_category(ErrorCategory::MasterStructure)
+        })?;
+        let mut text = String::new();
+        match self.format {
+            ExportFormat::Csv => {
+                if self.bom {
+                    text.push('\u{feff}');
+                }
+                for (index, column) in table.columns.iter().enumerate() {
+                    if index != 0 {
+                        text.push(',');
+                    }
+                    append_csv_field(&mut text, &column.header);
+                }
+                text.push_str("\r\n");
+                for row in &table.rows {
+                    for (index, value) in row.values.iter().enumerate() {
+                        if index != 0 {
+                            text.push(',');
+                        }
+                        append_csv_field(&mut text, value);
+                    }
+                    text.push_str("\r\n");
+                }
+            }
+            ExportFormat::Json => {
+                text.push_str("{\"columns\":[");
+                for (index, column) in table.columns.iter().enumerate() {
+                    if index != 0 {
+                        text.push(',');
+                    }
+                    text.push_str("{\"key\":");
+                    append_json_string(&mut text, column.key);
+                    text.push_str(",\"header\":");
+                    append_json_string(&mut text, &column.header);
+                    text.push('}');
+                }
+                text.push_str("],\"rows\":[");
+                for (index, row) in table.rows.iter().enumerate() {
+                    if index != 0 {
+                        text.push(',');
+                    }
+                    append_fmt(&mut text, format_args!("{{\"row\":{}", row.row));
+                    for (column, value) in table.columns.iter().zip(&row.values) {
+                        text.push(',');
+                        append_json_string(&mut text, column.key);
+                        text.push(':');
+                        if column.numeric && value.is_empty() {
+                            text.push_str("null");
+                        } else if column.numeric
+                            && JsonValue::try_from(value.as_str())
+                                .is_ok_and(|parsed| parsed.is_number())
+                        {
+                            text.push_str(value);
+                        } else {
+                            append_json_string(&mut text, value);
+                        }
+                    }
+                    text.push('}');
+                }
+                text.push_str("]}\n");
+            }
+            ExportFormat::Ods => {
+                return Err(err("--format ods는 --output 경로가 필요합니다."));
+            }
+        }
+        let Some(output_path) = self.output_path else {
+            self.out.write_all(text.as_bytes())?;
+            return Ok(());
+        };
+        let mut options = File::options();
+        options.write(true).create(true).truncate(true);
+        configure_no_follow(&mut options);
+        options
+            .open(output_path)
+            .and_then(|mut file| file.write_all(text.as_bytes()))
+            .map_err(|source| {
+                err_with_source(
+                    path_context_message("내보내기 파일 쓰기 실패", output_path),
+                    source,
+                )
+            })?;
+        write_line(
+            self.out,
+            format_args!(
+                "내보내기 완료: {} ({}행)",
+                terminal_safe(&output_path.display()),
+                table.rows.len()
+            ),
+        )?;
+        Ok(())
+    }
+}
+fn append_csv_field(out: &mut String, value: &str) {
+    if !value.contains([',', '"', '\r', '\n']) {
+        out.push_str(value);
+        return;
+    }
+    out.push('"');
+    for character in value.chars() {
+        if character == '"' {
+            out.push('"');
+        }
+        out.push(character);
+    }
+    out.push('"');
+}
@@ -0,0 +1,216 @@
+use crate::{
+    diagnostic::{AppError, Result, append_fmt, err, err_with_source, try_vec_with_capacity},
+    excel::reader::{Cell as XlsxCell, Workbook as XlsxWorkbook},
+    region::normalize_address_key_into,
+    sha256::Sha256,
+    temp_entry::open_regular,
+};
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    io::Read as _,
+    path::Path,
+};
+#[cfg(test)]
+mod tests;
+pub(super) const GEO_TABLE_SHEET_NAME: &str = "좌표";
+const GEO_TABLE_MAX_BYTES: u64 = 16 * 1024 * 1024;
+const MICRO_DEGREE_SCALE: i64 = 1_000_000;
+const HALF_TURN_MICRO_DEGREES: i128 = 180_000_000;
+const FULL_TURN_MICRO_DEGREES: i128 = 360_000_000;
+const EARTH_MEAN_RADIUS_METERS: i128 = 6_371_008;
+const PI_SCALED: i128 = 3_141_592_653_590;
+const TRIG_SCALE: i128 = 1_000_000_000_000;
+const COSINE_SERIES_TERMS: i128 = 8;
+const MILLIMETERS_PER_METER: u128 = 1000;
+const ARC_SCALE_PER_MILLIMETER: u128 = 1_000_000_000;
+#[derive(Clone, Copy)]
+pub(super) struct GeoPoint {
+    lat: i64,
+    lon: i64,
+}
+pub(super) struct GeoTable(HashMap<String, GeoPoint>);
+impl GeoPoint {
+    pub(super) fn distance_meters(self, other: Self) -> u64 {
+        let lat_delta = i128::from(other.lat).strict_sub(i128::from(self.lat));
+        let lon_delta = match i128::from(other.lon).strict_sub(i128::from(self.lon)) {
+            delta if delta > HALF_TURN_MICRO_DEGREES => delta.strict_sub(FULL_TURN_MICRO_DEGREES),
+            delta if delta < HALF_TURN_MICRO_DEGREES.strict_neg() => {
+                delta.strict_add(FULL_TURN_MICRO_DEGREES)
+            }
+            delta => delta,
+        };
+        let mean_lat = i128::from(self.lat)
+            .strict_add(i128::from(other.lat))
+            .div_euclid(2);
+        let angle = mean_lat
+            .strict_mul(PI_SCALED)
+            .div_euclid(HALF_TURN_MICRO_DEGREES);
+        let angle_squared = angle.strict_mul(angle).div_euclid(TRIG_SCALE);
+        let mut term = TRIG_SCALE;
+        let mut cosine = TRIG_SCALE;
+        for step in 1..COSINE_SERIES_TERMS {
+            let divisor = step
+                .strict_mul(2)
+                .strict_sub(1)
+                .strict_mul(step.strict_mul(2));
+            term = term
+                .strict_mul(angle_squared)
+                .div_euclid(TRIG_SCALE)
+                .div_euclid(divisor)
+                .strict_neg();
+            cosine = cosine.strict_add(term);
+        }
+        let arc_scaled = |delta: i128| {
+            delta
+                .unsigned_abs()
+                .strict_mul(PI_SCALED.unsigned_abs())
+                .strict_mul(EARTH_MEAN_RADIUS_METERS.unsigned_abs())
+                .div_euclid(HALF_TURN_MICRO_DEGREES.unsigned_abs())
+        };
+        let north_mm = arc_scaled(lat_delta).div_euclid(ARC_SCALE_PER_MILLIMETER);
+        let east_mm = arc_scaled(lon_delta)
+            .div_euclid(ARC_SCALE_PER_MILLIMETER)
+            .strict_mul(cosine.max(0).unsigned_abs())
+            .div_euclid(TRIG_SCALE.unsigned_abs());
+        let distance_mm = north_mm
+            .strict_mul(north_mm)
+            .strict_add(east_mm.strict_mul(east_mm))
+            .isqrt();
+        u64::try_from(
+            distance_mm
+                .strict_add(MILLIMETERS_PER_METER.div_euclid(2))
+                .div_euclid(MILLIMETERS_PER_METER),
+        )
+        .unwrap_or(u64::MAX)
+    }
+    pub(super) fn parse(lat_text: &str, lon_text: &str) -> Option<Self> {
+        let lat =
+            parse_micro_degrees(lat_text).filter(|value| value.unsigned_abs() <= 90_000_000)?;
+        let lon =
+            parse_micro_degrees(lon_text).filter(|value| value.unsigned_abs() <= 180_000_000)?;
+        Some(Self { lat, lon })
+    }
+}
+impl GeoTable {
+    pub(super) fn content_hash(&self) -> Result<String> {
+        let mut entries = try_vec_with_capacity(self.0.len(), "좌표표 해시 정렬 메모리 확보 실패")?;
+        entries.extend(self.0.iter());
+        entries.sort_unstable_by(|left, right| left.0.cmp(right.0));
+        let mut hasher = Sha256::default();
+        let mut line = String::new();
+        for (key, point) in entries {
+            line.clear();
+            append_fmt(
+                &mut line,
+                format_args!("{key}\t{}\t{}\n", point.lat, point.lon),
+            );
+            hasher.update(line.as_bytes());
+        }
+        Ok(hasher.finish_hex())
+    }
+    pub(super) fn get(&self, address_key: &str) -> Option<GeoPoint> {
+        self.0.get(address_key).copied()
+    }
+    fn insert(&mut self, raw_address: &str, raw_lat: &str, raw_lon: &str, line: u32) -> Result<()> {
+        let address = raw_address.trim().trim_matches('"').trim();
+        let (lat, lon) = (raw_lat.trim(), raw_lon.trim());
+        if address.is_empty() && lat.is_empty() && lon.is_empty() {
+            return Ok(());
+        }
+        let Some(point) = GeoPoint::parse(lat, lon) else {
+            if line == 1 {
+                return Ok(());
+            }
+            return Err(err(format!(
+                "좌표표의 위도/경도 값이 올바르지 않습니다: {line}행, 위도={lat}, 경도={lon}"
+            )));
+        };
+        if address.is_empty() {
+            return Err(err(format!("좌표표의 주소가 비어 있습니다: {line}행")));
+        }
+        let mut key = String::new();
+        normalize_address_key_into(address, &mut key)?;
+        match self.0.entry(key) {
+            Entry::Occupied(_) => Err(err(format!(
+                "좌표표에 같은 주소가 두 번 있습니다: {line}행, 주소={address}"
+            ))),
+            Entry::Vacant(entry) => {
+                entry.insert(point);
+                Ok(())
+            }
+        }
+    }
+}
+impl TryFrom<&Path> for GeoTable {
+    type Error = AppError;
+    fn try_from(path: &Path) -> Result<Self> {
+        let mut table = Self(HashMap::new());
+        let csv = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+        if !csv {
+            let sheet = XlsxWorkbook::open(path)
+                .and_then(|book| book.sheet(GEO_TABLE_SHEET_NAME))
+                .map_err(|source| source.0)?;
+            let mut previous_row = None;
+            for cell in sheet.cells() {
+                if previous_row == Some(cell.row) {
+                    continue;
+                }
+                previous_row = Some(cell.row);
+                let text = |col| {
+                    sheet
+                        .cell_at(cell.row, col)
+                        .map(XlsxCell::text)
+                        .unwrap_or_default()
+                };
+                table.insert(&text(1), &text(2), &text(3), cell.row)?;
+            }
+            return Ok(table);
+        }
+        let mut text = String::new();
+        open_regular(path, false)
+            .and_then(|opened| {
+                opened
+                    .file
+                    .take(GEO_TABLE_MAX_BYTES.strict_add(1))
+                    .read_to_string(&mut text)
+            })
+            .map_err(|source| err_with_source("좌표표 CSV 읽기 실패", source))?;
+        if u64::try_from(text.len()).is_ok_and(|len| len > GEO_TABLE_MAX_BYTES) {
+            return Err(err(format!(
+                "좌표표 CSV 크기가 허용 한도({GEO_TABLE_MAX_BYTES} bytes)를 초과했습니다."
+            )));
+        }
+        for (line, record) in (1_u32..).zip(text.trim_start_matches('\u{feff}').lines()) {
+            let mut fields = record.rsplitn(3, ',');
+            let lon = fields.next().unwrap_or_default();
+            let lat = fields.next().unwrap_or_default();
+            let address = fields.next().unwrap_or_default();
+            table.insert(address, lat, lon, line)?;
+        }
+        Ok(table)
+    }
+}
+fn parse_micro_degrees(text: &str) -> Option<i64> {
+    let (negative, digits) = text.strip_prefix('-').map_or_else(
+        || (false, text.strip_prefix('+').unwrap_or(text)),
+        |rest| (true, rest),
+    );
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if whole.is_empty()
+        || !whole
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|byte| byte.is_ascii_digit())
+    {
+        return None;
+    }
+    let mut value = whole.parse::<i64>().ok()?.checked_mul(MICRO_DEGREE_SCALE)?;
+    let mut place = MICRO_DEGREE_SCALE;
+    for digit in fraction.bytes().take(6) {
+        place = place.div_euclid(10);
+        value = value.checked_add(i64::from(digit.strict_sub(b'0')).checked_mul(place)?)?;
+    }
+    Some(if negative { value.strict_neg() } else { value })
+}
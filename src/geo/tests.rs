@@ -0,0 +1,106 @@
+use super::{GeoPoint, parse_micro_degrees};
+use crate::diagnostic::{Result, ensure, err};
+fn point(lat: &str, lon: &str) -> Result<GeoPoint> {
+    GeoPoint::parse(lat, lon).ok_or_else(|| err(format!("invalid coordinates: {lat}, {lon}")))
+}
+#[test]
+fn micro_degrees_are_parsed_and_truncated() -> Result<()> {
+    for (text, expected) in [
+        ("36.35", 36_350_000_i64),
+        ("127", 127_000_000_i64),
+        ("127.", 127_000_000_i64),
+        ("+127.1234567", 127_123_456_i64),
+        ("-0.5", -500_000_i64),
+        ("-180.000000", -180_000_000_i64),
+        ("0.000001", 1_i64),
+        ("0.0000009", 0_i64),
+    ] {
+        ensure(
+            parse_micro_degrees(text) == Some(expected),
+            format!("parsing {text}"),
+        )?;
+    }
+    Ok(())
+}
+#[test]
+fn malformed_micro_degrees_are_rejected() -> Result<()> {
+    for text in [
+        "",
+        "-",
+        ".5",
+        "1e3",
+        "--1",
+        "+-1",
+        "12a",
+        " 1",
+        "1.2.3",
+        "1,5",
+        "10000000000000",
+    ] {
+        ensure(
+            parse_micro_degrees(text).is_none(),
+            format!("parsing {text:?}"),
+        )?;
+    }
+    Ok(())
+}
+#[test]
+fn points_outside_the_globe_are_rejected() -> Result<()> {
+    ensure(
+        GeoPoint::parse("90.0000009", "-180").is_some(),
+        "bounds are inclusive after truncation",
+    )?;
+    for (lat, lon) in [
+        ("90.000001", "0"),
+        ("-90.1", "0"),
+        ("0", "180.000001"),
+        ("0", "-181"),
+    ] {
+        ensure(
+            GeoPoint::parse(lat, lon).is_none(),
+            format!("lat={lat}, lon={lon}"),
+        )?;
+    }
+    Ok(())
+}
+#[test]
+fn distances_follow_the_great_circle_within_a_tenth_of_a_percent() -> Result<()> {
+    for (from, to, great_circle) in [
+        (("0", "0"), ("1", "0"), 111_195_u64),
+        (("60", "0"), ("60", "1"), 55_597_u64),
+        (
+            ("36.815137", "127.113893"),
+            ("36.642434", "127.489032"),
+            38_555_u64,
+        ),
+        (
+            ("36.350412", "127.384548"),
+            ("36.480132", "127.289021"),
+            16_767_u64,
+        ),
+    ] {
+        let distance = point(from.0, from.1)?.distance_meters(point(to.0, to.1)?);
+        ensure(
+            distance.abs_diff(great_circle).saturating_mul(1_000_u64) <= great_circle,
+            format!("{from:?} -> {to:?}: {distance} m, expected about {great_circle} m"),
+        )?;
+        ensure(
+            point(to.0, to.1)?.distance_meters(point(from.0, from.1)?) == distance,
+            "distance is symmetric",
+        )?;
+    }
+    Ok(())
+}
+#[test]
+fn distances_handle_identical_points_and_the_antimeridian() -> Result<()> {
+    let station = point("36.642434", "127.489032")?;
+    ensure(station.distance_meters(station) == 0_u64, "same point")?;
+    ensure(
+        point("0", "179.9995")?.distance_meters(point("0", "-179.9995")?) == 111_u64,
+        "longitude wraps across the antimeridian",
+    )?;
+    ensure(
+        point("0", "0")?.distance_meters(point("0.001", "0")?) == 111_u64,
+        "a thousandth of a degree of latitude",
+    )
+}
@@ -0,0 +1,169 @@
+use crate::{
+    diagnostic::{Result, err_with_source, path_context_message},
+    excel::SourceRecord,
+    master_sheet::MasterSheetUpdateResult,
+    region::normalize_address_key_into,
+};
+use core::ffi::CStr;
+use sqlite::Connection;
+use std::{collections::HashMap, path::Path};
+mod sqlite;
+const SCHEMA_SQL: &CStr = c"
+PRAGMA foreign_keys = ON;
+CREATE TABLE IF NOT EXISTS runs (
+    id INTEGER PRIMARY KEY,
+    run_at TEXT NOT NULL,
+    run_date TEXT NOT NULL,
+    source_kind TEXT NOT NULL,
+    source_hash TEXT NOT NULL,
+    source_count INTEGER NOT NULL,
+    changed_count INTEGER NOT NULL,
+    added_count INTEGER NOT NULL,
+    deleted_count INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS stations (
+    id INTEGER PRIMARY KEY,
+    address_key TEXT NOT NULL UNIQUE,
+    address TEXT NOT NULL,
+    name TEXT NOT NULL,
+    brand TEXT NOT NULL,
+    region TEXT NOT NULL,
+    self_service TEXT NOT NULL,
+    first_run_id INTEGER REFERENCES runs (id),
+    last_run_id INTEGER REFERENCES runs (id)
+);
+CREATE TABLE IF NOT EXISTS prices (
+    run_id INTEGER NOT NULL REFERENCES runs (id),
+    station_id INTEGER NOT NULL REFERENCES stations (id),
+    fuel TEXT NOT NULL CHECK (fuel IN ('gasoline', 'premium', 'diesel')),
+    price INTEGER NOT NULL,
+    PRIMARY KEY (run_id, station_id, fuel)
+) WITHOUT ROWID;
+CREATE INDEX IF NOT EXISTS prices_station_fuel ON prices (station_id, fuel);
+CREATE TABLE IF NOT EXISTS station_events (
+    run_id INTEGER NOT NULL REFERENCES runs (id),
+    station_id INTEGER NOT NULL REFERENCES stations (id),
+    kind TEXT NOT NULL CHECK (kind IN ('changed', 'added', 'deleted')),
+    reason TEXT NOT NULL,
+    PRIMARY KEY (run_id, station_id, kind)
+) WITHOUT ROWID;
+";
+const INSERT_RUN_SQL: &CStr = c"
+INSERT INTO runs (
+    run_at, run_date, source_kind, source_hash, source_count,
+    changed_count, added_count, deleted_count
+) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+RETURNING id";
+const UPSERT_STATION_SQL: &CStr = c"
+INSERT INTO stations (
+    address_key, address, name, brand, region, self_service, first_run_id, last_run_id
+) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)
+ON CONFLICT (address_key) DO UPDATE SET
+    address = excluded.address,
+    name = excluded.name,
+    brand = excluded.brand,
+    region = excluded.region,
+    self_service = excluded.self_service,
+    first_run_id = coalesce(stations.first_run_id, excluded.first_run_id),
+    last_run_id = excluded.last_run_id
+RETURNING id";
+const INSERT_DELETED_STATION_SQL: &CStr = c"
+INSERT INTO stations (address_key, address, name, brand, region, self_service)
+VALUES (?1, ?2, ?3, '', ?4, '')
+ON CONFLICT (address_key) DO NOTHING";
+const INSERT_PRICE_SQL: &CStr = c"
+INSERT INTO prices (run_id, station_id, fuel, price) VALUES (?1, ?2, ?3, ?4)";
+const INSERT_EVENT_SQL: &CStr = c"
+INSERT INTO station_events (run_id, station_id, kind, reason)
+SELECT ?1, id, ?3, ?4 FROM stations WHERE address_key = ?2
+ON CONFLICT DO NOTHING";
+pub(super) struct RunHistory<'run> {
+    pub master_update: &'run MasterSheetUpdateResult<'run>,
+    pub run_at: &'run str,
+    pub run_date: &'run str,
+    pub source_hash: &'run str,
+    pub source_index: &'run HashMap<String, SourceRecord>,
+    pub source_kind: &'run str,
+}
+impl RunHistory<'_> {
+    fn insert_run(&self, connection: &Connection) -> Result<()> {
+        let count = |value: usize| {
+            i64::try_from(value).map_err(|source| err_with_source("이력 건수 변환 실패", source))
+        };
+        let mut insert_run = connection.prepare(INSERT_RUN_SQL)?;
+        insert_run.bind_text(1, self.run_at)?;
+        insert_run.bind_text(2, self.run_date)?;
+        insert_run.bind_text(3, self.source_kind)?;
+        insert_run.bind_text(4, self.source_hash)?;
+        insert_run.bind_i64(5, count(self.source_index.len())?)?;
+        insert_run.bind_i64(6, count(self.master_update.changes.len())?)?;
+        insert_run.bind_i64(7, count(self.master_update.added.len())?)?;
+        insert_run.bind_i64(8, count(self.master_update.deleted.len())?)?;
+        let run_id = insert_run.query_i64()?;
+        let mut upsert_station = connection.prepare(UPSERT_STATION_SQL)?;
+        let mut insert_price = connection.prepare(INSERT_PRICE_SQL)?;
+        insert_price.bind_i64(1, run_id)?;
+        upsert_station.bind_i64(7, run_id)?;
+        self.source_index
+            .iter()
+            .try_for_each(|(address_key, record)| -> Result<()> {
+                upsert_station.bind_text(1, address_key)?;
+                upsert_station.bind_text(2, &record.address)?;
+                upsert_station.bind_text(3, &record.name)?;
+                upsert_station.bind_text(4, &record.brand)?;
+                upsert_station.bind_text(5, record.region)?;
+                upsert_station.bind_text(6, record.service.label())?;
+                let station_id = upsert_station.query_i64()?;
+                insert_price.bind_i64(2, station_id)?;
+                for (fuel, price) in [
+                    ("gasoline", record.fuels.gasoline),
+                    ("premium", record.fuels.premium),
+                    ("diesel", record.fuels.diesel),
+                ] {
+                    if let Some(value) = price {
+                        insert_price.bind_text(3, fuel)?;
+                        insert_price.bind_i64(4, i64::from(value))?;
+                        insert_price.execute()?;
+                    }
+                }
+                Ok(())
+            })?;
+        let mut insert_deleted_station = connection.prepare(INSERT_DELETED_STATION_SQL)?;
+        let mut insert_event = connection.prepare(INSERT_EVENT_SQL)?;
+        insert_event.bind_i64(1, run_id)?;
+        let mut address_key = String::new();
+        let mut record_event = |address: &str, kind: &str, reason: &str| -> Result<()> {
+            normalize_address_key_into(address, &mut address_key)?;
+            insert_event.bind_text(2, &address_key)?;
+            insert_event.bind_text(3, kind)?;
+            insert_event.bind_text(4, reason)?;
+            insert_event.execute()
+        };
+        for change in &self.master_update.changes {
+            record_event(&change.record.address, "changed", &change.reason)?;
+        }
+        for record in &self.master_update.added {
+            record_event(&record.address, "added", "신규")?;
+        }
+        let mut deleted_key = String::new();
+        for store in &self.master_update.deleted {
+            normalize_address_key_into(&store.address, &mut deleted_key)?;
+            insert_deleted_station.bind_text(1, &deleted_key)?;
+            insert_deleted_station.bind_text(2, &store.address)?;
+            insert_deleted_station.bind_text(3, &store.name)?;
+            insert_deleted_station.bind_text(4, &store.region)?;
+            insert_deleted_station.execute()?;
+            record_event(&store.address, "deleted", "폐업")?;
+        }
+        Ok(())
+    }
+    pub(super) fn record(&self, path: &Path) -> Result<()> {
+        let connection = Connection::try_from(path)?;
+        connection.execute_batch(SCHEMA_SQL)?;
+        connection.execute_batch(c"BEGIN IMMEDIATE")?;
+        self.insert_run(&connection)?;
+        connection.execute_batch(c"COMMIT").map_err(|source| {
+            err_with_source(path_context_message("이력 DB 커밋 실패", path), source)
+        })
+    }
+}
@@ -0,0 +1,194 @@
+use crate::diagnostic::{AppError, Result, err, err_with_source, path_context_message};
+use alloc::ffi::CString;
+use core::{
+    ffi::{CStr, c_char, c_int, c_void},
+    marker::{PhantomData, PhantomPinned},
+    ptr::{self, NonNull, null, null_mut},
+};
+use std::path::Path;
+mod sys;
+const SQLITE_BUSY_TIMEOUT_MS: c_int = 5_000;
+const SQLITE_DONE: c_int = 101;
+const SQLITE_OK: c_int = 0;
+const SQLITE_OPEN_CREATE: c_int = 0x0000_0004;
+const SQLITE_OPEN_NOFOLLOW: c_int = 0x0100_0000;
+const SQLITE_OPEN_READWRITE: c_int = 0x0000_0002;
+const SQLITE_ROW: c_int = 100;
+#[repr(C)]
+struct Sqlite3 {
+    _data: (),
+    _marker: PhantomData<(*mut u8, PhantomPinned)>,
+}
+#[repr(C)]
+struct Sqlite3Stmt {
+    _data: (),
+    _marker: PhantomData<(*mut u8, PhantomPinned)>,
+}
+pub(super) struct Connection(NonNull<Sqlite3>);
+pub(super) struct Statement<'conn> {
+    connection: &'conn Connection,
+    stmt: NonNull<Sqlite3Stmt>,
+}
+impl Drop for Connection {
+    fn drop(&mut self) {
+        // SAFETY: self.0 is a connection opened by sqlite3_open_v2 and is closed exactly once here;
+        // close_v2 defers the close until every prepared statement has been finalized.
+        unsafe {
+            sys::sqlite3_close_v2(self.0.as_ptr());
+        }
+    }
+}
+impl Drop for Statement<'_> {
+    fn drop(&mut self) {
+        // SAFETY: self.stmt was prepared on self.connection and is finalized exactly once here.
+        unsafe {
+            sys::sqlite3_finalize(self.stmt.as_ptr());
+        }
+    }
+}
+impl Connection {
+    fn error(&self, context: &str, code: c_int) -> AppError {
+        // SAFETY: self.0 is an open connection; sqlite3_errmsg returns a NUL-terminated UTF-8
+        // message owned by the connection that stays valid until the next API call on it.
+        let message_ptr = unsafe { sys::sqlite3_errmsg(self.0.as_ptr()) };
+        // SAFETY: message_ptr is the NUL-terminated message returned just above.
+        let message = unsafe { CStr::from_ptr(message_ptr) }
+            .to_string_lossy()
+            .into_owned();
+        err(format!("SQLite {context} 실패 (code {code}): {message}"))
+    }
+    pub(super) fn execute_batch(&self, sql: &CStr) -> Result<()> {
+        // SAFETY: self.0 is an open connection, sql is NUL-terminated, and no callback or error
+        // message output is requested.
+        let code = unsafe {
+            sys::sqlite3_exec(
+                self.0.as_ptr(),
+                sql.as_ptr(),
+                null(),
+                null_mut(),
+                null_mut(),
+            )
+        };
+        if code == SQLITE_OK {
+            Ok(())
+        } else {
+            Err(self.error("SQL 실행", code))
+        }
+    }
+    pub(super) fn prepare(&self, sql: &CStr) -> Result<Statement<'_>> {
+        let mut stmt = null_mut::<Sqlite3Stmt>();
+        // SAFETY: self.0 is an open connection, sql is NUL-terminated (len -1 reads up to NUL), and
+        // stmt is a valid output pointer.
+        let code = unsafe {
+            sys::sqlite3_prepare_v2(self.0.as_ptr(), sql.as_ptr(), -1, &raw mut stmt, null_mut())
+        };
+        if code != SQLITE_OK {
+            return Err(self.error("SQL 준비", code));
+        }
+        let prepared =
+            NonNull::new(stmt).ok_or_else(|| err("SQLite SQL 준비 실패: 빈 SQL입니다"))?;
+        Ok(Statement {
+            connection: self,
+            stmt: prepared,
+        })
+    }
+}
+impl TryFrom<&Path> for Connection {
+    type Error = AppError;
+    fn try_from(path: &Path) -> Result<Self> {
+        let path_text = path.to_str().ok_or_else(|| {
+            err(path_context_message(
+                "이력 DB 경로가 UTF-8이 아닙니다",
+                path,
+            ))
+        })?;
+        let c_path = CString::new(path_text).map_err(|source| {
+            err_with_source(path_context_message("이력 DB 경로 변환 실패", path), source)
+        })?;
+        let mut db = null_mut::<Sqlite3>();
+        // SAFETY: c_path is NUL-terminated and db is a valid output pointer for the new handle.
+        let code = unsafe {
+            sys::sqlite3_open_v2(
+                c_path.as_ptr(),
+                &raw mut db,
+                SQLITE_OPEN_READWRITE | SQLITE_OPEN_CREATE | SQLITE_OPEN_NOFOLLOW,
+                null(),
+            )
+        };
+        let connection = Self(NonNull::new(db).ok_or_else(|| {
+            err(path_context_message(
+                "이력 DB 열기 실패: SQLite 메모리 확보 실패",
+                path,
+            ))
+        })?);
+        if code != SQLITE_OK {
+            return Err(connection.error("DB 열기", code));
+        }
+        // SAFETY: connection.0 is an open connection.
+        let timeout_code =
+            unsafe { sys::sqlite3_busy_timeout(connection.0.as_ptr(), SQLITE_BUSY_TIMEOUT_MS) };
+        if timeout_code != SQLITE_OK {
+            return Err(connection.error("busy timeout 설정", timeout_code));
+        }
+        Ok(connection)
+    }
+}
+impl Statement<'_> {
+    pub(super) fn bind_i64(&mut self, index: c_int, value: i64) -> Result<()> {
+        // SAFETY: self.stmt is a live prepared statement that is not currently stepping.
+        let code = unsafe { sys::sqlite3_bind_int64(self.stmt.as_ptr(), index, value) };
+        self.check(code, "값 바인딩")
+    }
+    pub(super) fn bind_text(&mut self, index: c_int, value: &str) -> Result<()> {
+        let len = c_int::try_from(value.len())
+            .map_err(|source| err_with_source("SQLite 값 바인딩 실패", source))?;
+        // SAFETY: value points to len initialized UTF-8 bytes, and SQLITE_TRANSIENT (-1) makes
+        // SQLite copy them before this call returns.
+        let code = unsafe {
+            sys::sqlite3_bind_text(
+                self.stmt.as_ptr(),
+                index,
+                value.as_ptr().cast::<c_char>(),
+                len,
+                ptr::without_provenance::<c_void>(usize::MAX),
+            )
+        };
+        self.check(code, "값 바인딩")
+    }
+    fn check(&self, code: c_int, context: &str) -> Result<()> {
+        if code == SQLITE_OK {
+            Ok(())
+        } else {
+            Err(self.connection.error(context, code))
+        }
+    }
+    pub(super) fn execute(&mut self) -> Result<()> {
+        // SAFETY: self.stmt is a live prepared statement with all parameters bound.
+        let code = unsafe { sys::sqlite3_step(self.stmt.as_ptr()) };
+        let step_result = if code == SQLITE_DONE {
+            Ok(())
+        } else {
+            Err(self.connection.error("SQL 실행", code))
+        };
+        let reset_result = self.reset();
+        step_result.and(reset_result)
+    }
+    pub(super) fn query_i64(&mut self) -> Result<i64> {
+        // SAFETY: self.stmt is a live prepared statement with all parameters bound.
+        let code = unsafe { sys::sqlite3_step(self.stmt.as_ptr()) };
+        let query_result = if code == SQLITE_ROW {
+            // SAFETY: the last step returned SQLITE_ROW, so column 0 of the current row is readable.
+            Ok(unsafe { sys::sqlite3_column_int64(self.stmt.as_ptr(), 0) })
+        } else {
+            Err(self.connection.error("SQL 조회", code))
+        };
+        let reset_result = self.reset();
+        let value = query_result?;
+        reset_result.map(|()| value)
+    }
+    fn reset(&mut self) -> Result<()> {
+        // SAFETY: self.stmt is a live prepared statement; resetting keeps its bindings.
+        let code = unsafe { sys::sqlite3_reset(self.stmt.as_ptr()) };
+        self.check(code, "SQL 초기화")
+    }
+}
@@ -0,0 +1,39 @@
+use super::{Sqlite3, Sqlite3Stmt, c_char, c_int, c_void};
+#[link(name = "sqlite3")]
+unsafe extern "C" {
+    pub(super) fn sqlite3_bind_int64(stmt: *mut Sqlite3Stmt, index: c_int, value: i64) -> c_int;
+    pub(super) fn sqlite3_bind_text(
+        stmt: *mut Sqlite3Stmt,
+        index: c_int,
+        value: *const c_char,
+        len: c_int,
+        destructor: *const c_void,
+    ) -> c_int;
+    pub(super) fn sqlite3_busy_timeout(db: *mut Sqlite3, millis: c_int) -> c_int;
+    pub(super) fn sqlite3_close_v2(db: *mut Sqlite3) -> c_int;
+    pub(super) fn sqlite3_column_int64(stmt: *mut Sqlite3Stmt, column: c_int) -> i64;
+    pub(super) fn sqlite3_errmsg(db: *mut Sqlite3) -> *const c_char;
+    pub(super) fn sqlite3_exec(
+        db: *mut Sqlite3,
+        sql: *const c_char,
+        callback: *const c_void,
+        callback_arg: *mut c_void,
+        errmsg: *mut *mut c_char,
+    ) -> c_int;
+    pub(super) fn sqlite3_finalize(stmt: *mut Sqlite3Stmt) -> c_int;
+    pub(super) fn sqlite3_open_v2(
+        filename: *const c_char,
+        db: *mut *mut Sqlite3,
+        flags: c_int,
+        vfs: *const c_char,
+    ) -> c_int;
+    pub(super) fn sqlite3_prepare_v2(
+        db: *mut Sqlite3,
+        sql: *const c_char,
+        len: c_int,
+        stmt: *mut *mut Sqlite3Stmt,
+        tail: *mut *const c_char,
+    ) -> c_int;
+    pub(super) fn sqlite3_reset(stmt: *mut Sqlite3Stmt) -> c_int;
+    pub(super) fn sqlite3_step(stmt: *mut Sqlite3Stmt) -> c_int;
+}
@@ -0,0 +1,2135 @@
+use crate::{HELP_TEXT, HELP_TEXT_EN};
+use alloc::borrow::Cow;
+use core::{mem, str};
+use std::{
+    env,
+    io::{self, Stdout, Write, stdout},
+};
+#[cfg(test)]
+mod tests;
+const CATALOG: &[(&str, &str)] = &[
+    ("(빈 값)", "(empty)"),
+    ("소스 파일", "source file"),
+    ("Opinet 자동 다운로드", "Opinet automatic download"),
+    ("I/O 오류", "I/O error"),
+    ("백업 폴더 경로가", "a backup directory path"),
+    ("소스 파일 경로가", "a source file path"),
+    ("이력 DB 경로가", "a history DB path"),
+    ("ODS 파일 경로가", "an ODS file path"),
+    ("웹훅 URL이", "a webhook URL"),
+    ("지도 URL 템플릿이", "a map URL template"),
+    ("좌표표 경로가", "a geocoding table path"),
+    ("price 또는 detour가", "price or detour"),
+    ("지역별 표시 건수가", "a per-region count"),
+    (
+        "\"주소:포트\" 형식의 수신 주소가",
+        "a listen address in \"host:port\" form",
+    ),
+    ("human 또는 json이", "human or json"),
+    ("csv, json 또는 ods가", "csv, json or ods"),
+    ("en 또는 ko가", "en or ko"),
+    ("출력 파일 경로가", "an output file path"),
+    (
+        "\"HH:MM,HH:MM KST\" 형식의 시각이",
+        "times in \"HH:MM,HH:MM KST\" form",
+    ),
+    ("상태 파일 경로가", "a status file path"),
+    ("보관할 백업 개수가", "a backup count"),
+    ("{} 뒤에 {} 필요합니다", "{} requires {msg}"),
+    ("알 수 없는 옵션: {}", "Unknown option: {}"),
+    (
+        "알 수 없는 --version 옵션: {}",
+        "Unknown --version option: {}",
+    ),
+    ("알 수 없는 {} 값: {}", "Unknown {} value: {}"),
+    (
+        "--source-api와 --source-file은 함께 사용할 수 없습니다.",
+        "--source-api and --source-file cannot be used together.",
+    ),
+    (
+        "-v와 -q는 함께 사용할 수 없습니다.",
+        "-v and -q cannot be used together.",
+    ),
+    ("로그 파일 경로가", "a log file path"),
+    ("로그 파일 열기 실패: {}", "Failed to open the log file: {}"),
+    (
+        "--history는 sqlite 기능을 포함해 빌드한 경우에만 사용할 수 있습니다.",
+        "--history is only available in builds with the sqlite feature.",
+    ),
+    (
+        "--webhook-top은 --webhook과 함께 사용해야 합니다.",
+        "--webhook-top must be used together with --webhook.",
+    ),
+    (
+        "--source-api 사용 시 {} 환경 변수가 필요합니다",
+        "--source-api requires the {} environment variable",
+    ),
+    (
+        "--webhook 값은 UTF-8 문자열이어야 합니다: {}",
+        "The --webhook value must be UTF-8: {}",
+    ),
+    (
+        "--map-url 값은 UTF-8 문자열이어야 합니다: {}",
+        "The --map-url value must be UTF-8: {}",
+    ),
+    (
+        "지도 URL 템플릿은 http(s):// 주소이고 {address} 또는 {name}만 자리표시자로 포함해야 합니다: {}",
+        "The map URL template must be an http(s):// address using only {address} or {name} as placeholders: {}",
+    ),
+    (
+        "--rank-by detour는 --geo-table과 함께 사용해야 합니다.",
+        "--rank-by detour must be used together with --geo-table.",
+    ),
+    (
+        "--listen 값은 UTF-8 문자열이어야 합니다: {}",
+        "The --listen value must be UTF-8: {}",
+    ),
+    (
+        "--schedule 시간대는 {}만 지원합니다: {}",
+        "--schedule only supports the {} time zone: {}",
+    ),
+    (
+        "--schedule 시각은 HH:MM 형식이어야 합니다: {}",
+        "--schedule times must be in HH:MM form: {}",
+    ),
+    (
+        "diff 명령에는 기존/비교 xlsx 경로 두 개가 필요합니다",
+        "The diff command requires two xlsx paths (old and new)",
+    ),
+    (
+        "--bom은 CSV 형식에서만 사용할 수 있습니다.",
+        "--bom is only available with the CSV format.",
+    ),
+    (
+        "--format ods는 --output으로 저장할 파일 경로가 필요합니다.",
+        "--format ods requires a file path given with --output.",
+    ),
+    (
+        "--format ods는 --output 경로가 필요합니다.",
+        "--format ods requires an --output path.",
+    ),
+    (
+        "복원할 백업 시각은 UTF-8 문자열이어야 합니다: {}",
+        "The backup timestamp to restore must be UTF-8: {}",
+    ),
+    (
+        "restore 명령에는 --list 또는 복원할 백업 시각 하나가 필요합니다",
+        "The restore command requires --list or a single backup timestamp to restore",
+    ),
+    (
+        "serve 명령에는 --schedule 실행 시각이 필요합니다",
+        "The serve command requires --schedule run times",
+    ),
+    (
+        "실행 잠금 파일 열기 실패",
+        "Failed to open the run lock file",
+    ),
+    (
+        "실행 잠금 파일 검증 실패",
+        "Failed to validate the run lock file",
+    ),
+    (
+        "실행 잠금 파일은 group/other 쓰기 권한이 없어야 합니다.",
+        "The run lock file must not be writable by group or others.",
+    ),
+    (
+        "다른 fcupdater 실행이 진행 중입니다.",
+        "Another fcupdater run is in progress.",
+    ),
+    ("실행 잠금 획득 실패", "Failed to acquire the run lock"),
+    ("현재 시간 조회 실패", "Failed to read the current time"),
+    (
+        "KST 날짜 초 계산 중 범위 오류가 발생했습니다.",
+        "KST date calculation went out of range.",
+    ),
+    (
+        "리파스 포인트는 허용되지 않습니다.",
+        "Reparse points are not allowed.",
+    ),
+    (
+        "경로는 일반 파일이어야 합니다.",
+        "The path must be a regular file.",
+    ),
+    (
+        "파일의 하드 링크 수는 1이어야 합니다.",
+        "The file must have exactly one hard link.",
+    ),
+    (
+        "Opinet 소스에서 대상 지역 레코드를 찾지 못했습니다.",
+        "No target-region records were found in the Opinet source.",
+    ),
+    (
+        "Opinet 소스에서 대상 지역 레코드를 찾지 못했습니다: {}",
+        "No records were found in the Opinet source for target region: {}",
+    ),
+    (
+        "Opinet 소스의 대상 지역 {} 값이 비정상적으로 부족합니다: {}건 / {}건",
+        "Too few {} values for the target regions in the Opinet source: {} of {}",
+    ),
+    (
+        "Opinet 소스의 대상 지역에서 유효한 고급휘발유 가격을 찾지 못했습니다.",
+        "No valid premium gasoline price was found for the target regions in the Opinet source.",
+    ),
+    (
+        "Opinet 소스 주소 중복: address={}, existing={}, incoming={}",
+        "Duplicate address in the Opinet source: address={}, existing={}, incoming={}",
+    ),
+    (
+        "Opinet Open API 데이터 준비 완료",
+        "Opinet Open API data ready",
+    ),
+    (
+        "Opinet Open API 응답 읽기 실패",
+        "Failed to read the Opinet Open API response",
+    ),
+    (
+        "소스 파일 열기 실패: {}",
+        "Failed to open the source file: {}",
+    ),
+    (
+        "소스 파일 정보 확인 실패: {}",
+        "Failed to read the source file metadata: {}",
+    ),
+    (
+        "소스 파일 크기가 허용 한도({} bytes)를 초과했습니다: {}",
+        "The source file exceeds the size limit ({} bytes): {}",
+    ),
+    ("소스 파일 읽기 실패", "Failed to read the source file"),
+    (
+        "소스 파일 읽기 실패: {}",
+        "Failed to read the source file: {}",
+    ),
+    ("소스 파일 준비 완료: {}", "Source file ready: {}"),
+    (
+        "좌표표 읽기 실패: {}",
+        "Failed to read the geocoding table: {}",
+    ),
+    (
+        "좌표표 CSV 읽기 실패",
+        "Failed to read the geocoding table CSV",
+    ),
+    (
+        "좌표표 CSV 크기가 허용 한도({} bytes)를 초과했습니다.",
+        "The geocoding table CSV exceeds the size limit ({} bytes).",
+    ),
+    (
+        "좌표표의 위도/경도 값이 올바르지 않습니다: {}행, 위도={}, 경도={}",
+        "Invalid latitude/longitude in the geocoding table: row {}, latitude={}, longitude={}",
+    ),
+    (
+        "좌표표의 주소가 비어 있습니다: {}행",
+        "Empty address in the geocoding table: row {}",
+    ),
+    (
+        "좌표표에 같은 주소가 두 번 있습니다: {}행, 주소={}",
+        "Duplicate address in the geocoding table: row {}, address={}",
+    ),
+    (
+        "출발지 입력에는 이름, 위도, 경도가 모두 필요합니다: row={}, 이름={}, 위도={}, 경도={}",
+        "Each depot needs a name, latitude and longitude: row={}, name={}, latitude={}, longitude={}",
+    ),
+    (
+        "우회 비용 순위에는 출발지 좌표(F4:H12)와 좌표표(--geo-table)가 필요합니다.",
+        "Detour ranking requires depot coordinates (F4:H12) and a geocoding table (--geo-table).",
+    ),
+    (
+        "출발지 좌표를 쓰려면 G13에 연비(km/L)를 0보다 크게 입력해야 합니다.",
+        "Depot coordinates require a fuel efficiency (km/L) greater than 0 in G13.",
+    ),
+    ("Opinet 소스 데이터 준비 완료", "Opinet source data ready"),
+    (
+        "Opinet 소스 xls 읽기 실패",
+        "Failed to read the Opinet source xls",
+    ),
+    ("마스터 파일 처리 중...", "Processing the master file..."),
+    (
+        "마스터 xlsx 파일 열기 실패: {}",
+        "Failed to open the master xlsx file: {}",
+    ),
+    ("대상 지역별 건수 확인:", "Record counts by target region:"),
+    (
+        "  {}: 기존 {}건 / 기존 주소 일치 {}건 / 소스 {}건",
+        "  {}: existing {} / existing addresses matched {} / source {}",
+    ),
+    (
+        "대상 지역의 기존 주소 일치 건수가 비정상적으로 적어 저장을 중단합니다: {} 기존 {}건 / 기존 주소 일치 {}건 / 소스 {}건",
+        "Too few existing addresses matched in a target region; not saving: {} existing {} / existing addresses matched {} / source {}",
+    ),
+    (
+        "현행화 대상 레코드를 찾지 못했습니다.",
+        "No records to update were found.",
+    ),
+    (
+        "폐업 처리 건수가 비정상적으로 많아 저장을 중단합니다: {}건 / {}건",
+        "Too many stations would be removed as closed; not saving: {} of {}",
+    ),
+    ("  ... ({}개 중 20개만 표시)", "  ... (showing 20 of {})"),
+    ("==== 현행화 요약 ====", "==== Update summary ===="),
+    ("- 파일: {}", "- File: {}"),
+    ("- 소스: {}", "- Source: {msg}"),
+    ("- 소스 해시: {}", "- Source hash: {}"),
+    ("- 기존 업체 변경: {}건", "- Existing stations changed: {}"),
+    ("- 신규 업체 추가: {}건", "- New stations added: {}"),
+    ("- 폐업 업체 삭제: {}건", "- Closed stations removed: {}"),
+    ("- 저장 검증: 사용", "- Save verification: enabled"),
+    ("- 저장 검증: 생략", "- Save verification: skipped"),
+    (
+        "신규 업체 추가 목록 (상위 20개)",
+        "New stations added (first 20)",
+    ),
+    (
+        "폐업 업체 삭제 목록 (상위 20개)",
+        "Closed stations removed (first 20)",
+    ),
+    ("웹훅 전송 완료", "Webhook sent"),
+    ("웹훅 전송 실패: {}", "Webhook delivery failed: {msg}"),
+    (
+        "소스 변경 없음: 워크북에 기록된 소스 해시와 현행화 설정이 같습니다 ({}). 파일을 수정하지 않고 종료합니다.",
+        "Source unchanged: the source hash and update settings match those recorded in the workbook ({}). Exiting without modifying the file.",
+    ),
+    (
+        "마스터 파일은 저장됐지만 ODS 저장에 실패했습니다: {}",
+        "The master file was saved, but saving the ODS failed: {}",
+    ),
+    ("ODS 저장 완료: {}", "ODS saved: {}"),
+    (
+        "마스터 파일은 저장됐지만 이력 DB 기록에 실패했습니다: {}",
+        "The master file was saved, but recording the history DB failed: {}",
+    ),
+    ("이력 DB 기록 완료: {}", "History DB recorded: {}"),
+    ("이력 DB 커밋 실패", "Failed to commit the history DB"),
+    ("마스터 파일 저장 중...", "Saving the master file..."),
+    (
+        "저장된 워크북의 소스 해시가 현행화 소스와 다릅니다.",
+        "The saved workbook's source hash differs from the update source.",
+    ),
+    (
+        "저장된 워크북의 설정 해시가 현행화 설정과 다릅니다.",
+        "The saved workbook's settings hash differs from the update settings.",
+    ),
+    (
+        "마스터 파일은 저장됐지만 실행 요약 출력에 실패했습니다.",
+        "The master file was saved, but printing the run summary failed.",
+    ),
+    ("이전 버전 백업: {}", "Previous version backed up: {}"),
+    (
+        "바뀐 업체가 없어 이전 버전 백업을 생략합니다.",
+        "No station changed; skipping the previous version backup.",
+    ),
+    (
+        "마스터 파일은 저장됐지만 오래된 백업 정리에 실패했습니다",
+        "The master file was saved, but pruning old backups failed",
+    ),
+    (
+        "보관 개수({})를 넘은 오래된 백업 {}건 삭제",
+        "Retention count ({}) exceeded: deleted {} old backups",
+    ),
+    (
+        "예약 실행 모드 시작: 매일 {} KST (상태 파일: {})",
+        "Scheduled mode started: daily at {} KST (status file: {})",
+    ),
+    (
+        "상태 파일 쓰기 실패: {}",
+        "Failed to write the status file: {}",
+    ),
+    (
+        "상태 파일을 갱신하지 못했지만 예약 실행은 계속합니다: {}",
+        "Could not update the status file; the schedule keeps running: {msg}",
+    ),
+    (
+        "상태 파일 읽기 실패: {}",
+        "Failed to read the status file: {}",
+    ),
+    ("상태 파일 형식 오류: {}", "Invalid status file: {}"),
+    ("다음 실행 예정: {}", "Next run: {}"),
+    ("==== 예약 실행: {} ====", "==== Scheduled run: {} ===="),
+    ("예약 실행 실패: {}", "Scheduled run failed: {msg}"),
+    ("백업이 없습니다: {}", "No backups: {}"),
+    ("백업 {}건 (최신순, {}):", "{} backups (newest first, {}):"),
+    (
+        "복원할 백업을 찾지 못했습니다: {} (restore --list로 목록 확인)",
+        "Backup to restore not found: {} (see restore --list)",
+    ),
+    (
+        "같은 시각의 백업이 {}건 있습니다. 파일 이름으로 지정하세요: {}",
+        "{} backups share that timestamp. Specify one by file name: {}",
+    ),
+    (
+        "백업 워크북 검증 실패: {}",
+        "Backup workbook validation failed: {}",
+    ),
+    ("복원 완료: {} -> {}", "Restored: {} -> {}"),
+    (
+        "복원 전 마스터 백업: {}",
+        "Master before restore backed up: {}",
+    ),
+    (
+        "마스터 파일은 복원됐지만 오래된 백업 정리에 실패했습니다",
+        "The master file was restored, but pruning old backups failed",
+    ),
+    (
+        "백업 폴더 읽기 실패: {}",
+        "Failed to read the backup directory: {}",
+    ),
+    (
+        "백업 폴더 생성 실패: {}",
+        "Failed to create the backup directory: {}",
+    ),
+    (
+        "오래된 백업 삭제 실패: {}",
+        "Failed to delete an old backup: {}",
+    ),
+    (
+        "백업 파일 확인 실패: {}",
+        "Failed to check the backup file: {}",
+    ),
+    (
+        "같은 시각의 백업 파일 이름이 너무 많습니다: {}",
+        "Too many backup file names for the same timestamp: {}",
+    ),
+    (
+        "내보낼 워크북 읽기 실패: {}",
+        "Failed to read the workbook to export: {}",
+    ),
+    ("ODS 내보내기 실패: {}", "ODS export failed: {}"),
+    (
+        "내보내기 파일 쓰기 실패: {}",
+        "Failed to write the export file: {}",
+    ),
+    ("내보내기 완료: {} (ODS)", "Exported: {} (ODS)"),
+    ("내보내기 완료: {} ({}행)", "Exported: {} ({} rows)"),
+    (
+        "API 서버 주소 바인딩 실패: {}",
+        "Failed to bind the API server address: {}",
+    ),
+    (
+        "API 서버 주소 조회 실패",
+        "Failed to read the API server address",
+    ),
+    (
+        "API 서버 시작: http://{} (워크북: {}, 업체 {}건)",
+        "API server started: http://{} (workbook: {}, {} stations)",
+    ),
+    (
+        "API 연결 수락 실패: {}",
+        "Failed to accept an API connection: {msg}",
+    ),
+    (
+        "워크북 교체 감지: 다시 읽었습니다 (업체 {}건)",
+        "Workbook replaced: reloaded ({} stations)",
+    ),
+    (
+        "워크북 다시 읽기 실패, 이전 데이터로 응답합니다: {}",
+        "Workbook reload failed; serving the previous data: {msg}",
+    ),
+    (
+        "워크북 확인 실패, 이전 데이터로 응답합니다: {}",
+        "Workbook check failed; serving the previous data: {msg}",
+    ),
+    (
+        "API 요청 처리 실패: {}",
+        "Failed to handle an API request: {msg}",
+    ),
+    (
+        "요청 처리 제한 시간을 넘겼습니다.",
+        "The request exceeded its time limit.",
+    ),
+    ("==== 워크북 비교 ====", "==== Workbook comparison ===="),
+    (
+        "- 기존 파일: {} (업체 {}건)",
+        "- Old file: {} ({} stations)",
+    ),
+    (
+        "- 비교 파일: {} (업체 {}건)",
+        "- New file: {} ({} stations)",
+    ),
+    (
+        "- 주소 없는 행(비교 제외): 기존 {}건 / 비교 {}건",
+        "- Rows without an address (not compared): old {} / new {}",
+    ),
+    ("- 정보 변경: {}건", "- Stations changed: {}"),
+    ("- 신규 업체: {}건", "- Stations added: {}"),
+    ("- 삭제 업체: {}건", "- Stations removed: {}"),
+    ("차이가 없습니다.", "No differences."),
+    ("정보 변경 목록", "Changed stations"),
+    ("     {}: (빈 값) -> {}", "     {}: (empty) -> {}"),
+    ("     {}: {} -> (빈 값)", "     {}: {} -> (empty)"),
+    ("     {}: {} -> {}", "     {}: {} -> {}"),
+    ("신규 업체 목록", "Added stations"),
+    ("삭제 업체 목록", "Removed stations"),
+    (
+        "  {}. {} / {} / {} (행 {} -> {})",
+        "  {}. {} / {} / {} (row {} -> {})",
+    ),
+    (
+        "  {}. {} / {} / {} / 휘발유 {} / 고급휘발유 {} / 경유 {}",
+        "  {}. {} / {} / {} / 휘발유 {} / 고급휘발유 {} / 경유 {}",
+    ),
+    (
+        "비교 대상 워크북 읽기 실패: {}",
+        "Failed to read a workbook to compare: {}",
+    ),
+    (
+        "기존 워크북 주소 중복: normalized_address={}, first_row={}, duplicate_row={}",
+        "Duplicate address in the old workbook: normalized_address={}, first_row={}, duplicate_row={}",
+    ),
+    (
+        "비교 워크북 주소 중복: normalized_address={}, first_row={}, duplicate_row={}",
+        "Duplicate address in the new workbook: normalized_address={}, first_row={}, duplicate_row={}",
+    ),
+    ("웹훅 POST 요청 실패", "Webhook POST request failed"),
+    ("웹훅 URL 확인 실패", "Invalid webhook URL"),
+    (
+        "Opinet 자동 다운로드 실패: {}",
+        "Opinet automatic download failed: {msg}",
+    ),
+    (
+        "Opinet Open API 조회 실패: {}",
+        "Opinet Open API request failed: {msg}",
+    ),
+    (
+        "{} 시군 코드 조회 실패",
+        "Request for the {} district codes failed",
+    ),
+    ("{} 조회 실패", "Request for {msg} failed"),
+    ("{} {} 최저가 목록", "{} {} lowest-price list"),
+    (
+        "주유소 상세 조회 실패: {}",
+        "Station detail request failed: {}",
+    ),
+    (
+        "Open API {} 응답에 RESULT.OIL 배열이 없습니다.",
+        "The Open API {msg} response has no RESULT.OIL array.",
+    ),
+    (
+        "Open API {} 응답 JSON 해석 실패",
+        "Failed to parse the Open API {msg} response JSON",
+    ),
+    (
+        "Open API {} 응답 UTF-8 변환 실패",
+        "Failed to decode the Open API {msg} response as UTF-8",
+    ),
+    (
+        "Open API {} 응답의 AREA_CD 값이 올바르지 않습니다: {}",
+        "Invalid AREA_CD in the Open API {msg} response: {}",
+    ),
+    (
+        "Open API {} 응답에 UNI_ID 값이 비어 있습니다.",
+        "Empty UNI_ID in the Open API {msg} response.",
+    ),
+    ("{} 시군 코드", "{} district codes"),
+    ("주유소 상세", "station detail"),
+    (
+        "Open API 시군 {}곳, 주유소 상세 {}건 조회",
+        "Open API: {} districts, {} station details requested",
+    ),
+    (
+        "Open API 조회 결과 중 기존 주소와 일치하는 업체가 비정상적으로 적어 저장을 중단합니다: {} 조회 {}건 / 기존 주소 일치 {}건",
+        "Stopping before save: too few Open API results match existing addresses: {} fetched {} / matched {}",
+    ),
+    (
+        "Open API 조회 결과 중 마스터에 없는 업체 {}건은 추가하지 않습니다.",
+        "Not adding {} Open API stations missing from the master.",
+    ),
+    (
+        "좌표표 해시 정렬 메모리 확보 실패",
+        "Failed to reserve memory for sorting the coordinate table hash",
+    ),
+    (
+        "마스터 주소 맵 메모리 확보 실패",
+        "Failed to reserve memory for the master address map",
+    ),
+    (
+        "가격 갱신 대상 맵 메모리 확보 실패",
+        "Failed to reserve memory for the price update map",
+    ),
+    (
+        "Open API 조회 결과에 없는 기존 업체 {}건은 그대로 둡니다.",
+        "Leaving {} existing stations missing from the Open API results unchanged.",
+    ),
+    (
+        "URL은 http:// 또는 https://로 시작해야 합니다: {}",
+        "The URL must start with http:// or https://: {}",
+    ),
+    (
+        "URL 형식이 올바르지 않습니다: {}",
+        "The URL is malformed: {}",
+    ),
+    (
+        "URL 포트가 올바르지 않습니다: {}",
+        "The URL port is invalid: {}",
+    ),
+    (
+        "URL 호스트가 비어 있습니다: {}",
+        "The URL host is empty: {}",
+    ),
+    (
+        "Open API key가 비어 있습니다.",
+        "The Open API key is empty.",
+    ),
+    ("NetFunnel IP 차단: {}", "NetFunnel blocked this IP: {}"),
+    ("NetFunnel 응답 오류: {}", "NetFunnel response error: {}"),
+    (
+        "NetFunnel 대기 횟수를 초과했습니다.",
+        "Exceeded the NetFunnel wait limit.",
+    ),
+    (
+        "Opinet 다운로드 페이지에서 key 할당 구문을 찾지 못했습니다.",
+        "Could not find the key assignment on the Opinet download page.",
+    ),
+    (
+        "다운로드 응답이 예상한 OLE2 .xls 파일이 아닙니다: {}",
+        "The downloaded response is not the expected OLE2 .xls file: {}",
+    ),
+    ("curl_easy_init 실패", "curl_easy_init failed"),
+    (
+        "curl_easy_perform 실패: {} ({})",
+        "curl_easy_perform failed: {} ({})",
+    ),
+    (
+        "curl 최종 scheme이 HTTPS가 아닙니다: {}",
+        "The final curl scheme is not HTTPS: {}",
+    ),
+    (
+        "libcurl {}은 HTTPS protocol 제한 최신 API를 지원하지 않습니다. libcurl 7.85.0 이상이 필요합니다.",
+        "libcurl {} does not support the API for restricting protocols to HTTPS. libcurl 7.85.0 or later is required.",
+    ),
+    ("{} 주소 해석 실패", "Failed to resolve the {} address"),
+    (
+        "{} 주소 해석 결과가 비어 있습니다.",
+        "Resolving the {} address returned no results.",
+    ),
+    ("{} TCP 연결 실패", "TCP connection to {} failed"),
+    (
+        "rustls TLS 설정 생성 실패",
+        "Failed to create the rustls TLS configuration",
+    ),
+    (
+        "rustls TLS 연결 생성 실패",
+        "Failed to create the rustls TLS connection",
+    ),
+    (
+        "TLS 서버 이름 해석 실패",
+        "Failed to parse the TLS server name",
+    ),
+    ("HTTP 요청 전송 실패", "Failed to send the HTTP request"),
+    (
+        "HTTP 응답 header 수신 실패",
+        "Failed to receive the HTTP response headers",
+    ),
+    (
+        "HTTP 응답 본문 수신 실패",
+        "Failed to receive the HTTP response body",
+    ),
+    (
+        "HTTP 응답 본문 크기가 허용 한도({} bytes)를 초과했습니다.",
+        "The HTTP response body exceeds the size limit ({} bytes).",
+    ),
+    (
+        "HTTP Content-Length가 허용 한도({} bytes)를 초과했습니다.",
+        "HTTP Content-Length exceeds the size limit ({} bytes).",
+    ),
+    (
+        "Opinet 소스 헤더 행을 찾지 못했습니다.",
+        "The Opinet source header row was not found.",
+    ),
+    (
+        "Opinet 소스 헤더 행을 찾지 못했습니다. ({}행까지 확인)",
+        "The Opinet source header row was not found. (checked up to row {})",
+    ),
+    (
+        "Opinet 소스 헤더에 필수 열이 없습니다: row={}, missing={}",
+        "The Opinet source header is missing required columns: row={}, missing={}",
+    ),
+    (
+        "Opinet 소스 헤더에 같은 열이 여러 번 있습니다: row={}, header={}",
+        "The Opinet source header repeats a column: row={}, header={}",
+    ),
+    (
+        "{} 시트에서 유효한 소스 데이터를 찾지 못했습니다.",
+        "No valid source data was found in the {} sheet.",
+    ),
+    (
+        "유효한 OLE2(CFB) Opinet xls 응답이 아닙니다.",
+        "Not a valid OLE2 (CFB) Opinet xls response.",
+    ),
+    (
+        "CSV 소스를 UTF-8 또는 CP949로 해석하지 못했습니다: offset={}",
+        "Could not decode the CSV source as UTF-8 or CP949: offset={}",
+    ),
+    (
+        "Opinet 소스 {}행에 주소 없이 데이터가 존재합니다.",
+        "Opinet source row {} has data but no address.",
+    ),
+    (
+        "Opinet 소스 {}행 상호명 값이 비어 있습니다.",
+        "Opinet source row {} has an empty station name.",
+    ),
+    (
+        "Opinet 소스 {}행 셀프 여부 값이 올바르지 않습니다: {}",
+        "Opinet source row {} has an invalid self-service value: {}",
+    ),
+    (
+        "Opinet 소스 {}행 {} 가격은 음수일 수 없습니다: {}",
+        "Opinet source row {}: the {} price cannot be negative: {}",
+    ),
+    (
+        "Opinet 소스 {}행 {} 가격 형식이 올바르지 않습니다: {}",
+        "Opinet source row {}: the {} price is malformed: {}",
+    ),
+    (
+        "Opinet 소스 {}행 {} 가격이 허용 범위({}~{})를 벗어났습니다: {}",
+        "Opinet source row {}: the {} price is outside the allowed range ({}-{}): {}",
+    ),
+    (
+        "Opinet 소스의 지역 값과 주소가 서로 다른 대상 지역을 가리킵니다: region={}, address={}",
+        "The Opinet source region and address point to different target regions: region={}, address={}",
+    ),
+    (
+        "Opinet 소스의 지역 값은 대상 지역이지만 주소는 대상 지역이 아닙니다: region={}, address={}",
+        "The Opinet source region is a target region but the address is not: region={}, address={}",
+    ),
+    (
+        "Opinet 소스의 주소는 대상 지역이지만 지역 값이 예상 형식과 다릅니다: region={}, address={}",
+        "The Opinet source address is in a target region but the region value has an unexpected form: region={}, address={}",
+    ),
+    (
+        "마스터 주소 중복: normalized_address={}, first_row={}, duplicate_row={}",
+        "Duplicate address in the master: normalized_address={}, first_row={}, duplicate_row={}",
+    ),
+    ("xlsx 파일 열기 실패", "Failed to open the xlsx file"),
+    ("단계 시작: {}", "Stage started: {msg}"),
+    ("단계 완료: {} ({} ms)", "Stage finished: {msg} ({} ms)"),
+    ("소스 준비", "source preparation"),
+    ("소스 해석", "source parsing"),
+    ("마스터 열기", "master open"),
+    ("마스터 현행화", "master update"),
+    ("변경내역 갱신", "change log update"),
+    ("마스터 저장", "master save"),
+    ("ODS 저장", "ODS export"),
+    ("이력 DB 기록", "history DB write"),
+    ("웹훅 전송", "webhook delivery"),
+    ("소스 xls 크기: {} bytes", "Source xls size: {} bytes"),
+    (
+        "소스 행 {}건 중 대상 지역 레코드 {}건",
+        "Source rows: {}, target-region records: {}",
+    ),
+    ("HTTP {}: 상태 {}, {} bytes", "HTTP {}: status {}, {} bytes"),
+    (
+        "NetFunnel {} 대기 {}회: ttl {}, {}초 후 재시도",
+        "NetFunnel {} wait {}: ttl {}, retrying in {}s",
+    ),
+    (
+        "NetFunnel {} 통과 (대기 {}회)",
+        "NetFunnel {} passed ({} waits)",
+    ),
+    (
+        "저장 단계: 임시 아카이브 기록 완료 (파트 {}개)",
+        "Save phase: temporary archive written ({} parts)",
+    ),
+    (
+        "저장 단계: 임시 아카이브 검증 완료",
+        "Save phase: temporary archive verified",
+    ),
+    (
+        "저장 단계: 대상 파일 교체 완료",
+        "Save phase: target file replaced",
+    ),
+    (
+        "limit은 1 이상 {} 이하의 정수여야 합니다: {}",
+        "limit must be an integer from 1 to {}: {}",
+    ),
+    ("알 수 없는 지역입니다: {}", "Unknown region: {}"),
+    (
+        "fuel은 gasoline, premium 또는 diesel이어야 합니다: {}",
+        "fuel must be gasoline, premium or diesel: {}",
+    ),
+    (
+        "잘못된 퍼센트 인코딩입니다: {}",
+        "Invalid percent-encoding: {}",
+    ),
+    (
+        "변경내역 데이터 행이 연속되지 않습니다: row={}, expected={}",
+        "The 변경내역 data rows are not contiguous: row={}, expected={}",
+    ),
+    (
+        "변경내역 저장 행 수가 기록한 항목 수와 다릅니다: saved={}, expected={}",
+        "The saved 변경내역 row count differs from the entries written: saved={}, expected={}",
+    ),
+    (
+        "xlsx 파일 크기가 허용 한도({} bytes)를 초과했습니다.",
+        "The xlsx file exceeds the size limit ({} bytes).",
+    ),
+    (
+        "xlsx에 시트가 없습니다: {}",
+        "The xlsx has no sheet named: {}",
+    ),
+    (
+        "xlsx sheet part가 없습니다: {}",
+        "The xlsx sheet part is missing: {}",
+    ),
+    (
+        "xlsx 숫자 셀 값 해석 실패: row={}, value={}",
+        "Failed to parse an xlsx numeric cell: row={}, value={}",
+    ),
+    (
+        "xlsx {} UTF-8 변환 실패",
+        "Failed to decode the xlsx {} as UTF-8",
+    ),
+    (
+        "xlsx sharedStrings 개수가 허용 한도({})를 초과했습니다.",
+        "The xlsx sharedStrings count exceeds the limit ({}).",
+    ),
+    (
+        "xlsx sheet의 relationships namespace 선언을 찾지 못했습니다: {}",
+        "No relationships namespace declaration was found for the xlsx sheet: {}",
+    ),
+    (
+        "xlsx sheet에 {} 속성이 없습니다: {}",
+        "The xlsx sheet has no {} attribute: {}",
+    ),
+    (
+        "xlsx workbook relationship을 찾지 못했습니다: {}",
+        "The xlsx workbook relationship was not found: {}",
+    ),
+    (
+        "xlsx sheet 경로가 올바르지 않습니다: {}",
+        "Invalid xlsx sheet path: {}",
+    ),
+    (
+        "xlsx 행 번호 해석 실패: {}",
+        "Failed to parse an xlsx row number: {}",
+    ),
+    (
+        "xlsx 행 순서가 올바르지 않습니다: row={}",
+        "The xlsx rows are out of order: row={}",
+    ),
+    (
+        "시트 행 인덱스가 비정상적으로 큽니다: {} (최대 {})",
+        "The sheet row index is abnormally large: {} (max {})",
+    ),
+    (
+        "xlsx cell reference 형식이 올바르지 않습니다: row={}, ref={}",
+        "Malformed xlsx cell reference: row={}, ref={}",
+    ),
+    (
+        "xlsx 셀 순서가 올바르지 않습니다: row={}, col={}",
+        "The xlsx cells are out of order: row={}, col={}",
+    ),
+    (
+        "시트 열 인덱스가 비정상적으로 큽니다: {}",
+        "The sheet column index is abnormally large: {}",
+    ),
+    (
+        "xlsx shared string cell에 값이 없습니다: row={}, col={}",
+        "The xlsx shared string cell has no value: row={}, col={}",
+    ),
+    (
+        "xlsx cell이 존재하지 않는 shared string index를 참조합니다: {}",
+        "An xlsx cell refers to a missing shared string index: {}",
+    ),
+    (
+        "xlsx shared formula 기준 셀을 찾지 못했습니다: si={}, row={}",
+        "The xlsx shared formula anchor cell was not found: si={}, row={}",
+    ),
+    (
+        "LABELSST가 존재하지 않는 SST index를 참조합니다: {}",
+        "LABELSST refers to a missing SST index: {}",
+    ),
+    (
+        "MULRK record 길이가 올바르지 않습니다: {}",
+        "Invalid MULRK record length: {}",
+    ),
+    (
+        "MULRK record 열 범위가 셀 수와 다릅니다: first={}, cells={}",
+        "The MULRK record column range does not match its cell count: first={}, cells={}",
+    ),
+    (
+        "BOOLERR 값 종류를 알 수 없습니다: {}",
+        "Unknown BOOLERR value kind: {}",
+    ),
+    (
+        "FORMULA 결과 형식을 알 수 없습니다: {}",
+        "Unknown FORMULA result type: {}",
+    ),
+    (
+        "SST total count가 LABELSST 레코드 수와 다릅니다: declared={}, actual={}",
+        "The SST total count differs from the LABELSST record count: declared={}, actual={}",
+    ),
+    (
+        "xls {}의 첫 레코드가 BOF가 아닙니다: {}",
+        "The first record of the xls {} is not BOF: {}",
+    ),
+    (
+        "xls {} BOF 길이가 예상과 다릅니다: expected=16, actual={}",
+        "Unexpected BOF length in the xls {}: expected=16, actual={}",
+    ),
+    (
+        "xls {} BOF가 예상과 다릅니다: version={}, substream={}",
+        "Unexpected BOF in the xls {}: version={}, substream={}",
+    ),
+    (
+        "CFB sector 범위를 벗어났습니다: sector={}, size={}",
+        "CFB sector out of range: sector={}, size={}",
+    ),
+    (
+        "FAT stream 시작 sector가 비정상입니다: {} ({})",
+        "Invalid FAT stream start sector: {} ({})",
+    ),
+    (
+        "FAT stream 선언 크기가 파일 크기보다 큽니다: {}, size={}, file_size={}",
+        "The declared FAT stream size exceeds the file size: {}, size={}, file_size={}",
+    ),
+    (
+        "FAT chain이 FAT entry 수를 초과했습니다(순환 가능): {} (sector={})",
+        "The FAT chain exceeds the FAT entry count (possible cycle): {} (sector={})",
+    ),
+    (
+        "FAT stream이 선언 크기 이후에도 계속됩니다: {} (sector={})",
+        "The FAT stream continues past its declared size: {} (sector={})",
+    ),
+    (
+        "FAT chain에 잘못된 sector id가 있습니다: {} ({})",
+        "The FAT chain has an invalid sector id: {} ({})",
+    ),
+    (
+        "FAT sector 변환 실패: {} (sector={})",
+        "Failed to convert a FAT sector: {} (sector={})",
+    ),
+    (
+        "FAT chain이 free sector를 참조합니다: {} (sector={})",
+        "The FAT chain refers to a free sector: {} (sector={})",
+    ),
+    (
+        "FAT stream이 선언 크기보다 짧습니다: {}, remaining={}",
+        "The FAT stream is shorter than its declared size: {}, remaining={}",
+    ),
+    (
+        "Opinet 고정 소스에서 예상하지 않은 CFB byte order: {}",
+        "Unexpected CFB byte order in the Opinet source: {}",
+    ),
+    (
+        "CFB 파일 길이가 sector 크기 단위가 아닙니다: file_size={}, sector_size={}",
+        "The CFB file length is not a multiple of the sector size: file_size={}, sector_size={}",
+    ),
+    (
+        "CFB DIFAT sector가 없지만 시작 sector가 end-of-chain이 아닙니다: {}",
+        "There are no CFB DIFAT sectors but the start sector is not end-of-chain: {}",
+    ),
+    (
+        "CFB mini FAT sector가 없지만 시작 sector가 end-of-chain이 아닙니다: {}",
+        "There are no CFB mini FAT sectors but the start sector is not end-of-chain: {}",
+    ),
+    (
+        "Opinet 고정 소스에서 예상하지 않은 CFB mini stream cutoff: {}",
+        "Unexpected CFB mini stream cutoff in the Opinet source: {}",
+    ),
+    (
+        "CFB directory entry object_type이 비정상입니다: {}",
+        "Invalid CFB directory entry object_type: {}",
+    ),
+    (
+        "CFB directory stream size 상위 32비트가 0이 아닙니다: {}",
+        "The upper 32 bits of a CFB directory stream size are not zero: {}",
+    ),
+    (
+        "CFB directory entry 이름 길이가 비정상입니다: object_type={}, name_len={}",
+        "Invalid CFB directory entry name length: object_type={}, name_len={}",
+    ),
+    (
+        "CFB directory entry 이름이 NUL로 끝나지 않습니다: object_type={}",
+        "A CFB directory entry name is not NUL-terminated: object_type={}",
+    ),
+    (
+        "CFB FAT sector 개수가 비정상적으로 큽니다: {} (최대 {})",
+        "The CFB FAT sector count is abnormally large: {} (max {})",
+    ),
+    (
+        "CFB FAT 엔트리 개수가 header DIFAT 용량을 초과했습니다: {}",
+        "The CFB FAT entry count exceeds the header DIFAT capacity: {}",
+    ),
+    (
+        "CFB FAT 엔트리 개수가 선언과 다릅니다: 선언 {}, 실제 {}",
+        "The CFB FAT entry count differs from the declaration: declared {}, actual {}",
+    ),
+    (
+        "xls {} record 위치가 파일 범위를 벗어났습니다.",
+        "An xls {} record position is outside the file.",
+    ),
+    (
+        "xls {} record header 범위 오류",
+        "xls {} record header out of range",
+    ),
+    ("xls {} 레코드 범위 오류", "xls {} record out of range"),
+    (
+        "FORMULA 문자열 결과 뒤에 STRING record가 없습니다: row={}, col={}",
+        "No STRING record follows a FORMULA string result: row={}, col={}",
+    ),
+    (
+        "Opinet 고정 소스 셀 순서가 올바르지 않습니다: row={}, col={}",
+        "The Opinet source cells are out of order: row={}, col={}",
+    ),
+    (
+        "Opinet 고정 소스 셀이 중복 선언되었습니다: row={}, col={}",
+        "An Opinet source cell is declared twice: row={}, col={}",
+    ),
+    (
+        "Opinet 고정 소스의 BIFF code page가 예상과 다릅니다: {}",
+        "Unexpected BIFF code page in the Opinet source: {}",
+    ),
+    (
+        "SST total count가 unique count보다 작습니다: total={}, unique={}",
+        "The SST total count is smaller than the unique count: total={}, unique={}",
+    ),
+    (
+        "CSV 소스 {}행 {}열 따옴표 뒤에 구분자가 없습니다.",
+        "CSV source row {} column {}: no delimiter after the closing quote.",
+    ),
+    (
+        "CSV 소스 {}행의 따옴표가 닫히지 않았습니다.",
+        "CSV source row {} has an unclosed quote.",
+    ),
+    (
+        "xlsx 소스 sheet part가 없습니다: {}",
+        "The xlsx source sheet part is missing: {}",
+    ),
+    (
+        "고정 workbook은 aca=\"false\" 외 formula 속성을 지원하지 않습니다: row={}, col={}",
+        "The fixed workbook supports no formula attributes other than aca=\"false\": row={}, col={}",
+    ),
+    (
+        "고정 workbook formula cache가 없습니다: row={}, col={}",
+        "The fixed workbook formula has no cached value: row={}, col={}",
+    ),
+    (
+        "shared formula ref가 세로 anchor 범위와 다릅니다: row={}, col={}",
+        "The shared formula ref differs from the vertical anchor range: row={}, col={}",
+    ),
+    (
+        "shared formula si anchor가 중복됩니다: {}",
+        "Duplicate shared formula si anchor: {}",
+    ),
+    (
+        "shared formula anchor를 찾지 못했습니다: si={}",
+        "The shared formula anchor was not found: si={}",
+    ),
+    (
+        "shared formula follower가 ref 범위를 벗어났습니다: si={}, row={}, col={}",
+        "A shared formula follower is outside the ref range: si={}, row={}, col={}",
+    ),
+    (
+        "worksheet row 번호가 Excel 범위를 벗어났습니다: {}",
+        "The worksheet row number is outside the Excel range: {}",
+    ),
+    (
+        "worksheet row 번호는 1부터 연속이어야 합니다: expected={}, current={}",
+        "Worksheet row numbers must be contiguous from 1: expected={}, current={}",
+    ),
+    (
+        "shared formula ref의 cell 수가 다릅니다: si={}, expected={}, actual={}",
+        "The shared formula ref cell count differs: si={}, expected={}, actual={}",
+    ),
+    (
+        "shared string index 범위 오류: {}",
+        "Shared string index out of range: {}",
+    ),
+    (
+        "worksheet style 원본 row가 없습니다: {}",
+        "The worksheet style source row is missing: {}",
+    ),
+    (
+        "worksheet autoFilter 범위가 고정 스키마와 다릅니다: {}",
+        "The worksheet autoFilter range differs from the fixed schema: {}",
+    ),
+    (
+        "worksheet에 #REF! 수식이 있습니다: {}!row={}, col={}",
+        "The worksheet has a #REF! formula: {}!row={}, col={}",
+    ),
+    (
+        "수식 cache 대상 cell이 비어 있습니다: row={}, col={}",
+        "The cell for a formula cache is empty: row={}, col={}",
+    ),
+    (
+        "고정 workbook의 텍스트 cell이 shared string이 아닙니다: row={}, col={}",
+        "A text cell in the fixed workbook is not a shared string: row={}, col={}",
+    ),
+    (
+        "변경내역 {}열 조건부 서식 기준 범위가 없습니다.",
+        "The 변경내역 column {} conditional formatting has no base range.",
+    ),
+    (
+        "worksheet가 참조한 style을 Excel 정규형으로 변환할 수 없습니다: {}",
+        "A style referenced by the worksheet cannot be normalized for Excel: {}",
+    ),
+    (
+        "worksheet {} 속성이 boolean 형식이 아닙니다: {}",
+        "The worksheet {} attribute is not a boolean: {}",
+    ),
+    (
+        "Excel worksheet의 {} 태그가 없습니다.",
+        "The Excel worksheet has no {} tag.",
+    ),
+    (
+        "Excel worksheet에 {} 태그가 여러 개 있습니다.",
+        "The Excel worksheet has more than one {} tag.",
+    ),
+    (
+        "XML의 {} 요소를 찾지 못했습니다.",
+        "The XML {} element was not found.",
+    ),
+    (
+        "XML에 {} 요소가 여러 개 있습니다.",
+        "The XML has more than one {} element.",
+    ),
+    (
+        "sharedStrings entry 개수가 허용 한도({})를 초과했습니다.",
+        "The sharedStrings entry count exceeds the limit ({}).",
+    ),
+    (
+        "XML {} 태그 용량 계산 실패",
+        "Failed to compute the XML {msg} tag capacity",
+    ),
+    (
+        "XML {} 태그 속성 용량 계산 실패",
+        "Failed to compute the XML {msg} tag attribute capacity",
+    ),
+    (
+        "workbook.xml의 {} namespace가 올바르지 않습니다.",
+        "Invalid {} namespace in workbook.xml.",
+    ),
+    (
+        "workbook.xml에 {} 요소가 여러 개 있습니다.",
+        "workbook.xml has more than one {} element.",
+    ),
+    (
+        "sharedStrings uniqueCount가 실제 entry 수와 다릅니다: declared={}, actual={}",
+        "The sharedStrings uniqueCount differs from the entry count: declared={}, actual={}",
+    ),
+    (
+        "고정 sharedStrings에 중복 문자열이 있습니다: index={}",
+        "The fixed sharedStrings have a duplicate string: index={}",
+    ),
+    (
+        "유류비 고정 입력값이 비어 있습니다: B{}",
+        "A fixed 유류비 input is empty: B{}",
+    ),
+    (
+        "유류비 autoFilter 마지막 행이 실제 데이터 마지막 행과 다릅니다: filter={}, actual={}",
+        "The 유류비 autoFilter last row differs from the last data row: filter={}, actual={}",
+    ),
+    (
+        "유류비 autoFilter 마지막 행이 실제 주소 데이터 마지막 행과 다릅니다: filter={}, actual={}",
+        "The 유류비 autoFilter last row differs from the last address row: filter={}, actual={}",
+    ),
+    (
+        "_FilterDatabase 범위가 autoFilter와 다릅니다: {} != {}",
+        "The _FilterDatabase range differs from the autoFilter: {} != {}",
+    ),
+    (
+        "sharedStrings count가 실제 참조 수와 다릅니다: declared={}, actual={}",
+        "The sharedStrings count differs from the reference count: declared={}, actual={}",
+    ),
+    (
+        "cell reference가 없습니다: row={}",
+        "Missing cell reference: row={}",
+    ),
+    (
+        "cell reference 형식이 비정상입니다: row={}, ref={}",
+        "Malformed cell reference: row={}, ref={}",
+    ),
+    (
+        "cell reference row가 row 태그와 다릅니다: row={}, ref={}",
+        "The cell reference row differs from the row tag: row={}, ref={}",
+    ),
+    (
+        "고정 workbook에서 지원하지 않는 cell type입니다: row={}, col={}, type={}",
+        "Unsupported cell type in the fixed workbook: row={}, col={}, type={}",
+    ),
+    (
+        "고정 workbook cell에 지원하지 않는 속성이 있습니다: row={}, col={}, attribute={}",
+        "A fixed workbook cell has an unsupported attribute: row={}, col={}, attribute={}",
+    ),
+    (
+        "worksheet cell 개수가 허용 한도({})를 초과했습니다.",
+        "The worksheet cell count exceeds the limit ({}).",
+    ),
+    (
+        "formula에 지원하지 않는 속성이 있습니다: {}",
+        "The formula has an unsupported attribute: {}",
+    ),
+    (
+        "Excel row 범위를 벗어났습니다: {}",
+        "Outside the Excel row range: {}",
+    ),
+    (
+        "Excel column 범위를 벗어났습니다: {}",
+        "Outside the Excel column range: {}",
+    ),
+    (
+        "shared formula 상대참조 이동 범위를 벗어났습니다: {} + {}",
+        "Shifting a shared formula relative reference goes out of range: {} + {}",
+    ),
+    ("row 높이가 올바르지 않습니다: {}", "Invalid row height: {}"),
+    (
+        "ODS로 변환할 수 없는 수식 참조입니다: {}",
+        "This formula reference cannot be converted to ODS: {}",
+    ),
+    (
+        "수식 검증이 지원하지 않는 TEXT 서식입니다: {}",
+        "Formula verification does not support this TEXT format: {}",
+    ),
+    (
+        "수식 검증이 지원하지 않는 함수입니다: {}",
+        "Formula verification does not support this function: {}",
+    ),
+    (
+        "수식 검증 대상 함수의 인자 형식이 올바르지 않습니다: {}",
+        "Invalid arguments for a function under formula verification: {}",
+    ),
+    (
+        "수식 검증 대상 숫자 cell 값을 해석하지 못했습니다: row={}, col={}, value={}",
+        "Could not parse a numeric cell under formula verification: row={}, col={}, value={}",
+    ),
+    (
+        "수식 끝에 해석하지 못한 내용이 있습니다: {}",
+        "Unparsed content at the end of the formula: {}",
+    ),
+    (
+        "수식 숫자 literal 해석 실패: {}",
+        "Failed to parse a formula number literal: {}",
+    ),
+    (
+        "수식 검증이 해석하지 못한 token입니다: {}",
+        "Formula verification could not parse this token: {}",
+    ),
+    (
+        "수식 검증이 지원하지 않는 이름입니다: {}",
+        "Formula verification does not support this name: {}",
+    ),
+    (
+        "core.xml에 {} 요소가 여러 개 있습니다.",
+        "core.xml has more than one {} element.",
+    ),
+    (
+        "core.xml의 {} 종료 태그가 없습니다.",
+        "core.xml has no closing {} tag.",
+    ),
+    (
+        "core.xml의 {} 본문이 올바르지 않습니다.",
+        "Invalid {} body in core.xml.",
+    ),
+    (
+        "core.xml의 {} 요소를 찾지 못했습니다.",
+        "The {} element was not found in core.xml.",
+    ),
+    (
+        "입력에서 Excel 공통 part를 찾지 못했습니다: {}",
+        "A common Excel part was not found in the input: {}",
+    ),
+    ("xlsx part를 찾지 못했습니다: {}", "xlsx part not found: {}"),
+    (
+        "sheet1.xml.rels에 지원하지 않는 관계가 있습니다: {} -> {}",
+        "sheet1.xml.rels has an unsupported relationship: {} -> {}",
+    ),
+    (
+        "custom.xml property pid가 올바르지 않습니다: {}",
+        "Invalid custom.xml property pid: {}",
+    ),
+    (
+        "custom.xml property 구성이 올바르지 않습니다: {}",
+        "Invalid custom.xml property layout: {}",
+    ),
+    (
+        "custom.xml의 해시 값이 올바르지 않습니다: {}",
+        "Invalid hash value in custom.xml: {}",
+    ),
+    (
+        "custom.xml property 값이 올바르지 않습니다: {}",
+        "Invalid custom.xml property value: {}",
+    ),
+    (
+        "xlsx part UTF-8 해석 실패: {}",
+        "Failed to decode an xlsx part as UTF-8: {}",
+    ),
+    (
+        "worksheet XML namespace 검증: {}",
+        "worksheet XML namespace check: {}",
+    ),
+    (
+        "[Content_Types].xml에 알 수 없는 {} 항목이 있습니다.",
+        "[Content_Types].xml has an unknown {} entry.",
+    ),
+    (
+        "[Content_Types].xml entry에 {} 속성이 없습니다.",
+        "A [Content_Types].xml entry has no {} attribute.",
+    ),
+    (
+        "[Content_Types].xml Override 대상 part가 없습니다: {}",
+        "The [Content_Types].xml Override target part is missing: {}",
+    ),
+    (
+        "[Content_Types].xml에 지원하지 않는 {} 항목이 있습니다: {}",
+        "[Content_Types].xml has an unsupported {} entry: {}",
+    ),
+    (
+        "[Content_Types].xml Default 확장자가 올바르지 않습니다: {}",
+        "Invalid [Content_Types].xml Default extension: {}",
+    ),
+    (
+        "[Content_Types].xml 항목이 중복되었습니다: {}",
+        "Duplicate [Content_Types].xml entry: {}",
+    ),
+    (
+        "[Content_Types].xml 필수 항목이 없습니다: {}",
+        "A required [Content_Types].xml entry is missing: {}",
+    ),
+    (
+        "styles.xml의 {} 시작 태그가 올바르지 않습니다.",
+        "Invalid {} start tag in styles.xml.",
+    ),
+    (
+        "styles.xml {} count 해석 실패",
+        "Failed to parse the styles.xml {} count",
+    ),
+    (
+        "styles.xml의 {} 종료 태그는 unprefixed여야 합니다.",
+        "The {} end tag in styles.xml must be unprefixed.",
+    ),
+    (
+        "styles.xml {}에 알 수 없는 text가 있습니다.",
+        "The styles.xml {} has unknown text.",
+    ),
+    (
+        "styles.xml {}에 알 수 없는 {} 요소가 있습니다.",
+        "The styles.xml {} has an unknown {} element.",
+    ),
+    (
+        "styles.xml {} 종료 태그가 중복되었습니다.",
+        "Duplicate styles.xml {} end tag.",
+    ),
+    (
+        "styles.xml {} 태그 쌍이 일치하지 않습니다: {} / {}",
+        "Mismatched styles.xml {} tags: {} / {}",
+    ),
+    (
+        "styles.xml {} 요소 구조가 올바르지 않습니다.",
+        "Invalid styles.xml {} element structure.",
+    ),
+    (
+        "styles.xml에 {} 태그가 여러 개 있습니다.",
+        "styles.xml has more than one {} tag.",
+    ),
+    (
+        "styles.xml {} count가 실제 {} 수와 다릅니다: declared={}, actual={}",
+        "The styles.xml {} count differs from the number of {} entries: declared={}, actual={}",
+    ),
+    (
+        "styles.xml {}에 count 속성이 없습니다.",
+        "The styles.xml {} has no count attribute.",
+    ),
+    (
+        "workbook의 {} 태그가 올바르지 않습니다.",
+        "Invalid workbook {} tag.",
+    ),
+    (
+        "workbook에 {} 태그가 여러 개 있습니다.",
+        "The workbook has more than one {} tag.",
+    ),
+    (
+        "workbook의 {} 태그 끝 계산 실패",
+        "Failed to find the end of the workbook {} tag",
+    ),
+    (
+        "xlsx 임시 저장 파일 삭제 실패: {} ({})",
+        "Failed to delete the temporary xlsx file: {} ({msg})",
+    ),
+    (
+        "xlsx 임시 파일 identity가 실행 중 변경되었습니다: {}",
+        "The temporary xlsx file identity changed during the run: {}",
+    ),
+    (
+        "경고: xlsx 임시 저장 파일 정리 실패: {} ({})",
+        "Warning: failed to clean up the temporary xlsx file: {} ({msg})",
+    ),
+    (
+        "원본 xlsx identity가 실행 중 변경되어 저장을 중단했습니다: {}",
+        "The original xlsx identity changed during the run; save aborted: {}",
+    ),
+    (
+        "xlsx 저장 실패: {} -> {}",
+        "Failed to save the xlsx: {} -> {}",
+    ),
+    (
+        "xlsx 저장 실패 후 원본 대상 파일 자동 복원 완료: {} -> {}",
+        "The xlsx save failed; the original target file was restored automatically: {} -> {}",
+    ),
+    (
+        "xlsx 저장 중 원본 대상 파일 자동 복구 실패: {} -> {}",
+        "Automatic recovery of the original target file failed during the xlsx save: {} -> {}",
+    ),
+    (
+        "xlsx는 저장됐지만 교체된 원본을 백업으로 옮기지 못했습니다: {} -> {}",
+        "The xlsx was saved, but the replaced original could not be moved to the backup: {} -> {}",
+    ),
+    (
+        "원본 xlsx 검증 실패 후 복구 실패 ({})",
+        "Recovery failed after the original xlsx failed validation ({msg})",
+    ),
+    (
+        "교체된 원본 xlsx identity가 실행 시작 시점과 다릅니다: {}",
+        "The replaced original xlsx identity differs from the start of the run: {}",
+    ),
+    (
+        "원본 xlsx가 실행 중 변경되어 저장을 중단했습니다: {}",
+        "The original xlsx changed during the run; save aborted: {}",
+    ),
+    (
+        "workbook definedName은 사용자 시트 범위에서만 허용됩니다: {}",
+        "Workbook definedNames are only allowed with a user sheet scope: {}",
+    ),
+    (
+        "workbook.xml 사용자 시트 state가 올바르지 않습니다: {}",
+        "Invalid user sheet state in workbook.xml: {}",
+    ),
+    (
+        "workbook.xml 사용자 시트에 r:id가 없습니다: {}",
+        "A user sheet in workbook.xml has no r:id: {}",
+    ),
+    (
+        "workbook.xml 사용자 시트의 관계를 찾지 못했습니다: {}",
+        "The relationship of a user sheet in workbook.xml was not found: {}",
+    ),
+    (
+        "workbook.xml 사용자 시트의 관계 형식을 지원하지 않습니다: {}",
+        "Unsupported relationship type for a user sheet in workbook.xml: {}",
+    ),
+    (
+        "workbook.xml 사용자 시트의 관계가 올바르지 않습니다: {}",
+        "Invalid relationship for a user sheet in workbook.xml: {}",
+    ),
+    (
+        "workbook.xml.rels의 시트 관계가 workbook.xml에 없습니다: {}",
+        "A sheet relationship in workbook.xml.rels is missing from workbook.xml: {}",
+    ),
+    (
+        "workbook.xml.rels에 고정 관계와 같은 형식의 관계가 있습니다: {}",
+        "workbook.xml.rels has a relationship of the same type as a fixed one: {}",
+    ),
+    (
+        "사용자 시트의 style index가 styles.xml 범위를 벗어났습니다: {}",
+        "A user sheet style index is outside styles.xml: {}",
+    ),
+    (
+        "사용자 시트 style의 {} 값이 styles.xml 범위를 벗어났습니다: {}",
+        "The {} value of a user sheet style is outside styles.xml: {}",
+    ),
+    (
+        "사용자 시트의 서식 index가 styles.xml 범위를 벗어났습니다: {}",
+        "A user sheet format index is outside styles.xml: {}",
+    ),
+    (
+        "사용자 시트 style 변환표에 없는 index입니다: {}",
+        "The index is missing from the user sheet style map: {}",
+    ),
+    (
+        "workbook.xml에 알 수 없는 관계 Id 참조가 있습니다: {}",
+        "workbook.xml refers to an unknown relationship Id: {}",
+    ),
+    (
+        "사용자 시트의 shared string index 범위 오류: {}",
+        "User sheet shared string index out of range: {}",
+    ),
+    (
+        "보존할 package part의 content type이 없습니다: {}",
+        "A package part to preserve has no content type: {}",
+    ),
+    (
+        "보존할 관계 part의 원본 part가 없습니다: {}",
+        "The source part of a relationship part to preserve is missing: {}",
+    ),
+    (
+        "사용자 시트 style의 숫자 서식을 찾지 못했습니다: {}",
+        "The number format of a user sheet style was not found: {}",
+    ),
+    (
+        "workbook.xml에 같은 이름의 시트가 있습니다: {}",
+        "workbook.xml has sheets with the same name: {}",
+    ),
+    (
+        "사용자 시트 style 참조 해석 실패: {}",
+        "Failed to parse a user sheet style reference: {}",
+    ),
+    (
+        "workbook.xml 사용자 시트 sheetId가 올바르지 않습니다: {}",
+        "Invalid user sheet sheetId in workbook.xml: {}",
+    ),
+    (
+        "workbook.xml sheetId가 중복되었습니다: {}",
+        "Duplicate sheetId in workbook.xml: {}",
+    ),
+    (
+        "XML <{}> 본문 시작 계산에 실패했습니다.",
+        "Failed to find the start of the XML <{}> body.",
+    ),
+    (
+        "XML </{}> 종료 태그를 찾지 못했습니다.",
+        "The XML </{}> end tag was not found.",
+    ),
+    (
+        "XML <{}> 요소는 같은 이름으로 중첩될 수 없습니다.",
+        "XML <{}> elements cannot be nested in themselves.",
+    ),
+    (
+        "XML </{}> 끝 계산에 실패했습니다.",
+        "Failed to find the end of XML </{}>.",
+    ),
+    (
+        "XML <{}> 본문 범위가 손상되었습니다.",
+        "The XML <{}> body range is corrupted.",
+    ),
+    (
+        "XML <{}> text 용량 계산 실패",
+        "Failed to compute the XML <{}> text capacity",
+    ),
+    (
+        "XML text: XML 1.0에서 허용되지 않는 문자가 포함되어 있습니다: U+{}",
+        "XML text: contains a character not allowed in XML 1.0: U+{}",
+    ),
+    (
+        "지원하지 않는 XML entity입니다: &{};",
+        "Unsupported XML entity: &{};",
+    ),
+    (
+        "XML numeric entity가 유효한 Unicode scalar value가 아닙니다: &{};",
+        "The XML numeric entity is not a valid Unicode scalar value: &{};",
+    ),
+    (
+        "XML numeric entity가 XML 1.0 유효 문자 범위를 벗어났습니다: &{};",
+        "The XML numeric entity is outside the valid XML 1.0 character range: &{};",
+    ),
+    (
+        "ZIP local record가 연속된 고정 순서가 아닙니다: {}",
+        "The ZIP local records are not in the fixed contiguous order: {}",
+    ),
+    (
+        "ZIP 압축 데이터 크기가 허용 한도({} bytes)를 초과했습니다.",
+        "The ZIP compressed data exceeds the size limit ({} bytes).",
+    ),
+    (
+        "ZIP entry 수가 지원 상한을 초과했습니다: {}",
+        "The ZIP entry count exceeds the supported maximum: {}",
+    ),
+    (
+        "ZIP entry 이름이 중복되었습니다: {}",
+        "Duplicate ZIP entry name: {}",
+    ),
+    (
+        "ZIP entry 이름이 package part 이름 규칙에 맞지 않습니다: {}",
+        "The ZIP entry name does not follow the package part naming rules: {}",
+    ),
+    (
+        "ZIP 필수 entry가 없습니다: {}",
+        "A required ZIP entry is missing: {}",
+    ),
+    (
+        "xlsx 압축 파일 크기 변환 실패({}): {}",
+        "Failed to convert the xlsx archive size ({}): {msg}",
+    ),
+    (
+        "xlsx 압축 파일 크기가 허용 한도({} bytes)를 초과했습니다: {}",
+        "The xlsx archive exceeds the size limit ({} bytes): {}",
+    ),
+    (
+        "xlsx 압축 파일이 읽는 중 변경되었습니다: {}",
+        "The xlsx archive changed while it was being read: {}",
+    ),
+    (
+        "ZIP {} 크기가 허용 한도({} bytes)를 초과했습니다: {}",
+        "The ZIP {msg} size exceeds the limit ({} bytes): {}",
+    ),
+    (
+        "xlsx part 크기가 허용 한도({} bytes)를 초과했습니다: {} -> {}",
+        "An xlsx part exceeds the size limit ({} bytes): {} -> {}",
+    ),
+    (
+        "고정 XLSX part 압축 작업 한도를 초과했습니다: {}",
+        "A fixed XLSX part exceeds the compression work limit: {}",
+    ),
+    (
+        "ZIP entry 출력 크기가 계획과 다릅니다: expected={}, actual={}",
+        "The ZIP entry output size differs from the plan: expected={}, actual={}",
+    ),
+    (
+        "xlsx 압축 출력 크기가 허용 한도({} bytes)를 초과합니다: {}",
+        "The xlsx archive output exceeds the size limit ({} bytes): {}",
+    ),
+    (
+        "SQLite {} 실패 (code {}): {}",
+        "SQLite {msg} failed (code {}): {}",
+    ),
+    (
+        "JSON 형식 오류: {} (offset={})",
+        "JSON syntax error: {msg} (offset={})",
+    ),
+    (
+        "JSON 값 뒤에 불필요한 내용이 있습니다: offset={}",
+        "Unexpected content after the JSON value: offset={}",
+    ),
+    (
+        "유류비 숫자 셀 값이 올바르지 않습니다: row={}, col={}, value={}",
+        "Invalid 유류비 numeric cell value: row={}, col={}, value={}",
+    ),
+    (
+        "지도 URL 템플릿 자리표시자가 올바르지 않습니다: {}",
+        "Invalid map URL template placeholder: {}",
+    ),
+    (
+        "유류비 신규행 template이 없습니다: row={}",
+        "The 유류비 new-row template is missing: row={}",
+    ),
+    (
+        "유류비 기존행 XML이 없습니다: row={}",
+        "The 유류비 existing row XML is missing: row={}",
+    ),
+    (
+        "유류비 순위가 있는 행이 순위 없는 행 뒤에 있습니다: row={}, 순위 없는 행={}",
+        "A ranked 유류비 row follows an unranked row: row={}, unranked row={}",
+    ),
+    (
+        "유류비 순위가 정렬 순서와 맞지 않습니다: row={}, rank={}, previous={}, position={}",
+        "The 유류비 ranks do not match the sort order: row={}, rank={}, previous={}, position={}",
+    ),
+    (
+        "저장된 유류비 주소 중복: normalized_address={}, first_row={}, duplicate_row={}",
+        "Duplicate address in the saved 유류비 sheet: normalized_address={}, first_row={}, duplicate_row={}",
+    ),
+    (
+        "저장된 유류비 행 수가 현행화 결과와 다릅니다: 주소 행 saved={}, expected={} / 주소 없는 행 saved={}, expected={}",
+        "The saved 유류비 row counts differ from the update result: address rows saved={}, expected={} / rows without an address saved={}, expected={}",
+    ),
+    (
+        "폐업 처리한 업체가 저장 결과에 남아 있습니다: row={}, 업체={} / {}",
+        "A station removed as closed remains in the saved result: row={}, station={} / {}",
+    ),
+    (
+        "지역화폐 적용 대상 행의 적용률을 찾지 못했습니다: 지역={}",
+        "No local currency rate was found for an eligible row: region={}",
+    ),
+    (
+        "HTTP 응답 {} 크기 계산 실패",
+        "Failed to compute the HTTP response {msg} size",
+    ),
+    (
+        "HTTP 응답 {} 크기가 허용 한도({} bytes)를 초과했습니다.",
+        "The HTTP response {msg} exceeds the size limit ({} bytes).",
+    ),
+    (
+        "HTTP 응답 본문 길이가 Content-Length와 다릅니다: expected={}, actual={}",
+        "The HTTP response body length differs from Content-Length: expected={}, actual={}",
+    ),
+    (
+        "지원하지 않는 HTTP Transfer-Encoding입니다: {}",
+        "Unsupported HTTP Transfer-Encoding: {}",
+    ),
+    (
+        "HTTP {} header에 제어 문자가 포함되어 있습니다.",
+        "The HTTP {} header contains control characters.",
+    ),
+    (
+        "호스트별 Cookie 수가 허용 한도({}개)를 초과했습니다.",
+        "The number of cookies for the host exceeds the limit ({}).",
+    ),
+    (
+        "Cookie 이름과 값이 허용 한도({} bytes)를 초과했습니다.",
+        "The cookie name and value exceed the size limit ({} bytes).",
+    ),
+    ("NetFunnel 코드 없음: {}", "NetFunnel code missing: {}"),
+    (
+        "NetFunnel 대기 응답에 ttl 없음: {}",
+        "NetFunnel wait response has no ttl: {}",
+    ),
+    (
+        "HTTP Set-Cookie 형식이 올바르지 않습니다: {}",
+        "Malformed HTTP Set-Cookie: {}",
+    ),
+    (
+        "NetFunnel result 파싱 실패: {}",
+        "Failed to parse the NetFunnel result: {}",
+    ),
+    ("NetFunnel key 없음: {}", "NetFunnel key missing: {}"),
+    ("NetFunnel key 비어 있음: {}", "NetFunnel key empty: {}"),
+    (
+        "Opinet 소스 {}행 주소 값이 비어 있습니다.",
+        "Opinet source row {} has an empty address.",
+    ),
+    (
+        "fcupdater 현행화 완료: 변경 {}건, 신규 {}건, 폐업 {}건",
+        "fcupdater update finished: {} changed, {} added, {} closed",
+    ),
+    (
+        "fcupdater 현행화 실패: {}",
+        "fcupdater update failed: {msg}",
+    ),
+    (
+        "fcupdater 소스 변경 없음: 워크북을 수정하지 않았습니다.",
+        "fcupdater source unchanged: the workbook was not modified.",
+    ),
+    (
+        "{} 최대 인하: {} {} {}→{} ({})",
+        "{} largest cut: {} {} {}→{} ({})",
+    ),
+    (
+        "{} 최대 인상: {} {} {}→{} ({})",
+        "{} largest raise: {} {} {}→{} ({})",
+    ),
+    (
+        "{}에 예약 비트가 설정되었습니다: {}",
+        "Reserved bits are set in {msg}: {}",
+    ),
+    (
+        "{} record 길이가 예상과 다릅니다: expected={}, actual={}",
+        "Unexpected {} record length: expected={}, actual={}",
+    ),
+    (
+        "{} 시트의 고정 formula가 다릅니다: row={}, col={}",
+        "A fixed formula differs in the {} sheet: row={}, col={}",
+    ),
+    (
+        "{} 시트의 고정 위치 밖에 formula가 있습니다: row={}, col={}",
+        "The {} sheet has a formula outside the fixed positions: row={}, col={}",
+    ),
+    (
+        "{} worksheet에 cell이 없습니다.",
+        "The {} worksheet has no cells.",
+    ),
+    (
+        "{} worksheet에 dimension이 없습니다.",
+        "The {} worksheet has no dimension.",
+    ),
+    (
+        "{} worksheet dimension ref가 없습니다.",
+        "The {} worksheet dimension has no ref.",
+    ),
+    (
+        "{} worksheet dimension이 중복되어 있습니다.",
+        "The {} worksheet has more than one dimension.",
+    ),
+    (
+        "{} worksheet dimension 시작 ref가 잘못되었습니다.",
+        "Invalid start ref in the {} worksheet dimension.",
+    ),
+    (
+        "{} worksheet dimension 끝 ref가 잘못되었습니다.",
+        "Invalid end ref in the {} worksheet dimension.",
+    ),
+    (
+        "{} worksheet dimension이 실제 cell 범위와 다릅니다: declared={}, actual=col {} row {}:col {} row {}",
+        "The {} worksheet dimension differs from the cell range: declared={}, actual=col {} row {}:col {} row {}",
+    ),
+    (
+        "{} 시트의 고정 formula 수가 다릅니다: expected={}, actual={}",
+        "The number of fixed formulas in the {} sheet differs: expected={}, actual={}",
+    ),
+    (
+        "{} 시트의 {}: row={}, col={}",
+        "{} sheet: {msg}: row={}, col={}",
+    ),
+    (
+        "{} col min 속성이 없습니다.",
+        "The {} col has no min attribute.",
+    ),
+    (
+        "{} col max 속성이 없습니다.",
+        "The {} col has no max attribute.",
+    ),
+    (
+        "{} col 정의가 고정 스키마 열 범위를 벗어났습니다: min={}, max={}",
+        "A {} col definition is outside the fixed schema columns: min={}, max={}",
+    ),
+    (
+        "{} 시트의 마지막 열이 고정 스키마와 다릅니다: expected={}, actual={}",
+        "The last column of the {} sheet differs from the fixed schema: expected={}, actual={}",
+    ),
+    (
+        "{} 헤더가 고정 스키마와 다릅니다: row={}, col={}, expected={}, actual={}",
+        "The {} header differs from the fixed schema: row={}, col={}, expected={}, actual={}",
+    ),
+    (
+        "{} mergeCell에 ref가 없습니다.",
+        "A {} mergeCell has no ref.",
+    ),
+    (
+        "{} mergeCell 범위 해석 실패: {}",
+        "Failed to parse a {} mergeCell range: {}",
+    ),
+    (
+        "{} 조건부 서식 범위 해석 실패: {}",
+        "Failed to parse a {} conditional formatting range: {}",
+    ),
+    (
+        "{}!{} 수식 재계산 실패: {}",
+        "Failed to recalculate the {}!{} formula: {}",
+    ),
+    (
+        "{}!{} 수식 cache가 재계산 결과와 다릅니다: cached={}, expected={}, formula={}",
+        "The {}!{} formula cache differs from the recalculated result: cached={msg}, expected={msg}, formula={}",
+    ),
+    (
+        "{} 업체가 저장 결과에 없습니다: {} / {}",
+        "A {msg} station is missing from the saved result: {} / {}",
+    ),
+    (
+        "{} 업체 값이 소스와 다릅니다: row={}, col={}, saved={}, expected={}",
+        "A {msg} station value differs from the source: row={}, col={}, saved={}, expected={}",
+    ),
+    (
+        "{} 업체 가격이 소스와 다릅니다: row={}, 업체={}, saved=({}, {}, {}), expected=({}, {}, {})",
+        "A {msg} station price differs from the source: row={}, station={}, saved=({}, {}, {}), expected=({}, {}, {})",
+    ),
+    (
+        "{}; 자동 복구 실패 후 수동 복구를 위해 현재 경로 상태를 보존했습니다: target={}, replacement={}, backup={}",
+        "{msg}; automatic recovery failed, so the current paths were kept for manual recovery: target={}, replacement={}, backup={}",
+    ),
+    (
+        "{}; 자동 복구 실패 후 수동 복구를 위해 현재 경로 상태를 보존했습니다: target={}, replacement={}",
+        "{msg}; automatic recovery failed, so the current paths were kept for manual recovery: target={}, replacement={}",
+    ),
+    (
+        "{}; 원본 대상 파일 자동 복원도 실패했습니다: {}",
+        "{msg}; restoring the original target file also failed: {msg}",
+    ),
+    (
+        "{} offset 계산 중 overflow가 발생했습니다. (base={}, add={})",
+        "Overflow while computing the {msg} offset. (base={}, add={})",
+    ),
+    (
+        "{} 계산 중 overflow가 발생했습니다. ({} + {})",
+        "Overflow while computing {msg}. ({} + {})",
+    ),
+    ("{} 중 overflow가 발생했습니다.", "Overflow during {msg}."),
+    (
+        "{} 값이 너무 큽니다. (value={})",
+        "The {msg} value is too large. (value={})",
+    ),
+    (
+        "{}: 음이 아닌 10진수 형식이 아닙니다.",
+        "{msg}: not a non-negative decimal number.",
+    ),
+    (
+        "{}: XML 1.0에서 허용되지 않는 문자가 포함되어 있습니다: U+{}",
+        "{msg}: contains a character not allowed in XML 1.0: U+{}",
+    ),
+    (
+        "{} 관계 태그가 올바르지 않습니다.",
+        "Invalid {msg} relationship tag.",
+    ),
+    (
+        "{} 관계에 Id가 없습니다.",
+        "A {msg} relationship has no Id.",
+    ),
+    (
+        "{} 관계 Id가 비어 있습니다.",
+        "A {msg} relationship Id is empty.",
+    ),
+    (
+        "{} 관계에 Type이 없습니다.",
+        "A {msg} relationship has no Type.",
+    ),
+    (
+        "{} 관계에 Target이 없습니다.",
+        "A {msg} relationship has no Target.",
+    ),
+    (
+        "{} 관계 Id가 중복되었습니다: {}",
+        "Duplicate {msg} relationship Id: {}",
+    ),
+    (
+        "{}에 지원하지 않는 관계가 있습니다: {} -> {}",
+        "{msg} has an unsupported relationship: {} -> {}",
+    ),
+    (
+        "{} 관계의 TargetMode가 올바르지 않습니다: {}",
+        "Invalid TargetMode in a {msg} relationship: {}",
+    ),
+    (
+        "{} 관계 대상 part가 없습니다: {}",
+        "The target part of a {msg} relationship is missing: {}",
+    ),
+    (
+        "{} 관계가 중복되었습니다: {}",
+        "Duplicate {msg} relationship: {}",
+    ),
+    (
+        "{} 필수 관계 구성이 올바르지 않습니다: {}",
+        "Invalid required {msg} relationships: {}",
+    ),
+    (
+        "{}의 XML root 태그가 없습니다.",
+        "{msg} has no XML root tag.",
+    ),
+    (
+        "{}의 XML root 태그가 올바르지 않습니다.",
+        "Invalid XML root tag in {msg}.",
+    ),
+    (
+        "{}의 XML root 범위가 손상되었습니다.",
+        "The XML root range of {msg} is corrupted.",
+    ),
+    (
+        "{}의 XML root 앞 내용이 올바르지 않습니다.",
+        "Invalid content before the XML root of {msg}.",
+    ),
+    (
+        "{}의 XML root 태그가 비어 있습니다.",
+        "The XML root tag of {msg} is empty.",
+    ),
+    (
+        "{}의 root namespace가 올바르지 않습니다.",
+        "Invalid root namespace in {msg}.",
+    ),
+    (
+        "{}의 prefixed core element는 지원하지 않습니다: {}",
+        "Prefixed core elements are not supported in {msg}: {}",
+    ),
+    (
+        "{}의 descendant namespace 재정의는 지원하지 않습니다.",
+        "Redefining namespaces on descendants is not supported in {msg}.",
+    ),
+    (
+        "{}의 XML 중첩 깊이가 너무 큽니다.",
+        "The XML nesting in {msg} is too deep.",
+    ),
+    (
+        "{}의 종료 태그 순서가 올바르지 않습니다.",
+        "The end tags in {msg} are out of order.",
+    ),
+    (
+        "{}의 XML 중첩 깊이가 손상되었습니다.",
+        "The XML nesting depth of {msg} is corrupted.",
+    ),
+    (
+        "{}의 XML 태그 쌍이 일치하지 않습니다: {} / {}",
+        "Mismatched XML tags in {msg}: {} / {}",
+    ),
+    (
+        "{}의 XML root 끝 계산 실패",
+        "Failed to find the end of the XML root of {msg}",
+    ),
+    (
+        "{}의 XML root 뒤 내용이 올바르지 않습니다.",
+        "Invalid content after the XML root of {msg}.",
+    ),
+    (
+        "{}에 닫히지 않은 XML 요소가 있습니다.",
+        "{msg} has an unclosed XML element.",
+    ),
+    (
+        "{}의 XML child 범위가 손상되었습니다.",
+        "The XML child range of {msg} is corrupted.",
+    ),
+    (
+        "{}의 XML 요소 사이 내용이 올바르지 않습니다.",
+        "Invalid content between XML elements of {msg}.",
+    ),
+    (
+        "{}의 XML child 태그는 중첩될 수 없습니다.",
+        "XML child tags of {msg} cannot be nested.",
+    ),
+    (
+        "{}의 prefixed child element는 지원하지 않습니다: {}",
+        "Prefixed child elements are not supported in {msg}: {}",
+    ),
+    (
+        "{}의 XML child 종료 태그가 일치하지 않습니다.",
+        "Mismatched XML child end tag in {msg}.",
+    ),
+    (
+        "{}의 XML root 종료 태그가 일치하지 않습니다.",
+        "Mismatched XML root end tag in {msg}.",
+    ),
+    (
+        "{}의 XML 종료 태그가 없습니다.",
+        "{msg} has no XML end tag.",
+    ),
+    (
+        "{}에 알 수 없는 {} 속성이 있습니다.",
+        "{msg} has an unknown {} attribute.",
+    ),
+    (
+        "{}에 {} 속성이 중복되었습니다.",
+        "{msg} has a duplicate {} attribute.",
+    ),
+    (
+        "{}에 중복 {} 속성이 있습니다.",
+        "{msg} has a duplicate {} attribute.",
+    ),
+    ("{}에 {} 속성이 없습니다.", "{msg} has no {} attribute."),
+    (
+        "{}의 {} 값이 고정 스키마와 다릅니다.",
+        "{msg}: the {} value differs from the fixed schema.",
+    ),
+    (
+        "{} 속성 수가 고정 스키마와 다릅니다.",
+        "The {msg} attribute count differs from the fixed schema.",
+    ),
+    (
+        "{} 요소를 찾지 못했습니다.",
+        "The {msg} element was not found.",
+    ),
+    (
+        "{}에 예상하지 않은 본문이 있습니다.",
+        "{msg} has an unexpected body.",
+    ),
+    ("{} 용량 계산 실패", "Failed to compute the {msg} capacity"),
+    (
+        "{}에 NUL 문자가 포함되어 있습니다",
+        "{msg} contains a NUL character",
+    ),
+    ("{} 해석 실패", "Failed to parse {msg}"),
+    (
+        "{} 실패: Windows error {}",
+        "{msg} failed: Windows error {}",
+    ),
+    ("{} 실패: {} ({})", "{msg} failed: {} ({})"),
+];
+// English templates copy captures verbatim unless the slot is `{msg}`, which marks a
+// capture that is itself a program message (an error, a stage or an option label).
+// Workbook content such as station names and column labels therefore stays Korean, and
+// a line that matches no whole template is printed as is rather than split at ": ".
+const MESSAGE_SLOT: &str = "{msg}";
+const PLAIN_SLOT: &str = "{}";
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub(super) enum Lang {
+    En,
+    Ko,
+}
+pub(super) struct LocalizedWriter {
+    inner: Stdout,
+    lang: Lang,
+    pending: Vec<u8>,
+}
+struct Template<'catalog>(&'catalog str);
+impl Lang {
+    pub(super) const fn help_text(self) -> &'static str {
+        match self {
+            Self::En => HELP_TEXT_EN,
+            Self::Ko => HELP_TEXT,
+        }
+    }
+    pub(super) fn translate(self, text: &str) -> Cow<'_, str> {
+        match self {
+            Self::En => Cow::Owned(english(text)),
+            Self::Ko => Cow::Borrowed(text),
+        }
+    }
+}
+impl LocalizedWriter {
+    fn write_translated(&mut self, line: &[u8]) -> io::Result<()> {
+        let Ok(text) = str::from_utf8(line) else {
+            return self.inner.write_all(line);
+        };
+        let (body, newline) = text
+            .strip_suffix('\n')
+            .map_or((text, ""), |body| (body, "\n"));
+        self.inner.write_all(english(body).as_bytes())?;
+        self.inner.write_all(newline.as_bytes())
+    }
+}
+impl Template<'_> {
+    fn render(&self, text: &str, translated: &str) -> Option<String> {
+        let segments: Vec<&str> = self.0.split(PLAIN_SLOT).collect();
+        let (&last, init) = segments.split_last()?;
+        let Some((&first, middle)) = init.split_first() else {
+            return (text == self.0).then(|| translated.to_owned());
+        };
+        let mut rest = text.strip_prefix(first)?.strip_suffix(last)?;
+        let mut captures = Vec::with_capacity(segments.len().strict_sub(1));
+        for segment in middle {
+            let (capture, after) = rest.split_once(segment)?;
+            captures.push(capture);
+            rest = after;
+        }
+        captures.push(rest);
+        let mut rendered = String::new();
+        let mut tail = translated;
+        for capture in captures {
+            let slot = match (tail.find(PLAIN_SLOT), tail.find(MESSAGE_SLOT)) {
+                (Some(plain), Some(message)) if message < plain => (message, MESSAGE_SLOT),
+                (Some(plain), _) => (plain, PLAIN_SLOT),
+                (None, Some(message)) => (message, MESSAGE_SLOT),
+                (None, None) => break,
+            };
+            let (before, slot_and_tail) = tail.split_at_checked(slot.0)?;
+            rendered.push_str(before);
+            if slot.1 == MESSAGE_SLOT {
+                rendered.push_str(&english(capture));
+            } else {
+                rendered.push_str(capture);
+            }
+            tail = slot_and_tail.strip_prefix(slot.1)?;
+        }
+        rendered.push_str(tail);
+        Some(rendered)
+    }
+}
+impl Default for Lang {
+    fn default() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .filter(|value| value.starts_with("en"))
+            .map_or(Self::Ko, |_| Self::En)
+    }
+}
+impl From<Lang> for LocalizedWriter {
+    fn from(lang: Lang) -> Self {
+        Self {
+            inner: stdout(),
+            lang,
+            pending: Vec::new(),
+        }
+    }
+}
+impl Write for LocalizedWriter {
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.pending.is_empty() {
+            let line = mem::take(&mut self.pending);
+            self.write_translated(&line)?;
+        }
+        self.inner.flush()
+    }
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.lang == Lang::Ko {
+            return self.inner.write(buf);
+        }
+        self.pending.extend_from_slice(buf);
+        while let Some(end) = self.pending.iter().position(|&byte| byte == b'\n') {
+            let line: Vec<u8> = self.pending.drain(..=end).collect();
+            self.write_translated(&line)?;
+        }
+        Ok(buf.len())
+    }
+}
+impl Drop for LocalizedWriter {
+    fn drop(&mut self) {
+        match self.flush() {
+            Ok(()) | Err(_) => {}
+        }
+    }
+}
+fn english(text: &str) -> String {
+    if let Some(body) = text
+        .strip_suffix(HELP_TEXT)
+        .or_else(|| text.strip_suffix(HELP_TEXT_EN))
+    {
+        let mut translated = english(body.trim_end_matches('\n'));
+        translated.push_str("\n\n");
+        translated.push_str(HELP_TEXT_EN);
+        return translated;
+    }
+    CATALOG
+        .iter()
+        .find_map(|&(korean, translated)| Template(korean).render(text, translated))
+        .unwrap_or_else(|| text.to_owned())
+}
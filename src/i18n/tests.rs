@@ -0,0 +1,191 @@
+use super::{CATALOG, Lang, MESSAGE_SLOT, PLAIN_SLOT};
+use crate::{
+    HELP_TEXT, HELP_TEXT_EN,
+    diagnostic::{Result, ensure},
+};
+use std::{fs, path::Path};
+const FILE_NAME_LABELS: [&str; 2] = ["변경{}_신규{}_폐업{}", "복원전_{}"];
+const FORMAT_MACROS: [&str; 4] = ["format!(", "format_args!(", "write!(", "writeln!("];
+#[test]
+fn every_format_string_has_a_catalog_entry() -> Result<()> {
+    let mut pending = vec![Path::new(env!("CARGO_MANIFEST_DIR")).join("src")];
+    let mut scanned = 0_usize;
+    let mut missing = Vec::new();
+    while let Some(dir) = pending.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            if path.extension().is_none_or(|ext| ext != "rs")
+                || path.file_name().is_some_and(|name| name == "tests.rs")
+            {
+                continue;
+            }
+            scanned = scanned.saturating_add(1);
+            let source = fs::read_to_string(&path)?;
+            for args in FORMAT_MACROS
+                .iter()
+                .flat_map(|name| source.split(name).skip(1))
+            {
+                let Some((head, after)) = args.split_once('"') else {
+                    continue;
+                };
+                if head.len() > 80 || head.contains(';') {
+                    continue;
+                }
+                let mut template = String::new();
+                let mut chars = after.chars().peekable();
+                while let Some(ch) = chars.next() {
+                    match ch {
+                        '"' => break,
+                        '\\' => match chars.next() {
+                            Some('n') => template.push('\n'),
+                            Some('t') => template.push('\t'),
+                            Some(escaped) => template.push(escaped),
+                            None => {}
+                        },
+                        '{' if chars.next_if_eq(&'{').is_some() => template.push('{'),
+                        '}' if chars.next_if_eq(&'}').is_some() => template.push('}'),
+                        '{' => {
+                            for inner in chars.by_ref() {
+                                if inner == '}' {
+                                    break;
+                                }
+                            }
+                            template.push_str(PLAIN_SLOT);
+                        }
+                        _ => template.push(ch),
+                    }
+                }
+                for line in template.split('\n') {
+                    if line.chars().any(|ch| ('가'..='힣').contains(&ch))
+                        && !FILE_NAME_LABELS.contains(&line)
+                        && !CATALOG.iter().any(|&(korean, _)| korean == line)
+                    {
+                        missing.push(format!("{}: {line}", path.display()));
+                    }
+                }
+            }
+        }
+    }
+    ensure(
+        scanned > 50,
+        format!("source scan found only {scanned} files"),
+    )?;
+    ensure(
+        missing.is_empty(),
+        format!(
+            "format strings without a catalog entry:\n{}",
+            missing.join("\n")
+        ),
+    )
+}
+#[test]
+fn catalog_templates_are_unique_and_keep_their_slots() -> Result<()> {
+    for (index, &(korean, english)) in CATALOG.iter().enumerate() {
+        ensure(
+            !CATALOG
+                .iter()
+                .take(index)
+                .any(|&(earlier, _)| earlier == korean),
+            format!("duplicate catalog entry: {korean}"),
+        )?;
+        ensure(
+            korean.matches(PLAIN_SLOT).count()
+                == english
+                    .replace(MESSAGE_SLOT, PLAIN_SLOT)
+                    .matches(PLAIN_SLOT)
+                    .count(),
+            format!("slot count differs: {korean}"),
+        )?;
+        ensure(
+            !korean.contains(MESSAGE_SLOT),
+            format!("Korean templates use plain slots only: {korean}"),
+        )?;
+    }
+    Ok(())
+}
+#[test]
+fn captured_values_are_not_translated() -> Result<()> {
+    for (korean, english) in [
+        ("     경유: 1550 -> 1520", "     경유: 1550 -> 1520"),
+        (
+            "     상표: (빈 값) -> SK에너지",
+            "     상표: (empty) -> SK에너지",
+        ),
+        (
+            "Opinet 소스의 대상 지역 휘발유 가격 값이 비정상적으로 부족합니다: 3건 / 40건",
+            "Too few 휘발유 가격 values for the target regions in the Opinet source: 3 of 40",
+        ),
+        (
+            "xlsx 숫자 셀 값 해석 실패: row=7, value=사용",
+            "Failed to parse an xlsx numeric cell: row=7, value=사용",
+        ),
+        ("- 파일: 기존", "- File: 기존"),
+        ("단계 시작: 비교", "Stage started: 비교"),
+        ("사용", "사용"),
+        ("알 수 없는 문장: 생략", "알 수 없는 문장: 생략"),
+        ("마스터 파일: (빈 값)", "마스터 파일: (빈 값)"),
+    ] {
+        ensure(
+            Lang::En.translate(korean) == english,
+            format!("translation of {korean}"),
+        )?;
+    }
+    Ok(())
+}
+#[test]
+fn message_slots_are_translated() -> Result<()> {
+    for (korean, english) in [
+        ("- 저장 검증: 생략", "- Save verification: skipped"),
+        (
+            "- 기존 파일: a.xlsx (업체 3건)",
+            "- Old file: a.xlsx (3 stations)",
+        ),
+        (
+            "--webhook 뒤에 웹훅 URL이 필요합니다",
+            "--webhook requires a webhook URL",
+        ),
+        (
+            "Opinet 자동 다운로드 실패: HTTP 응답 본문 수신 실패",
+            "Opinet automatic download failed: Failed to receive the HTTP response body",
+        ),
+        (
+            "충북 B027 최저가 목록 조회 실패",
+            "Request for 충북 B027 lowest-price list failed",
+        ),
+        (
+            "단계 완료: 소스 준비 (12 ms)",
+            "Stage finished: source preparation (12 ms)",
+        ),
+    ] {
+        ensure(
+            Lang::En.translate(korean) == english,
+            format!("translation of {korean}"),
+        )?;
+    }
+    Ok(())
+}
+#[test]
+fn korean_and_unknown_text_pass_through() -> Result<()> {
+    let text = "마스터 파일 처리 중...";
+    ensure(
+        Lang::Ko.translate(text) == text,
+        "Korean output is unchanged",
+    )?;
+    ensure(
+        Lang::En.translate("fcupdater 0.1.0") == "fcupdater 0.1.0",
+        "text without a catalog entry is unchanged",
+    )?;
+    for help in [HELP_TEXT, HELP_TEXT_EN] {
+        let usage = format!("알 수 없는 --version 옵션: --bogus\n\n{help}");
+        let translated = Lang::En.translate(&usage);
+        ensure(
+            translated == format!("Unknown --version option: --bogus\n\n{HELP_TEXT_EN}"),
+            format!("usage error keeps only the English help text: {translated}"),
+        )?;
+    }
+    Ok(())
+}
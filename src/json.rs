@@ -0,0 +1,343 @@
+use crate::diagnostic::{AppError, Result, append_fmt, err, err_with_source};
+use alloc::borrow::Cow;
+const MAX_JSON_DEPTH: usize = 64;
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum JsonKind {
+    Array,
+    False,
+    Null,
+    Number,
+    Object,
+    String,
+    True,
+}
+pub(super) struct JsonValue<'json> {
+    items: Vec<Self>,
+    keys: Vec<Cow<'json, str>>,
+    kind: JsonKind,
+    text: Cow<'json, str>,
+}
+struct JsonParser<'json> {
+    depth: usize,
+    pos: usize,
+    text: &'json str,
+}
+impl<'json> JsonValue<'json> {
+    pub(super) fn as_array(&self) -> Option<&[Self]> {
+        (self.kind == JsonKind::Array).then_some(self.items.as_slice())
+    }
+    pub(super) fn as_text(&self) -> Option<&str> {
+        matches!(self.kind, JsonKind::Number | JsonKind::String).then_some(self.text.as_ref())
+    }
+    const fn container(kind: JsonKind, keys: Vec<Cow<'json, str>>, items: Vec<Self>) -> Self {
+        Self {
+            items,
+            keys,
+            kind,
+            text: Cow::Borrowed(""),
+        }
+    }
+    pub(super) fn get(&self, key: &str) -> Option<&Self> {
+        if self.kind != JsonKind::Object {
+            return None;
+        }
+        let index = self.keys.iter().position(|name| name.as_ref() == key)?;
+        self.items.get(index)
+    }
+    pub(super) fn is_number(&self) -> bool {
+        self.kind == JsonKind::Number
+    }
+    const fn scalar(kind: JsonKind, text: Cow<'json, str>) -> Self {
+        Self {
+            items: Vec::new(),
+            keys: Vec::new(),
+            kind,
+            text,
+        }
+    }
+}
+impl<'json> TryFrom<&'json str> for JsonValue<'json> {
+    type Error = AppError;
+    fn try_from(text: &'json str) -> Result<Self> {
+        let mut parser = JsonParser {
+            depth: 0,
+            pos: 0,
+            text,
+        };
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.pos != text.len() {
+            return Err(err(format!(
+                "JSON 값 뒤에 불필요한 내용이 있습니다: offset={}",
+                parser.pos
+            )));
+        }
+        Ok(value)
+    }
+}
+impl<'json> JsonParser<'json> {
+    fn expect_literal(
+        &mut self,
+        literal: &'static str,
+        kind: JsonKind,
+    ) -> Result<JsonValue<'json>> {
+        if self
+            .text
+            .get(self.pos..)
+            .is_some_and(|rest| rest.starts_with(literal))
+        {
+            self.pos = self.pos.strict_add(literal.len());
+            Ok(JsonValue::scalar(kind, Cow::Borrowed(literal)))
+        } else {
+            Err(self.syntax_error("알 수 없는 literal"))
+        }
+    }
+    fn next_byte(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.pos = self.pos.strict_add(1);
+        Some(byte)
+    }
+    fn parse_array(&mut self) -> Result<JsonValue<'json>> {
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos = self.pos.strict_add(1);
+            return Ok(JsonValue::container(JsonKind::Array, Vec::new(), values));
+        }
+        loop {
+            let value = self.parse_value()?;
+            values
+                .try_reserve(1)
+                .map_err(|source| err_with_source("JSON 배열 메모리 확보 실패", source))?;
+            values.push(value);
+            self.skip_whitespace();
+            match self.next_byte() {
+                Some(b',') => {}
+                Some(b']') => {
+                    return Ok(JsonValue::container(JsonKind::Array, Vec::new(), values));
+                }
+                Some(_) | None => return Err(self.syntax_error("배열 구분자가 올바르지 않습니다")),
+            }
+        }
+    }
+    fn parse_hex4(&mut self) -> Result<u32> {
+        let mut code = 0_u32;
+        for _ in 0_u8..4 {
+            let digit = self
+                .next_byte()
+                .and_then(|byte| char::from(byte).to_digit(16))
+                .ok_or_else(|| self.syntax_error("\\u escape 형식이 올바르지 않습니다"))?;
+            code = code.strict_mul(16).strict_add(digit);
+        }
+        Ok(code)
+    }
+    fn parse_number(&mut self) -> Result<JsonValue<'json>> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos = self.pos.strict_add(1);
+        }
+        let int_start = self.pos;
+        self.skip_digits();
+        let int_len = self.pos.strict_sub(int_start);
+        let leading_zero = self.text.as_bytes().get(int_start) == Some(&b'0');
+        if int_len == 0 || (leading_zero && int_len > 1) {
+            return Err(self.syntax_error("숫자 형식이 올바르지 않습니다"));
+        }
+        if self.peek() == Some(b'.') {
+            self.pos = self.pos.strict_add(1);
+            if self.skip_digits() == 0 {
+                return Err(self.syntax_error("소수 형식이 올바르지 않습니다"));
+            }
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.pos = self.pos.strict_add(1);
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.pos = self.pos.strict_add(1);
+            }
+            if self.skip_digits() == 0 {
+                return Err(self.syntax_error("지수 형식이 올바르지 않습니다"));
+            }
+        }
+        self.text
+            .get(start..self.pos)
+            .map(|number| JsonValue::scalar(JsonKind::Number, Cow::Borrowed(number)))
+            .ok_or_else(|| self.syntax_error("숫자 범위가 올바르지 않습니다"))
+    }
+    fn parse_object(&mut self) -> Result<JsonValue<'json>> {
+        let mut keys = Vec::new();
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos = self.pos.strict_add(1);
+            return Ok(JsonValue::container(JsonKind::Object, keys, values));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.next_byte() != Some(b'"') {
+                return Err(self.syntax_error("객체 key가 문자열이 아닙니다"));
+            }
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            if self.next_byte() != Some(b':') {
+                return Err(self.syntax_error("객체 key 뒤에 ':'가 없습니다"));
+            }
+            let value = self.parse_value()?;
+            keys.try_reserve(1)
+                .and_then(|()| values.try_reserve(1))
+                .map_err(|source| err_with_source("JSON 객체 메모리 확보 실패", source))?;
+            keys.push(key);
+            values.push(value);
+            self.skip_whitespace();
+            match self.next_byte() {
+                Some(b',') => {}
+                Some(b'}') => {
+                    return Ok(JsonValue::container(JsonKind::Object, keys, values));
+                }
+                Some(_) | None => return Err(self.syntax_error("객체 구분자가 올바르지 않습니다")),
+            }
+        }
+    }
+    fn parse_string(&mut self) -> Result<Cow<'json, str>> {
+        let start = self.pos;
+        let mut owned: Option<String> = None;
+        let mut segment_start = start;
+        loop {
+            let byte = self
+                .next_byte()
+                .ok_or_else(|| self.syntax_error("문자열이 종료되지 않았습니다"))?;
+            match byte {
+                b'"' => {
+                    let segment = self
+                        .text
+                        .get(segment_start..self.pos.strict_sub(1))
+                        .ok_or_else(|| self.syntax_error("문자열 범위가 올바르지 않습니다"))?;
+                    return Ok(match owned {
+                        Some(mut text) => {
+                            text.try_reserve(segment.len()).map_err(|source| {
+                                err_with_source("JSON 문자열 메모리 확보 실패", source)
+                            })?;
+                            text.push_str(segment);
+                            Cow::Owned(text)
+                        }
+                        None => Cow::Borrowed(segment),
+                    });
+                }
+                b'\\' => {
+                    let segment = self
+                        .text
+                        .get(segment_start..self.pos.strict_sub(1))
+                        .ok_or_else(|| self.syntax_error("문자열 범위가 올바르지 않습니다"))?;
+                    let text = owned.get_or_insert_default();
+                    text.try_reserve(segment.len().strict_add(4))
+                        .map_err(|source| {
+                            err_with_source("JSON 문자열 메모리 확보 실패", source)
+                        })?;
+                    text.push_str(segment);
+                    let escaped = match self.next_byte() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            let high = self.parse_hex4()?;
+                            let code = if (0xD800..0xDC00).contains(&high) {
+                                if self.next_byte() != Some(b'\\') || self.next_byte() != Some(b'u')
+                                {
+                                    return Err(self.syntax_error("surrogate pair가 불완전합니다"));
+                                }
+                                let low = self.parse_hex4()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(self.syntax_error("surrogate pair가 불완전합니다"));
+                                }
+                                0x1_0000_u32
+                                    .strict_add(high.strict_sub(0xD800).strict_mul(0x400))
+                                    .strict_add(low.strict_sub(0xDC00))
+                            } else {
+                                high
+                            };
+                            char::from_u32(code)
+                                .ok_or_else(|| self.syntax_error("유효하지 않은 유니코드 escape"))?
+                        }
+                        Some(_) | None => {
+                            return Err(self.syntax_error("문자열 escape가 올바르지 않습니다"));
+                        }
+                    };
+                    text.push(escaped);
+                    segment_start = self.pos;
+                }
+                0x00..=0x1F => return Err(self.syntax_error("문자열에 제어 문자가 있습니다")),
+                _ => {}
+            }
+        }
+    }
+    fn parse_value(&mut self) -> Result<JsonValue<'json>> {
+        self.skip_whitespace();
+        let byte = self
+            .peek()
+            .ok_or_else(|| self.syntax_error("JSON 값이 없습니다"))?;
+        match byte {
+            b'{' | b'[' => {
+                if self.depth >= MAX_JSON_DEPTH {
+                    return Err(self.syntax_error("JSON 중첩 깊이가 허용 한도를 초과했습니다"));
+                }
+                self.depth = self.depth.strict_add(1);
+                self.pos = self.pos.strict_add(1);
+                let value = if byte == b'{' {
+                    self.parse_object()
+                } else {
+                    self.parse_array()
+                };
+                self.depth = self.depth.strict_sub(1);
+                value
+            }
+            b'"' => {
+                self.pos = self.pos.strict_add(1);
+                self.parse_string()
+                    .map(|text| JsonValue::scalar(JsonKind::String, text))
+            }
+            b't' => self.expect_literal("true", JsonKind::True),
+            b'f' => self.expect_literal("false", JsonKind::False),
+            b'n' => self.expect_literal("null", JsonKind::Null),
+            _ => self.parse_number(),
+        }
+    }
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+    fn skip_digits(&mut self) -> usize {
+        let start = self.pos;
+        while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            self.pos = self.pos.strict_add(1);
+        }
+        self.pos.strict_sub(start)
+    }
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos = self.pos.strict_add(1);
+        }
+    }
+    fn syntax_error(&self, detail: &str) -> AppError {
+        err(format!("JSON 형식 오류: {detail} (offset={})", self.pos))
+    }
+}
+pub(super) fn append_json_string(out: &mut String, text: &str) {
+    out.push('"');
+    for character in text.chars() {
+        match character {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            control if control.is_control() => {
+                append_fmt(out, format_args!("\\u{:04x}", u32::from(control)));
+            }
+            other => out.push(other),
+        }
+    }
+    out.push('"');
+}
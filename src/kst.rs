@@ -0,0 +1,67 @@
+use crate::diagnostic::{Result, err, err_with_source};
+use core::time::Duration;
+use std::time::{SystemTime, UNIX_EPOCH};
+pub(super) const SECS_PER_DAY: u64 = 86_400;
+const KST_OFFSET: Duration = Duration::from_hours(9);
+#[derive(Clone, Copy)]
+pub(super) struct KstTime {
+    pub secs: u64,
+}
+impl KstTime {
+    pub(super) fn date(self) -> String {
+        let shifted_days = self.secs.div_euclid(SECS_PER_DAY).strict_add(719_468);
+        let era = shifted_days.div_euclid(146_097);
+        let day_of_era = shifted_days.rem_euclid(146_097);
+        let year_of_era = day_of_era
+            .strict_sub(day_of_era.div_euclid(1_460))
+            .strict_add(day_of_era.div_euclid(36_524))
+            .strict_sub(day_of_era.div_euclid(146_096))
+            .div_euclid(365);
+        let year_base = year_of_era.strict_add(era.strict_mul(400));
+        let day_of_year = day_of_era.strict_sub(
+            365_u64
+                .strict_mul(year_of_era)
+                .strict_add(year_of_era.div_euclid(4))
+                .strict_sub(year_of_era.div_euclid(100)),
+        );
+        let march_month = 5_u64.strict_mul(day_of_year).strict_add(2).div_euclid(153);
+        let day = day_of_year
+            .strict_sub(153_u64.strict_mul(march_month).strict_add(2).div_euclid(5))
+            .strict_add(1);
+        let month = if march_month < 10 {
+            march_month.strict_add(3)
+        } else {
+            march_month.strict_sub(9)
+        };
+        let year = if month <= 2 {
+            year_base.strict_add(1)
+        } else {
+            year_base
+        };
+        format!("{year:04}-{month:02}-{day:02}")
+    }
+    pub(super) fn now() -> Result<Self> {
+        let since_epoch = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|source| err_with_source("현재 시간 조회 실패", source))?;
+        let kst = since_epoch
+            .checked_add(KST_OFFSET)
+            .ok_or_else(|| err("KST 날짜 초 계산 중 범위 오류가 발생했습니다."))?;
+        Ok(Self {
+            secs: kst.as_secs(),
+        })
+    }
+    pub(super) const fn seconds_of_day(self) -> u64 {
+        self.secs.rem_euclid(SECS_PER_DAY)
+    }
+    pub(super) fn timestamp(self) -> String {
+        let seconds_of_day = self.seconds_of_day();
+        format!(
+            "{}T{:02}:{:02}:{:02}+09:00",
+            self.date(),
+            seconds_of_day.div_euclid(3_600),
+            seconds_of_day.rem_euclid(3_600).div_euclid(60),
+            seconds_of_day.rem_euclid(60)
+        )
+    }
+}
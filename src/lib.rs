@@ -0,0 +1,739 @@
+//! 충청권 주유소 유류비 마스터 워크북(`fuel_cost_chungcheong.xlsx`)을 Opinet 가격 소스로 현행화합니다.
+//!
+//! [`fetch_source`]로 소스를 받고, [`SourceRecords::parse`]로 해석한 뒤, [`update_workbook`]으로
+//! 워크북에 반영합니다. 명령행 도구는 [`run_cli`]를 호출하는 얇은 래퍼입니다.
+extern crate alloc;
+use api_run::ApiRun;
+use backup::BackupStore;
+use cli::{
+    ApiOptions, BACKUP_KEEP, DiffOptions, ErrorFormat, ExportOptions, RestoreOptions, ServeOptions,
+    UpdateOptions,
+};
+use core::{
+    error::Error,
+    fmt::{self, Arguments, Display},
+    result::Result as CoreResult,
+};
+use diagnostic::{
+    AppError, ErrorCategory, ErrorReport, LocalizedError, Result, err, err_with_source,
+};
+use diff_run::{DiffFormat, DiffRun};
+use excel::{SaveVerification, StationService};
+use export_run::ExportRun;
+use i18n::{Lang, LocalizedWriter};
+use logging::{LogLevel, LogOptions};
+use master_sheet::validate_map_url_template;
+use restore_run::RestoreRun;
+use serve_run::ServeRun;
+use source_download::{OPEN_API_BASE_URL, SourceDownload};
+use std::{
+    ffi::{OsStr, OsString},
+    fs::{File, TryLockError},
+    io::{self, Write, stdout},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+cfg_select! {
+    target_os = "windows" => {
+        use std::os::windows::fs::OpenOptionsExt as _;
+    }
+    any(target_os = "linux", target_os = "macos") => {
+        use std::os::unix::fs::{MetadataExt as _, OpenOptionsExt as _};
+    }
+}
+use temp_entry::{configure_no_follow, validate_regular_file};
+use update_run::{LoadedSource, SourceInput, UpdateRun};
+use webhook::{EarlyWebhook, WEBHOOK_DEFAULT_TOP};
+mod api_run;
+mod backup;
+mod change_log;
+mod cli;
+mod diagnostic;
+mod diff_run;
+pub mod excel;
+mod export_run;
+mod geo;
+#[cfg(feature = "sqlite")]
+mod history;
+mod i18n;
+mod json;
+mod kst;
+mod logging;
+mod master_sheet;
+mod region;
+mod restore_run;
+mod serve_run;
+mod sha256;
+mod sheet_util;
+mod source_download;
+#[cfg(test)]
+mod stand_in;
+mod temp_entry;
+mod update_run;
+mod webhook;
+const APP_NAME: &str = env!("CARGO_PKG_NAME");
+const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+const HELP_TEXT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    " ",
+    env!("CARGO_PKG_VERSION"),
+    "\n주유소 가격/정보 현행화 (Excel 미설치 OK)\n\n",
+    "사용법:\n  ",
+    env!("CARGO_PKG_NAME"),
+    " [--verify] [--force] [--source-api | --source-file <경로>]\n",
+    "      [--history <경로>] [--ods <경로>] [--webhook <URL> [--webhook-top <N>]]\n",
+    "      [--map-links] [--map-url <템플릿>]\n",
+    "      [--geo-table <경로>] [--rank-by price|detour]\n",
+    "      [--backup-dir <경로>] [--backup-keep <N>] [--error-format human|json]\n",
+    "      [--lang en|ko] [-v | -q] [--log-file <경로>]\n  ",
+    env!("CARGO_PKG_NAME"),
+    " diff <기존.xlsx> <비교.xlsx> [--format human|json]\n  ",
+    env!("CARGO_PKG_NAME"),
+    " export [<마스터.xlsx>] [--format csv|json|ods] [--bom] [--output <경로>]\n  ",
+    env!("CARGO_PKG_NAME"),
+    " serve --schedule \"HH:MM[,HH:MM...] KST\" [--status <경로>] [현행화 옵션]\n  ",
+    env!("CARGO_PKG_NAME"),
+    " serve-api [<마스터.xlsx>] [--listen <주소:포트>]\n  ",
+    env!("CARGO_PKG_NAME"),
+    " restore [--list | <백업 시각>] [--backup-dir <경로>] [--backup-keep <N>]\n\n",
+    "고정 동작:\n",
+    "  마스터: fuel_cost_chungcheong.xlsx 직접 현행화\n",
+    "  소스: Opinet 현재 판매가격(주유소) 자동 다운로드 .xls\n",
+    "        (--source-api 사용 시 OPINET_API_KEY로 Opinet Open API 조회, 기존 업체 가격만 갱신)\n",
+    "        (--source-file 사용 시 xls/xlsx/CSV 파일 사용)\n",
+    "  변경내역 시트: 항상 갱신\n",
+    "  저장 검증: 기본 생략 (--verify 사용 시 수행)\n",
+    "  소스 변경 없음: 워크북에 기록된 소스 해시와 같으면 저장하지 않고 종료 코드 3으로 종료\n",
+    "  오류 종료 코드: 1 기타, 2 사용법, 10 다운로드, 11 소스 형식, 12 지역 검증,\n",
+    "                  13 마스터 구조, 14 안전 기준, 15 실행 잠금, 16 저장/교체\n",
+    "  백업: --backup-dir 또는 --backup-keep 지정 시 저장 전 마스터를 보관\n",
+    "        (이름은 교체한 실행의 KST 시각과 변경 요약, 최근 10개 유지,\n",
+    "         바뀐 업체가 없으면 생략)\n\n",
+    "명령:\n",
+    "  diff                     두 마스터 파일을 주소 기준으로 비교해 업체별 차이 출력\n",
+    "                           (--format json 사용 시 JSON 출력)\n",
+    "  export                   유류비 시트의 순위표와 계산값을 CSV(기본) 또는 JSON으로 출력\n",
+    "                           (--bom: Excel용 UTF-8 BOM 추가, --output: 파일로 저장)\n",
+    "                           (--format ods: 두 시트 전체를 LibreOffice용 .ods로 저장,\n",
+    "                            --output 필요)\n",
+    "  serve                    상주하며 --schedule의 KST 시각마다 현행화 실행\n",
+    "                           (실행 중에만 잠금 유지, 소스가 바뀌지 않았으면 건너뜀,\n",
+    "                            --status: 상태 파일 경로, 기본 fcupdater_status.json)\n",
+    "  serve-api                마스터 워크북을 읽어 읽기 전용 JSON API 제공\n",
+    "                           (GET /status, /ranking?region=&fuel=&limit=,\n",
+    "                            /stations?q=, /changes?region=&reason=&limit=,\n",
+    "                            --listen 기본 127.0.0.1:8080, 워크북 교체 시 다시 읽음)\n",
+    "  restore                  --list: 백업 목록 출력, <백업 시각>: 해당 백업으로 마스터 복원\n",
+    "                           (시각은 YYYYMMDDTHHMMSS 또는 백업 파일 이름,\n",
+    "                            복원 전 마스터도 백업으로 보관)\n\n",
+    "옵션:\n",
+    "  -h, --help               도움말\n",
+    "  --verify                 저장 후 임시 XLSX를 재열고 수식 cache를 재계산 검증한 뒤 승격\n",
+    "  --force                  소스 해시가 워크북과 같아도 현행화 후 저장\n",
+    "  --source-api             웹 다운로드 대신 Opinet Open API 사용(가격만 갱신)\n",
+    "  --source-file <경로>     웹 다운로드 대신 xls/xlsx/CSV(UTF-8, CP949) 소스 파일 사용\n",
+    "  --history <경로>         실행 이력 SQLite DB 경로 (기본: fuel_cost_history.sqlite,\n",
+    "                           sqlite 기능으로 빌드한 경우에만 사용 가능)\n",
+    "  --ods <경로>             마스터 저장 후 같은 내용을 OpenDocument(.ods)로도 저장\n",
+    "  --map-links              유류비 행 상호 셀에 주소로 만든 지도 검색 링크 추가\n",
+    "                           (기본 https://map.kakao.com/link/search/{address})\n",
+    "  --map-url <템플릿>       지도 링크 URL 템플릿 ({address}, {name} 자리에 URL 인코딩한\n",
+    "                           주소와 상호를 넣음, 지정하면 --map-links 없이도 링크 추가)\n",
+    "  --geo-table <경로>       주소별 위도/경도 좌표표 (CSV 또는 \"좌표\" 시트가 있는 xlsx),\n",
+    "                           F4:H12 출발지 기준 최근접 출발지/거리/우회 비용을 X:Z에 기록\n",
+    "  --rank-by <기준>         순위 기준 price(기본) 또는 detour (detour는 합계에 왕복 우회\n",
+    "                           비용을 더해 순위 결정, --geo-table 필요)\n",
+    "  --webhook <URL>          실행이 끝나면 결과 요약을 JSON으로 POST (실패 시에도 전송)\n",
+    "  --webhook-top <N>        웹훅에 담을 지역별 가격 인하/인상 상위 건수 (기본 5, 최대 50)\n",
+    "  --backup-dir <경로>      이전 마스터 백업 폴더 (기본: fcupdater_backups, 지정하면 백업 사용)\n",
+    "  --backup-keep <N>        보관할 백업 개수 (기본 10, 지정하면 백업 사용, 0이면 백업하지 않음)\n",
+    "  --error-format <형식>    오류 출력 형식 human(기본) 또는 json (모든 명령에서 사용 가능)\n",
+    "  --lang <언어>            출력 언어 ko 또는 en (모든 명령에서 사용 가능, 생략 시 LC_ALL,\n",
+    "                           LC_MESSAGES, LANG이 en으로 시작하면 en, 그 외 ko)\n",
+    "  -v, --verbose            단계별 소요 시간, HTTP 응답, 저장 단계 등 상세 로그 출력\n",
+    "  -q, --quiet              진행 메시지를 숨기고 경고와 결과 요약만 출력\n",
+    "  --log-file <경로>        로그를 파일에도 기록 (항상 상세 수준, 1MiB마다 교체해 5개 보관)\n",
+    "  --version                버전"
+);
+const HELP_TEXT_EN: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    " ",
+    env!("CARGO_PKG_VERSION"),
+    "\nFuel station price/info updater (no Excel installation required)\n\n",
+    "Usage:\n  ",
+    env!("CARGO_PKG_NAME"),
+    " [--verify] [--force] [--source-api | --source-file <path>]\n",
+    "      [--history <path>] [--ods <path>] [--webhook <URL> [--webhook-top <N>]]\n",
+    "      [--map-links] [--map-url <template>]\n",
+    "      [--geo-table <path>] [--rank-by price|detour]\n",
+    "      [--backup-dir <path>] [--backup-keep <N>] [--error-format human|json]\n",
+    "      [--lang en|ko] [-v | -q] [--log-file <path>]\n  ",
+    env!("CARGO_PKG_NAME"),
+    " diff <old.xlsx> <new.xlsx> [--format human|json]\n  ",
+    env!("CARGO_PKG_NAME"),
+    " export [<master.xlsx>] [--format csv|json|ods] [--bom] [--output <path>]\n  ",
+    env!("CARGO_PKG_NAME"),
+    " serve --schedule \"HH:MM[,HH:MM...] KST\" [--status <path>] [update options]\n  ",
+    env!("CARGO_PKG_NAME"),
+    " serve-api [<master.xlsx>] [--listen <host:port>]\n  ",
+    env!("CARGO_PKG_NAME"),
+    " restore [--list | <backup time>] [--backup-dir <path>] [--backup-keep <N>]\n\n",
+    "Fixed behavior:\n",
+    "  Master: updates fuel_cost_chungcheong.xlsx in place\n",
+    "  Source: downloads the Opinet current station prices .xls automatically\n",
+    "        (with --source-api, queries the Opinet Open API using OPINET_API_KEY\n",
+    "         and only refreshes prices of existing stations)\n",
+    "        (with --source-file, uses an xls/xlsx/CSV file)\n",
+    "  Change log sheet: always refreshed\n",
+    "  Save verification: skipped by default (performed with --verify)\n",
+    "  Source unchanged: if it matches the source hash recorded in the workbook,\n",
+    "        exits with code 3 without saving\n",
+    "  Error exit codes: 1 other, 2 usage, 10 download, 11 source format,\n",
+    "                    12 region validation, 13 master structure, 14 safety threshold,\n",
+    "                    15 run lock, 16 save/replace\n",
+    "  Backups: with --backup-dir or --backup-keep, keeps the master as it was before\n",
+    "        the save, named by the KST time and change summary of the replacing run\n",
+    "        (latest 10 kept, skipped when no station changed)\n\n",
+    "Commands:\n",
+    "  diff                     compare two master files by address and print per-station\n",
+    "                           differences (JSON output with --format json)\n",
+    "  export                   print the ranking table and computed values of the fuel cost\n",
+    "                           sheet as CSV (default) or JSON\n",
+    "                           (--bom: add a UTF-8 BOM for Excel, --output: write to a file)\n",
+    "                           (--format ods: save both sheets as a LibreOffice .ods,\n",
+    "                            requires --output)\n",
+    "  serve                    stay resident and run the update at each KST time in --schedule\n",
+    "                           (holds the lock only while running, skips unchanged sources,\n",
+    "                            --status: status file path, default fcupdater_status.json)\n",
+    "  serve-api                serve a read-only JSON API from the master workbook\n",
+    "                           (GET /status, /ranking?region=&fuel=&limit=,\n",
+    "                            /stations?q=, /changes?region=&reason=&limit=,\n",
+    "                            --listen default 127.0.0.1:8080, reloads on workbook replace)\n",
+    "  restore                  --list: list backups, <backup time>: restore the master from it\n",
+    "                           (time is YYYYMMDDTHHMMSS or a backup file name,\n",
+    "                            the master before restore is also kept as a backup)\n\n",
+    "Options:\n",
+    "  -h, --help               help\n",
+    "  --verify                 reopen the saved temporary XLSX and verify recalculated formula\n",
+    "                           caches before promoting it\n",
+    "  --force                  update and save even if the source hash matches the workbook\n",
+    "  --source-api             use the Opinet Open API instead of the web download\n",
+    "                           (prices only)\n",
+    "  --source-file <path>     use an xls/xlsx/CSV (UTF-8, CP949) source file instead of the\n",
+    "                           web download\n",
+    "  --history <path>         run history SQLite DB path (default: fuel_cost_history.sqlite,\n",
+    "                           only in builds with the sqlite feature)\n",
+    "  --ods <path>             after saving the master, also save it as OpenDocument (.ods)\n",
+    "  --map-links              link each fuel cost row's name cell to a map search for its\n",
+    "                           address (default https://map.kakao.com/link/search/{address})\n",
+    "  --map-url <template>     map link URL template ({address} and {name} are replaced with\n",
+    "                           the URL-encoded address and name; implies --map-links)\n",
+    "  --geo-table <path>       address latitude/longitude table (CSV, or xlsx with a \"좌표\"\n",
+    "                           sheet); writes the nearest F4:H12 depot, distance and detour\n",
+    "                           cost to columns X:Z\n",
+    "  --rank-by <basis>        rank by price (default) or detour (adds the round-trip detour\n",
+    "                           cost to the total; requires --geo-table)\n",
+    "  --webhook <URL>          POST a JSON run summary when the run ends (also on failure)\n",
+    "  --webhook-top <N>        top price cuts/raises per region in the webhook (default 5, max 50)\n",
+    "  --backup-dir <path>      previous master backup directory (default: fcupdater_backups,\n",
+    "                           enables backups)\n",
+    "  --backup-keep <N>        number of backups to keep (default 10, enables backups,\n",
+    "                           0 disables them)\n",
+    "  --error-format <format>  error output format human (default) or json (all commands)\n",
+    "  --lang <language>        output language ko or en (all commands; if omitted, en when\n",
+    "                           LC_ALL, LC_MESSAGES or LANG starts with en, otherwise ko)\n",
+    "  -v, --verbose            detailed logs: stage timings, HTTP responses, save phases\n",
+    "  -q, --quiet              hide progress messages; show only warnings and the summary\n",
+    "  --log-file <path>        also write logs to a file (always detailed, rotated at 1MiB, 5 kept)\n",
+    "  --version                version"
+);
+#[cfg(feature = "sqlite")]
+const HISTORY_PATH: &str = "fuel_cost_history.sqlite";
+const MASTER_PATH: &str = "fuel_cost_chungcheong.xlsx";
+const OPEN_API_KEY_ENV: &str = "OPINET_API_KEY";
+const RUN_LOCK_PATH: &str = ".fcupdater.lock";
+const UNCHANGED_EXIT_CODE: u8 = 3;
+#[cfg(target_os = "windows")]
+const RUN_LOCK_SHARE_MODE: u32 = 0x0000_0003;
+/// 해석과 검증을 마친 대상 지역 소스 레코드 모음입니다.
+pub struct SourceRecords(LoadedSource);
+/// 소스에서 읽은 대상 지역 주유소 한 곳의 정보입니다.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct StationRecord<'records> {
+    /// 주소.
+    pub address: &'records str,
+    /// 상표.
+    pub brand: &'records str,
+    /// 경유 가격(원). 판매하지 않으면 `None`입니다.
+    pub diesel: Option<i32>,
+    /// 휘발유 가격(원). 판매하지 않으면 `None`입니다.
+    pub gasoline: Option<i32>,
+    /// 상호.
+    pub name: &'records str,
+    /// 고급휘발유 가격(원). 판매하지 않으면 `None`입니다.
+    pub premium: Option<i32>,
+    /// 대상 지역 이름(예: `대전`, `청주`).
+    pub region: &'static str,
+    /// 셀프 주유소 여부. Open API처럼 소스가 알려 주지 않으면 `None`입니다.
+    pub self_service: Option<bool>,
+}
+/// [`update_workbook`] 실행 설정입니다. [`Default`]로 만든 뒤 필요한 필드만 바꿉니다.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct UpdateConfig {
+    /// 저장 전 이전 워크북을 보관할 폴더. `None`이면 백업하지 않습니다.
+    /// 백업 파일 이름은 그 워크북을 교체한 실행의 시각과 변경 요약이며, 바뀐 업체가 없으면 백업하지 않습니다.
+    pub backup_dir: Option<PathBuf>,
+    /// 보관할 백업 개수(기본 10).
+    pub backup_keep: usize,
+    /// 합계 금액에 최근접 출발지까지의 왕복 우회 비용을 더한 값으로 순위를 매깁니다.
+    /// 워크북 입력 영역의 출발지 좌표와 [`geo_table`](Self::geo_table)이 필요합니다.
+    pub detour_ranking: bool,
+    /// 소스 해시가 워크북에 기록된 값과 같아도 현행화합니다.
+    pub force: bool,
+    /// 주소별 위도·경도 좌표표(`주소,위도,경도` CSV 또는 `좌표` 시트가 있는 xlsx) 경로.
+    /// 출발지 좌표가 있으면 최근접 출발지, 거리, 우회 비용 열을 채웁니다.
+    pub geo_table: Option<PathBuf>,
+    /// 실행 이력을 기록할 `SQLite` DB 경로. `None`이면 기록하지 않습니다.
+    #[cfg(feature = "sqlite")]
+    pub history_path: Option<PathBuf>,
+    /// 유류비 행 상호 셀에 걸 지도 링크 URL 템플릿. `{address}`와 `{name}` 자리에 URL 인코딩한
+    /// 주소와 상호를 넣습니다. `None`이면 링크를 걸지 않습니다.
+    pub map_url: Option<String>,
+    /// 저장한 임시 워크북을 다시 열어 수식 cache를 검증한 뒤 교체합니다.
+    pub verify: bool,
+}
+/// [`update_workbook`] 실행 결과입니다.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct UpdateReport {
+    /// 새로 추가된 업체 수.
+    pub added: usize,
+    /// 정보나 가격이 바뀐 기존 업체 수.
+    pub changed: usize,
+    /// 폐업으로 삭제된 업체 수.
+    pub deleted: usize,
+    /// 적용한 소스의 내용 해시(SHA-256 hex).
+    pub source_hash: String,
+    /// 워크북을 저장했으면 `true`, 소스 변경이 없어 건너뛰었으면 `false`.
+    pub updated: bool,
+}
+/// 라이브러리 API가 돌려주는 오류입니다. 표시 문자열은 원인까지 이어진 한국어 메시지입니다.
+pub struct UpdaterError(AppError);
+impl SourceRecords {
+    /// Opinet Open API로 대상 지역 주유소 정보를 조회해 해석합니다.
+    ///
+    /// # Errors
+    ///
+    /// 조회에 실패하거나 응답이 대상 지역 검증을 통과하지 못하면 오류를 돌려줍니다.
+    #[inline]
+    pub fn fetch_open_api(api_key: &str) -> CoreResult<Self, UpdaterError> {
+        let source_data = SourceDownload::default()
+            .fetch_open_api(api_key, OPEN_API_BASE_URL)
+            .map_err(|download_err| {
+                UpdaterError(download_err.with_category(ErrorCategory::Download))
+            })?;
+        LoadedSource::parse(
+            SourceInput::OpenApi(source_data),
+            "Opinet Open API 응답 읽기 실패",
+        )
+        .map(Self)
+        .map_err(UpdaterError)
+    }
+    /// 대상 지역 레코드가 없으면 `true`입니다.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.index.is_empty()
+    }
+    /// 대상 지역 레코드 수입니다.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.index.len()
+    }
+    /// xls, xlsx 또는 CSV(UTF-8, CP949) 소스 바이트를 해석합니다.
+    ///
+    /// 대상 지역 레코드만 남기고, CLI와 같은 지역·필수 값 검증을 거칩니다.
+    ///
+    /// # Errors
+    ///
+    /// 형식을 읽을 수 없거나, 주소가 중복되거나, 대상 지역 검증을 통과하지 못하면 오류를 돌려줍니다.
+    #[inline]
+    pub fn parse(source_data: Vec<u8>) -> CoreResult<Self, UpdaterError> {
+        LoadedSource::parse(SourceInput::Bytes(source_data), "소스 파일 읽기 실패")
+            .map(Self)
+            .map_err(UpdaterError)
+    }
+    /// 대상 지역 레코드를 순회합니다. 순서는 정해져 있지 않습니다.
+    #[inline]
+    pub fn records(&self) -> impl Iterator<Item = StationRecord<'_>> {
+        self.0.index.values().map(|record| StationRecord {
+            address: &record.address,
+            brand: &record.brand,
+            diesel: record.fuels.diesel,
+            gasoline: record.fuels.gasoline,
+            name: &record.name,
+            premium: record.fuels.premium,
+            region: record.region,
+            self_service: match record.service {
+                StationService::General => Some(false),
+                StationService::SelfService => Some(true),
+                StationService::Unknown => None,
+            },
+        })
+    }
+    /// 레코드 내용으로 계산한 소스 해시(SHA-256 hex)입니다. 워크북에 기록된 값과 비교해 변경 여부를 판단합니다.
+    #[inline]
+    #[must_use]
+    pub fn source_hash(&self) -> &str {
+        &self.0.source_hash
+    }
+}
+impl UpdaterError {
+    /// 오류 분류 이름(`download`, `source_format`, `save` 등)입니다. 오류 JSON의 `category`와 같습니다.
+    #[inline]
+    #[must_use]
+    pub fn category(&self) -> &'static str {
+        self.0.category().code()
+    }
+    /// 오류 분류에 해당하는 CLI 종료 코드입니다.
+    #[inline]
+    #[must_use]
+    pub fn exit_code(&self) -> u8 {
+        self.0.category().exit_code()
+    }
+}
+impl Default for UpdateConfig {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            backup_dir: None,
+            backup_keep: BACKUP_KEEP,
+            detour_ranking: false,
+            force: false,
+            geo_table: None,
+            #[cfg(feature = "sqlite")]
+            history_path: None,
+            map_url: None,
+            verify: false,
+        }
+    }
+}
+impl Display for UpdaterError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+impl fmt::Debug for SourceRecords {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SourceRecords")
+            .field("len", &self.0.index.len())
+            .field("source_hash", &self.0.source_hash)
+            .finish_non_exhaustive()
+    }
+}
+impl fmt::Debug for UpdaterError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+impl Error for UpdaterError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+fn acquire_run_lock() -> Result<File> {
+    let mut lock_options = File::options();
+    lock_options
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false);
+    configure_no_follow(&mut lock_options);
+    cfg_select! {
+        target_os = "windows" => {
+            lock_options.share_mode(RUN_LOCK_SHARE_MODE);
+        }
+        any(target_os = "linux", target_os = "macos") => {
+            lock_options.mode(0o600);
+        }
+    }
+    let run_lock = lock_options
+        .open(Path::new(RUN_LOCK_PATH))
+        .map_err(|source| err_with_source("실행 잠금 파일 열기 실패", source))?;
+    cfg_select! {
+        target_os = "windows" => {
+            validate_regular_file(&run_lock)
+                .map(|_| ())
+                .map_err(|source| err_with_source("실행 잠금 파일 검증 실패", source))?;
+        }
+        any(target_os = "linux", target_os = "macos") => {
+            let (lock_metadata, _) = validate_regular_file(&run_lock)
+                .map_err(|source| err_with_source("실행 잠금 파일 검증 실패", source))?;
+            if lock_metadata.mode() & 0o022 != 0 {
+                return Err(err(
+                    "실행 잠금 파일은 group/other 쓰기 권한이 없어야 합니다.",
+                ));
+            }
+        }
+        _ => {}
+    }
+    match run_lock.try_lock() {
+        Ok(()) => Ok(run_lock),
+        Err(TryLockError::WouldBlock) => {
+            Err(err("다른 fcupdater 실행이 진행 중입니다.").with_category(ErrorCategory::LockHeld))
+        }
+        Err(TryLockError::Error(source)) => Err(err_with_source("실행 잠금 획득 실패", source)),
+    }
+}
+/// Opinet 현재 판매가격(주유소) 페이지에서 소스 xls를 내려받습니다.
+///
+/// # Errors
+///
+/// 네트워크 요청, 대기열(`NetFunnel`) 통과 또는 응답 검증에 실패하면 오류를 돌려줍니다.
+#[inline]
+pub fn fetch_source() -> CoreResult<Vec<u8>, UpdaterError> {
+    SourceDownload::default()
+        .refresh_source()
+        .map_err(|download_err| UpdaterError(download_err.with_category(ErrorCategory::Download)))
+}
+/// 명령행 인자(프로그램 이름 제외)로 `fcupdater` 명령을 실행하고 종료 코드를 돌려줍니다.
+#[inline]
+#[must_use]
+pub fn run_cli(mut arg_list: Vec<OsString>) -> ExitCode {
+    let parsed_lang = Lang::try_from(&mut arg_list);
+    let lang = parsed_lang
+        .as_ref()
+        .map_or_else(|_| Lang::default(), |&lang| lang);
+    let mut raw_out = stdout();
+    let mut out = LocalizedWriter::from(lang);
+    let parsed_format = ErrorFormat::try_from(&mut arg_list);
+    let parsed_log = LogOptions::try_from(&mut arg_list);
+    let error_format = parsed_format
+        .as_ref()
+        .map_or(ErrorFormat::Human, |&format| format);
+    let mut early_webhook = EarlyWebhook::from((arg_list.as_slice(), lang));
+    let usage = |cli_err: AppError| cli_err.with_category(ErrorCategory::Usage);
+    let result = (|| -> Result<ExitCode> {
+        parsed_lang.map_err(usage)?;
+        parsed_format.map_err(usage)?;
+        parsed_log.map_err(usage)?.install(lang)?;
+        let mut raw_args = arg_list.into_iter().peekable();
+        if let Some(first) = raw_args.peek() {
+            let is_help = first == OsStr::new("-h") || first == OsStr::new("--help");
+            let is_version = first == OsStr::new("--version");
+            if is_help || is_version {
+                raw_args.next();
+                if let Some(extra) = raw_args.next() {
+                    let context = if is_version {
+                        "알 수 없는 --version 옵션"
+                    } else {
+                        "알 수 없는 옵션"
+                    };
+                    return Err(usage(err(format!(
+                        "{context}: {}\n\n{}",
+                        extra.to_string_lossy(),
+                        lang.help_text()
+                    ))));
+                }
+                if is_help {
+                    write_line(&mut out, format_args!("{}", lang.help_text()))?;
+                } else {
+                    write_line(&mut out, format_args!("{APP_NAME} {APP_VERSION}"))?;
+                }
+                return Ok(ExitCode::SUCCESS);
+            }
+        }
+        if raw_args
+            .next_if(|first| first == OsStr::new("diff"))
+            .is_some()
+        {
+            let options = DiffOptions::try_from(&mut raw_args).map_err(usage)?;
+            return DiffRun {
+                format: options.format,
+                new_path: &options.new_path,
+                old_path: &options.old_path,
+                out: match options.format {
+                    DiffFormat::Human => &mut out,
+                    DiffFormat::Json => &mut raw_out,
+                },
+            }
+            .run()
+            .map(|()| ExitCode::SUCCESS);
+        }
+        if raw_args
+            .next_if(|first| first == OsStr::new("serve"))
+            .is_some()
+        {
+            let options = ServeOptions::try_from(&mut raw_args).map_err(usage)?;
+            early_webhook.url = None;
+            return ServeRun {
+                schedule: &options.schedule,
+                status_path: &options.status_path,
+                update: options.update.update_run(lang, &mut out),
+            }
+            .run()
+            .map(|()| ExitCode::SUCCESS);
+        }
+        if raw_args
+            .next_if(|first| first == OsStr::new("export"))
+            .is_some()
+        {
+            let options = ExportOptions::try_from(&mut raw_args).map_err(usage)?;
+            return ExportRun {
+                bom: options.bom,
+                format: options.format,
+                master_path: &options.master_path,
+                out: if options.output_path.is_some() {
+                    &mut out
+                } else {
+                    &mut raw_out
+                },
+                output_path: options.output_path.as_deref(),
+            }
+            .run()
+            .map(|()| ExitCode::SUCCESS);
+        }
+        if raw_args
+            .next_if(|first| first == OsStr::new("serve-api"))
+            .is_some()
+        {
+            let options = ApiOptions::try_from(&mut raw_args).map_err(usage)?;
+            return ApiRun {
+                listen: &options.listen,
+                master_path: &options.master_path,
+                out: &mut out,
+            }
+            .run()
+            .map(|()| ExitCode::SUCCESS);
+        }
+        if raw_args
+            .next_if(|first| first == OsStr::new("restore"))
+            .is_some()
+        {
+            let options = RestoreOptions::try_from(&mut raw_args).map_err(usage)?;
+            return RestoreRun {
+                backups: BackupStore {
+                    dir: &options.backup_dir,
+                    keep: options.backup_keep,
+                },
+                master_path: Path::new(MASTER_PATH),
+                out: &mut out,
+                target: options.target.as_deref(),
+            }
+            .run()
+            .map(|()| ExitCode::SUCCESS);
+        }
+        let options = UpdateOptions::try_from(&mut raw_args).map_err(usage)?;
+        let _run_lock = acquire_run_lock()?;
+        early_webhook.url = None;
+        let outcome = options.update_run(lang, &mut out).run()?;
+        Ok(if outcome.updated {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::from(UNCHANGED_EXIT_CODE)
+        })
+    })();
+    match result {
+        Ok(exit_code) => exit_code,
+        Err(run_err) => {
+            logging::log(LogLevel::Error, format_args!("{run_err}"));
+            let mut stderr = io::stderr();
+            let reported = match error_format {
+                ErrorFormat::Human => write_line(
+                    &mut stderr,
+                    format_args!(
+                        "Error: {}",
+                        LocalizedError {
+                            error: &run_err,
+                            lang
+                        }
+                    ),
+                ),
+                ErrorFormat::Json => write_line(
+                    &mut stderr,
+                    format_args!(
+                        "{}",
+                        ErrorReport {
+                            error: &run_err,
+                            lang
+                        }
+                    ),
+                ),
+            };
+            match reported {
+                Ok(()) | Err(_) => {}
+            }
+            early_webhook.notify(&run_err);
+            ExitCode::from(run_err.category().exit_code())
+        }
+    }
+}
+/// 해석한 소스를 `master_path`의 마스터 워크북에 반영하고 변경내역 시트를 갱신해 저장합니다.
+///
+/// 저장은 임시 파일을 쓴 뒤 원자적으로 교체합니다. CLI와 달리 실행 잠금을 잡지 않으므로,
+/// 같은 워크북을 동시에 갱신하지 않도록 호출하는 쪽에서 조정해야 합니다.
+///
+/// # Errors
+///
+/// 워크북 구조가 올바르지 않거나, 안전 기준을 넘거나, 저장·백업·이력 기록에 실패하면 오류를 돌려줍니다.
+#[inline]
+pub fn update_workbook(
+    master_path: &Path,
+    records: &SourceRecords,
+    config: &UpdateConfig,
+) -> CoreResult<UpdateReport, UpdaterError> {
+    if let Some(template) = config.map_url.as_deref() {
+        validate_map_url_template(template).map_err(UpdaterError)?;
+    }
+    let mut sink = io::sink();
+    let outcome = UpdateRun {
+        backups: BackupStore {
+            dir: config
+                .backup_dir
+                .as_deref()
+                .unwrap_or_else(|| Path::new("")),
+            keep: config.backup_dir.as_ref().map_or(0, |_| config.backup_keep),
+        },
+        detour_ranking: config.detour_ranking,
+        force: config.force,
+        geo_table_path: config.geo_table.as_deref(),
+        #[cfg(feature = "sqlite")]
+        history_path: config.history_path.as_deref(),
+        map_url: config.map_url.as_deref(),
+        master_path,
+        ods_path: None,
+        open_api_key: None,
+        out: &mut sink,
+        save_verification: if config.verify {
+            SaveVerification::Verify
+        } else {
+            SaveVerification::Skip
+        },
+        source_path: None,
+        webhook: None,
+        webhook_top: WEBHOOK_DEFAULT_TOP,
+    }
+    .apply(&records.0)
+    .map_err(UpdaterError)?;
+    let (added, changed, deleted) = outcome.summary.map_or((0, 0, 0), |summary| {
+        (summary.added, summary.changed, summary.deleted)
+    });
+    Ok(UpdateReport {
+        added,
+        changed,
+        deleted,
+        source_hash: outcome.source_hash,
+        updated: outcome.updated,
+    })
+}
+fn write_line(output: &mut dyn Write, args: Arguments<'_>) -> io::Result<()> {
+    output.write_fmt(args)?;
+    output.write_all(b"\n")
+}
@@ -0,0 +1,192 @@
+#[cfg(target_os = "windows")]
+use crate::temp_entry::configure_replaceable_file;
+use crate::{
+    diagnostic::{Result, err_with_source, path_context_message, terminal_safe},
+    i18n::Lang,
+    kst::KstTime,
+    temp_entry::{configure_no_follow, validate_regular_file},
+};
+use core::fmt::Arguments;
+use std::{
+    ffi::OsString,
+    fs::{File, rename},
+    io::{self, Write as _, stderr},
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+    time::Instant,
+};
+const LOG_FILE_KEEP: usize = 5;
+const LOG_FILE_MAX_BYTES: u64 = 1024 * 1024;
+static LOGGER: OnceLock<Logger> = OnceLock::new();
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub(super) enum LogLevel {
+    Debug = 3,
+    Error = 0,
+    Info = 2,
+    Warn = 1,
+}
+pub(super) struct LogOptions {
+    pub file: Option<PathBuf>,
+    pub level: LogLevel,
+}
+pub(super) struct LogStage {
+    label: &'static str,
+    started: Instant,
+}
+struct LogFile {
+    file: File,
+    path: PathBuf,
+    written: u64,
+}
+struct Logger {
+    console: LogLevel,
+    file: Option<Mutex<LogFile>>,
+    lang: Lang,
+}
+impl LogFile {
+    fn append(&mut self, level: LogLevel, message: &str) {
+        let Ok(now) = KstTime::now() else {
+            return;
+        };
+        let line = format!(
+            "{} {} {}\n",
+            now.timestamp(),
+            level.tag(),
+            terminal_safe(message)
+        );
+        let line_len = u64::try_from(line.len()).unwrap_or(u64::MAX);
+        if self.written != 0 && self.written.saturating_add(line_len) > LOG_FILE_MAX_BYTES {
+            self.rotate();
+        }
+        if self.file.write_all(line.as_bytes()).is_ok() {
+            self.written = self.written.saturating_add(line_len);
+        }
+    }
+    fn rotate(&mut self) {
+        for index in (1..LOG_FILE_KEEP).rev() {
+            match rename(
+                rotated_path(&self.path, index),
+                rotated_path(&self.path, index.strict_add(1)),
+            ) {
+                Ok(()) | Err(_) => {}
+            }
+        }
+        match rename(&self.path, rotated_path(&self.path, 1)) {
+            Ok(()) | Err(_) => {}
+        }
+        if let Ok((file, written)) = open_log_file(&self.path) {
+            self.file = file;
+            self.written = written;
+        }
+    }
+}
+impl LogLevel {
+    const fn tag(self) -> &'static str {
+        match self {
+            Self::Debug => "DEBUG",
+            Self::Error => "ERROR",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+        }
+    }
+}
+impl LogOptions {
+    pub(super) fn install(self, lang: Lang) -> Result<()> {
+        let file = self
+            .file
+            .map(|path| {
+                open_log_file(&path)
+                    .map(|(file, written)| {
+                        Mutex::new(LogFile {
+                            file,
+                            path: path.clone(),
+                            written,
+                        })
+                    })
+                    .map_err(|source| {
+                        err_with_source(path_context_message("로그 파일 열기 실패", &path), source)
+                    })
+            })
+            .transpose()?;
+        match LOGGER.set(Logger {
+            console: self.level,
+            file,
+            lang,
+        }) {
+            Ok(()) | Err(_) => {}
+        }
+        Ok(())
+    }
+}
+impl LogStage {
+    pub(super) fn finish(self) {
+        debug(format_args!(
+            "단계 완료: {} ({} ms)",
+            self.label,
+            self.started.elapsed().as_millis()
+        ));
+    }
+    pub(super) fn start(label: &'static str) -> Self {
+        debug(format_args!("단계 시작: {label}"));
+        Self {
+            label,
+            started: Instant::now(),
+        }
+    }
+}
+pub(super) fn debug(args: Arguments<'_>) {
+    log(LogLevel::Debug, args);
+}
+pub(super) fn info(args: Arguments<'_>) {
+    log(LogLevel::Info, args);
+}
+pub(super) fn log(level: LogLevel, args: Arguments<'_>) {
+    let Some(logger) = LOGGER.get() else {
+        return;
+    };
+    let console = level != LogLevel::Error && level <= logger.console;
+    if !console && logger.file.is_none() {
+        return;
+    }
+    let message = args.to_string();
+    let localized = logger.lang.translate(&message);
+    if console {
+        let mut console_out = stderr().lock();
+        let written = if level == LogLevel::Info {
+            writeln!(console_out, "{}", terminal_safe(&localized))
+        } else {
+            writeln!(
+                console_out,
+                "[{}] {}",
+                level.tag(),
+                terminal_safe(&localized)
+            )
+        };
+        match written {
+            Ok(()) | Err(_) => {}
+        }
+    }
+    if let Some(file) = logger.file.as_ref()
+        && let Ok(mut log_file) = file.lock()
+    {
+        log_file.append(level, &localized);
+    }
+}
+fn open_log_file(path: &Path) -> io::Result<(File, u64)> {
+    let mut options = File::options();
+    options.append(true).create(true);
+    configure_no_follow(&mut options);
+    #[cfg(target_os = "windows")]
+    configure_replaceable_file(&mut options);
+    let file = options.open(path)?;
+    let (metadata, _) = validate_regular_file(&file)?;
+    Ok((file, metadata.len()))
+}
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(format!(".{index}"));
+    PathBuf::from(name)
+}
+pub(super) fn warn(args: Arguments<'_>) {
+    log(LogLevel::Warn, args);
+}
@@ -1,11 +1,16 @@
-use self::format::{format_scaled_value_into, format_unit_price_text_into};
+use self::{
+    detour::{DetourBasis, DetourColumns, DetourEstimate},
+    format::{format_scaled_value_into, format_unit_price_text_into},
+};
 use crate::{
     diagnostic::{
-        Result, append_fmt, err, err_with_source, try_string_with_capacity, try_vec_with_capacity,
+        AppError, Result, append_fmt, err, err_with_source, try_string_with_capacity,
+        try_vec_with_capacity,
     },
     excel,
     excel::writer::{SharedStringTable, Workbook as StdWorkbook},
     excel::{FuelValues, SourceRecord},
+    geo::GeoTable,
     region::{
         TARGET_REGION_COUNT, TargetRegion, TargetRegionPolicy, increment_target_region_count,
         normalize_address_key_into, target_region,
@@ -15,6 +20,7 @@ use crate::{
 use alloc::borrow::Cow;
 use core::{fmt::Arguments, mem, range::RangeInclusive};
 use std::collections::{HashMap, hash_map::Entry};
+mod detour;
 mod format;
 const MASTER_HEADER_ROW: u32 = 14;
 const MASTER_DATA_START_ROW: u32 = 15;
@@ -26,6 +32,7 @@ const COL_SELF_YN: u32 = 5;
 const COL_ADDRESS: u32 = 6;
 const COL_GASOLINE: u32 = 7;
 const COL_PREMIUM: u32 = 8;
+const COL_ULTRA_KAGEN: u32 = 9;
 const COL_DIESEL: u32 = 10;
 const COL_SMART_DISCOUNT: u32 = 11;
 const COL_ADJUSTED_GASOLINE: u32 = 12;
@@ -40,19 +47,63 @@ const COL_REGIONAL_TOTAL: u32 = 20;
 const COL_UNIT_PRICE_WITH_CURRENCY: u32 = 21;
 const COL_UNIT_PRICE_WITHOUT_CURRENCY: u32 = 22;
 const COL_SORT_KEY: u32 = 23;
+const MASTER_TABLE_COLUMNS: [(u32, &str, bool); 22] = [
+    (COL_RANK, "rank", true),
+    (COL_REGION, "region", false),
+    (COL_NAME, "name", false),
+    (COL_BRAND, "brand", false),
+    (COL_SELF_YN, "self_service", false),
+    (COL_ADDRESS, "address", false),
+    (COL_GASOLINE, "gasoline", true),
+    (COL_PREMIUM, "premium", true),
+    (COL_ULTRA_KAGEN, "ultra_kagen", false),
+    (COL_DIESEL, "diesel", true),
+    (COL_SMART_DISCOUNT, "smart_discount", true),
+    (COL_ADJUSTED_GASOLINE, "adjusted_gasoline", true),
+    (COL_ADJUSTED_PREMIUM, "adjusted_premium", true),
+    (COL_ADJUSTED_DIESEL, "adjusted_diesel", true),
+    (COL_FUEL_TOTAL_TEXT, "fuel_total_text", false),
+    (COL_TOTAL_PRICE, "total_price", true),
+    (COL_CURRENCY_APPLY, "local_currency_apply", false),
+    (COL_REGION_RATE, "local_currency_rate", true),
+    (COL_REGION_DISCOUNT, "regional_discount", true),
+    (COL_REGIONAL_TOTAL, "regional_total", true),
+    (
+        COL_UNIT_PRICE_WITH_CURRENCY,
+        "unit_price_with_currency",
+        true,
+    ),
+    (
+        COL_UNIT_PRICE_WITHOUT_CURRENCY,
+        "unit_price_without_currency",
+        true,
+    ),
+];
 const MASTER_FORMULA_BUFFER_CAPACITY: usize = 512;
 const USIZE_DECIMAL_TEXT_MAX_LEN: usize = 20;
 const SMART_DISCOUNT_BRAND_KEYWORD: &str = "현대오일뱅크";
 const SMART_DISCOUNT_DIRECT_KEYWORD: &str = "직영";
 const SMART_DISCOUNT_INPUT_COL: u32 = 2;
 const SMART_DISCOUNT_INPUT_ROW: u32 = 13;
+pub(super) const MAP_URL_DEFAULT: &str = "https://map.kakao.com/link/search/{address}";
+const MAP_URL_ADDRESS_PLACEHOLDER: &str = "{address}";
+const MAP_URL_NAME_PLACEHOLDER: &str = "{name}";
 const DECIMAL_SCALE: ScaledDecimal = ScaledDecimal(1_000_000);
 const DECIMAL_SCALE_SQUARED: ScaledSortKey = ScaledSortKey(1_000_000_000_000);
 const DECIMAL_SCALE_CUBED: ScaledSortKey = ScaledSortKey(1_000_000_000_000_000_000);
 type RowRange = RangeInclusive<u32>;
-pub(super) struct MasterSheetUpdater<'source> {
+pub(super) struct MasterSheetUpdater<'source, 'template> {
+    pub detour_ranking: bool,
+    pub geo_table: Option<&'template GeoTable>,
+    pub map_url: Option<&'template str>,
+    pub prices_only: bool,
     pub source_index: &'source HashMap<String, SourceRecord>,
 }
+pub(super) struct UpdateSettings<'run> {
+    pub detour_ranking: bool,
+    pub geo_table: Option<&'run GeoTable>,
+    pub map_url: Option<&'run str>,
+}
 pub(super) struct ChangeRow<'source> {
     pub old_fuels: FuelValues<Option<i32>>,
     pub reason: String,
@@ -71,7 +122,37 @@ pub(super) struct MasterSheetUpdateResult<'source> {
     pub deleted: Vec<StoreRow>,
     pub existing_count: usize,
     pub existing_region_counts: [usize; TARGET_REGION_COUNT],
+    pub kept: Vec<&'source SourceRecord>,
     pub matched_existing_region_counts: [usize; TARGET_REGION_COUNT],
+    pub unaddressed_count: usize,
+    pub untouched_count: usize,
+}
+pub(super) struct MasterStation {
+    pub address: String,
+    pub address_key: String,
+    pub brand: String,
+    pub fuels: FuelValues<Option<i32>>,
+    pub name: String,
+    pub region: String,
+    pub row: u32,
+    pub self_service: String,
+}
+pub(super) struct MasterTableColumn {
+    pub header: String,
+    pub key: &'static str,
+    pub numeric: bool,
+}
+pub(super) struct MasterTableRow {
+    pub row: u32,
+    pub values: Vec<String>,
+}
+pub(super) struct MasterTable {
+    pub columns: Vec<MasterTableColumn>,
+    pub rows: Vec<MasterTableRow>,
+}
+pub(super) struct MasterStations {
+    pub stations: Vec<MasterStation>,
+    pub unaddressed_count: usize,
 }
 #[derive(Clone, Copy, Eq, PartialEq)]
 struct ScaledDecimal(i64);
@@ -127,10 +208,14 @@ enum MasterRowDecision<'source> {
         src: &'source SourceRecord,
     },
     Unaddressed,
+    Untouched {
+        normalized_address: String,
+    },
 }
 struct SortableRankRow<'text> {
     address: &'text str,
     adjusted_prices: AdjustedFuelPrices,
+    detour: Option<DetourEstimate>,
     fuels: FuelValues<ScaledSortKey>,
     name: &'text str,
     rank_total: Option<ScaledSortKey>,
@@ -138,10 +223,12 @@ struct SortableRankRow<'text> {
     region_rate: ScaledDecimal,
     smart_discount: ScaledDecimal,
     smart_discount_excluded: bool,
+    sort_total: Option<ScaledSortKey>,
     source_index: usize,
 }
-struct RankSortRefresher<'sheet, 'strings> {
+struct RankSortRefresher<'sheet, 'strings, 'basis> {
     data_rows: RowRange,
+    detour_basis: Option<&'basis DetourBasis<'basis>>,
     shared_strings: &'strings SharedStringTable,
     ws: &'sheet mut excel::writer::Worksheet,
 }
@@ -161,6 +248,198 @@ impl RankSortContext {
         region.value(&self.region_rates)
     }
 }
+impl MasterSheetUpdateResult<'_> {
+    pub(super) fn verify_saved(&self, book: &StdWorkbook) -> Result<()> {
+        let (ws, shared_strings) = book.master_sheet();
+        let mut saved_address_rows: HashMap<String, u32> = HashMap::new();
+        saved_address_rows
+            .try_reserve(ws.row_count())
+            .map_err(|source| err_with_source("저장 검증 주소 행 맵 메모리 확보 실패", source))?;
+        let mut address_key_scratch = String::new();
+        let mut saved_unaddressed_count = 0_usize;
+        let mut ranked_count = 0_usize;
+        let mut previous_rank = None;
+        let mut unranked_row = None;
+        for row in ws.row_numbers_from(MASTER_DATA_START_ROW)? {
+            let address = trim_cow(ws.try_get_display_at(COL_ADDRESS, row, shared_strings)?);
+            if address.is_empty()
+                && !ws.row_has_any_data(row, &[COL_REGION, COL_NAME], shared_strings)?
+            {
+                continue;
+            }
+            if let Some(rank) = ws.get_i32_at(COL_RANK, row, shared_strings)? {
+                ranked_count = ranked_count.strict_add(1);
+                if let Some(blank_row) = unranked_row {
+                    return Err(err(format!(
+                        "유류비 순위가 있는 행이 순위 없는 행 뒤에 있습니다: row={row}, 순위 없는 행={blank_row}"
+                    )));
+                }
+                if previous_rank != Some(rank) && usize::try_from(rank).ok() != Some(ranked_count) {
+                    return Err(err(format!(
+                        "유류비 순위가 정렬 순서와 맞지 않습니다: row={row}, rank={rank}, previous={previous_rank:?}, position={ranked_count}"
+                    )));
+                }
+                previous_rank = Some(rank);
+            } else {
+                unranked_row = unranked_row.or(Some(row));
+            }
+            if address.is_empty() {
+                saved_unaddressed_count = saved_unaddressed_count.strict_add(1);
+                continue;
+            }
+            normalize_address_key_into(address.as_ref(), &mut address_key_scratch)?;
+            match saved_address_rows.entry(mem::take(&mut address_key_scratch)) {
+                Entry::Occupied(entry) => {
+                    return Err(err(format!(
+                        "저장된 유류비 주소 중복: normalized_address={}, first_row={}, duplicate_row={row}",
+                        entry.key(),
+                        entry.get(),
+                    )));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(row);
+                }
+            }
+        }
+        let expected_address_count = self
+            .kept
+            .len()
+            .strict_add(self.added.len())
+            .strict_add(self.untouched_count);
+        if saved_address_rows.len() != expected_address_count
+            || saved_unaddressed_count != self.unaddressed_count
+        {
+            return Err(err(format!(
+                "저장된 유류비 행 수가 현행화 결과와 다릅니다: 주소 행 saved={}, expected={expected_address_count} / 주소 없는 행 saved={saved_unaddressed_count}, expected={}",
+                saved_address_rows.len(),
+                self.unaddressed_count,
+            )));
+        }
+        for (label, record) in self
+            .kept
+            .iter()
+            .map(|&record| ("유지", record))
+            .chain(self.added.iter().map(|&record| ("신규", record)))
+        {
+            normalize_address_key_into(&record.address, &mut address_key_scratch)?;
+            let row = *saved_address_rows
+                .get(address_key_scratch.as_str())
+                .ok_or_else(|| {
+                    err(format!(
+                        "{label} 업체가 저장 결과에 없습니다: {} / {}",
+                        record.name, record.address
+                    ))
+                })?;
+            for (col, expected) in [
+                (COL_REGION, record.region),
+                (COL_NAME, record.name.as_str()),
+                (COL_BRAND, record.brand.as_str()),
+                (COL_SELF_YN, record.service.label()),
+                (COL_ADDRESS, record.address.as_str()),
+            ] {
+                if col == COL_SELF_YN && !record.service.is_known() {
+                    continue;
+                }
+                let saved = ws.try_get_display_at(col, row, shared_strings)?;
+                if saved != expected {
+                    return Err(err(format!(
+                        "{label} 업체 값이 소스와 다릅니다: row={row}, col={col}, saved={saved}, expected={expected}"
+                    )));
+                }
+            }
+            let saved_fuels = read_master_fuels(ws, row, shared_strings)?;
+            if saved_fuels != record.fuels {
+                return Err(err(format!(
+                    "{label} 업체 가격이 소스와 다릅니다: row={row}, 업체={}, saved=({:?}, {:?}, {:?}), expected=({:?}, {:?}, {:?})",
+                    record.name,
+                    saved_fuels.gasoline,
+                    saved_fuels.premium,
+                    saved_fuels.diesel,
+                    record.fuels.gasoline,
+                    record.fuels.premium,
+                    record.fuels.diesel,
+                )));
+            }
+        }
+        for deleted_row in &self.deleted {
+            normalize_address_key_into(&deleted_row.address, &mut address_key_scratch)?;
+            if let Some(row) = saved_address_rows.get(address_key_scratch.as_str()) {
+                return Err(err(format!(
+                    "폐업 처리한 업체가 저장 결과에 남아 있습니다: row={row}, 업체={} / {}",
+                    deleted_row.name, deleted_row.address
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+impl TryFrom<&StdWorkbook> for MasterTable {
+    type Error = AppError;
+    fn try_from(book: &StdWorkbook) -> Result<Self> {
+        let (ws, shared_strings) = book.master_sheet();
+        let mut columns =
+            try_vec_with_capacity(MASTER_TABLE_COLUMNS.len(), "유류비 표 열 메모리 확보 실패")?;
+        for (col, key, numeric) in MASTER_TABLE_COLUMNS {
+            columns.push(MasterTableColumn {
+                header: trim_cow(ws.try_get_display_at(col, MASTER_HEADER_ROW, shared_strings)?)
+                    .into_owned(),
+                key,
+                numeric,
+            });
+        }
+        let mut rows = reserved_row_vec(ws.row_count())?;
+        for row in ws.row_numbers_from(MASTER_DATA_START_ROW)? {
+            if !ws.row_has_any_data(row, &[COL_REGION, COL_NAME, COL_ADDRESS], shared_strings)? {
+                continue;
+            }
+            let mut values =
+                try_vec_with_capacity(MASTER_TABLE_COLUMNS.len(), "유류비 표 행 메모리 확보 실패")?;
+            for (col, _, _) in MASTER_TABLE_COLUMNS {
+                values
+                    .push(trim_cow(ws.try_get_display_at(col, row, shared_strings)?).into_owned());
+            }
+            rows.push(MasterTableRow { row, values });
+        }
+        Ok(Self { columns, rows })
+    }
+}
+impl TryFrom<&StdWorkbook> for MasterStations {
+    type Error = AppError;
+    fn try_from(book: &StdWorkbook) -> Result<Self> {
+        let (ws, shared_strings) = book.master_sheet();
+        let mut stations = reserved_row_vec(ws.row_count())?;
+        let mut unaddressed_count = 0_usize;
+        for row in ws.row_numbers_from(MASTER_DATA_START_ROW)? {
+            let address = trim_cow(ws.try_get_display_at(COL_ADDRESS, row, shared_strings)?);
+            let name = trim_cow(ws.try_get_display_at(COL_NAME, row, shared_strings)?);
+            let region = trim_cow(ws.try_get_display_at(COL_REGION, row, shared_strings)?);
+            if address.is_empty() {
+                if !name.is_empty() || !region.is_empty() {
+                    unaddressed_count = unaddressed_count.strict_add(1);
+                }
+                continue;
+            }
+            let mut address_key = String::new();
+            normalize_address_key_into(address.as_ref(), &mut address_key)?;
+            stations.push(MasterStation {
+                address: address.into_owned(),
+                address_key,
+                brand: trim_cow(ws.try_get_display_at(COL_BRAND, row, shared_strings)?)
+                    .into_owned(),
+                fuels: read_master_fuels(ws, row, shared_strings)?,
+                name: name.into_owned(),
+                region: region.into_owned(),
+                row,
+                self_service: trim_cow(ws.try_get_display_at(COL_SELF_YN, row, shared_strings)?)
+                    .into_owned(),
+            });
+        }
+        Ok(Self {
+            stations,
+            unaddressed_count,
+        })
+    }
+}
 type AdjustedFuelPrices = FuelValues<Option<ScaledDecimal>>;
 struct ParsedMasterIdentity<'text> {
     address: Cow<'text, str>,
@@ -174,8 +453,9 @@ struct MasterRowEvaluation<'source> {
     existing_region_counts: [usize; TARGET_REGION_COUNT],
     kept_source_rows: Vec<(u32, Option<&'source SourceRecord>)>,
     matched_existing_region_counts: [usize; TARGET_REGION_COUNT],
+    untouched_count: usize,
 }
-impl<'strings> RankSortRefresher<'_, 'strings> {
+impl<'strings, 'basis> RankSortRefresher<'_, 'strings, 'basis> {
     fn apply_formula_cache(
         &mut self,
         row: u32,
@@ -362,25 +642,7 @@ impl<'strings> RankSortRefresher<'_, 'strings> {
             DECIMAL_SCALE_SQUARED.as_i128(),
             buffers,
         )?;
-        if let Some(value) = rank_total {
-            format_scaled_value_into(
-                &mut buffers.cache,
-                value.as_i128(),
-                DECIMAL_SCALE_SQUARED.as_i128(),
-            );
-        } else {
-            buffers.cache.clear();
-            buffers.cache.push_str("1000000000000000");
-        }
-        let sort_key_cache = buffers.cache.as_str();
-        self.apply_formula_cache(
-            row_num,
-            COL_SORT_KEY,
-            format_args!(r#"IF($T{row_num}="",1000000000000000,$T{row_num})"#),
-            Some(sort_key_cache),
-            false,
-            &mut buffers.formula,
-        )?;
+        self.apply_sort_key(row_num, total_qty.and(plan.sort_total), buffers)?;
         let has_discounted_unit_price = match rank_total.zip(total_qty) {
             Some((value, qty)) => format_unit_price_text_into(&mut buffers.cache, value, qty)?,
             None => false,
@@ -409,6 +671,45 @@ impl<'strings> RankSortRefresher<'_, 'strings> {
         )?;
         Ok(())
     }
+    fn apply_sort_key(
+        &mut self,
+        row_num: u32,
+        sort_total: Option<ScaledSortKey>,
+        buffers: &mut FormulaBuffers,
+    ) -> Result<()> {
+        if let Some(value) = sort_total {
+            format_scaled_value_into(
+                &mut buffers.cache,
+                value.as_i128(),
+                DECIMAL_SCALE_SQUARED.as_i128(),
+            );
+        } else {
+            buffers.cache.clear();
+            buffers.cache.push_str("1000000000000000");
+        }
+        let sort_key_cache = buffers.cache.as_str();
+        if matches!(self.detour_basis, Some(basis) if basis.ranks_by_detour()) {
+            self.apply_formula_cache(
+                row_num,
+                COL_SORT_KEY,
+                format_args!(
+                    r#"IF(OR($T{row_num}="",$Z{row_num}=""),1000000000000000,$T{row_num}+$Z{row_num})"#
+                ),
+                Some(sort_key_cache),
+                false,
+                &mut buffers.formula,
+            )
+        } else {
+            self.apply_formula_cache(
+                row_num,
+                COL_SORT_KEY,
+                format_args!(r#"IF($T{row_num}="",1000000000000000,$T{row_num})"#),
+                Some(sort_key_cache),
+                false,
+                &mut buffers.formula,
+            )
+        }
+    }
     fn build_sort_plan(
         &self,
         source_index: usize,
@@ -488,9 +789,18 @@ impl<'strings> RankSortRefresher<'_, 'strings> {
                 total_price.checked_sub(discount)
             }
         });
+        let detour = self
+            .detour_basis
+            .map(|basis| basis.estimate(address, rank_total, sort_context.total_qty))
+            .transpose()?
+            .flatten();
+        let sort_total = self
+            .detour_basis
+            .map_or(rank_total, |basis| basis.sort_total(rank_total, detour));
         Ok(SortableRankRow {
             address,
             adjusted_prices,
+            detour,
             fuels: regional_adjusted.map(|value| value.unwrap_or(ScaledSortKey::MAX)),
             name,
             rank_total,
@@ -498,10 +808,11 @@ impl<'strings> RankSortRefresher<'_, 'strings> {
             region_rate,
             smart_discount,
             smart_discount_excluded,
+            sort_total,
             source_index,
         })
     }
-    fn refresh(&mut self) -> Result<()> {
+    fn refresh(&mut self) -> Result<DetourColumns<'basis>> {
         let gasoline = MasterSheetUpdater::get_f64_at(self.ws, 2, 4, self.shared_strings)?
             .unwrap_or(ScaledDecimal::ZERO);
         let premium = MasterSheetUpdater::get_f64_at(self.ws, 2, 5, self.shared_strings)?
@@ -546,7 +857,14 @@ impl<'strings> RankSortRefresher<'_, 'strings> {
             region_rates,
         };
         let row_plans = self.sort(&sort_context)?;
-        self.refresh_rows(&sort_context, &row_plans)
+        self.refresh_rows(&sort_context, &row_plans)?;
+        let mut estimates =
+            try_vec_with_capacity(row_plans.len(), "우회 비용 결과 메모리 확보 실패")?;
+        estimates.extend(row_plans.iter().map(|plan| plan.detour));
+        Ok(DetourColumns {
+            basis: self.detour_basis,
+            estimates,
+        })
     }
     fn refresh_rows(
         &mut self,
@@ -568,7 +886,7 @@ impl<'strings> RankSortRefresher<'_, 'strings> {
         let mut ranked_count = 0_usize;
         let mut previous_total = None;
         for (row, plan) in self.data_rows.into_iter().zip(row_plans) {
-            let rank_cache = if ranking_enabled && let Some(current) = plan.rank_total {
+            let rank_cache = if ranking_enabled && let Some(current) = plan.sort_total {
                 ranked_count = ranked_count.strict_add(1);
                 if previous_total != Some(current) {
                     rank_text.clear();
@@ -600,10 +918,10 @@ impl<'strings> RankSortRefresher<'_, 'strings> {
             data_rows.push(self.build_sort_plan(source_index, row_num, sort_context)?);
         }
         data_rows.sort_by(|left, right| {
-            left.rank_total
+            left.sort_total
                 .is_none()
-                .cmp(&right.rank_total.is_none())
-                .then_with(|| left.rank_total.cmp(&right.rank_total))
+                .cmp(&right.sort_total.is_none())
+                .then_with(|| left.sort_total.cmp(&right.sort_total))
                 .then_with(|| {
                     left.fuels
                         .gasoline
@@ -639,7 +957,7 @@ impl<'strings> RankSortRefresher<'_, 'strings> {
         Ok(data_rows)
     }
 }
-impl<'source> MasterSheetUpdater<'source> {
+impl<'source> MasterSheetUpdater<'source, '_> {
     fn collect_new_sources(
         &self,
         existing_address_rows: &HashMap<Cow<'source, str>, u32>,
@@ -695,6 +1013,11 @@ impl<'source> MasterSheetUpdater<'source> {
             .source_index
             .get_key_value(address_key_scratch.as_str())
         else {
+            if self.prices_only {
+                return Ok(MasterRowDecision::Untouched {
+                    normalized_address: mem::take(address_key_scratch),
+                });
+            }
             let ParsedMasterIdentity {
                 address,
                 name,
@@ -722,10 +1045,11 @@ impl<'source> MasterSheetUpdater<'source> {
         let region_changed = !same_trimmed(old_region, source_region);
         let name_changed = !same_trimmed(old_name, &src.name);
         let brand_changed = !same_trimmed(old_brand, &src.brand);
-        let self_yn_changed = !old_self_yn
-            .chars()
-            .filter(|ch| !ch.is_whitespace())
-            .eq(src.service.label().chars());
+        let self_yn_changed = src.service.is_known()
+            && !old_self_yn
+                .chars()
+                .filter(|ch| !ch.is_whitespace())
+                .eq(src.service.label().chars());
         let price_changed = fuels != src.fuels;
         let change =
             (region_changed || name_changed || brand_changed || self_yn_changed || price_changed)
@@ -772,6 +1096,7 @@ impl<'source> MasterSheetUpdater<'source> {
         let mut deleted = reserved_row_vec(row_count)?;
         let mut existing_region_counts = [0_usize; TARGET_REGION_COUNT];
         let mut matched_existing_region_counts = [0_usize; TARGET_REGION_COUNT];
+        let mut untouched_count = 0_usize;
         let mut target_region_scratch = String::new();
         for old_row in ws.row_numbers_from(MASTER_DATA_START_ROW)? {
             let identity = ParsedMasterIdentity {
@@ -834,6 +1159,11 @@ impl<'source> MasterSheetUpdater<'source> {
                     kept_source_rows.push((old_row, Some(src)));
                 }
                 MasterRowDecision::Unaddressed => kept_source_rows.push((old_row, None)),
+                MasterRowDecision::Untouched { normalized_address } => {
+                    record_address(Cow::Owned(normalized_address))?;
+                    untouched_count = untouched_count.strict_add(1);
+                    kept_source_rows.push((old_row, None));
+                }
             }
         }
         let added = self.collect_new_sources(&master_address_rows)?;
@@ -844,6 +1174,7 @@ impl<'source> MasterSheetUpdater<'source> {
             existing_region_counts,
             kept_source_rows,
             matched_existing_region_counts,
+            untouched_count,
         })
     }
     fn get_f64_at(
@@ -926,6 +1257,54 @@ impl<'source> MasterSheetUpdater<'source> {
             .map(Some)
             .ok_or_else(invalid_value)
     }
+    fn link_station_rows(
+        &self,
+        ws: &mut excel::writer::Worksheet,
+        shared_strings: &SharedStringTable,
+        last_data_row: u32,
+    ) -> Result<()> {
+        let Some(template) = self.map_url else {
+            return Ok(());
+        };
+        for row in MASTER_DATA_START_ROW..=last_data_row {
+            let address = trim_cow(ws.try_get_display_at(COL_ADDRESS, row, shared_strings)?);
+            if address.is_empty() {
+                continue;
+            }
+            let name = trim_cow(ws.try_get_display_at(COL_NAME, row, shared_strings)?);
+            let mut url = try_string_with_capacity(
+                template.len().strict_add(address.len().strict_mul(3)),
+                "지도 링크 URL 메모리 확보 실패",
+            )?;
+            let mut rest = template;
+            while let Some(start) = rest.find('{') {
+                let (literal, placeholder) = rest.split_at(start);
+                url.push_str(literal);
+                let (value, remaining) = if let Some(remaining) =
+                    placeholder.strip_prefix(MAP_URL_ADDRESS_PLACEHOLDER)
+                {
+                    (address.as_ref(), remaining)
+                } else if let Some(remaining) = placeholder.strip_prefix(MAP_URL_NAME_PLACEHOLDER) {
+                    (name.as_ref(), remaining)
+                } else {
+                    return Err(err(format!(
+                        "지도 URL 템플릿 자리표시자가 올바르지 않습니다: {template}"
+                    )));
+                };
+                for byte in value.bytes() {
+                    if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+                        url.push(char::from(byte));
+                    } else {
+                        append_fmt(&mut url, format_args!("%{byte:02X}"));
+                    }
+                }
+                rest = remaining;
+            }
+            url.push_str(rest);
+            ws.push_hyperlink(COL_NAME, row, url)?;
+        }
+        Ok(())
+    }
     fn normalize_fuel_price(value: Option<i32>) -> Option<i32> {
         value.filter(|price| *price > 0_i32)
     }
@@ -941,6 +1320,7 @@ impl<'source> MasterSheetUpdater<'source> {
             existing_region_counts,
             kept_source_rows,
             matched_existing_region_counts,
+            untouched_count,
         } = self.evaluate_master_rows(ws, shared_strings)?;
         let kept_count = kept_source_rows.len();
         let existing_count = kept_count.strict_add(deleted.len());
@@ -1006,31 +1386,43 @@ impl<'source> MasterSheetUpdater<'source> {
         }
         original_rows.extend(trailing_rows);
         ws.replace_rows(original_rows);
+        let mut kept = reserved_row_vec(kept_count)?;
         for (i, (_, source)) in kept_source_rows.into_iter().enumerate() {
             let new_row = add_row_offset(MASTER_DATA_START_ROW, i, "유류비 기존행 재배치")?;
             if let Some(src) = source {
                 Self::write_master_row_from_source(ws, shared_strings, new_row, src)?;
+                kept.push(src);
             }
         }
+        let unaddressed_count = kept_count
+            .strict_sub(kept.len())
+            .strict_sub(untouched_count);
         for (i, &source) in added.iter().enumerate() {
             let offset = kept_count.strict_add(i);
             let new_row = add_row_offset(MASTER_DATA_START_ROW, offset, "유류비 신규행 추가")?;
             Self::write_master_row_from_source(ws, shared_strings, new_row, source)?;
+            if !source.service.is_known() {
+                shared_strings.set_cell(ws, COL_SELF_YN, new_row, "")?;
+            }
             ws.set_i32_at(COL_SMART_DISCOUNT, new_row, None)?;
         }
         let last_data_row = MASTER_DATA_START_ROW.strict_add(final_count_u32.strict_sub(1));
-        RankSortRefresher {
+        let detour_basis = self.detour_basis(ws, shared_strings)?;
+        let detour_columns = RankSortRefresher {
             data_rows: RowRange {
                 start: MASTER_DATA_START_ROW,
                 last: last_data_row,
             },
+            detour_basis: detour_basis.as_ref(),
             shared_strings,
             ws,
         }
         .refresh()?;
         ws.update_auto_filter_ref(last_data_row)?;
+        detour_columns.write(ws, shared_strings, last_data_row)?;
         ws.prune_empty_style_artifacts_after_col(COL_SORT_KEY)?;
         ws.update_dimension()?;
+        self.link_station_rows(ws, shared_strings, last_data_row)?;
         ws.extend_conditional_formats(
             old_data_rows,
             RowRange {
@@ -1046,7 +1438,10 @@ impl<'source> MasterSheetUpdater<'source> {
             deleted,
             existing_count,
             existing_region_counts,
+            kept,
             matched_existing_region_counts,
+            unaddressed_count,
+            untouched_count,
         })
     }
     fn write_master_row_from_source(
@@ -1062,6 +1457,9 @@ impl<'source> MasterSheetUpdater<'source> {
             (COL_SELF_YN, src.service.label()),
             (COL_ADDRESS, src.address.as_str()),
         ] {
+            if col == COL_SELF_YN && !src.service.is_known() {
+                continue;
+            }
             shared_strings.set_cell(ws, col, row, value)?;
         }
         ws.set_i32_at(COL_GASOLINE, row, src.fuels.gasoline)?;
@@ -1170,3 +1568,30 @@ fn trim_cow(value: Cow<'_, str>) -> Cow<'_, str> {
 fn same_trimmed(left: &str, right: &str) -> bool {
     left.trim() == right.trim()
 }
+pub(super) fn validate_map_url_template(template: &str) -> Result<()> {
+    let mut placeholder_count = 0_usize;
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let placeholder = rest.get(start..).unwrap_or_default();
+        let Some(remaining) = placeholder
+            .strip_prefix(MAP_URL_ADDRESS_PLACEHOLDER)
+            .or_else(|| placeholder.strip_prefix(MAP_URL_NAME_PLACEHOLDER))
+        else {
+            placeholder_count = 0;
+            break;
+        };
+        placeholder_count = placeholder_count.strict_add(1);
+        rest = remaining;
+    }
+    if placeholder_count == 0
+        || !(template.starts_with("https://") || template.starts_with("http://"))
+        || template
+            .chars()
+            .any(|ch| ch.is_whitespace() || ch.is_control() || matches!(ch, '"' | '<' | '>'))
+    {
+        return Err(err(format!(
+            "지도 URL 템플릿은 http(s):// 주소이고 {{address}} 또는 {{name}}만 자리표시자로 포함해야 합니다: {template}"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,268 @@
+use super::{
+    DECIMAL_SCALE_SQUARED, MASTER_DATA_START_ROW, MASTER_HEADER_ROW, MasterSheetUpdater,
+    ScaledDecimal, ScaledSortKey, UpdateSettings, format::format_scaled_value_into, trim_cow,
+};
+use crate::{
+    diagnostic::{Result, append_fmt, err},
+    excel::writer::{MASTER_DETOUR_HEADERS, SharedStringTable, Workbook as StdWorkbook, Worksheet},
+    geo::{GeoPoint, GeoTable},
+    region::normalize_address_key_into,
+    sha256::Sha256,
+};
+const COL_DEPOT_NAME: u32 = 6;
+const COL_DEPOT_LAT: u32 = 7;
+const COL_DEPOT_LON: u32 = 8;
+const DEPOT_FIRST_ROW: u32 = 4;
+const DEPOT_LAST_ROW: u32 = 12;
+const FUEL_EFFICIENCY_COL: u32 = 7;
+const FUEL_EFFICIENCY_ROW: u32 = 13;
+const COL_NEAREST_DEPOT: u32 = 24;
+const COL_DEPOT_DISTANCE: u32 = 25;
+const COL_DETOUR_COST: u32 = 26;
+const DETOUR_COLUMNS: [u32; 3] = [COL_NEAREST_DEPOT, COL_DEPOT_DISTANCE, COL_DETOUR_COST];
+const METERS_PER_DISTANCE_STEP: u64 = 100;
+const DISTANCE_STEPS_PER_KM: i128 = 10;
+const ROUND_TRIP_FACTOR: i128 = 2;
+const METERS_PER_KM: i128 = 1000;
+const DETOUR_UNRANKED_SORT_TOTAL: ScaledSortKey =
+    ScaledSortKey(1_000_000_000_000_000_000_000_000_000);
+struct Depot {
+    name: String,
+    point: GeoPoint,
+}
+pub(super) struct DetourBasis<'geo> {
+    depots: Vec<Depot>,
+    fuel_efficiency: ScaledDecimal,
+    geo_table: &'geo GeoTable,
+    ranking: bool,
+}
+#[derive(Clone, Copy)]
+pub(super) struct DetourEstimate {
+    cost: Option<i64>,
+    depot: usize,
+    distance_meters: u64,
+}
+pub(super) struct DetourColumns<'basis> {
+    pub basis: Option<&'basis DetourBasis<'basis>>,
+    pub estimates: Vec<Option<DetourEstimate>>,
+}
+impl DetourBasis<'_> {
+    pub(super) fn estimate(
+        &self,
+        address: &str,
+        rank_total: Option<ScaledSortKey>,
+        total_qty: Option<ScaledDecimal>,
+    ) -> Result<Option<DetourEstimate>> {
+        let mut address_key = String::new();
+        normalize_address_key_into(address, &mut address_key)?;
+        let Some(point) = self.geo_table.get(&address_key) else {
+            return Ok(None);
+        };
+        let Some((depot, distance_meters)) = self
+            .depots
+            .iter()
+            .map(|depot| depot.point.distance_meters(point))
+            .enumerate()
+            .min_by_key(|&(_, meters)| meters)
+        else {
+            return Ok(None);
+        };
+        let cost = match rank_total.zip(total_qty) {
+            Some((total, qty)) => {
+                let numerator = ROUND_TRIP_FACTOR
+                    .checked_mul(i128::from(distance_meters))
+                    .and_then(|value| value.checked_mul(total.as_i128()));
+                let denominator = METERS_PER_KM
+                    .checked_mul(self.fuel_efficiency.as_i128())
+                    .and_then(|value| value.checked_mul(qty.as_i128()));
+                let rounded = numerator.zip(denominator).and_then(|(num, den)| {
+                    num.checked_mul(2)?
+                        .checked_add(den)?
+                        .checked_div_euclid(den.checked_mul(2)?)
+                });
+                Some(
+                    rounded
+                        .and_then(|value| i64::try_from(value).ok())
+                        .ok_or_else(|| err("우회 비용 계산 중 overflow가 발생했습니다."))?,
+                )
+            }
+            None => None,
+        };
+        Ok(Some(DetourEstimate {
+            cost,
+            depot,
+            distance_meters,
+        }))
+    }
+    pub(super) const fn ranks_by_detour(&self) -> bool {
+        self.ranking
+    }
+    pub(super) fn sort_total(
+        &self,
+        rank_total: Option<ScaledSortKey>,
+        estimate: Option<DetourEstimate>,
+    ) -> Option<ScaledSortKey> {
+        if !self.ranking {
+            return rank_total;
+        }
+        rank_total.map(|total| {
+            estimate
+                .and_then(|detour| detour.cost)
+                .and_then(|cost| ScaledSortKey(i128::from(cost)).checked_mul(DECIMAL_SCALE_SQUARED))
+                .and_then(|cost| total.checked_add(cost))
+                .unwrap_or(DETOUR_UNRANKED_SORT_TOTAL)
+        })
+    }
+}
+impl DetourColumns<'_> {
+    pub(super) fn write(
+        &self,
+        ws: &mut Worksheet,
+        shared_strings: &mut SharedStringTable,
+        last_data_row: u32,
+    ) -> Result<()> {
+        let Some(basis) = self.basis else {
+            let header =
+                ws.try_get_display_at(COL_NEAREST_DEPOT, MASTER_HEADER_ROW, shared_strings)?;
+            if Some(&trim_cow(header).as_ref()) == MASTER_DETOUR_HEADERS.first() {
+                for row in MASTER_HEADER_ROW..=last_data_row {
+                    for col in DETOUR_COLUMNS {
+                        ws.set_decimal_at(col, row, None)?;
+                    }
+                }
+            }
+            return Ok(());
+        };
+        for (col, header) in DETOUR_COLUMNS.into_iter().zip(MASTER_DETOUR_HEADERS) {
+            shared_strings.set_cell(ws, col, MASTER_HEADER_ROW, header)?;
+        }
+        let mut value_text = String::new();
+        for (row, estimate) in (MASTER_DATA_START_ROW..=last_data_row).zip(&self.estimates) {
+            let &Some(detour) = estimate else {
+                for col in DETOUR_COLUMNS {
+                    ws.set_decimal_at(col, row, None)?;
+                }
+                continue;
+            };
+            let depot = basis
+                .depots
+                .get(detour.depot)
+                .ok_or_else(|| err("최근접 출발지 index가 범위를 벗어났습니다."))?;
+            shared_strings.set_cell(ws, COL_NEAREST_DEPOT, row, &depot.name)?;
+            let distance_steps = detour
+                .distance_meters
+                .strict_add(METERS_PER_DISTANCE_STEP.div_euclid(2))
+                .div_euclid(METERS_PER_DISTANCE_STEP);
+            format_scaled_value_into(
+                &mut value_text,
+                i128::from(distance_steps),
+                DISTANCE_STEPS_PER_KM,
+            );
+            ws.set_decimal_at(COL_DEPOT_DISTANCE, row, Some(&value_text))?;
+            value_text.clear();
+            if let Some(cost) = detour.cost {
+                append_fmt(&mut value_text, format_args!("{cost}"));
+            }
+            ws.set_decimal_at(
+                COL_DETOUR_COST,
+                row,
+                detour.cost.map(|_| value_text.as_str()),
+            )?;
+        }
+        Ok(())
+    }
+}
+impl UpdateSettings<'_> {
+    pub(crate) fn hash(&self, book: &StdWorkbook) -> Result<String> {
+        let mut settings = String::new();
+        append_fmt(
+            &mut settings,
+            format_args!(
+                "rank\t{}\n",
+                if self.detour_ranking {
+                    "detour"
+                } else {
+                    "price"
+                }
+            ),
+        );
+        append_fmt(
+            &mut settings,
+            format_args!("map\t{}\n", self.map_url.unwrap_or_default()),
+        );
+        settings.push_str("geo\t");
+        if let Some(geo_table) = self.geo_table {
+            settings.push_str(&geo_table.content_hash()?);
+        }
+        settings.push('\n');
+        let (ws, shared_strings) = book.master_sheet();
+        for row in DEPOT_FIRST_ROW..=DEPOT_LAST_ROW {
+            for col in [COL_DEPOT_NAME, COL_DEPOT_LAT, COL_DEPOT_LON] {
+                settings.push_str(&trim_cow(ws.try_get_display_at(
+                    col,
+                    row,
+                    shared_strings,
+                )?));
+                settings.push('\t');
+            }
+            settings.push('\n');
+        }
+        settings.push_str(&trim_cow(ws.try_get_display_at(
+            FUEL_EFFICIENCY_COL,
+            FUEL_EFFICIENCY_ROW,
+            shared_strings,
+        )?));
+        settings.push('\n');
+        let mut hasher = Sha256::default();
+        hasher.update(settings.as_bytes());
+        Ok(hasher.finish_hex())
+    }
+}
+impl<'geo> MasterSheetUpdater<'_, 'geo> {
+    pub(super) fn detour_basis(
+        &self,
+        ws: &Worksheet,
+        shared_strings: &SharedStringTable,
+    ) -> Result<Option<DetourBasis<'geo>>> {
+        let mut depots = Vec::new();
+        for row in DEPOT_FIRST_ROW..=DEPOT_LAST_ROW {
+            let name = trim_cow(ws.try_get_display_at(COL_DEPOT_NAME, row, shared_strings)?);
+            let lat = trim_cow(ws.try_get_display_at(COL_DEPOT_LAT, row, shared_strings)?);
+            let lon = trim_cow(ws.try_get_display_at(COL_DEPOT_LON, row, shared_strings)?);
+            if name.is_empty() && lat.is_empty() && lon.is_empty() {
+                continue;
+            }
+            let point = GeoPoint::parse(&lat, &lon)
+                .filter(|_| !name.is_empty())
+                .ok_or_else(|| {
+                    err(format!(
+                        "출발지 입력에는 이름, 위도, 경도가 모두 필요합니다: row={row}, 이름={name}, 위도={lat}, 경도={lon}"
+                    ))
+                })?;
+            depots.push(Depot {
+                name: name.into_owned(),
+                point,
+            });
+        }
+        let Some(geo_table) = self.geo_table.filter(|_| !depots.is_empty()) else {
+            if self.detour_ranking {
+                return Err(err(
+                    "우회 비용 순위에는 출발지 좌표(F4:H12)와 좌표표(--geo-table)가 필요합니다.",
+                ));
+            }
+            return Ok(None);
+        };
+        let fuel_efficiency =
+            Self::get_f64_at(ws, FUEL_EFFICIENCY_COL, FUEL_EFFICIENCY_ROW, shared_strings)?
+                .filter(|efficiency| efficiency.as_i64() > 0)
+                .ok_or_else(|| {
+                    err("출발지 좌표를 쓰려면 G13에 연비(km/L)를 0보다 크게 입력해야 합니다.")
+                })?;
+        Ok(Some(DetourBasis {
+            depots,
+            fuel_efficiency,
+            geo_table,
+            ranking: self.detour_ranking,
+        }))
+    }
+}
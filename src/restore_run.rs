@@ -0,0 +1,143 @@
+use crate::{
+    acquire_run_lock,
+    backup::{BackupEntry, BackupStore},
+    diagnostic::{
+        ErrorCategory, Result, err, err_with_source, path_context_message, terminal_safe,
+    },
+    excel::{writer::Workbook as StdWorkbook, xlsx_container::ArchiveRestore},
+    kst::KstTime,
+    master_sheet::MasterTable,
+    write_line,
+};
+use std::{io::Write, path::Path};
+pub(super) struct RestoreRun<'out> {
+    pub backups: BackupStore<'out>,
+    pub master_path: &'out Path,
+    pub out: &'out mut dyn Write,
+    pub target: Option<&'out str>,
+}
+impl RestoreRun<'_> {
+    pub(super) fn run(&mut self) -> Result<()> {
+        let entries = self.backups.entries()?;
+        let Some(target) = self.target else {
+            if entries.is_empty() {
+                write_line(
+                    self.out,
+                    format_args!(
+                        "백업이 없습니다: {}",
+                        terminal_safe(&self.backups.dir.display())
+                    ),
+                )?;
+                return Ok(());
+            }
+            write_line(
+                self.out,
+                format_args!(
+                    "백업 {}건 (최신순, {}):",
+                    entries.len(),
+                    terminal_safe(&self.backups.dir.display())
+                ),
+            )?;
+            for entry in &entries {
+                write_line(
+                    self.out,
+                    format_args!("  {}  {}", entry.stamp, terminal_safe(&entry.summary)),
+                )?;
+            }
+            return Ok(());
+        };
+        let key = target.strip_suffix(".xlsx").unwrap_or(target);
+        let by_name: Vec<&BackupEntry> = entries
+            .iter()
+            .filter(|entry| entry.file_name.strip_suffix(".xlsx") == Some(key))
+            .collect();
+        let matched: Vec<&BackupEntry> = if by_name.is_empty() {
+            entries.iter().filter(|entry| entry.stamp == key).collect()
+        } else {
+            by_name
+        };
+        let backup = match *matched.as_slice() {
+            [] => {
+                return Err(err(format!(
+                    "복원할 백업을 찾지 못했습니다: {} (restore --list로 목록 확인)",
+                    terminal_safe(&target)
+                ))
+                .with_category(ErrorCategory::Usage));
+            }
+            [single] => single,
+            [..] => {
+                return Err(err(format!(
+                    "같은 시각의 백업이 {}건 있습니다. 파일 이름으로 지정하세요: {}",
+                    matched.len(),
+                    matched
+                        .iter()
+                        .map(|entry| entry.file_name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+                .with_category(ErrorCategory::Usage));
+            }
+        };
+        StdWorkbook::open(&backup.path)
+            .and_then(|book| MasterTable::try_from(&book).map(drop))
+            .map_err(|source| {
+                err_with_source(
+                    path_context_message("백업 워크북 검증 실패", &backup.path),
+                    source,
+                )
+                .with_category(ErrorCategory::MasterStructure)
+            })?;
+        let _run_lock = acquire_run_lock()?;
+        let displaced_backup = if self.backups.keep == 0 {
+            None
+        } else {
+            Some(
+                self.backups
+                    .reserve_path(KstTime::now()?, &format!("복원전_{}", backup.stamp))
+                    .map_err(|backup_err| backup_err.with_category(ErrorCategory::Save))?,
+            )
+        };
+        ArchiveRestore {
+            backup_xlsx: &backup.path,
+            displaced_backup: displaced_backup.as_deref(),
+            target_xlsx: self.master_path,
+        }
+        .run()
+        .map_err(|restore_err| restore_err.with_category(ErrorCategory::Save))?;
+        write_line(
+            self.out,
+            format_args!(
+                "복원 완료: {} -> {}",
+                terminal_safe(&backup.path.display()),
+                terminal_safe(&self.master_path.display())
+            ),
+        )?;
+        if let Some(backup_path) = displaced_backup {
+            write_line(
+                self.out,
+                format_args!(
+                    "복원 전 마스터 백업: {}",
+                    terminal_safe(&backup_path.display())
+                ),
+            )?;
+            let expired = self.backups.prune().map_err(|source| {
+                err_with_source(
+                    "마스터 파일은 복원됐지만 오래된 백업 정리에 실패했습니다",
+                    source,
+                )
+                .with_category(ErrorCategory::Save)
+            })?;
+            if !expired.is_empty() {
+                write_line(
+                    self.out,
+                    format_args!(
+                        "보관 개수({})를 넘은 오래된 백업 {}건 삭제",
+                        self.backups.keep,
+                        expired.len()
+                    ),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+use crate::{
+    acquire_run_lock,
+    diagnostic::{AppError, Result, append_fmt, err_with_source, path_context_message},
+    json::{JsonValue, append_json_string},
+    kst::{KstTime, SECS_PER_DAY},
+    logging,
+    temp_entry::{configure_no_follow, open_regular},
+    update_run::UpdateRun,
+    write_line,
+};
+use core::{
+    fmt::{self, Display},
+    time::Duration,
+};
+use std::{
+    fs::{self, File},
+    io::{self, ErrorKind, Read as _, Write as _},
+    path::Path,
+    process, thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+#[cfg(test)]
+mod tests;
+const SCHEDULE_POLL_SECS: u64 = 60;
+const STATUS_FILE_MAX_BYTES: u64 = 1024 * 1024;
+#[derive(Clone, Copy)]
+struct ScheduleTime(u64);
+pub(super) struct ServeRun<'out> {
+    pub schedule: &'out [u64],
+    pub status_path: &'out Path,
+    pub update: UpdateRun<'out>,
+}
+struct RunFailure {
+    at: String,
+    error: String,
+}
+struct RunSuccess {
+    at: String,
+    source_hash: String,
+    updated: bool,
+}
+struct ServeStatus {
+    last_failure: Option<RunFailure>,
+    last_success: Option<RunSuccess>,
+    next_run: String,
+}
+impl Display for ScheduleTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}",
+            self.0.div_euclid(3_600),
+            self.0.rem_euclid(3_600).div_euclid(60)
+        )
+    }
+}
+impl Display for ServeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut json = String::from("{\"last_success\":");
+        if let Some(success) = self.last_success.as_ref() {
+            json.push_str("{\"at\":");
+            append_json_string(&mut json, &success.at);
+            json.push_str(",\"result\":");
+            append_json_string(
+                &mut json,
+                if success.updated {
+                    "updated"
+                } else {
+                    "unchanged"
+                },
+            );
+            json.push_str(",\"source_hash\":");
+            append_json_string(&mut json, &success.source_hash);
+            json.push('}');
+        } else {
+            json.push_str("null");
+        }
+        json.push_str(",\"last_failure\":");
+        if let Some(failure) = self.last_failure.as_ref() {
+            json.push_str("{\"at\":");
+            append_json_string(&mut json, &failure.at);
+            json.push_str(",\"error\":");
+            append_json_string(&mut json, &failure.error);
+            json.push('}');
+        } else {
+            json.push_str("null");
+        }
+        json.push_str(",\"next_run\":");
+        append_json_string(&mut json, &self.next_run);
+        json.push('}');
+        f.write_str(&json)
+    }
+}
+impl ServeRun<'_> {
+    pub(super) fn run(&mut self) -> Result<()> {
+        let mut status = ServeStatus::try_from(self.status_path)?;
+        let mut schedule_label = String::new();
+        for (index, &time) in self.schedule.iter().enumerate() {
+            if index != 0 {
+                schedule_label.push_str(", ");
+            }
+            append_fmt(&mut schedule_label, format_args!("{}", ScheduleTime(time)));
+        }
+        write_line(
+            self.update.out,
+            format_args!(
+                "예약 실행 모드 시작: 매일 {schedule_label} KST (상태 파일: {})",
+                self.status_path.display()
+            ),
+        )?;
+        loop {
+            let now = KstTime::now()?;
+            let day_start = now.secs.strict_sub(now.seconds_of_day());
+            let next = KstTime {
+                secs: self
+                    .schedule
+                    .iter()
+                    .find(|&&time| time > now.seconds_of_day())
+                    .map_or_else(
+                        || {
+                            day_start
+                                .strict_add(SECS_PER_DAY)
+                                .strict_add(self.schedule.first().copied().unwrap_or_default())
+                        },
+                        |&time| day_start.strict_add(time),
+                    ),
+            };
+            status.next_run = next.timestamp();
+            if let Err(write_err) = status.save(self.status_path) {
+                logging::warn(format_args!(
+                    "상태 파일을 갱신하지 못했지만 예약 실행은 계속합니다: {write_err}"
+                ));
+            }
+            write_line(
+                self.update.out,
+                format_args!("다음 실행 예정: {}", status.next_run),
+            )?;
+            loop {
+                let waiting = KstTime::now()?;
+                if waiting.secs >= next.secs {
+                    break;
+                }
+                thread::sleep(Duration::from_secs(
+                    next.secs.strict_sub(waiting.secs).min(SCHEDULE_POLL_SECS),
+                ));
+            }
+            write_line(
+                self.update.out,
+                format_args!("\n==== 예약 실행: {} ====", KstTime::now()?.timestamp()),
+            )?;
+            let result = acquire_run_lock().and_then(|_run_lock| self.update.run());
+            let finished_at = KstTime::now()?.timestamp();
+            match result {
+                Ok(outcome) => {
+                    status.last_success = Some(RunSuccess {
+                        at: finished_at,
+                        source_hash: outcome.source_hash,
+                        updated: outcome.updated,
+                    });
+                }
+                Err(run_err) => {
+                    logging::warn(format_args!("예약 실행 실패: {run_err}"));
+                    status.last_failure = Some(RunFailure {
+                        at: finished_at,
+                        error: run_err.to_string(),
+                    });
+                }
+            }
+        }
+    }
+}
+impl ServeStatus {
+    fn save(&self, path: &Path) -> Result<()> {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default();
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos());
+        let temp_path = path.with_file_name(format!(".{file_name}.tmp_{}_{nanos}", process::id()));
+        let mut options = File::options();
+        options.write(true).create_new(true);
+        configure_no_follow(&mut options);
+        let written = options
+            .open(&temp_path)
+            .and_then(|mut file| {
+                writeln!(file, "{self}")?;
+                file.sync_all()
+            })
+            .and_then(|()| fs::rename(&temp_path, path));
+        if written.is_err() {
+            match fs::remove_file(&temp_path) {
+                Ok(()) | Err(_) => {}
+            }
+        }
+        written.map_err(|source: io::Error| {
+            err_with_source(path_context_message("상태 파일 쓰기 실패", path), source)
+        })
+    }
+}
+impl TryFrom<&Path> for ServeStatus {
+    type Error = AppError;
+    fn try_from(path: &Path) -> Result<Self> {
+        let mut status = Self {
+            last_failure: None,
+            last_success: None,
+            next_run: String::new(),
+        };
+        let mut text = String::new();
+        match open_regular(path, false) {
+            Ok(validated) => validated
+                .file
+                .take(STATUS_FILE_MAX_BYTES)
+                .read_to_string(&mut text)
+                .map(drop),
+            Err(open_err) if open_err.kind() == ErrorKind::NotFound => return Ok(status),
+            Err(open_err) => Err(open_err),
+        }
+        .map_err(|source| {
+            err_with_source(path_context_message("상태 파일 읽기 실패", path), source)
+        })?;
+        let value = JsonValue::try_from(text.as_str()).map_err(|source| {
+            err_with_source(path_context_message("상태 파일 형식 오류", path), source)
+        })?;
+        let field = |entry: &JsonValue<'_>, key: &str| {
+            entry
+                .get(key)
+                .and_then(JsonValue::as_text)
+                .map(str::to_owned)
+        };
+        status.last_success = value.get("last_success").and_then(|success| {
+            Some(RunSuccess {
+                at: field(success, "at")?,
+                source_hash: field(success, "source_hash")?,
+                updated: field(success, "result").as_deref() == Some("updated"),
+            })
+        });
+        status.last_failure = value.get("last_failure").and_then(|failure| {
+            Some(RunFailure {
+                at: field(failure, "at")?,
+                error: field(failure, "error")?,
+            })
+        });
+        Ok(status)
+    }
+}
@@ -0,0 +1,52 @@
+use super::{RunFailure, RunSuccess, ServeStatus};
+use crate::diagnostic::{Result, ensure};
+use std::{env, fs, process};
+#[test]
+fn status_is_replaced_whole_and_reads_back() -> Result<()> {
+    let dir = env::temp_dir().join(format!("fcupdater-serve-{}", process::id()));
+    fs::create_dir_all(&dir)?;
+    let path = dir.join("fcupdater_status.json");
+    fs::write(
+        &path,
+        "{\"next_run\":\"stale and longer than the replacement status\"}",
+    )?;
+    let status = ServeStatus {
+        last_failure: Some(RunFailure {
+            at: "2026-10-18T06:00:05+09:00".to_owned(),
+            error: "소스 다운로드 실패: \"timeout\"".to_owned(),
+        }),
+        last_success: Some(RunSuccess {
+            at: "2026-10-18T12:00:09+09:00".to_owned(),
+            source_hash: "ab".repeat(32),
+            updated: true,
+        }),
+        next_run: "2026-10-18T18:00:00+09:00".to_owned(),
+    };
+    status.save(&path)?;
+    ensure(
+        fs::read_to_string(&path)? == format!("{status}\n"),
+        "status file holds exactly the new JSON",
+    )?;
+    let mut reread = ServeStatus::try_from(path.as_path())?;
+    reread.next_run.clone_from(&status.next_run);
+    ensure(
+        reread.to_string() == status.to_string(),
+        "last results survive a save and reload",
+    )?;
+    ensure(
+        fs::read_dir(&dir)?.count() == 1,
+        "no temporary status files are left behind",
+    )?;
+    let blocked = dir.join("blocked");
+    fs::create_dir_all(blocked.join("inner"))?;
+    ensure(
+        status.save(&blocked).is_err(),
+        "replacing a non-empty directory fails with an error",
+    )?;
+    ensure(
+        fs::read_dir(&dir)?.count() == 2,
+        "a failed save removes its temporary file",
+    )?;
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,196 @@
+use crate::diagnostic::append_fmt;
+use core::mem;
+const BLOCK_LEN: usize = 64;
+const LENGTH_OFFSET: usize = 56;
+const INITIAL_STATE: [u32; 8] = [
+    0x6a09_e667,
+    0xbb67_ae85,
+    0x3c6e_f372,
+    0xa54f_f53a,
+    0x510e_527f,
+    0x9b05_688c,
+    0x1f83_d9ab,
+    0x5be0_cd19,
+];
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a_2f98,
+    0x7137_4491,
+    0xb5c0_fbcf,
+    0xe9b5_dba5,
+    0x3956_c25b,
+    0x59f1_11f1,
+    0x923f_82a4,
+    0xab1c_5ed5,
+    0xd807_aa98,
+    0x1283_5b01,
+    0x2431_85be,
+    0x550c_7dc3,
+    0x72be_5d74,
+    0x80de_b1fe,
+    0x9bdc_06a7,
+    0xc19b_f174,
+    0xe49b_69c1,
+    0xefbe_4786,
+    0x0fc1_9dc6,
+    0x240c_a1cc,
+    0x2de9_2c6f,
+    0x4a74_84aa,
+    0x5cb0_a9dc,
+    0x76f9_88da,
+    0x983e_5152,
+    0xa831_c66d,
+    0xb003_27c8,
+    0xbf59_7fc7,
+    0xc6e0_0bf3,
+    0xd5a7_9147,
+    0x06ca_6351,
+    0x1429_2967,
+    0x27b7_0a85,
+    0x2e1b_2138,
+    0x4d2c_6dfc,
+    0x5338_0d13,
+    0x650a_7354,
+    0x766a_0abb,
+    0x81c2_c92e,
+    0x9272_2c85,
+    0xa2bf_e8a1,
+    0xa81a_664b,
+    0xc24b_8b70,
+    0xc76c_51a3,
+    0xd192_e819,
+    0xd699_0624,
+    0xf40e_3585,
+    0x106a_a070,
+    0x19a4_c116,
+    0x1e37_6c08,
+    0x2748_774c,
+    0x34b0_bcb5,
+    0x391c_0cb3,
+    0x4ed8_aa4a,
+    0x5b9c_ca4f,
+    0x682e_6ff3,
+    0x748f_82ee,
+    0x78a5_636f,
+    0x84c8_7814,
+    0x8cc7_0208,
+    0x90be_fffa,
+    0xa450_6ceb,
+    0xbef9_a3f7,
+    0xc671_78f2,
+];
+pub(super) struct Sha256 {
+    buffer: Vec<u8>,
+    length: u64,
+    state: [u32; 8],
+}
+impl Default for Sha256 {
+    fn default() -> Self {
+        Self {
+            buffer: Vec::with_capacity(BLOCK_LEN),
+            length: 0,
+            state: INITIAL_STATE,
+        }
+    }
+}
+impl Sha256 {
+    fn compress(&mut self, block: &[u8]) {
+        let mut window = [0_u32; 16];
+        for (word, bytes) in window.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes(<[u8; 4]>::try_from(bytes).unwrap_or_default());
+        }
+        let mut message_words = window.into_iter();
+        let [
+            mut va,
+            mut vb,
+            mut vc,
+            mut vd,
+            mut ve,
+            mut vf,
+            mut vg,
+            mut vh,
+        ] = self.state;
+        for constant in ROUND_CONSTANTS {
+            let word = message_words.next().unwrap_or_else(|| {
+                let low = window[1];
+                let high = window[14];
+                let sigma0 = low.rotate_right(7) ^ low.rotate_right(18) ^ (low >> 3_u32);
+                let sigma1 = high.rotate_right(17) ^ high.rotate_right(19) ^ (high >> 10_u32);
+                let next = sigma1
+                    .wrapping_add(window[9])
+                    .wrapping_add(sigma0)
+                    .wrapping_add(window[0]);
+                window.rotate_left(1);
+                window[15] = next;
+                next
+            });
+            let choose = (ve & vf) ^ (!ve & vg);
+            let sum1 = ve.rotate_right(6) ^ ve.rotate_right(11) ^ ve.rotate_right(25);
+            let temp1 = vh
+                .wrapping_add(sum1)
+                .wrapping_add(choose)
+                .wrapping_add(constant)
+                .wrapping_add(word);
+            let majority = (va & vb) ^ (va & vc) ^ (vb & vc);
+            let sum0 = va.rotate_right(2) ^ va.rotate_right(13) ^ va.rotate_right(22);
+            let temp2 = sum0.wrapping_add(majority);
+            vh = vg;
+            vg = vf;
+            vf = ve;
+            ve = vd.wrapping_add(temp1);
+            vd = vc;
+            vc = vb;
+            vb = va;
+            va = temp1.wrapping_add(temp2);
+        }
+        for (state, value) in self.state.iter_mut().zip([va, vb, vc, vd, ve, vf, vg, vh]) {
+            *state = state.wrapping_add(value);
+        }
+    }
+    pub(super) fn finish_hex(mut self) -> String {
+        let bit_length = self.length.wrapping_mul(8);
+        let buffered = self.buffer.len();
+        let zero_count = if buffered < LENGTH_OFFSET {
+            LENGTH_OFFSET.strict_sub(buffered).strict_sub(1)
+        } else {
+            BLOCK_LEN
+                .strict_add(LENGTH_OFFSET)
+                .strict_sub(buffered)
+                .strict_sub(1)
+        };
+        let padding = [0_u8; BLOCK_LEN];
+        self.update(&[0x80]);
+        self.update(padding.get(..zero_count).unwrap_or_default());
+        self.update(&bit_length.to_be_bytes());
+        let mut hex = String::with_capacity(64);
+        for word in self.state {
+            for byte in word.to_be_bytes() {
+                append_fmt(&mut hex, format_args!("{byte:02x}"));
+            }
+        }
+        hex
+    }
+    pub(super) fn update(&mut self, data: &[u8]) {
+        self.length = self
+            .length
+            .wrapping_add(u64::try_from(data.len()).unwrap_or(u64::MAX));
+        let mut rest = data;
+        if !self.buffer.is_empty() {
+            let (head, tail) =
+                rest.split_at(BLOCK_LEN.strict_sub(self.buffer.len()).min(rest.len()));
+            self.buffer.extend_from_slice(head);
+            rest = tail;
+            if self.buffer.len() < BLOCK_LEN {
+                return;
+            }
+            let block = mem::take(&mut self.buffer);
+            self.compress(&block);
+            self.buffer = block;
+            self.buffer.clear();
+        }
+        let mut blocks = rest.chunks_exact(BLOCK_LEN);
+        for block in &mut blocks {
+            self.compress(block);
+        }
+        self.buffer.extend_from_slice(blocks.remainder());
+    }
+}
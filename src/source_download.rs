@@ -1,8 +1,13 @@
+pub(super) use self::http_client::OpenApiSource;
 use crate::diagnostic::{
-    Result as DownloadResult, err_with_source as download_error_with_source,
+    AppError, Result as DownloadResult, err_with_source as download_error_with_source,
     try_string_with_capacity,
 };
 cfg_select! {
+    feature = "rustls" => {
+        use self::rustls_tls::Client as PlatformHttpClient;
+        mod rustls_tls;
+    }
     any(target_os = "linux", target_os = "macos") => {
         use self::libcurl::Client as PlatformHttpClient;
         mod libcurl;
@@ -15,6 +20,7 @@ cfg_select! {
         compile_error!("fcupdater supports only Windows, Linux, and macOS.");
     }
 }
+mod http1;
 mod http_client;
 const HTTP_MAX_BODY_BYTES: usize = 32 * 1024 * 1024;
 const HTTP_MAX_HEADER_BYTES: usize = 256 * 1024;
@@ -24,6 +30,7 @@ const _: () = assert!(
     OLE2_SIGNATURE.len() <= HTTP_ERROR_PREVIEW_BYTES,
     "OLE2 signature preview must include the full signature"
 );
+pub(super) const OPEN_API_BASE_URL: &str = "https://www.opinet.co.kr";
 const OPINET_HOST: &str = "www.opinet.co.kr";
 const NETFUNNEL_HOST: &str = "nfl.opinet.co.kr";
 const OPDOWNLOAD_PATH: &str = "/user/opdown/opDownload.do";
@@ -46,8 +53,15 @@ pub(super) struct SourceDownload {
     cookie_jars: (http_client::CookieJar, http_client::CookieJar),
     form_body_buffer: String,
     netfunnel_path_buffer: String,
+    plain: http1::PlainClient,
     platform: PlatformHttpClient,
 }
+#[derive(Clone, Copy)]
+pub(crate) struct HttpUrl<'url> {
+    authority: &'url str,
+    path: &'url str,
+    secure: bool,
+}
 #[derive(Default)]
 struct ResponseHeaders {
     content_length: Option<usize>,
@@ -83,7 +97,6 @@ impl RequestHeaders<'_> {
     }
 }
 impl ResponseHeaders {
-    #[cfg(any(target_os = "linux", target_os = "macos"))]
     fn parse_content_length(&mut self, raw_value: &str) -> DownloadResult<()> {
         let value = raw_value.trim_ascii();
         if value.is_empty() {
@@ -131,6 +144,34 @@ impl ResponseHeaders {
         Ok(())
     }
 }
+impl<'url> TryFrom<&'url str> for HttpUrl<'url> {
+    type Error = AppError;
+    fn try_from(url: &'url str) -> DownloadResult<Self> {
+        let (secure, rest) = if let Some(rest) = url.strip_prefix("https://") {
+            (true, rest)
+        } else if let Some(rest) = url.strip_prefix("http://") {
+            (false, rest)
+        } else {
+            return Err(format!("URL은 http:// 또는 https://로 시작해야 합니다: {url}").into());
+        };
+        let (authority, path) = rest
+            .find('/')
+            .map_or((rest, "/"), |slash| rest.split_at(slash));
+        if authority.contains('@')
+            || url
+                .bytes()
+                .any(|byte| byte.is_ascii_control() || byte == b' ')
+        {
+            return Err(format!("URL 형식이 올바르지 않습니다: {url}").into());
+        }
+        split_authority(authority, 0)?;
+        Ok(Self {
+            authority,
+            path,
+            secure,
+        })
+    }
+}
 fn checked_http_buffer_len(
     label: &str,
     current_len: usize,
@@ -146,3 +187,24 @@ fn checked_http_buffer_len(
         Ok(next_len)
     }
 }
+fn split_authority(authority: &str, default_port: u16) -> DownloadResult<(&str, u16)> {
+    let (raw_host, port) = match authority.rsplit_once(':') {
+        Some((host, port_text)) if !authority.ends_with(']') => (
+            host,
+            port_text
+                .parse::<u16>()
+                .ok()
+                .filter(|port| *port != 0)
+                .ok_or_else(|| format!("URL 포트가 올바르지 않습니다: {authority}"))?,
+        ),
+        Some(_) | None => (authority, default_port),
+    };
+    let host = raw_host
+        .strip_prefix('[')
+        .and_then(|bracketed| bracketed.strip_suffix(']'))
+        .unwrap_or(raw_host);
+    if host.is_empty() {
+        return Err(format!("URL 호스트가 비어 있습니다: {authority}").into());
+    }
+    Ok((host, port))
+}
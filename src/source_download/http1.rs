@@ -0,0 +1,306 @@
+use super::{
+    DownloadResult, HTTP_MAX_BODY_BYTES, HTTP_MAX_HEADER_BYTES, HttpResponse, RequestHeaders,
+    ResponseHeaders, checked_http_buffer_len, download_error_with_source, split_authority,
+};
+use core::{str, time::Duration};
+use std::{
+    io::{BufRead as _, BufReader, Read, Write},
+    net::{TcpStream, ToSocketAddrs as _},
+};
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+const IO_TIMEOUT: Duration = Duration::from_mins(1);
+const HTTP_PORT: u16 = 80;
+const CHUNK_SIZE_LINE_LIMIT: usize = 1024;
+const RESPONSE_HEADER_CONTENT_LENGTH: &[u8] = b"Content-Length";
+const RESPONSE_HEADER_SET_COOKIE: &[u8] = b"Set-Cookie";
+const RESPONSE_HEADER_TRANSFER_ENCODING: &[u8] = b"Transfer-Encoding";
+#[derive(Default)]
+pub(super) struct PlainClient {
+    request_buffer: RequestBuffer,
+}
+#[derive(Default)]
+pub(super) struct RequestBuffer(Vec<u8>);
+struct ResponseReader<S>(BufReader<S>);
+struct HeaderBlock {
+    chunked: bool,
+    headers: ResponseHeaders,
+    status: u32,
+}
+impl PlainClient {
+    pub(super) fn request(
+        &mut self,
+        request_body: Option<&[u8]>,
+        authority: &str,
+        path: &str,
+        request_headers: RequestHeaders<'_>,
+    ) -> DownloadResult<HttpResponse> {
+        self.request_buffer
+            .encode(request_body, authority, path, request_headers)?;
+        let (host, port) = split_authority(authority, HTTP_PORT)?;
+        self.request_buffer.exchange(host, port, Ok)
+    }
+}
+impl RequestBuffer {
+    pub(super) fn encode(
+        &mut self,
+        request_body: Option<&[u8]>,
+        authority: &str,
+        path: &str,
+        request_headers: RequestHeaders<'_>,
+    ) -> DownloadResult<()> {
+        if !path.starts_with('/') || path.bytes().any(|byte| byte.is_ascii_control()) {
+            return Err("HTTP 요청 경로 형식이 올바르지 않습니다.".into());
+        }
+        self.0.clear();
+        let method = if request_body.is_some() {
+            "POST"
+        } else {
+            "GET"
+        };
+        for part in [method, " ", path, " HTTP/1.1\r\nHost: ", authority, "\r\n"] {
+            self.push(part.as_bytes())?;
+        }
+        for (name, value) in request_headers.iter() {
+            if value.bytes().any(|byte| byte.is_ascii_control()) {
+                return Err(format!("HTTP {name} header에 제어 문자가 포함되어 있습니다.").into());
+            }
+            for part in [name, ": ", value, "\r\n"] {
+                self.push(part.as_bytes())?;
+            }
+        }
+        if let Some(body_bytes) = request_body {
+            let content_length = format!("Content-Length: {}\r\n", body_bytes.len());
+            self.push(content_length.as_bytes())?;
+        }
+        self.push(b"Accept-Encoding: identity\r\nConnection: close\r\n\r\n")?;
+        if let Some(body_bytes) = request_body {
+            self.push(body_bytes)?;
+        }
+        Ok(())
+    }
+    pub(super) fn exchange<S: Read + Write>(
+        &self,
+        host: &str,
+        port: u16,
+        wrap: impl FnOnce(TcpStream) -> DownloadResult<S>,
+    ) -> DownloadResult<HttpResponse> {
+        let addresses = (host, port).to_socket_addrs().map_err(|source| {
+            download_error_with_source(format!("{host} 주소 해석 실패"), source)
+        })?;
+        let mut last_error = None;
+        let mut connected = None;
+        for address in addresses {
+            match TcpStream::connect_timeout(&address, CONNECT_TIMEOUT) {
+                Ok(socket) => {
+                    connected = Some(socket);
+                    break;
+                }
+                Err(source) => last_error = Some(source),
+            }
+        }
+        let Some(socket) = connected else {
+            return Err(last_error.map_or_else(
+                || format!("{host} 주소 해석 결과가 비어 있습니다.").into(),
+                |source| download_error_with_source(format!("{host} TCP 연결 실패"), source),
+            ));
+        };
+        socket.set_read_timeout(Some(IO_TIMEOUT))?;
+        socket.set_write_timeout(Some(IO_TIMEOUT))?;
+        socket.set_nodelay(true)?;
+        let mut stream = wrap(socket)?;
+        stream
+            .write_all(&self.0)
+            .and_then(|()| stream.flush())
+            .map_err(|source| download_error_with_source("HTTP 요청 전송 실패", source))?;
+        let mut reader = ResponseReader(BufReader::new(stream));
+        let mut header_bytes_seen = 0_usize;
+        let block = loop {
+            let block = reader.read_header_block(&mut header_bytes_seen)?;
+            if !(100..200).contains(&block.status) {
+                break block;
+            }
+        };
+        let body = if matches!(block.status, 204 | 304) {
+            Vec::new()
+        } else if block.chunked {
+            reader.read_chunked_body(&mut header_bytes_seen)?
+        } else if let Some(expected_len) = block.headers.content_length {
+            let mut body = Vec::new();
+            reader.read_body_exact(&mut body, expected_len)?;
+            body
+        } else {
+            reader.read_body_to_end()?
+        };
+        Ok(HttpResponse {
+            body,
+            headers: block.headers,
+            status: block.status,
+        })
+    }
+    fn push(&mut self, bytes: &[u8]) -> DownloadResult<()> {
+        self.0
+            .try_reserve(bytes.len())
+            .map_err(|source| download_error_with_source("HTTP 요청 메모리 확보 실패", source))?;
+        self.0.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+impl<S: Read> ResponseReader<S> {
+    fn read_body_exact(&mut self, body: &mut Vec<u8>, len: usize) -> DownloadResult<()> {
+        checked_http_buffer_len("본문", body.len(), len, HTTP_MAX_BODY_BYTES)?;
+        body.try_reserve(len).map_err(|source| {
+            download_error_with_source("HTTP 응답 본문 메모리 확보 실패", source)
+        })?;
+        let read_limit = u64::try_from(len)
+            .map_err(|source| download_error_with_source("HTTP 본문 길이 변환 실패", source))?;
+        let read_len = self
+            .0
+            .by_ref()
+            .take(read_limit)
+            .read_to_end(body)
+            .map_err(|source| download_error_with_source("HTTP 응답 본문 수신 실패", source))?;
+        if read_len == len {
+            Ok(())
+        } else {
+            Err(format!(
+                "HTTP 응답 본문 길이가 Content-Length와 다릅니다: expected={len}, actual={read_len}"
+            )
+            .into())
+        }
+    }
+    fn read_body_to_end(&mut self) -> DownloadResult<Vec<u8>> {
+        let mut body = Vec::new();
+        let read_limit = u64::try_from(HTTP_MAX_BODY_BYTES.strict_add(1))
+            .map_err(|source| download_error_with_source("HTTP 본문 한도 변환 실패", source))?;
+        self.0
+            .by_ref()
+            .take(read_limit)
+            .read_to_end(&mut body)
+            .map_err(|source| download_error_with_source("HTTP 응답 본문 수신 실패", source))?;
+        checked_http_buffer_len("본문", 0, body.len(), HTTP_MAX_BODY_BYTES)?;
+        Ok(body)
+    }
+    fn read_chunked_body(&mut self, header_bytes_seen: &mut usize) -> DownloadResult<Vec<u8>> {
+        let mut body = Vec::new();
+        let mut line = Vec::new();
+        loop {
+            let mut line_bytes = 0_usize;
+            self.read_line_limited(&mut line, &mut line_bytes, CHUNK_SIZE_LINE_LIMIT)?;
+            let size_end = line
+                .iter()
+                .position(|byte| *byte == b';')
+                .unwrap_or(line.len());
+            let size_text = line.get(..size_end).unwrap_or_default().trim_ascii();
+            if size_text.is_empty() {
+                return Err("HTTP chunk 크기가 비어 있습니다.".into());
+            }
+            let mut chunk_len = 0_usize;
+            for byte in size_text {
+                let digit = char::from(*byte)
+                    .to_digit(16)
+                    .ok_or("HTTP chunk 크기가 16진수 형식이 아닙니다.")?;
+                chunk_len = chunk_len
+                    .checked_mul(16)
+                    .and_then(|scaled| scaled.checked_add(usize::try_from(digit).ok()?))
+                    .filter(|len| *len <= HTTP_MAX_BODY_BYTES)
+                    .ok_or_else(|| {
+                        format!("HTTP 응답 본문 크기가 허용 한도({HTTP_MAX_BODY_BYTES} bytes)를 초과했습니다.")
+                    })?;
+            }
+            if chunk_len == 0 {
+                break;
+            }
+            self.read_body_exact(&mut body, chunk_len)?;
+            let mut terminator_bytes = 0_usize;
+            self.read_line_limited(&mut line, &mut terminator_bytes, 2)?;
+            if !line.is_empty() {
+                return Err("HTTP chunk 종결 형식이 올바르지 않습니다.".into());
+            }
+        }
+        loop {
+            self.read_line_limited(&mut line, header_bytes_seen, HTTP_MAX_HEADER_BYTES)?;
+            if line.is_empty() {
+                break;
+            }
+        }
+        Ok(body)
+    }
+    fn read_header_block(&mut self, bytes_seen: &mut usize) -> DownloadResult<HeaderBlock> {
+        let mut line = Vec::new();
+        self.read_line_limited(&mut line, bytes_seen, HTTP_MAX_HEADER_BYTES)?;
+        let mut status_parts = line.splitn(3, |byte| *byte == b' ');
+        let version = status_parts.next().unwrap_or_default();
+        let code = status_parts.next().unwrap_or_default();
+        if !version.starts_with(b"HTTP/1.")
+            || code.len() != 3
+            || !code.iter().all(u8::is_ascii_digit)
+        {
+            return Err("HTTP 응답 status line 형식이 올바르지 않습니다.".into());
+        }
+        let status = code.iter().fold(0_u32, |parsed, digit| {
+            parsed
+                .strict_mul(10)
+                .strict_add(u32::from(digit.strict_sub(b'0')))
+        });
+        let mut block = HeaderBlock {
+            chunked: false,
+            headers: ResponseHeaders::default(),
+            status,
+        };
+        loop {
+            self.read_line_limited(&mut line, bytes_seen, HTTP_MAX_HEADER_BYTES)?;
+            if line.is_empty() {
+                break;
+            }
+            let Some(colon) = line.iter().position(|byte| *byte == b':') else {
+                return Err("HTTP 응답 header 형식이 올바르지 않습니다.".into());
+            };
+            let (raw_name, tail) = line.split_at(colon);
+            let name = raw_name.trim_ascii();
+            let raw_value = tail.get(1..).unwrap_or_default().trim_ascii();
+            let value = str::from_utf8(raw_value).map_err(|source| {
+                download_error_with_source("HTTP header 값 UTF-8 변환 실패", source)
+            })?;
+            let transfer_encoding = name.eq_ignore_ascii_case(RESPONSE_HEADER_TRANSFER_ENCODING);
+            if transfer_encoding && !value.eq_ignore_ascii_case("chunked") {
+                return Err(format!("지원하지 않는 HTTP Transfer-Encoding입니다: {value}").into());
+            }
+            if name.eq_ignore_ascii_case(RESPONSE_HEADER_CONTENT_LENGTH) {
+                block.headers.parse_content_length(value)?;
+            } else if name.eq_ignore_ascii_case(RESPONSE_HEADER_SET_COOKIE) {
+                block.headers.push_set_cookie(value)?;
+            } else {
+                block.chunked |= transfer_encoding;
+            }
+        }
+        if block.chunked && block.headers.content_length.is_some() {
+            return Err("HTTP 응답에 Content-Length와 chunked 전송이 함께 있습니다.".into());
+        }
+        Ok(block)
+    }
+    fn read_line_limited(
+        &mut self,
+        line: &mut Vec<u8>,
+        bytes_seen: &mut usize,
+        limit: usize,
+    ) -> DownloadResult<()> {
+        line.clear();
+        let remaining = limit.saturating_sub(*bytes_seen);
+        let read_limit = u64::try_from(remaining.strict_add(1))
+            .map_err(|source| download_error_with_source("HTTP 헤더 한도 변환 실패", source))?;
+        let read_len = self
+            .0
+            .by_ref()
+            .take(read_limit)
+            .read_until(b'\n', line)
+            .map_err(|source| download_error_with_source("HTTP 응답 header 수신 실패", source))?;
+        *bytes_seen = checked_http_buffer_len("헤더", *bytes_seen, read_len, limit)?;
+        if line.pop() != Some(b'\n') {
+            return Err("HTTP 응답 header가 종결되지 않았습니다.".into());
+        }
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+        Ok(())
+    }
+}
@@ -1,17 +1,28 @@
+pub(crate) use self::open_api::OpenApiSource;
+use self::open_api::{
+    OPEN_API_AREA_PATH, OPEN_API_DETAIL_PATH, OPEN_API_LOWEST_COUNT, OPEN_API_LOWEST_PATH,
+    OPEN_API_PRODUCTS, OPEN_API_SIDO_CODES, OpenApiAreas, OpenApiStationIds,
+};
 use super::{
     CURRENT_PRICE_PAGE_DIV, DEFAULT_REGION_LABEL, DownloadResult, GAS_STATION_API_GBN,
-    GAS_STATION_LPG_CODE, HTTP_ERROR_PREVIEW_BYTES, HttpResponse, NETFUNNEL_DOWNLOAD_ACTION_ID,
-    NETFUNNEL_ENTRY_ACTION_ID, NETFUNNEL_HOST, NETFUNNEL_POLL_LIMIT, NETFUNNEL_SERVICE_ID,
-    OIL_PRICE_DOWNLOAD_TAR_URL, OLE2_SIGNATURE, OPDOWNLOAD_EXCEL_PATH, OPDOWNLOAD_LAYOUT_PATH,
-    OPDOWNLOAD_PATH, OPDOWNLOAD_URL, OPINET_HOST, RequestHeaders, SourceDownload,
-    download_error_with_source, try_string_with_capacity,
+    GAS_STATION_LPG_CODE, HTTP_ERROR_PREVIEW_BYTES, HttpResponse, HttpUrl,
+    NETFUNNEL_DOWNLOAD_ACTION_ID, NETFUNNEL_ENTRY_ACTION_ID, NETFUNNEL_HOST, NETFUNNEL_POLL_LIMIT,
+    NETFUNNEL_SERVICE_ID, OIL_PRICE_DOWNLOAD_TAR_URL, OLE2_SIGNATURE, OPDOWNLOAD_EXCEL_PATH,
+    OPDOWNLOAD_LAYOUT_PATH, OPDOWNLOAD_PATH, OPDOWNLOAD_URL, OPINET_HOST, RequestHeaders,
+    SourceDownload, download_error_with_source, try_string_with_capacity,
+};
+use crate::{
+    diagnostic::{append_fmt, try_vec_with_capacity},
+    logging,
 };
-use crate::diagnostic::append_fmt;
 use core::{mem, time::Duration};
 use std::{
     thread::sleep,
     time::{SystemTime, UNIX_EPOCH},
 };
+mod open_api;
+#[cfg(test)]
+mod tests;
 const MAX_COOKIE_PAIR_BYTES: usize = 4096;
 const MAX_COOKIES_PER_HOST: usize = 64;
 #[derive(Clone, Copy)]
@@ -128,7 +139,7 @@ impl SourceDownload {
     fn fetch_netfunnel_ticket(&mut self, action_id: &str) -> DownloadResult<String> {
         let mut current_key: Option<String> = None;
         let mut current_ttl: Option<u32> = None;
-        for _ in 0..NETFUNNEL_POLL_LIMIT {
+        for attempt in 1..=NETFUNNEL_POLL_LIMIT {
             let result = self.request_netfunnel(action_id, current_key.as_deref(), current_ttl)?;
             self.add_cookie_for_host(HttpHost::Netfunnel, "NetFunnel_ID", &result)?;
             self.add_cookie_for_host(HttpHost::Opinet, "NetFunnel_ID", &result)?;
@@ -139,6 +150,10 @@ impl SourceDownload {
             let code = parse_netfunnel_u32(code_text, "NetFunnel 코드 파싱 실패")?;
             match code {
                 200 | 300 | 303 => {
+                    logging::debug(format_args!(
+                        "NetFunnel {action_id} 통과 (대기 {}회)",
+                        attempt.strict_sub(1)
+                    ));
                     return take_netfunnel_key(result);
                 }
                 201 | 202 => {
@@ -150,6 +165,9 @@ impl SourceDownload {
                     let wait_secs = ttl.clamp(1, 30);
                     current_ttl = Some(ttl);
                     current_key = Some(take_netfunnel_key(result)?);
+                    logging::debug(format_args!(
+                        "NetFunnel {action_id} 대기 {attempt}회: ttl {ttl}, {wait_secs}초 후 재시도"
+                    ));
                     sleep(Duration::from_secs(u64::from(wait_secs)));
                 }
                 302 => return Err(format!("NetFunnel IP 차단: {result}").into()),
@@ -158,29 +176,117 @@ impl SourceDownload {
         }
         Err("NetFunnel 대기 횟수를 초과했습니다.".into())
     }
+    pub(crate) fn fetch_open_api(
+        mut self,
+        api_key: &str,
+        base_url: &str,
+    ) -> DownloadResult<OpenApiSource> {
+        let result = (|| -> DownloadResult<OpenApiSource> {
+            if api_key.is_empty() {
+                return Err("Open API key가 비어 있습니다.".into());
+            }
+            let base = HttpUrl::try_from(base_url)?;
+            let mut areas = OpenApiAreas::default();
+            for (sido_code, sido_label) in OPEN_API_SIDO_CODES {
+                let body = self
+                    .get_open_api(base, OPEN_API_AREA_PATH, &[("area", sido_code)], api_key)
+                    .map_err(|source| {
+                        download_error_with_source(
+                            format!("{sido_label} 시군 코드 조회 실패"),
+                            source,
+                        )
+                    })?;
+                areas.push_from(sido_label, &body)?;
+            }
+            let mut station_ids = OpenApiStationIds::default();
+            for (area_index, area) in areas.0.iter().enumerate() {
+                for product in OPEN_API_PRODUCTS {
+                    let context = format!("{} {product} 최저가 목록", area.region);
+                    let query = [
+                        ("area", area.code.as_str()),
+                        ("prodcd", product),
+                        ("cnt", OPEN_API_LOWEST_COUNT),
+                    ];
+                    let body = self
+                        .get_open_api(base, OPEN_API_LOWEST_PATH, &query, api_key)
+                        .map_err(|source| {
+                            download_error_with_source(format!("{context} 조회 실패"), source)
+                        })?;
+                    station_ids.push_from(&context, &body, area_index)?;
+                }
+            }
+            let mut details =
+                try_vec_with_capacity(station_ids.0.len(), "Open API 응답 목록 메모리 확보 실패")?;
+            for (station_id, area_index) in station_ids.0 {
+                let body = self
+                    .get_open_api(base, OPEN_API_DETAIL_PATH, &[("id", &station_id)], api_key)
+                    .map_err(|source| {
+                        download_error_with_source(
+                            format!("주유소 상세 조회 실패: {station_id}"),
+                            source,
+                        )
+                    })?;
+                let region = areas
+                    .0
+                    .get(area_index)
+                    .map(|area| area.region.clone())
+                    .unwrap_or_default();
+                details.push((region, body));
+            }
+            logging::debug(format_args!(
+                "Open API 시군 {}곳, 주유소 상세 {}건 조회",
+                areas.0.len(),
+                details.len()
+            ));
+            Ok(OpenApiSource::from(details))
+        })();
+        result.map_err(|mut error| {
+            error.update_message(|message| format!("Opinet Open API 조회 실패: {message}"));
+            error
+        })
+    }
     fn finish_response(
         &mut self,
         host: HttpHost,
+        request: &str,
         response: HttpResponse,
     ) -> DownloadResult<Vec<u8>> {
-        let HttpResponse {
-            body,
-            headers,
-            status,
-        } = response;
-        for value in &headers.set_cookies {
+        for value in &response.headers.set_cookies {
             let pair = split_head_or_all(value, ';');
             let (cookie_name, cookie_value) = pair
                 .split_once('=')
                 .ok_or_else(|| format!("HTTP Set-Cookie 형식이 올바르지 않습니다: {value}"))?;
             self.add_cookie_for_host(host, cookie_name.trim_ascii(), cookie_value.trim_ascii())?;
         }
-        if !(200..300).contains(&status) {
-            let body_preview =
-                String::from_utf8_lossy(body.get(..HTTP_ERROR_PREVIEW_BYTES).unwrap_or(&body));
-            return Err(format!("HTTP {status}: {body_preview}").into());
+        response_body(request, response)
+    }
+    fn get_open_api(
+        &mut self,
+        base: HttpUrl<'_>,
+        endpoint: &str,
+        query: &[(&str, &str)],
+        api_key: &str,
+    ) -> DownloadResult<Vec<u8>> {
+        let mut path = String::new();
+        path.push_str(base.path.trim_end_matches('/'));
+        path.push_str(endpoint);
+        path.push_str("?out=json&code=");
+        Self::push_percent_encoded(&mut path, api_key.as_bytes());
+        for &(name, value) in query {
+            path.push('&');
+            path.push_str(name);
+            path.push('=');
+            Self::push_percent_encoded(&mut path, value.as_bytes());
         }
-        Ok(body)
+        let headers = RequestHeaders {
+            accept: "application/json",
+            content_type: None,
+            cookie: None,
+            referer: None,
+            requested_with: false,
+        };
+        let response = self.send(base, &path, headers, None)?;
+        response_body(endpoint, response)
     }
     fn post_form(
         &mut self,
@@ -221,11 +327,22 @@ impl SourceDownload {
             let response = self
                 .platform
                 .post(OPINET_HOST, path, headers, body.as_bytes())?;
-            self.finish_response(HttpHost::Opinet, response)
+            self.finish_response(HttpHost::Opinet, path, response)
         })();
         self.form_body_buffer = body;
         result
     }
+    pub(crate) fn post_json(mut self, url: HttpUrl<'_>, body: &[u8]) -> DownloadResult<()> {
+        let headers = RequestHeaders {
+            accept: "application/json, */*; q=0.8",
+            content_type: Some("application/json; charset=utf-8"),
+            cookie: None,
+            referer: None,
+            requested_with: false,
+        };
+        let response = self.send(url, url.path, headers, Some(body))?;
+        response_body(url.authority, response).map(drop)
+    }
     fn push_percent_encoded(out: &mut String, bytes: &[u8]) {
         for byte in bytes {
             match *byte {
@@ -258,7 +375,8 @@ impl SourceDownload {
                 false,
             )?;
             let opdownload_response = self.platform.get(OPINET_HOST, OPDOWNLOAD_PATH, headers)?;
-            let body = self.finish_response(HttpHost::Opinet, opdownload_response)?;
+            let body =
+                self.finish_response(HttpHost::Opinet, OPDOWNLOAD_PATH, opdownload_response)?;
             let opdownload_page = String::from_utf8(body).map_err(|source| {
                 download_error_with_source("HTTP 응답 UTF-8 변환 실패", source)
             })?;
@@ -424,7 +542,7 @@ impl SourceDownload {
                 false,
             )?;
             let response = self.platform.get(NETFUNNEL_HOST, &path, headers)?;
-            self.finish_response(HttpHost::Netfunnel, response)
+            self.finish_response(HttpHost::Netfunnel, action_id, response)
         };
         self.netfunnel_path_buffer = path;
         let response = response_result?;
@@ -443,6 +561,21 @@ impl SourceDownload {
         text.replace_range(..value_start, "");
         Ok(text)
     }
+    fn send(
+        &mut self,
+        url: HttpUrl<'_>,
+        path: &str,
+        headers: RequestHeaders<'_>,
+        body: Option<&[u8]>,
+    ) -> DownloadResult<HttpResponse> {
+        match (url.secure, body) {
+            (true, None) => self.platform.get(url.authority, path, headers),
+            (true, Some(body_bytes)) => {
+                self.platform.post(url.authority, path, headers, body_bytes)
+            }
+            (false, _) => self.plain.request(body, url.authority, path, headers),
+        }
+    }
 }
 const fn hex_digit(nibble: u8) -> u8 {
     if nibble < 10 {
@@ -465,6 +598,19 @@ fn take_netfunnel_key(mut result: String) -> DownloadResult<String> {
     result.replace_range(..value_start, "");
     Ok(result)
 }
+fn response_body(request: &str, response: HttpResponse) -> DownloadResult<Vec<u8>> {
+    let HttpResponse { body, status, .. } = response;
+    logging::debug(format_args!(
+        "HTTP {request}: 상태 {status}, {} bytes",
+        body.len()
+    ));
+    if !(200..300).contains(&status) {
+        let body_preview =
+            String::from_utf8_lossy(body.get(..HTTP_ERROR_PREVIEW_BYTES).unwrap_or(&body));
+        return Err(format!("HTTP {status}: {body_preview}").into());
+    }
+    Ok(body)
+}
 fn split_head_or_all(value: &str, separator: char) -> &str {
     value.split_once(separator).map_or(value, |(head, _)| head)
 }
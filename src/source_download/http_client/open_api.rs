@@ -0,0 +1,210 @@
+use crate::{
+    diagnostic::{Result, err, err_with_source},
+    excel::{FuelValues, SourceRecordRef, StationService, parse_source_fuel_price},
+    json::JsonValue,
+    region::{TargetRegionPolicy, target_region},
+};
+use core::str;
+pub(super) const OPEN_API_AREA_PATH: &str = "/api/areaCode.do";
+pub(super) const OPEN_API_DETAIL_PATH: &str = "/api/detailById.do";
+pub(super) const OPEN_API_LOWEST_PATH: &str = "/api/lowTop10.do";
+pub(super) const OPEN_API_LOWEST_COUNT: &str = "20";
+pub(super) const OPEN_API_SIDO_CODES: [(&str, &str); 4] = [
+    ("04", "충북"),
+    ("05", "충남"),
+    ("17", "대전"),
+    ("19", OPEN_API_SEJONG_LABEL),
+];
+pub(super) const OPEN_API_PRODUCTS: [&str; 3] = [
+    OPEN_API_PRODUCT_GASOLINE,
+    OPEN_API_PRODUCT_PREMIUM,
+    OPEN_API_PRODUCT_DIESEL,
+];
+const OPEN_API_PRODUCT_DIESEL: &str = "D047";
+const OPEN_API_PRODUCT_GASOLINE: &str = "B027";
+const OPEN_API_PRODUCT_PREMIUM: &str = "B034";
+const OPEN_API_SEJONG_LABEL: &str = "세종";
+const OPEN_API_SEJONG_REGION: &str = "세종시";
+const OPEN_API_BRANDS: [(&str, &str); 10] = [
+    ("E1G", "E1"),
+    ("ETC", "자가상표"),
+    ("GSC", "GS칼텍스"),
+    ("HDO", "HD현대오일뱅크"),
+    ("NHO", "NH-OIL"),
+    ("RTO", "알뜰주유소"),
+    ("RTX", "알뜰(ex)"),
+    ("SKE", "SK에너지"),
+    ("SKG", "SK가스"),
+    ("SOL", "S-OIL"),
+];
+pub(super) struct OpenApiArea {
+    pub code: String,
+    pub region: String,
+}
+#[derive(Default)]
+pub(super) struct OpenApiAreas(pub Vec<OpenApiArea>);
+pub(crate) struct OpenApiSource(Vec<(String, Vec<u8>)>);
+#[derive(Default)]
+pub(super) struct OpenApiStationIds(pub Vec<(String, usize)>);
+impl OpenApiAreas {
+    pub(super) fn push_from(&mut self, sido_label: &str, body: &[u8]) -> Result<()> {
+        let context = format!("{sido_label} 시군 코드");
+        let document = parse_document(body, &context)?;
+        let mut region_scratch = String::new();
+        for area in result_oil(&document, &context)? {
+            let field = |name: &str| {
+                area.get(name)
+                    .and_then(JsonValue::as_text)
+                    .map_or("", str::trim)
+            };
+            let code = field("AREA_CD");
+            let area_name = field("AREA_NM");
+            if code.is_empty() || !code.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err(err(format!(
+                    "Open API {context} 응답의 AREA_CD 값이 올바르지 않습니다: {code}"
+                )));
+            }
+            let region = if sido_label == OPEN_API_SEJONG_LABEL {
+                OPEN_API_SEJONG_REGION.to_owned()
+            } else {
+                let district = area_name.split_whitespace().next().unwrap_or_default();
+                format!("{sido_label} {district}")
+            };
+            if target_region(
+                &region,
+                "",
+                &mut region_scratch,
+                TargetRegionPolicy::Flexible,
+            )?
+            .is_some()
+            {
+                self.0.push(OpenApiArea {
+                    code: code.to_owned(),
+                    region,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+impl From<Vec<(String, Vec<u8>)>> for OpenApiSource {
+    fn from(details: Vec<(String, Vec<u8>)>) -> Self {
+        Self(details)
+    }
+}
+impl OpenApiSource {
+    pub(crate) fn visit_rows(
+        self,
+        mut visitor: impl FnMut(SourceRecordRef<'_>) -> Result<()>,
+    ) -> Result<Result<()>> {
+        let mut station_num = 0_usize;
+        for (region, body) in self.0 {
+            let document = parse_document(&body, "주유소 상세")?;
+            for station in result_oil(&document, "주유소 상세")? {
+                station_num = station_num.strict_add(1);
+                let field = |name: &str| {
+                    station
+                        .get(name)
+                        .and_then(JsonValue::as_text)
+                        .map_or("", str::trim)
+                };
+                let name = field("OS_NM");
+                if name.is_empty() {
+                    return Err(
+                        format!("Opinet 소스 {station_num}행 상호명 값이 비어 있습니다.").into(),
+                    );
+                }
+                let address = Some(field("NEW_ADR"))
+                    .filter(|address| !address.is_empty())
+                    .unwrap_or_else(|| field("VAN_ADR"));
+                if address.is_empty() {
+                    return Err(
+                        format!("Opinet 소스 {station_num}행 주소 값이 비어 있습니다.").into(),
+                    );
+                }
+                let brand_code = field("POLL_DIV_CO");
+                let brand = OPEN_API_BRANDS
+                    .iter()
+                    .find(|&&(code, _)| code == brand_code)
+                    .map_or(brand_code, |&(_, label)| label);
+                let mut fuels = FuelValues::default();
+                for price in station
+                    .get("OIL_PRICE")
+                    .and_then(JsonValue::as_array)
+                    .unwrap_or_default()
+                {
+                    let product = price.get("PRODCD").and_then(JsonValue::as_text);
+                    let (slot, label) = match product {
+                        Some(OPEN_API_PRODUCT_DIESEL) => (&mut fuels.diesel, "경유"),
+                        Some(OPEN_API_PRODUCT_GASOLINE) => (&mut fuels.gasoline, "휘발유"),
+                        Some(OPEN_API_PRODUCT_PREMIUM) => (&mut fuels.premium, "고급휘발유"),
+                        Some(_) | None => continue,
+                    };
+                    *slot = parse_source_fuel_price(
+                        price.get("PRICE").and_then(JsonValue::as_text),
+                        station_num,
+                        label,
+                    )?;
+                }
+                if let Err(source) = visitor(SourceRecordRef {
+                    address,
+                    brand,
+                    fuels,
+                    name,
+                    region: &region,
+                    service: StationService::Unknown,
+                }) {
+                    return Ok(Err(source));
+                }
+            }
+        }
+        Ok(Ok(()))
+    }
+}
+impl OpenApiStationIds {
+    pub(super) fn push_from(
+        &mut self,
+        context: &str,
+        body: &[u8],
+        area_index: usize,
+    ) -> Result<()> {
+        let document = parse_document(body, context)?;
+        for station in result_oil(&document, context)? {
+            let id = station
+                .get("UNI_ID")
+                .and_then(JsonValue::as_text)
+                .map_or("", str::trim);
+            if id.is_empty() {
+                return Err(err(format!(
+                    "Open API {context} 응답에 UNI_ID 값이 비어 있습니다."
+                )));
+            }
+            if !self.0.iter().any(|station_id| station_id.0 == id) {
+                self.0.push((id.to_owned(), area_index));
+            }
+        }
+        Ok(())
+    }
+}
+fn parse_document<'body>(body: &'body [u8], context: &str) -> Result<JsonValue<'body>> {
+    let text = str::from_utf8(body).map_err(|source| {
+        err_with_source(format!("Open API {context} 응답 UTF-8 변환 실패"), source)
+    })?;
+    JsonValue::try_from(text).map_err(|source| {
+        err_with_source(format!("Open API {context} 응답 JSON 해석 실패"), source)
+    })
+}
+fn result_oil<'doc, 'json>(
+    document: &'doc JsonValue<'json>,
+    context: &str,
+) -> Result<&'doc [JsonValue<'json>]> {
+    document
+        .get("RESULT")
+        .and_then(|result| result.get("OIL"))
+        .and_then(JsonValue::as_array)
+        .ok_or_else(|| {
+            err(format!(
+                "Open API {context} 응답에 RESULT.OIL 배열이 없습니다."
+            ))
+        })
+}
@@ -0,0 +1,279 @@
+use super::OpenApiSource;
+use crate::{
+    diagnostic::{ErrorCategory, Result, ensure, err},
+    excel::{FuelValues, StationService},
+    source_download::SourceDownload,
+    stand_in::{StandInRequest, StandInServer},
+    update_run::{LoadedSource, SourceInput},
+};
+const API_KEY: &str = "test key/1";
+const AREAS: [(&str, &str, &str); 10] = [
+    ("04", "0401", "청주시"),
+    ("04", "0402", "충주시"),
+    ("05", "0501", "천안시"),
+    ("05", "0502", "공주시"),
+    ("05", "0503", "보령시"),
+    ("05", "0504", "아산시"),
+    ("05", "0505", "당진시"),
+    ("17", "1701", "유성구"),
+    ("17", "1702", "동구"),
+    ("19", "1901", "세종시"),
+];
+const STATIONS: [(&str, &str, &str, &str); 8] = [
+    (
+        "0401",
+        "청주주유소",
+        "SKE",
+        "충청북도 청주시 상당구 상당로 1",
+    ),
+    (
+        "0501",
+        "천안주유소",
+        "RTO",
+        "충청남도 천안시 동남구 만남로 1",
+    ),
+    ("0502", "공주주유소", "GSC", "충남 공주시 봉황로 1"),
+    ("0503", "보령주유소", "HDO", "충남 보령시 대천로 1"),
+    ("0504", "아산주유소", "SOL", "충남 아산시 번영로 1"),
+    ("1701", "유성주유소", "NHO", "대전광역시 유성구 대학로 1"),
+    ("1702", "동구주유소", "ETC", "대전 동구 중앙로 1"),
+    ("1901", "세종주유소", "XYZ", "세종특별자치시 한누리대로 1"),
+];
+fn detail_json(area_code: &str, oil_price: &str) -> String {
+    let Some(&(_, name, brand, address)) = STATIONS.iter().find(|station| station.0 == area_code)
+    else {
+        return String::from("{\"RESULT\":{\"OIL\":[]}}");
+    };
+    format!(
+        "{{\"RESULT\":{{\"OIL\":[{{\"UNI_ID\":\"A{area_code}\",\"POLL_DIV_CO\":\"{brand}\",\"OS_NM\":\"{name}\",\"VAN_ADR\":\"{address}\",\"NEW_ADR\":\"{address}\"{oil_price}}}]}}}}"
+    )
+}
+fn fetch(server: &StandInServer) -> Result<OpenApiSource> {
+    SourceDownload::default().fetch_open_api(API_KEY, &server.base_url)
+}
+fn fixture_response(request: &StandInRequest) -> (u16, String) {
+    let query = |name: &str| {
+        request
+            .target
+            .split_once('?')
+            .map_or("", |(_, query)| query)
+            .split('&')
+            .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+            .unwrap_or_default()
+            .to_owned()
+    };
+    let path = request.target.split('?').next().unwrap_or_default();
+    let area = query("area");
+    let checked_body = || -> Result<Option<String>> {
+        ensure(
+            query("code") == "test+key%2F1",
+            "API key must be percent-encoded",
+        )?;
+        ensure(
+            query("out") == "json",
+            "responses must be requested as JSON",
+        )?;
+        Ok(Some(match path {
+            "/api/areaCode.do" => {
+                let items: Vec<String> = AREAS
+                    .iter()
+                    .filter(|entry| entry.0 == area)
+                    .map(|&(_, code, name)| {
+                        format!("{{\"AREA_CD\":\"{code}\",\"AREA_NM\":\"{name}\"}}")
+                    })
+                    .collect();
+                format!("{{\"RESULT\":{{\"OIL\":[{}]}}}}", items.join(","))
+            }
+            "/api/lowTop10.do" => {
+                ensure(query("cnt") == "20", "lowest price list size")?;
+                ensure(
+                    ["B027", "B034", "D047"].contains(&query("prodcd").as_str()),
+                    "unexpected product code",
+                )?;
+                format!("{{\"RESULT\":{{\"OIL\":[{{\"UNI_ID\":\"A{area}\",\"PRICE\":1600}}]}}}}")
+            }
+            "/api/detailById.do" => detail_json(
+                query("id").trim_start_matches('A'),
+                ",\"OIL_PRICE\":[{\"PRODCD\":\"B027\",\"PRICE\":1650},{\"PRODCD\":\"B034\",\"PRICE\":\"1899.5\"},{\"PRODCD\":\"D047\",\"PRICE\":1550},{\"PRODCD\":\"K015\",\"PRICE\":1100}]",
+            ),
+            _ => return Ok(None),
+        }))
+    };
+    match checked_body() {
+        Ok(Some(body)) => (200, body),
+        Ok(None) => (404, String::from("not found")),
+        Err(check_err) => (400, check_err.to_string()),
+    }
+}
+#[test]
+fn open_api_records_take_region_from_requested_area_code() -> Result<()> {
+    let server = StandInServer::start(fixture_response)?;
+    let source = fetch(&server)?;
+    let requests = server.requests();
+    ensure(
+        requests
+            .iter()
+            .all(|request| request.method == "GET" && request.body.is_empty()),
+        "open api requests must be bodiless GETs",
+    )?;
+    ensure(
+        !requests.iter().any(|request| {
+            request.target.contains("area=0402") || request.target.contains("area=0505")
+        }),
+        "non-target areas must not be queried",
+    )?;
+    let detail_count = requests
+        .iter()
+        .filter(|request| request.target.starts_with("/api/detailById.do"))
+        .count();
+    ensure(detail_count == 8, "each station detail is fetched once")?;
+    let loaded = LoadedSource::parse(SourceInput::OpenApi(source), "open api")?;
+    ensure(loaded.prices_only, "open api sources are prices-only")?;
+    ensure(loaded.index.len() == 8, "target station count")?;
+    let mut regions: Vec<(&str, &str, &str)> = loaded
+        .index
+        .values()
+        .map(|record| (record.name.as_str(), record.region, record.brand.as_str()))
+        .collect();
+    regions.sort_unstable();
+    ensure(
+        regions
+            == [
+                ("공주주유소", "공주", "GS칼텍스"),
+                ("동구주유소", "대전", "자가상표"),
+                ("보령주유소", "보령", "HD현대오일뱅크"),
+                ("세종주유소", "세종", "XYZ"),
+                ("아산주유소", "아산", "S-OIL"),
+                ("유성주유소", "대전", "NH-OIL"),
+                ("천안주유소", "천안", "알뜰주유소"),
+                ("청주주유소", "청주", "SK에너지"),
+            ],
+        "region and brand per station",
+    )?;
+    ensure(
+        loaded
+            .index
+            .values()
+            .all(|record| matches!(record.service, StationService::Unknown)),
+        "open api has no self-service field",
+    )?;
+    ensure(
+        loaded.index.values().all(|record| {
+            (
+                record.fuels.gasoline,
+                record.fuels.premium,
+                record.fuels.diesel,
+            ) == (Some(1_650_i32), Some(1_900_i32), Some(1_550_i32))
+        }),
+        "fuel prices are taken from OIL_PRICE",
+    )
+}
+#[test]
+fn open_api_region_mismatch_is_rejected() -> Result<()> {
+    let server = StandInServer::start(|request| {
+        if request.target.contains("id=A0504") {
+            return (
+                200,
+                detail_json(
+                    "0501",
+                    ",\"OIL_PRICE\":[{\"PRODCD\":\"B034\",\"PRICE\":1900}]",
+                ),
+            );
+        }
+        fixture_response(request)
+    })?;
+    let source = fetch(&server)?;
+    let Err(error) = LoadedSource::parse(SourceInput::OpenApi(source), "open api") else {
+        return Err(err("region mismatch must fail"));
+    };
+    ensure(
+        error.category() == ErrorCategory::RegionValidation,
+        format!("region mismatch category: {error}"),
+    )?;
+    ensure(
+        error.to_string().contains("region=충남 아산시"),
+        format!("region comes from the requested area code: {error}"),
+    )
+}
+#[test]
+fn open_api_missing_or_null_prices_are_empty() -> Result<()> {
+    let server = StandInServer::start(|request| {
+        if request.target.contains("id=A0401") {
+            return (
+                200,
+                detail_json(
+                    "0401",
+                    ",\"OIL_PRICE\":[{\"PRODCD\":\"B027\",\"PRICE\":null},{\"PRODCD\":\"D047\"},{\"PRODCD\":\"B034\",\"PRICE\":\"\"}]",
+                ),
+            );
+        }
+        if request.target.contains("id=A0501") {
+            return (200, detail_json("0501", ""));
+        }
+        fixture_response(request)
+    })?;
+    let mut fuels_by_name = Vec::new();
+    fetch(&server)?.visit_rows(|record| {
+        fuels_by_name.push((record.name.to_owned(), record.fuels));
+        Ok(())
+    })??;
+    let fuels = |name: &str| -> Result<FuelValues<Option<i32>>> {
+        fuels_by_name
+            .iter()
+            .find(|entry| entry.0 == name)
+            .map(|entry| entry.1)
+            .ok_or_else(|| err(format!("fixture station not visited: {name}")))
+    };
+    for name in ["청주주유소", "천안주유소"] {
+        let empty = fuels(name)?;
+        ensure(
+            (empty.gasoline, empty.premium, empty.diesel) == (None, None, None),
+            format!("{name} prices must be empty"),
+        )?;
+    }
+    ensure(
+        fuels("공주주유소")?.diesel == Some(1_550_i32),
+        "other stations keep their prices",
+    )
+}
+#[test]
+fn open_api_error_payloads_are_reported() -> Result<()> {
+    for (status, body, expected) in [
+        (
+            500,
+            "{\"message\":\"server busy\"}",
+            "HTTP 500: {\"message\":\"server busy\"}",
+        ),
+        (
+            200,
+            "{\"RESULT\":{\"ERROR\":\"invalid key\"}}",
+            "응답에 RESULT.OIL 배열이 없습니다.",
+        ),
+        (
+            200,
+            "<RESULT><ERROR>invalid key</ERROR></RESULT>",
+            "응답 JSON 해석 실패",
+        ),
+    ] {
+        let server = StandInServer::start(move |_: &StandInRequest| (status, String::from(body)))?;
+        let Err(fetch_err) = fetch(&server) else {
+            return Err(err(format!("error payload must fail: {body}")));
+        };
+        let message = fetch_err.to_string();
+        ensure(
+            message.starts_with("Opinet Open API 조회 실패: ")
+                && message.contains("충북")
+                && message.contains(expected),
+            format!("error chain for {body}: {message}"),
+        )?;
+    }
+    let Err(empty_key) = SourceDownload::default().fetch_open_api("", "http://127.0.0.1:9") else {
+        return Err(err("empty key must fail"));
+    };
+    ensure(
+        empty_key
+            .to_string()
+            .contains("Open API key가 비어 있습니다."),
+        format!("empty key message: {empty_key}"),
+    )
+}
@@ -0,0 +1,68 @@
+use super::{
+    DownloadResult, HttpResponse, RequestHeaders, download_error_with_source, http1,
+    split_authority,
+};
+use alloc::sync::Arc;
+use rustls::{
+    ClientConfig, ClientConnection, RootCertStore, StreamOwned, crypto::ring, pki_types::ServerName,
+};
+const HTTPS_PORT: u16 = 443;
+#[derive(Default)]
+pub(super) struct Client {
+    config: Option<Arc<ClientConfig>>,
+    request_buffer: http1::RequestBuffer,
+}
+impl Client {
+    fn connection(&mut self, host: &str) -> DownloadResult<ClientConnection> {
+        let config = if let Some(config) = self.config.as_ref() {
+            Arc::clone(config)
+        } else {
+            let roots = RootCertStore {
+                roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+            };
+            let config = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+                .with_safe_default_protocol_versions()
+                .map_err(|source| download_error_with_source("rustls TLS 설정 생성 실패", source))?
+                .with_root_certificates(roots)
+                .with_no_client_auth();
+            Arc::clone(self.config.insert(Arc::new(config)))
+        };
+        let server_name = ServerName::try_from(host)
+            .map_err(|source| download_error_with_source("TLS 서버 이름 해석 실패", source))?
+            .to_owned();
+        ClientConnection::new(config, server_name)
+            .map_err(|source| download_error_with_source("rustls TLS 연결 생성 실패", source))
+    }
+    pub(super) fn get(
+        &mut self,
+        host: &str,
+        path: &str,
+        request_headers: RequestHeaders<'_>,
+    ) -> DownloadResult<HttpResponse> {
+        self.request(None, host, path, request_headers)
+    }
+    pub(super) fn post(
+        &mut self,
+        host: &str,
+        path: &str,
+        request_headers: RequestHeaders<'_>,
+        body: &[u8],
+    ) -> DownloadResult<HttpResponse> {
+        self.request(Some(body), host, path, request_headers)
+    }
+    fn request(
+        &mut self,
+        request_body: Option<&[u8]>,
+        host: &str,
+        path: &str,
+        request_headers: RequestHeaders<'_>,
+    ) -> DownloadResult<HttpResponse> {
+        self.request_buffer
+            .encode(request_body, host, path, request_headers)?;
+        let (host_name, port) = split_authority(host, HTTPS_PORT)?;
+        let connection = self.connection(host_name)?;
+        self.request_buffer.exchange(host_name, port, |socket| {
+            Ok(StreamOwned::new(connection, socket))
+        })
+    }
+}
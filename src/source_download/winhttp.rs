@@ -1,6 +1,6 @@
 use super::{
     DownloadResult, HTTP_MAX_BODY_BYTES, HTTP_MAX_HEADER_BYTES, HttpResponse, RequestHeaders,
-    ResponseHeaders, checked_http_buffer_len, download_error_with_source,
+    ResponseHeaders, checked_http_buffer_len, download_error_with_source, split_authority,
 };
 use crate::diagnostic::{try_string_with_capacity, try_vec_with_capacity};
 use alloc::{string::String, vec::Vec};
@@ -283,15 +283,11 @@ impl Client {
         {
             return Ok(entry.handle.0);
         }
-        let host_wide = wide(host)?;
+        let (host_name, port) = split_authority(host, INTERNET_DEFAULT_HTTPS_PORT)?;
+        let host_wide = wide(host_name)?;
         // SAFETY: host_wide is NUL-terminated and cache.session is a valid session handle.
         let raw_connect = unsafe {
-            sys::WinHttpConnect(
-                cache.session.as_ptr(),
-                host_wide.as_ptr(),
-                INTERNET_DEFAULT_HTTPS_PORT,
-                0,
-            )
+            sys::WinHttpConnect(cache.session.as_ptr(), host_wide.as_ptr(), port, 0)
         };
         let handle = NonNull::new(raw_connect)
             .map(Handle)
@@ -0,0 +1,94 @@
+use crate::diagnostic::{AppError, Result, err_with_source};
+use alloc::sync::Arc;
+use std::{
+    io::{BufRead as _, BufReader, Read as _, Write as _},
+    net::{TcpListener, TcpStream},
+    sync::{Mutex, PoisonError},
+    thread,
+};
+pub(super) struct StandInRequest {
+    pub body: String,
+    pub method: String,
+    pub target: String,
+}
+pub(super) struct StandInServer {
+    pub base_url: String,
+    pub log: Arc<Mutex<Vec<StandInRequest>>>,
+}
+impl StandInServer {
+    pub(super) fn requests(&self) -> Vec<StandInRequest> {
+        self.log
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .drain(..)
+            .collect()
+    }
+    pub(super) fn start(
+        respond: impl Fn(&StandInRequest) -> (u16, String) + Send + 'static,
+    ) -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let port = listener.local_addr()?.port();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(connection) = stream else {
+                    continue;
+                };
+                let Ok(request) = StandInRequest::try_from(&connection) else {
+                    continue;
+                };
+                let (status, body) = respond(&request);
+                log.lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(request);
+                write!(
+                    &connection,
+                    "HTTP/1.1 {status} Stand-In\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap_or_default();
+            }
+        });
+        Ok(Self {
+            base_url: format!("http://127.0.0.1:{port}"),
+            log: requests,
+        })
+    }
+}
+impl TryFrom<&TcpStream> for StandInRequest {
+    type Error = AppError;
+    fn try_from(connection: &TcpStream) -> Result<Self> {
+        let mut reader = BufReader::new(connection);
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let mut parts = line.split_whitespace();
+        let method = parts.next().unwrap_or_default().to_owned();
+        let target = parts.next().unwrap_or_default().to_owned();
+        let mut content_length = 0_usize;
+        loop {
+            line.clear();
+            reader.read_line(&mut line)?;
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':')
+                && name.eq_ignore_ascii_case("Content-Length")
+            {
+                content_length = value
+                    .trim()
+                    .parse()
+                    .map_err(|source| err_with_source("stand-in Content-Length", source))?;
+            }
+        }
+        let mut body = vec![0_u8; content_length];
+        reader.read_exact(&mut body)?;
+        Ok(Self {
+            body: String::from_utf8(body)
+                .map_err(|source| err_with_source("stand-in request body", source))?,
+            method,
+            target,
+        })
+    }
+}
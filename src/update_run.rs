@@ -1,44 +1,108 @@
+#[cfg(feature = "sqlite")]
+use crate::history::RunHistory;
 use crate::{
-    change_log::ChangeLogUpdater,
-    diagnostic::{Result, err, err_with_source, path_context_message, terminal_safe},
-    excel::{SaveVerification, SourceReader, SourceRecord},
+    backup::BackupStore,
+    change_log::{ChangeLogUpdater, SavedChangeLog},
+    diagnostic::{
+        AppError, ErrorCategory, Result, append_fmt, err, err_with_source, path_context_message,
+        terminal_safe, try_vec_with_capacity,
+    },
+    excel::{SaveVerification, SourceReader, SourceRecord, SourceRecordRef, StationService},
     excel::{writer::Workbook as StdWorkbook, xlsx_container::XlsxContainer},
-    master_sheet::{ChangeRow, MasterSheetUpdateResult, MasterSheetUpdater, StoreRow},
+    geo::GeoTable,
+    kst::KstTime,
+    logging::{self, LogStage},
+    master_sheet::{
+        ChangeRow, MasterSheetUpdateResult, MasterSheetUpdater, MasterStations, StoreRow,
+        UpdateSettings,
+    },
     region::{
         TARGET_REGION_COUNT, TARGET_REGIONS, TargetRegionPolicy, increment_target_region_count,
         normalize_address_key_into, target_region,
     },
-    source_download::SourceDownload,
+    sha256::Sha256,
+    source_download::{OPEN_API_BASE_URL, OpenApiSource, SourceDownload},
     temp_entry::open_regular,
+    webhook::{RunSummary, WebhookTarget},
     write_line,
 };
-use core::{mem, time::Duration};
+use core::mem;
 use std::{
     collections::{HashMap, hash_map::Entry},
-    io::Write,
+    io::{Read as _, Write},
     path::Path,
-    time::{SystemTime, UNIX_EPOCH},
 };
+#[cfg(test)]
+mod tests;
+pub(super) const DOWNLOAD_SOURCE_LABEL: &str = "Opinet 자동 다운로드";
+pub(super) const OPEN_API_SOURCE_LABEL: &str = "Opinet Open API";
+pub(super) const SOURCE_FILE_LABEL: &str = "소스 파일";
 const HALF_COUNT_DIVISOR: usize = 2;
-const KST_OFFSET: Duration = Duration::from_hours(9);
-const SECS_PER_DAY_U64: u64 = 86_400;
+const SOURCE_FILE_MAX_BYTES: u64 = 32 * 1024 * 1024;
 const SOURCE_INDEX_GROWTH: usize = 256;
-struct LoadedSource {
-    index: HashMap<String, SourceRecord>,
-    region_counts: [usize; TARGET_REGION_COUNT],
+pub(super) enum SourceInput {
+    Bytes(Vec<u8>),
+    OpenApi(OpenApiSource),
+}
+pub(super) struct LoadedSource {
+    pub index: HashMap<String, SourceRecord>,
+    pub prices_only: bool,
+    pub region_counts: [usize; TARGET_REGION_COUNT],
+    pub source_hash: String,
 }
 impl LoadedSource {
+    fn content_hash(&self) -> Result<String> {
+        let mut entries =
+            try_vec_with_capacity(self.index.len(), "소스 해시 정렬 메모리 확보 실패")?;
+        entries.extend(self.index.iter());
+        entries.sort_unstable_by(|left, right| left.0.cmp(right.0));
+        let mut hasher = Sha256::default();
+        let mut line = String::new();
+        for (key, record) in entries {
+            line.clear();
+            for field in [
+                key.as_str(),
+                &record.name,
+                &record.brand,
+                record.region,
+                record.service.label(),
+            ] {
+                line.push_str(field);
+                line.push('\t');
+            }
+            for (index, price) in [
+                record.fuels.gasoline,
+                record.fuels.premium,
+                record.fuels.diesel,
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                if index != 0 {
+                    line.push('\t');
+                }
+                if let Some(value) = price {
+                    append_fmt(&mut line, format_args!("{value}"));
+                }
+            }
+            line.push('\n');
+            hasher.update(line.as_bytes());
+        }
+        Ok(hasher.finish_hex())
+    }
     fn finish_validation(&self) -> Result<()> {
         let target_record_count = self.index.len();
         if target_record_count == 0 {
-            return Err(err("Opinet 소스에서 대상 지역 레코드를 찾지 못했습니다."));
+            return Err(err("Opinet 소스에서 대상 지역 레코드를 찾지 못했습니다.")
+                .with_category(ErrorCategory::RegionValidation));
         }
         for (region, count) in TARGET_REGIONS.iter().zip(self.region_counts.iter()) {
             if *count == 0 {
                 return Err(err(format!(
                     "Opinet 소스에서 대상 지역 레코드를 찾지 못했습니다: {}",
                     region.label(),
-                )));
+                ))
+                .with_category(ErrorCategory::RegionValidation));
             }
         }
         let required_populated_count = target_record_count.div_ceil(HALF_COUNT_DIVISOR);
@@ -46,7 +110,8 @@ impl LoadedSource {
             if populated_count < required_populated_count {
                 return Err(err(format!(
                     "Opinet 소스의 대상 지역 {label} 값이 비정상적으로 부족합니다: {populated_count}건 / {target_record_count}건"
-                )));
+                ))
+                .with_category(ErrorCategory::SafetyThreshold));
             }
             Ok(())
         };
@@ -71,94 +136,103 @@ impl LoadedSource {
         }
         Ok(())
     }
-}
-pub(super) struct UpdateRun<'out> {
-    pub master_path: &'out Path,
-    pub out: &'out mut dyn Write,
-    pub save_verification: SaveVerification,
-}
-impl UpdateRun<'_> {
-    fn load_source(&mut self) -> Result<LoadedSource> {
-        let source_data = SourceDownload::default().refresh_source()?;
-        write_line(self.out, format_args!("Opinet 소스 데이터 준비 완료"))?;
-        let mut loaded_source = LoadedSource {
+    pub(super) fn parse(source_input: SourceInput, read_context: &'static str) -> Result<Self> {
+        let mut loaded_source = Self {
             index: HashMap::new(),
+            prices_only: matches!(source_input, SourceInput::OpenApi(_)),
             region_counts: [0; TARGET_REGION_COUNT],
+            source_hash: String::new(),
         };
         let mut address_key_scratch = String::new();
         let mut target_region_scratch = String::new();
-        let source_index_result = SourceReader::from(source_data)
-            .visit_rows(|borrowed_record| {
-                if let Some(region) = target_region(
-                    borrowed_record.region,
-                    borrowed_record.address,
-                    &mut target_region_scratch,
-                    TargetRegionPolicy::StrictSource,
-                )? {
-                    normalize_address_key_into(borrowed_record.address, &mut address_key_scratch)?;
-                    let key = mem::take(&mut address_key_scratch);
-                    if loaded_source.index.len() == loaded_source.index.capacity() {
-                        loaded_source
-                            .index
-                            .try_reserve(SOURCE_INDEX_GROWTH)
-                            .map_err(|source| {
-                                err_with_source("소스 index 맵 추가 메모리 확보 실패", source)
-                            })?;
+        let mut row_count = 0_usize;
+        let visit_record = |borrowed_record: SourceRecordRef<'_>| {
+            row_count = row_count.strict_add(1);
+            if let Some(region) = target_region(
+                borrowed_record.region,
+                borrowed_record.address,
+                &mut target_region_scratch,
+                TargetRegionPolicy::StrictSource,
+            )
+            .map_err(|region_err| region_err.with_category(ErrorCategory::RegionValidation))?
+            {
+                normalize_address_key_into(borrowed_record.address, &mut address_key_scratch)?;
+                let key = mem::take(&mut address_key_scratch);
+                if loaded_source.index.len() == loaded_source.index.capacity() {
+                    loaded_source
+                        .index
+                        .try_reserve(SOURCE_INDEX_GROWTH)
+                        .map_err(|source| {
+                            err_with_source("소스 index 맵 추가 메모리 확보 실패", source)
+                        })?;
+                }
+                match loaded_source.index.entry(key) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(borrowed_record.into_owned_with_region(region.label())?);
+                        increment_target_region_count(&mut loaded_source.region_counts, region);
                     }
-                    match loaded_source.index.entry(key) {
-                        Entry::Vacant(entry) => {
-                            entry.insert(borrowed_record.into_owned_with_region(region.label())?);
-                            increment_target_region_count(&mut loaded_source.region_counts, region);
-                        }
-                        Entry::Occupied(entry) => {
-                            let existing = entry.get();
-                            return Err(err(format!(
-                                "Opinet 소스 주소 중복: address={}, existing={}, incoming={}",
-                                existing.address, existing.name, borrowed_record.name
-                            )));
-                        }
+                    Entry::Occupied(entry) => {
+                        let existing = entry.get();
+                        return Err(err(format!(
+                            "Opinet 소스 주소 중복: address={}, existing={}, incoming={}",
+                            existing.address, existing.name, borrowed_record.name
+                        )));
                     }
                 }
-                Ok(())
-            })
-            .map_err(|source_err| err_with_source("Opinet 소스 xls 읽기 실패", source_err))?;
-        source_index_result?;
-        loaded_source.finish_validation()?;
+            }
+            Ok(())
+        };
+        let parse_stage = LogStage::start("소스 해석");
+        let visited = match source_input {
+            SourceInput::Bytes(source_data) => {
+                SourceReader::from(source_data).visit_rows(visit_record)
+            }
+            SourceInput::OpenApi(source_data) => source_data.visit_rows(visit_record),
+        }
+        .map_err(|source_err| {
+            err_with_source(read_context, source_err).with_category(ErrorCategory::SourceFormat)
+        })?;
+        parse_stage.finish();
+        visited
+            .and_then(|()| loaded_source.finish_validation())
+            .map_err(|source_err| source_err.with_category(ErrorCategory::SourceFormat))?;
+        logging::debug(format_args!(
+            "소스 행 {row_count}건 중 대상 지역 레코드 {}건",
+            loaded_source.index.len()
+        ));
+        loaded_source.source_hash = loaded_source.content_hash()?;
         Ok(loaded_source)
     }
-    fn open_updated_workbook<'source>(
-        &mut self,
-        loaded_source: &'source LoadedSource,
-    ) -> Result<(StdWorkbook, MasterSheetUpdateResult<'source>)> {
-        write_line(self.out, format_args!("마스터 파일 처리 중..."))?;
-        let master_file = open_regular(self.master_path, false).map_err(|source| {
-            err_with_source(
-                path_context_message("마스터 xlsx 파일 열기 실패", self.master_path),
-                source,
-            )
-        })?;
-        let container = XlsxContainer::from_validated_file(master_file, self.master_path)?;
-        let mut book = StdWorkbook::from_container(container)?;
+    fn update_master(
+        &self,
+        book: &mut StdWorkbook,
+        settings: &UpdateSettings<'_>,
+    ) -> Result<MasterSheetUpdateResult<'_>> {
+        let update_stage = LogStage::start("마스터 현행화");
         let master_update = MasterSheetUpdater {
-            source_index: &loaded_source.index,
+            detour_ranking: settings.detour_ranking,
+            geo_table: settings.geo_table,
+            map_url: settings.map_url,
+            prices_only: self.prices_only,
+            source_index: &self.index,
         }
-        .update(&mut book)?;
-        write_line(self.out, format_args!("대상 지역별 건수 확인:"))?;
+        .update(book)
+        .map_err(|master_err| master_err.with_category(ErrorCategory::MasterStructure))?;
+        update_stage.finish();
+        logging::info(format_args!("대상 지역별 건수 확인:"));
         let mut region_validation_error = None;
         for (((region, existing_count), matched_existing_count), source_count) in TARGET_REGIONS
             .iter()
             .zip(master_update.existing_region_counts.iter())
             .zip(master_update.matched_existing_region_counts.iter())
-            .zip(loaded_source.region_counts.iter())
+            .zip(self.region_counts.iter())
         {
             let label = region.label();
-            write_line(
-                self.out,
-                format_args!(
-                    "  {label}: 기존 {existing_count}건 / 기존 주소 일치 {matched_existing_count}건 / 소스 {source_count}건"
-                ),
-            )?;
+            logging::info(format_args!(
+                "  {label}: 기존 {existing_count}건 / 기존 주소 일치 {matched_existing_count}건 / 소스 {source_count}건"
+            ));
             if region_validation_error.is_none()
+                && !self.prices_only
                 && *existing_count != 0
                 && *matched_existing_count < existing_count.div_ceil(HALF_COUNT_DIVISOR)
             {
@@ -168,19 +242,327 @@ impl UpdateRun<'_> {
             }
         }
         if let Some(message) = region_validation_error {
-            return Err(err(message));
+            return Err(err(message).with_category(ErrorCategory::RegionValidation));
+        }
+        if master_update.untouched_count != 0 {
+            logging::info(format_args!(
+                "Open API 조회 결과에 없는 기존 업체 {}건은 그대로 둡니다.",
+                master_update.untouched_count
+            ));
         }
         if master_update.existing_count == 0 {
-            return Err(err("현행화 대상 레코드를 찾지 못했습니다."));
+            return Err(err("현행화 대상 레코드를 찾지 못했습니다.")
+                .with_category(ErrorCategory::MasterStructure));
         }
         let deleted_count = master_update.deleted.len();
         if deleted_count >= master_update.existing_count.div_ceil(HALF_COUNT_DIVISOR) {
             return Err(err(format!(
                 "폐업 처리 건수가 비정상적으로 많아 저장을 중단합니다: {deleted_count}건 / {}건",
                 master_update.existing_count
-            )));
+            ))
+            .with_category(ErrorCategory::SafetyThreshold));
+        }
+        Ok(master_update)
+    }
+}
+impl TryFrom<(&Self, &StdWorkbook)> for LoadedSource {
+    type Error = AppError;
+    fn try_from((api_source, book): (&Self, &StdWorkbook)) -> Result<Self> {
+        let master = MasterStations::try_from(book)?;
+        let mut master_index = HashMap::new();
+        master_index
+            .try_reserve(master.stations.len())
+            .map_err(|source| err_with_source("마스터 주소 맵 메모리 확보 실패", source))?;
+        for station in &master.stations {
+            master_index.insert(station.address_key.as_str(), station);
+        }
+        let mut index = HashMap::new();
+        index
+            .try_reserve(api_source.index.len())
+            .map_err(|source| err_with_source("가격 갱신 대상 맵 메모리 확보 실패", source))?;
+        let mut matched_counts = [0_usize; TARGET_REGION_COUNT];
+        let mut target_region_scratch = String::new();
+        api_source.index.iter().try_for_each(|(key, record)| {
+            let Some(station) = master_index.get(key.as_str()) else {
+                return Ok(());
+            };
+            if let Some(position) = TARGET_REGIONS
+                .iter()
+                .position(|region| region.label() == record.region)
+                && let Some(count) = matched_counts.get_mut(position)
+            {
+                *count = count.strict_add(1);
+            }
+            let region = target_region(
+                &station.region,
+                &station.address,
+                &mut target_region_scratch,
+                TargetRegionPolicy::Flexible,
+            )?
+            .map_or(record.region, |master_region| master_region.label());
+            index.insert(
+                key.clone(),
+                SourceRecord {
+                    address: station.address.clone(),
+                    brand: station.brand.clone(),
+                    fuels: record.fuels,
+                    name: station.name.clone(),
+                    region,
+                    service: StationService::Unknown,
+                },
+            );
+            Ok::<(), AppError>(())
+        })?;
+        for ((region, fetched_count), matched_count) in TARGET_REGIONS
+            .iter()
+            .zip(api_source.region_counts.iter())
+            .zip(matched_counts.iter())
+        {
+            if *matched_count < fetched_count.div_ceil(HALF_COUNT_DIVISOR) {
+                return Err(err(format!(
+                    "Open API 조회 결과 중 기존 주소와 일치하는 업체가 비정상적으로 적어 저장을 중단합니다: {} 조회 {fetched_count}건 / 기존 주소 일치 {matched_count}건",
+                    region.label(),
+                ))
+                .with_category(ErrorCategory::RegionValidation));
+            }
+        }
+        let refused_count = api_source.index.len().saturating_sub(index.len());
+        if refused_count > 0 {
+            logging::info(format_args!(
+                "Open API 조회 결과 중 마스터에 없는 업체 {refused_count}건은 추가하지 않습니다."
+            ));
+        }
+        Ok(Self {
+            index,
+            prices_only: true,
+            region_counts: api_source.region_counts,
+            source_hash: api_source.source_hash.clone(),
+        })
+    }
+}
+pub(super) struct UpdateOutcome {
+    pub source_hash: String,
+    pub summary: Option<RunSummary>,
+    pub updated: bool,
+}
+pub(super) struct UpdateRun<'out> {
+    pub backups: BackupStore<'out>,
+    pub detour_ranking: bool,
+    pub force: bool,
+    pub geo_table_path: Option<&'out Path>,
+    #[cfg(feature = "sqlite")]
+    pub history_path: Option<&'out Path>,
+    pub map_url: Option<&'out str>,
+    pub master_path: &'out Path,
+    pub ods_path: Option<&'out Path>,
+    pub open_api_key: Option<String>,
+    pub out: &'out mut dyn Write,
+    pub save_verification: SaveVerification,
+    pub source_path: Option<&'out Path>,
+    pub webhook: Option<WebhookTarget<'out>>,
+    pub webhook_top: usize,
+}
+impl UpdateRun<'_> {
+    pub(super) fn apply(&mut self, loaded_source: &LoadedSource) -> Result<UpdateOutcome> {
+        let geo_table = self
+            .geo_table_path
+            .map(|path| {
+                GeoTable::try_from(path).map_err(|source| {
+                    err_with_source(path_context_message("좌표표 읽기 실패", path), source)
+                })
+            })
+            .transpose()?;
+        let settings = UpdateSettings {
+            detour_ranking: self.detour_ranking,
+            geo_table: geo_table.as_ref(),
+            map_url: self.map_url,
+        };
+        let Some((mut book, settings_hash)) = self.open_master(loaded_source, &settings)? else {
+            write_line(
+                self.out,
+                format_args!(
+                    "소스 변경 없음: 워크북에 기록된 소스 해시와 현행화 설정이 같습니다 ({}). 파일을 수정하지 않고 종료합니다.",
+                    loaded_source.source_hash
+                ),
+            )?;
+            return Ok(UpdateOutcome {
+                source_hash: loaded_source.source_hash.clone(),
+                summary: None,
+                updated: false,
+            });
+        };
+        let master_source;
+        let update_source = if loaded_source.prices_only {
+            master_source = LoadedSource::try_from((loaded_source, &book))?;
+            &master_source
+        } else {
+            loaded_source
+        };
+        let master_update = update_source.update_master(&mut book, &settings)?;
+        let kst = KstTime::now()?;
+        let today = kst.date();
+        self.save_workbook_with_change_log(
+            &master_update,
+            book,
+            kst,
+            &today,
+            (&loaded_source.source_hash, &settings_hash),
+        )?;
+        if let Some(ods_path) = self.ods_path {
+            let ods_stage = LogStage::start("ODS 저장");
+            StdWorkbook::open(self.master_path)
+                .and_then(|saved| saved.save_ods(ods_path))
+                .map_err(|source| {
+                    err_with_source(
+                        path_context_message(
+                            "마스터 파일은 저장됐지만 ODS 저장에 실패했습니다",
+                            ods_path,
+                        ),
+                        source,
+                    )
+                    .with_category(ErrorCategory::Save)
+                })?;
+            ods_stage.finish();
+            logging::info(format_args!(
+                "ODS 저장 완료: {}",
+                terminal_safe(&ods_path.display())
+            ));
+        }
+        #[cfg(feature = "sqlite")]
+        if let Some(history_path) = self.history_path {
+            let run_at = kst.timestamp();
+            let history_stage = LogStage::start("이력 DB 기록");
+            RunHistory {
+                master_update: &master_update,
+                run_at: &run_at,
+                run_date: &today,
+                source_hash: &loaded_source.source_hash,
+                source_index: &update_source.index,
+                source_kind: self.source_label(),
+            }
+            .record(history_path)
+            .map_err(|source| {
+                err_with_source(
+                    path_context_message(
+                        "마스터 파일은 저장됐지만 이력 DB 기록에 실패했습니다",
+                        history_path,
+                    ),
+                    source,
+                )
+                .with_category(ErrorCategory::Save)
+            })?;
+            history_stage.finish();
+            logging::info(format_args!(
+                "이력 DB 기록 완료: {}",
+                terminal_safe(&history_path.display())
+            ));
+        }
+        Ok(UpdateOutcome {
+            source_hash: loaded_source.source_hash.clone(),
+            summary: Some(RunSummary::from((&master_update, self.webhook_top))),
+            updated: true,
+        })
+    }
+    fn load_source(&self) -> Result<LoadedSource> {
+        let fetch_stage = LogStage::start("소스 준비");
+        let (source_input, read_context) = if let Some(api_key) = self.open_api_key.as_deref() {
+            let source_data = SourceDownload::default()
+                .fetch_open_api(api_key, OPEN_API_BASE_URL)
+                .map_err(|download_err| download_err.with_category(ErrorCategory::Download))?;
+            fetch_stage.finish();
+            logging::info(format_args!("Opinet Open API 데이터 준비 완료"));
+            (
+                SourceInput::OpenApi(source_data),
+                "Opinet Open API 응답 읽기 실패",
+            )
+        } else if let Some(source_path) = self.source_path {
+            let source_file = open_regular(source_path, false)
+                .map_err(|source_err| {
+                    err_with_source(
+                        path_context_message("소스 파일 열기 실패", source_path),
+                        source_err,
+                    )
+                    .with_category(ErrorCategory::SourceFormat)
+                })?
+                .file;
+            let source_len = source_file
+                .metadata()
+                .map_err(|source_err| {
+                    err_with_source(
+                        path_context_message("소스 파일 정보 확인 실패", source_path),
+                        source_err,
+                    )
+                    .with_category(ErrorCategory::SourceFormat)
+                })?
+                .len();
+            if source_len > SOURCE_FILE_MAX_BYTES {
+                return Err(err(format!(
+                    "소스 파일 크기가 허용 한도({SOURCE_FILE_MAX_BYTES} bytes)를 초과했습니다: {}",
+                    source_path.display()
+                ))
+                .with_category(ErrorCategory::SourceFormat));
+            }
+            let mut source_data = try_vec_with_capacity(
+                usize::try_from(source_len).map_err(|source_err| {
+                    err_with_source("소스 파일 크기 변환 실패", source_err)
+                })?,
+                "소스 파일 메모리 확보 실패",
+            )?;
+            source_file
+                .take(SOURCE_FILE_MAX_BYTES)
+                .read_to_end(&mut source_data)
+                .map_err(|source_err| {
+                    err_with_source(
+                        path_context_message("소스 파일 읽기 실패", source_path),
+                        source_err,
+                    )
+                    .with_category(ErrorCategory::SourceFormat)
+                })?;
+            fetch_stage.finish();
+            logging::info(format_args!(
+                "소스 파일 준비 완료: {}",
+                terminal_safe(&source_path.display())
+            ));
+            (SourceInput::Bytes(source_data), "소스 파일 읽기 실패")
+        } else {
+            let source_data = SourceDownload::default()
+                .refresh_source()
+                .map_err(|download_err| download_err.with_category(ErrorCategory::Download))?;
+            fetch_stage.finish();
+            logging::info(format_args!("Opinet 소스 데이터 준비 완료"));
+            logging::debug(format_args!("소스 xls 크기: {} bytes", source_data.len()));
+            (SourceInput::Bytes(source_data), "Opinet 소스 xls 읽기 실패")
+        };
+        LoadedSource::parse(source_input, read_context)
+    }
+    fn open_master(
+        &self,
+        loaded_source: &LoadedSource,
+        settings: &UpdateSettings<'_>,
+    ) -> Result<Option<(StdWorkbook, String)>> {
+        logging::info(format_args!("마스터 파일 처리 중..."));
+        let open_stage = LogStage::start("마스터 열기");
+        let master_file = open_regular(self.master_path, false).map_err(|source| {
+            err_with_source(
+                path_context_message("마스터 xlsx 파일 열기 실패", self.master_path),
+                source,
+            )
+            .with_category(ErrorCategory::MasterStructure)
+        })?;
+        let book = XlsxContainer::from_validated_file(master_file, self.master_path)
+            .and_then(StdWorkbook::from_container)
+            .map_err(|master_err| master_err.with_category(ErrorCategory::MasterStructure))?;
+        open_stage.finish();
+        let settings_hash = settings
+            .hash(&book)
+            .map_err(|master_err| master_err.with_category(ErrorCategory::MasterStructure))?;
+        if !self.force
+            && book.source_hash() == Some(loaded_source.source_hash.as_str())
+            && book.settings_hash() == Some(settings_hash.as_str())
+        {
+            return Ok(None);
         }
-        Ok((book, master_update))
+        Ok(Some((book, settings_hash)))
     }
     fn print_summary_rows<'row>(
         &mut self,
@@ -216,13 +598,16 @@ impl UpdateRun<'_> {
         changes: &[ChangeRow<'source>],
         added: &[&'source SourceRecord],
         deleted: &[StoreRow],
+        source_hash: &str,
     ) -> Result<()> {
         write_line(self.out, format_args!("\n==== 현행화 요약 ===="))?;
         write_line(
             self.out,
             format_args!("- 파일: {}", self.master_path.display()),
         )?;
-        write_line(self.out, format_args!("- 소스: Opinet 자동 다운로드"))?;
+        let source_label = self.source_label();
+        write_line(self.out, format_args!("- 소스: {source_label}"))?;
+        write_line(self.out, format_args!("- 소스 해시: {source_hash}"))?;
         write_line(
             self.out,
             format_args!("- 기존 업체 변경: {}건", changes.len()),
@@ -235,11 +620,10 @@ impl UpdateRun<'_> {
             self.out,
             format_args!("- 폐업 업체 삭제: {}건", deleted.len()),
         )?;
-        let verification_state = match self.save_verification {
-            SaveVerification::Verify => "사용",
-            SaveVerification::Skip => "생략",
-        };
-        write_line(self.out, format_args!("- 저장 검증: {verification_state}"))?;
+        match self.save_verification {
+            SaveVerification::Verify => write_line(self.out, format_args!("- 저장 검증: 사용"))?,
+            SaveVerification::Skip => write_line(self.out, format_args!("- 저장 검증: 생략"))?,
+        }
         self.print_summary_rows(
             "신규 업체 추가 목록 (상위 20개)",
             added
@@ -259,56 +643,24 @@ impl UpdateRun<'_> {
         write_line(self.out, format_args!("=====================\n"))?;
         Ok(())
     }
-    pub(super) fn run(&mut self) -> Result<()> {
-        let loaded_source = self.load_source()?;
-        let (book, master_update) = self.open_updated_workbook(&loaded_source)?;
-        let since_epoch = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map_err(|source| err_with_source("현재 시간 조회 실패", source))?;
-        let kst = since_epoch
-            .checked_add(KST_OFFSET)
-            .ok_or_else(|| err("KST 날짜 초 계산 중 범위 오류가 발생했습니다."))?;
-        let shifted_days = kst
-            .as_secs()
-            .div_euclid(SECS_PER_DAY_U64)
-            .strict_add(719_468);
-        let era = shifted_days.div_euclid(146_097);
-        let day_of_era = shifted_days.rem_euclid(146_097);
-        let year_of_era = day_of_era
-            .strict_sub(day_of_era.div_euclid(1_460))
-            .strict_add(day_of_era.div_euclid(36_524))
-            .strict_sub(day_of_era.div_euclid(146_096))
-            .div_euclid(365);
-        let year_base = year_of_era.strict_add(era.strict_mul(400));
-        let day_of_year = day_of_era.strict_sub(
-            365_u64
-                .strict_mul(year_of_era)
-                .strict_add(year_of_era.div_euclid(4))
-                .strict_sub(year_of_era.div_euclid(100)),
-        );
-        let march_month = 5_u64.strict_mul(day_of_year).strict_add(2).div_euclid(153);
-        let day = day_of_year
-            .strict_sub(153_u64.strict_mul(march_month).strict_add(2).div_euclid(5))
-            .strict_add(1);
-        let month = if march_month < 10 {
-            march_month.strict_add(3)
-        } else {
-            march_month.strict_sub(9)
-        };
-        let year = if month <= 2 {
-            year_base.strict_add(1)
-        } else {
-            year_base
-        };
-        let today = format!("{year:04}-{month:02}-{day:02}");
-        self.save_workbook_with_change_log(&master_update, book, &today)
+    pub(super) fn run(&mut self) -> Result<UpdateOutcome> {
+        let result = self
+            .load_source()
+            .and_then(|loaded_source| self.apply(&loaded_source));
+        if let Some(webhook) = self.webhook.as_ref() {
+            webhook.notify(self.master_path, result.as_ref(), self.source_label());
+        }
+        result
     }
     fn save_workbook_with_change_log(
         &mut self,
         master_update: &MasterSheetUpdateResult<'_>,
         mut book: StdWorkbook,
+        kst: KstTime,
         today: &str,
+        run_hashes: (&str, &str),
     ) -> Result<()> {
+        let change_log_stage = LogStage::start("변경내역 갱신");
         let (worksheet, shared_string_table) = book.change_log_sheet_mut();
         ChangeLogUpdater {
             added: &master_update.added,
@@ -318,13 +670,70 @@ impl UpdateRun<'_> {
             today,
             worksheet,
         }
-        .update()?;
-        write_line(self.out, format_args!("마스터 파일 저장 중..."))?;
-        book.save(self.master_path, self.save_verification)?;
+        .update()
+        .map_err(|change_log_err| change_log_err.with_category(ErrorCategory::MasterStructure))?;
+        change_log_stage.finish();
+        let unchanged = master_update.changes.is_empty()
+            && master_update.added.is_empty()
+            && master_update.deleted.is_empty();
+        if unchanged && self.backups.keep != 0 {
+            logging::info(format_args!(
+                "바뀐 업체가 없어 이전 버전 백업을 생략합니다."
+            ));
+        }
+        let displaced_backup = if self.backups.keep == 0 || unchanged {
+            None
+        } else {
+            Some(
+                self.backups
+                    .reserve_path(
+                        kst,
+                        &format!(
+                            "변경{}_신규{}_폐업{}",
+                            master_update.changes.len(),
+                            master_update.added.len(),
+                            master_update.deleted.len()
+                        ),
+                    )
+                    .map_err(|backup_err| backup_err.with_category(ErrorCategory::Save))?,
+            )
+        };
+        logging::info(format_args!("마스터 파일 저장 중..."));
+        let save_stage = LogStage::start("마스터 저장");
+        book.save(
+            self.master_path,
+            self.save_verification,
+            run_hashes,
+            displaced_backup.as_deref(),
+            |saved| {
+                if saved.source_hash() != Some(run_hashes.0) {
+                    return Err(err("저장된 워크북의 소스 해시가 현행화 소스와 다릅니다."));
+                }
+                if saved.settings_hash() != Some(run_hashes.1) {
+                    return Err(err("저장된 워크북의 설정 해시가 현행화 설정과 다릅니다."));
+                }
+                master_update.verify_saved(saved)?;
+                let (saved_worksheet, saved_strings) = saved.change_log_sheet();
+                SavedChangeLog {
+                    shared_strings: saved_strings,
+                    worksheet: saved_worksheet,
+                }
+                .verify_entry_count(
+                    master_update
+                        .changes
+                        .len()
+                        .strict_add(master_update.added.len())
+                        .strict_add(master_update.deleted.len()),
+                )
+            },
+        )
+        .map_err(|save_err| save_err.with_category(ErrorCategory::Save))?;
+        save_stage.finish();
         self.print_update_summary(
             &master_update.changes,
             &master_update.added,
             &master_update.deleted,
+            run_hashes.0,
         )
         .map_err(|source| {
             err_with_source(
@@ -332,6 +741,35 @@ impl UpdateRun<'_> {
                 source,
             )
         })?;
+        if let Some(backup_path) = displaced_backup {
+            logging::info(format_args!(
+                "이전 버전 백업: {}",
+                terminal_safe(&backup_path.display())
+            ));
+            let expired = self.backups.prune().map_err(|source| {
+                err_with_source(
+                    "마스터 파일은 저장됐지만 오래된 백업 정리에 실패했습니다",
+                    source,
+                )
+                .with_category(ErrorCategory::Save)
+            })?;
+            if !expired.is_empty() {
+                logging::info(format_args!(
+                    "보관 개수({})를 넘은 오래된 백업 {}건 삭제",
+                    self.backups.keep,
+                    expired.len()
+                ));
+            }
+        }
         Ok(())
     }
+    const fn source_label(&self) -> &'static str {
+        if self.open_api_key.is_some() {
+            OPEN_API_SOURCE_LABEL
+        } else if self.source_path.is_some() {
+            SOURCE_FILE_LABEL
+        } else {
+            DOWNLOAD_SOURCE_LABEL
+        }
+    }
 }
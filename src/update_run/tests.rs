@@ -0,0 +1,178 @@
+use super::LoadedSource;
+use crate::{
+    diagnostic::{ErrorCategory, Result, ensure, err},
+    excel::{FuelValues, SourceRecord, StationService, writer::Workbook as StdWorkbook},
+    master_sheet::{MAP_URL_DEFAULT, MasterStations, UpdateSettings},
+    region::{
+        TARGET_REGION_COUNT, TargetRegion, TargetRegionPolicy, increment_target_region_count,
+        target_region,
+    },
+};
+use std::{collections::HashMap, path::Path};
+const UNKNOWN_ADDRESS_KEY: &str = "대전유성구없는로1";
+#[test]
+fn open_api_records_keep_master_identity_and_skip_unknown_stations() -> Result<()> {
+    let book = StdWorkbook::open(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("fuel_cost_chungcheong.xlsx"),
+    )?;
+    let master = MasterStations::try_from(&book)?;
+    let mut api_source = LoadedSource {
+        index: HashMap::new(),
+        prices_only: true,
+        region_counts: [0; TARGET_REGION_COUNT],
+        source_hash: String::from("open-api-hash"),
+    };
+    let mut scratch = String::new();
+    for station in &master.stations {
+        if let Some(region) = target_region(
+            &station.region,
+            &station.address,
+            &mut scratch,
+            TargetRegionPolicy::Flexible,
+        )? && !api_source.index.contains_key(&station.address_key)
+        {
+            api_source.index.insert(
+                station.address_key.clone(),
+                SourceRecord {
+                    address: String::from("API 주소"),
+                    brand: String::from("API 상표"),
+                    fuels: FuelValues {
+                        diesel: Some(1_500_i32),
+                        gasoline: Some(1_600_i32),
+                        premium: None,
+                    },
+                    name: String::from("API 상호"),
+                    region: region.label(),
+                    service: StationService::SelfService,
+                },
+            );
+            increment_target_region_count(&mut api_source.region_counts, region);
+        }
+    }
+    let matched_count = api_source.index.len();
+    api_source.index.insert(
+        String::from(UNKNOWN_ADDRESS_KEY),
+        SourceRecord {
+            address: String::from("대전 유성구 없는로 1"),
+            brand: String::from("API 상표"),
+            fuels: FuelValues::default(),
+            name: String::from("없는주유소"),
+            region: TargetRegion::Daejeon.label(),
+            service: StationService::SelfService,
+        },
+    );
+    increment_target_region_count(&mut api_source.region_counts, TargetRegion::Daejeon);
+    let merged = LoadedSource::try_from((&api_source, &book))?;
+    ensure(merged.prices_only, "merged source stays prices-only")?;
+    ensure(merged.source_hash == "open-api-hash", "source hash is kept")?;
+    ensure(
+        merged.index.len() == matched_count,
+        "only master stations are kept",
+    )?;
+    ensure(
+        !merged.index.contains_key(UNKNOWN_ADDRESS_KEY),
+        "stations missing from the master are not added",
+    )?;
+    for station in &master.stations {
+        if let Some(record) = merged.index.get(&station.address_key) {
+            ensure(record.address == station.address, "master address is kept")?;
+            ensure(record.brand == station.brand, "master brand is kept")?;
+            ensure(record.name == station.name, "master name is kept")?;
+            ensure(
+                record.fuels.diesel == Some(1_500_i32),
+                "prices come from the API",
+            )?;
+            ensure(
+                matches!(record.service, StationService::Unknown),
+                "self-service flag is left untouched",
+            )?;
+        }
+    }
+    Ok(())
+}
+#[test]
+fn open_api_results_without_master_matches_are_rejected() -> Result<()> {
+    let book = StdWorkbook::open(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("fuel_cost_chungcheong.xlsx"),
+    )?;
+    let mut api_source = LoadedSource {
+        index: HashMap::new(),
+        prices_only: true,
+        region_counts: [0; TARGET_REGION_COUNT],
+        source_hash: String::from("open-api-hash"),
+    };
+    api_source.index.insert(
+        String::from(UNKNOWN_ADDRESS_KEY),
+        SourceRecord {
+            address: String::from("대전 유성구 없는로 1"),
+            brand: String::from("API 상표"),
+            fuels: FuelValues::default(),
+            name: String::from("없는주유소"),
+            region: TargetRegion::Daejeon.label(),
+            service: StationService::SelfService,
+        },
+    );
+    increment_target_region_count(&mut api_source.region_counts, TargetRegion::Daejeon);
+    let Err(error) = LoadedSource::try_from((&api_source, &book)) else {
+        return Err(err("unmatched open api results must be rejected"));
+    };
+    ensure(
+        error.category() == ErrorCategory::RegionValidation,
+        "region validation category",
+    )
+}
+#[test]
+fn settings_hash_covers_rank_mode_and_depot_inputs() -> Result<()> {
+    let mut book = StdWorkbook::open(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("fuel_cost_chungcheong.xlsx"),
+    )?;
+    let by_price = UpdateSettings {
+        detour_ranking: false,
+        geo_table: None,
+        map_url: None,
+    };
+    let by_detour = UpdateSettings {
+        detour_ranking: true,
+        geo_table: None,
+        map_url: None,
+    };
+    let price_hash = by_price.hash(&book)?;
+    ensure(
+        price_hash == by_price.hash(&book)?,
+        "settings hash is stable",
+    )?;
+    ensure(
+        by_detour.hash(&book)? != price_hash,
+        "rank mode changes the settings hash",
+    )?;
+    let with_map_links = UpdateSettings {
+        detour_ranking: false,
+        geo_table: None,
+        map_url: Some(MAP_URL_DEFAULT),
+    };
+    let map_hash = with_map_links.hash(&book)?;
+    ensure(map_hash != price_hash, "map links change the settings hash")?;
+    ensure(
+        UpdateSettings {
+            detour_ranking: false,
+            geo_table: None,
+            map_url: Some("https://map.kakao.com/link/search/{name}"),
+        }
+        .hash(&book)?
+            != map_hash,
+        "the map URL template changes the settings hash",
+    )?;
+    let (worksheet, shared_strings) = book.master_sheet_mut();
+    shared_strings.set_cell(worksheet, 7, 13, "99.9")?;
+    let efficiency_hash = by_price.hash(&book)?;
+    ensure(
+        efficiency_hash != price_hash,
+        "fuel efficiency (G13) changes the settings hash",
+    )?;
+    let (depot_worksheet, depot_strings) = book.master_sheet_mut();
+    depot_strings.set_cell(depot_worksheet, 6, 4, "테스트 출발지")?;
+    ensure(
+        by_price.hash(&book)? != efficiency_hash,
+        "depot inputs (F4:H12) change the settings hash",
+    )
+}
@@ -0,0 +1,346 @@
+use crate::{
+    MASTER_PATH,
+    diagnostic::{AppError, ErrorReport, Result, append_fmt, err_with_source},
+    i18n::Lang,
+    json::append_json_string,
+    kst::KstTime,
+    logging::{self, LogStage},
+    master_sheet::MasterSheetUpdateResult,
+    region::TARGET_REGIONS,
+    source_download::{HttpUrl, SourceDownload},
+    update_run::{DOWNLOAD_SOURCE_LABEL, OPEN_API_SOURCE_LABEL, SOURCE_FILE_LABEL, UpdateOutcome},
+};
+use core::{
+    cmp::Reverse,
+    fmt::{self, Display},
+    result::Result as CoreResult,
+};
+use std::{
+    ffi::{OsStr, OsString},
+    path::Path,
+};
+#[cfg(test)]
+mod tests;
+pub(super) const WEBHOOK_DEFAULT_TOP: usize = 5;
+pub(super) const WEBHOOK_MAX_TOP: usize = 50;
+const EARLY_WEBHOOK_SKIPPED_COMMANDS: [&str; 4] = ["diff", "export", "restore", "serve-api"];
+pub(super) struct EarlyWebhook {
+    pub lang: Lang,
+    pub source_kind: &'static str,
+    pub url: Option<String>,
+}
+pub(super) struct PriceMove {
+    pub address: String,
+    pub delta: i32,
+    pub fuel: &'static str,
+    pub name: String,
+    pub new_price: i32,
+    pub old_price: i32,
+}
+struct PriceMovesJson<'moves>(&'moves [PriceMove]);
+pub(super) struct RegionPriceMoves {
+    pub drops: Vec<PriceMove>,
+    pub region: &'static str,
+    pub rises: Vec<PriceMove>,
+}
+pub(super) struct RunSummary {
+    pub added: usize,
+    pub changed: usize,
+    pub deleted: usize,
+    pub regions: Vec<RegionPriceMoves>,
+}
+pub(super) struct WebhookPayload<'run> {
+    pub at: &'run str,
+    pub lang: Lang,
+    pub master_path: &'run str,
+    pub result: CoreResult<&'run UpdateOutcome, &'run AppError>,
+    pub source_kind: &'run str,
+}
+pub(super) struct WebhookTarget<'url> {
+    lang: Lang,
+    url: HttpUrl<'url>,
+}
+impl EarlyWebhook {
+    pub(super) fn notify(&self, run_err: &AppError) {
+        if let Some(Ok(target)) = self
+            .url
+            .as_deref()
+            .map(|url| WebhookTarget::try_from((url, self.lang)))
+        {
+            target.notify(Path::new(MASTER_PATH), Err(run_err), self.source_kind);
+        }
+    }
+}
+impl WebhookTarget<'_> {
+    pub(super) fn notify(
+        &self,
+        master_path: &Path,
+        result: CoreResult<&UpdateOutcome, &AppError>,
+        source_kind: &str,
+    ) {
+        let webhook_stage = LogStage::start("웹훅 전송");
+        let sent = KstTime::now().and_then(|kst| {
+            let payload = WebhookPayload {
+                at: &kst.timestamp(),
+                lang: self.lang,
+                master_path: &master_path.display().to_string(),
+                result,
+                source_kind,
+            }
+            .to_string();
+            SourceDownload::default()
+                .post_json(self.url, payload.as_bytes())
+                .map_err(|source| err_with_source("웹훅 POST 요청 실패", source))
+        });
+        match sent {
+            Ok(()) => {
+                webhook_stage.finish();
+                logging::info(format_args!("웹훅 전송 완료"));
+            }
+            Err(send_err) => logging::warn(format_args!("웹훅 전송 실패: {send_err}")),
+        }
+    }
+}
+impl Display for PriceMovesJson<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut json = String::from("[");
+        for (index, price_move) in self.0.iter().enumerate() {
+            if index != 0 {
+                json.push(',');
+            }
+            json.push_str("{\"name\":");
+            append_json_string(&mut json, &price_move.name);
+            json.push_str(",\"address\":");
+            append_json_string(&mut json, &price_move.address);
+            append_fmt(
+                &mut json,
+                format_args!(
+                    ",\"fuel\":\"{}\",\"old\":{},\"new\":{},\"delta\":{}}}",
+                    price_move.fuel, price_move.old_price, price_move.new_price, price_move.delta
+                ),
+            );
+        }
+        json.push(']');
+        f.write_str(&json)
+    }
+}
+impl Display for WebhookPayload<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fuel_label = |fuel: &str| match fuel {
+            "gasoline" => "휘발유",
+            "premium" => "고급휘발유",
+            _ => "경유",
+        };
+        let mut text = Vec::new();
+        let status = match self.result.map(|outcome| outcome.summary.as_ref()) {
+            Ok(Some(summary)) => {
+                text.push(format!(
+                    "fcupdater 현행화 완료: 변경 {}건, 신규 {}건, 폐업 {}건",
+                    summary.changed, summary.added, summary.deleted
+                ));
+                for region in &summary.regions {
+                    if let Some(moved) = region.drops.first() {
+                        text.push(format!(
+                            "{} 최대 인하: {} {} {}→{} ({:+})",
+                            region.region,
+                            moved.name,
+                            fuel_label(moved.fuel),
+                            moved.old_price,
+                            moved.new_price,
+                            moved.delta
+                        ));
+                    }
+                    if let Some(moved) = region.rises.first() {
+                        text.push(format!(
+                            "{} 최대 인상: {} {} {}→{} ({:+})",
+                            region.region,
+                            moved.name,
+                            fuel_label(moved.fuel),
+                            moved.old_price,
+                            moved.new_price,
+                            moved.delta
+                        ));
+                    }
+                }
+                "updated"
+            }
+            Ok(None) => {
+                text.push(String::from(
+                    "fcupdater 소스 변경 없음: 워크북을 수정하지 않았습니다.",
+                ));
+                "unchanged"
+            }
+            Err(run_err) => {
+                text.push(format!("fcupdater 현행화 실패: {run_err}"));
+                "failed"
+            }
+        };
+        let mut json = String::from("{\"text\":");
+        let translated: Vec<_> = text.iter().map(|line| self.lang.translate(line)).collect();
+        append_json_string(&mut json, &translated.join("\n"));
+        append_fmt(&mut json, format_args!(",\"status\":\"{status}\",\"at\":"));
+        append_json_string(&mut json, self.at);
+        json.push_str(",\"master\":");
+        append_json_string(&mut json, self.master_path);
+        json.push_str(",\"source\":");
+        append_json_string(&mut json, self.source_kind);
+        json.push_str(",\"source_hash\":");
+        match self.result {
+            Ok(outcome) => append_json_string(&mut json, &outcome.source_hash),
+            Err(_) => json.push_str("null"),
+        }
+        json.push_str(",\"summary\":");
+        match self
+            .result
+            .ok()
+            .and_then(|outcome| outcome.summary.as_ref())
+        {
+            Some(summary) => {
+                append_fmt(
+                    &mut json,
+                    format_args!(
+                        "{{\"changed\":{},\"added\":{},\"deleted\":{}}},\"price_moves\":[",
+                        summary.changed, summary.added, summary.deleted
+                    ),
+                );
+                for (index, region) in summary.regions.iter().enumerate() {
+                    if index != 0 {
+                        json.push(',');
+                    }
+                    json.push_str("{\"region\":");
+                    append_json_string(&mut json, region.region);
+                    append_fmt(
+                        &mut json,
+                        format_args!(
+                            ",\"drops\":{},\"rises\":{}}}",
+                            PriceMovesJson(&region.drops),
+                            PriceMovesJson(&region.rises)
+                        ),
+                    );
+                }
+                json.push(']');
+            }
+            None => json.push_str("null,\"price_moves\":[]"),
+        }
+        json.push_str(",\"error\":");
+        if let Err(run_err) = self.result {
+            append_fmt(
+                &mut json,
+                format_args!(
+                    "{}",
+                    ErrorReport {
+                        error: run_err,
+                        lang: self.lang
+                    }
+                ),
+            );
+        } else {
+            json.push_str("null");
+        }
+        json.push('}');
+        f.write_str(&json)
+    }
+}
+impl From<(&[OsString], Lang)> for EarlyWebhook {
+    fn from((args, lang): (&[OsString], Lang)) -> Self {
+        let has_flag = |flag: &str| args.iter().any(|arg| arg == OsStr::new(flag));
+        let source_kind = if has_flag("--source-api") {
+            OPEN_API_SOURCE_LABEL
+        } else if has_flag("--source-file") {
+            SOURCE_FILE_LABEL
+        } else {
+            DOWNLOAD_SOURCE_LABEL
+        };
+        let url = args
+            .first()
+            .filter(|first| {
+                !EARLY_WEBHOOK_SKIPPED_COMMANDS
+                    .iter()
+                    .any(|command| *first == OsStr::new(command))
+            })
+            .and_then(|_| {
+                args.windows(2).find(|pair| {
+                    pair.first()
+                        .is_some_and(|flag| flag == OsStr::new("--webhook"))
+                })
+            })
+            .and_then(|pair| pair.last()?.to_str())
+            .map(str::to_owned);
+        Self {
+            lang,
+            source_kind,
+            url,
+        }
+    }
+}
+impl From<(&MasterSheetUpdateResult<'_>, usize)> for RunSummary {
+    fn from((master_update, top): (&MasterSheetUpdateResult<'_>, usize)) -> Self {
+        let mut regions = Vec::new();
+        for target in TARGET_REGIONS {
+            let region = target.label();
+            let mut moves = Vec::new();
+            for change in master_update
+                .changes
+                .iter()
+                .filter(|change| change.record.region == region)
+            {
+                for (fuel, old_price, new_price) in [
+                    (
+                        "gasoline",
+                        change.old_fuels.gasoline,
+                        change.record.fuels.gasoline,
+                    ),
+                    (
+                        "premium",
+                        change.old_fuels.premium,
+                        change.record.fuels.premium,
+                    ),
+                    (
+                        "diesel",
+                        change.old_fuels.diesel,
+                        change.record.fuels.diesel,
+                    ),
+                ] {
+                    if let (Some(old), Some(new)) = (old_price, new_price)
+                        && old != new
+                    {
+                        moves.push(PriceMove {
+                            address: change.record.address.clone(),
+                            delta: new.strict_sub(old),
+                            fuel,
+                            name: change.record.name.clone(),
+                            new_price: new,
+                            old_price: old,
+                        });
+                    }
+                }
+            }
+            let (mut drops, mut rises): (Vec<_>, Vec<_>) = moves
+                .into_iter()
+                .partition(|price_move| price_move.delta < 0_i32);
+            drops.sort_by_key(|price_move| price_move.delta);
+            drops.truncate(top);
+            rises.sort_by_key(|price_move| Reverse(price_move.delta));
+            rises.truncate(top);
+            regions.push(RegionPriceMoves {
+                drops,
+                region,
+                rises,
+            });
+        }
+        Self {
+            added: master_update.added.len(),
+            changed: master_update.changes.len(),
+            deleted: master_update.deleted.len(),
+            regions,
+        }
+    }
+}
+impl<'url> TryFrom<(&'url str, Lang)> for WebhookTarget<'url> {
+    type Error = AppError;
+    fn try_from((text, lang): (&'url str, Lang)) -> Result<Self> {
+        HttpUrl::try_from(text)
+            .map(|url| Self { lang, url })
+            .map_err(|source| err_with_source("웹훅 URL 확인 실패", source))
+    }
+}
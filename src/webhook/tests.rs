@@ -0,0 +1,281 @@
+use super::{EarlyWebhook, PriceMove, RegionPriceMoves, RunSummary, WebhookTarget};
+use crate::{
+    diagnostic::{ErrorCategory, Result, ensure, err, err_with_source},
+    i18n::Lang,
+    json::JsonValue,
+    source_download::{HttpUrl, SourceDownload},
+    stand_in::{StandInRequest, StandInServer},
+    update_run::UpdateOutcome,
+};
+use std::{ffi::OsString, path::Path};
+fn accepting_server() -> Result<StandInServer> {
+    StandInServer::start(|_: &StandInRequest| (200, String::from("{\"ok\":true}")))
+}
+fn delivered(server: &StandInServer) -> Result<StandInRequest> {
+    let mut requests = server.requests();
+    ensure(
+        requests.len() == 1,
+        format!("exactly one webhook request, got {}", requests.len()),
+    )?;
+    let request = requests.pop().ok_or("missing webhook request")?;
+    ensure(
+        request.method == "POST",
+        format!("webhooks are POSTed: {}", request.method),
+    )?;
+    ensure(
+        request.target == "/hooks/fcupdater",
+        format!("webhook path: {}", request.target),
+    )?;
+    Ok(request)
+}
+fn text<'json>(document: &'json JsonValue<'_>, key: &str) -> &'json str {
+    document
+        .get(key)
+        .and_then(JsonValue::as_text)
+        .unwrap_or_default()
+}
+#[test]
+fn updated_run_payload_carries_summary_and_price_moves() -> Result<()> {
+    let server = accepting_server()?;
+    let url = format!("{}/hooks/fcupdater", server.base_url);
+    let outcome = UpdateOutcome {
+        source_hash: String::from("abc123"),
+        summary: Some(RunSummary {
+            added: 1,
+            changed: 2,
+            deleted: 0,
+            regions: vec![RegionPriceMoves {
+                drops: vec![PriceMove {
+                    address: String::from("충남 천안시 동남구 만남로 1"),
+                    delta: -30,
+                    fuel: "diesel",
+                    name: String::from("천안\"주유소\""),
+                    new_price: 1_520,
+                    old_price: 1_550,
+                }],
+                region: "천안",
+                rises: Vec::new(),
+            }],
+        }),
+        updated: true,
+    };
+    WebhookTarget::try_from((url.as_str(), Lang::Ko))?.notify(
+        Path::new("master.xlsx"),
+        Ok(&outcome),
+        "소스 파일",
+    );
+    let request = delivered(&server)?;
+    let payload = JsonValue::try_from(request.body.as_str())?;
+    ensure(
+        [
+            text(&payload, "status"),
+            text(&payload, "master"),
+            text(&payload, "source"),
+            text(&payload, "source_hash"),
+        ] == ["updated", "master.xlsx", "소스 파일", "abc123"],
+        format!(
+            "status, master path, source kind and hash: {}",
+            request.body
+        ),
+    )?;
+    ensure(
+        text(&payload, "text").starts_with("fcupdater 현행화 완료: 변경 2건, 신규 1건, 폐업 0건"),
+        format!("summary text: {}", request.body),
+    )?;
+    let summary = payload.get("summary").ok_or("missing summary object")?;
+    ensure(
+        ["changed", "added", "deleted"].map(|key| text(summary, key)) == ["2", "1", "0"],
+        format!("summary counts: {}", request.body),
+    )?;
+    let region = payload
+        .get("price_moves")
+        .and_then(JsonValue::as_array)
+        .and_then(<[JsonValue<'_>]>::first)
+        .ok_or("missing price_moves region")?;
+    ensure(
+        text(region, "region") == "천안",
+        format!("region label: {}", request.body),
+    )?;
+    let drop = region
+        .get("drops")
+        .and_then(JsonValue::as_array)
+        .and_then(<[JsonValue<'_>]>::first)
+        .ok_or("missing price drop")?;
+    ensure(
+        ["name", "fuel", "old", "new", "delta"].map(|key| text(drop, key))
+            == ["천안\"주유소\"", "diesel", "1550", "1520", "-30"],
+        format!("price drop fields: {}", request.body),
+    )?;
+    ensure(
+        request.body.ends_with(",\"error\":null}"),
+        format!("no error on success: {}", request.body),
+    )
+}
+#[test]
+fn failed_run_payload_carries_error_chain() -> Result<()> {
+    let server = accepting_server()?;
+    let url = format!("{}/hooks/fcupdater", server.base_url);
+    let run_err = err_with_source("Opinet 자동 다운로드 실패", err("HTTP 503: busy"))
+        .with_category(ErrorCategory::Download);
+    WebhookTarget::try_from((url.as_str(), Lang::Ko))?.notify(
+        Path::new("master.xlsx"),
+        Err(&run_err),
+        "Opinet 자동 다운로드",
+    );
+    let request = delivered(&server)?;
+    let payload = JsonValue::try_from(request.body.as_str())?;
+    ensure(
+        text(&payload, "status") == "failed",
+        format!("status: {}", request.body),
+    )?;
+    ensure(
+        text(&payload, "text")
+            .starts_with("fcupdater 현행화 실패: Opinet 자동 다운로드 실패: HTTP 503: busy"),
+        format!("failure text: {}", request.body),
+    )?;
+    ensure(
+        request.body.contains("\"source_hash\":null")
+            && request.body.contains("\"summary\":null,\"price_moves\":[]"),
+        format!("no summary on failure: {}", request.body),
+    )?;
+    let error = payload.get("error").ok_or("missing error object")?;
+    ensure(
+        [
+            text(error, "category"),
+            text(error, "exit_code"),
+            text(error, "message"),
+        ] == [
+            "download",
+            "10",
+            "Opinet 자동 다운로드 실패: HTTP 503: busy",
+        ],
+        format!("error category, exit code and message: {}", request.body),
+    )?;
+    let chain: Vec<&str> = error
+        .get("chain")
+        .and_then(JsonValue::as_array)
+        .ok_or("missing error chain")?
+        .iter()
+        .filter_map(JsonValue::as_text)
+        .collect();
+    ensure(
+        chain == ["Opinet 자동 다운로드 실패", "HTTP 503: busy"],
+        format!("error chain: {}", request.body),
+    )
+}
+#[test]
+fn english_payload_translates_messages_but_keeps_data() -> Result<()> {
+    let server = accepting_server()?;
+    let url = format!("{}/hooks/fcupdater", server.base_url);
+    let outcome = UpdateOutcome {
+        source_hash: String::from("abc123"),
+        summary: Some(RunSummary {
+            added: 0,
+            changed: 1,
+            deleted: 0,
+            regions: vec![RegionPriceMoves {
+                drops: Vec::new(),
+                region: "청주",
+                rises: vec![PriceMove {
+                    address: String::from("충북 청주시 상당구 상당로 1"),
+                    delta: 20,
+                    fuel: "diesel",
+                    name: String::from("청주주유소"),
+                    new_price: 1_570,
+                    old_price: 1_550,
+                }],
+            }],
+        }),
+        updated: true,
+    };
+    WebhookTarget::try_from((url.as_str(), Lang::En))?.notify(
+        Path::new("master.xlsx"),
+        Ok(&outcome),
+        "소스 파일",
+    );
+    let payload_body = delivered(&server)?.body;
+    let payload = JsonValue::try_from(payload_body.as_str())?;
+    ensure(
+        text(&payload, "text")
+            == "fcupdater update finished: 1 changed, 0 added, 0 closed\n청주 largest raise: 청주주유소 경유 1550→1570 (+20)",
+        format!("summary text: {payload_body}"),
+    )?;
+    ensure(
+        text(&payload, "source") == "소스 파일",
+        format!("source kind: {payload_body}"),
+    )?;
+    let run_err =
+        err("Opinet 자동 다운로드 실패: HTTP 503: busy").with_category(ErrorCategory::Download);
+    WebhookTarget::try_from((url.as_str(), Lang::En))?.notify(
+        Path::new("master.xlsx"),
+        Err(&run_err),
+        "소스 파일",
+    );
+    let failure_body = delivered(&server)?.body;
+    let failure = JsonValue::try_from(failure_body.as_str())?;
+    ensure(
+        text(&failure, "text")
+            == "fcupdater update failed: Opinet automatic download failed: HTTP 503: busy",
+        format!("failure text: {failure_body}"),
+    )?;
+    ensure(
+        text(
+            failure.get("error").ok_or("missing error object")?,
+            "message",
+        ) == "Opinet automatic download failed: HTTP 503: busy",
+        format!("error message: {failure_body}"),
+    )
+}
+#[test]
+fn early_failures_are_reported_for_update_commands_only() -> Result<()> {
+    let server = accepting_server()?;
+    let url = format!("{}/hooks/fcupdater", server.base_url);
+    let args = |list: &[&str]| -> Vec<OsString> { list.iter().map(OsString::from).collect() };
+    let run_err =
+        err("다른 fcupdater 실행이 진행 중입니다.").with_category(ErrorCategory::LockHeld);
+    let early = EarlyWebhook::from((
+        args(&["--source-api", "--webhook", &url]).as_slice(),
+        Lang::Ko,
+    ));
+    early.notify(&run_err);
+    let request = delivered(&server)?;
+    let payload = JsonValue::try_from(request.body.as_str())?;
+    ensure(
+        [text(&payload, "status"), text(&payload, "source")] == ["failed", "Opinet Open API"],
+        format!("status and source kind: {}", request.body),
+    )?;
+    let error = payload.get("error").ok_or("missing error object")?;
+    ensure(
+        text(error, "category") == "lock_held",
+        format!("error category: {}", request.body),
+    )?;
+    for command in ["diff", "export", "restore", "serve-api"] {
+        let skipped =
+            EarlyWebhook::from((args(&[command, "--webhook", &url]).as_slice(), Lang::Ko));
+        ensure(
+            skipped.url.is_none(),
+            format!("{command} has no webhook option"),
+        )?;
+    }
+    let serve = EarlyWebhook::from((args(&["serve", "--webhook", &url]).as_slice(), Lang::Ko));
+    ensure(
+        serve.url.as_deref() == Some(url.as_str()),
+        "serve webhook url",
+    )
+}
+#[test]
+fn non_success_status_is_an_error() -> Result<()> {
+    let server = StandInServer::start(|_: &StandInRequest| (500, String::from("{\"ok\":false}")))?;
+    let url = format!("{}/hooks/fcupdater", server.base_url);
+    let Err(sent_err) =
+        SourceDownload::default().post_json(HttpUrl::try_from(url.as_str())?, b"{}")
+    else {
+        return Err(err("HTTP 500 must fail"));
+    };
+    let message = sent_err.to_string();
+    ensure(
+        message.contains("HTTP 500"),
+        format!("status in error: {message}"),
+    )?;
+    ensure(delivered(&server)?.body == "{}", "request body")
+}